@@ -0,0 +1,180 @@
+use anyhow::Context;
+use clap::Parser;
+use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    FuelClient,
+};
+use output::OutputFormat;
+use std::fmt::{
+    LowerHex,
+    Write,
+};
+
+pub mod balance;
+pub mod block;
+pub mod chain;
+pub mod coin;
+pub mod contract;
+pub mod gas_price;
+pub mod message;
+pub mod output;
+pub mod peers;
+pub mod transaction;
+pub mod wallet;
+
+#[derive(Debug, Parser)]
+pub enum Command {
+    #[clap(subcommand)]
+    Transaction(transaction::Command),
+    #[clap(subcommand)]
+    Block(block::Command),
+    #[clap(subcommand)]
+    Chain(chain::Command),
+    #[clap(subcommand)]
+    Coin(coin::Command),
+    #[clap(subcommand)]
+    Balance(balance::Command),
+    #[clap(subcommand)]
+    Message(message::Command),
+    #[clap(subcommand)]
+    Contract(contract::Command),
+    #[clap(subcommand)]
+    GasPrice(gas_price::Command),
+    #[clap(subcommand)]
+    Peers(peers::Command),
+}
+
+#[derive(Debug, Parser)]
+#[clap(name = "fuel-gql-cli", about = "Fuel GraphQL Endpoint CLI", version)]
+pub struct CliArgs {
+    #[clap(name = "endpoint", default_value = "127.0.0.1:4000", long = "endpoint")]
+    pub endpoint: String,
+    /// The format of the printed results.
+    #[clap(
+        long = "output",
+        short = 'o',
+        value_enum,
+        default_value_t = OutputFormat::Json,
+        global = true
+    )]
+    pub output: OutputFormat,
+    #[clap(subcommand)]
+    pub command: Command,
+}
+
+impl CliArgs {
+    pub async fn exec(self) -> anyhow::Result<()> {
+        let client = FuelClient::new(self.endpoint.as_str())
+            .with_context(|| format!("Invalid endpoint `{}`", self.endpoint))?;
+        let output = self.output;
+
+        match self.command {
+            Command::Transaction(command) => command.exec(&client, output).await,
+            Command::Block(command) => command.exec(&client, output).await,
+            Command::Chain(command) => command.exec(&client, output).await,
+            Command::Coin(command) => command.exec(&client, output).await,
+            Command::Balance(command) => command.exec(&client, output).await,
+            Command::Message(command) => command.exec(&client, output).await,
+            Command::Contract(command) => command.exec(&client, output).await,
+            Command::GasPrice(command) => command.exec(&client, output).await,
+            Command::Peers(command) => command.exec(&client, output).await,
+        }
+    }
+}
+
+/// The pagination arguments shared by the listing commands.
+#[derive(Debug, Clone, clap::Args)]
+pub struct PaginationArgs {
+    /// The number of results to fetch.
+    #[clap(long = "limit", default_value = "10")]
+    pub limit: i32,
+    /// The cursor returned by the previous page.
+    #[clap(long = "cursor")]
+    pub cursor: Option<String>,
+    /// Fetch the results in the backward direction.
+    #[clap(long = "backward")]
+    pub backward: bool,
+}
+
+impl PaginationArgs {
+    pub fn into_request(self) -> PaginationRequest<String> {
+        let direction = if self.backward {
+            PageDirection::Backward
+        } else {
+            PageDirection::Forward
+        };
+        PaginationRequest {
+            cursor: self.cursor,
+            results: self.limit,
+            direction,
+        }
+    }
+}
+
+/// Formats the value as the `0x` prefixed hex string, the same as the GraphQL API does.
+pub fn hex<T: LowerHex>(value: &T) -> String {
+    format!("{value:#x}")
+}
+
+/// Encodes the `bytes` as the `0x` prefixed hex string.
+pub fn hex_bytes(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::from("0x"), |mut encoded, byte| {
+        let _ = write!(encoded, "{byte:02x}");
+        encoded
+    })
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse__global_output_flag_after_subcommand() {
+        // Given
+        let args = ["fuel-core-client", "block", "latest", "--output", "table"];
+
+        // When
+        let cli = CliArgs::try_parse_from(args).unwrap();
+
+        // Then
+        assert_eq!(cli.output, OutputFormat::Table);
+        assert!(matches!(
+            cli.command,
+            Command::Block(block::Command::Latest)
+        ));
+    }
+
+    #[test]
+    fn parse__block_get_accepts_height_and_id() {
+        // Given
+        let by_height = ["fuel-core-client", "block", "get", "42"];
+        let by_id = [
+            "fuel-core-client",
+            "block",
+            "get",
+            "0x0000000000000000000000000000000000000000000000000000000000000001",
+        ];
+
+        // When
+        let by_height = CliArgs::try_parse_from(by_height).unwrap();
+        let by_id = CliArgs::try_parse_from(by_id).unwrap();
+
+        // Then
+        assert!(matches!(
+            by_height.command,
+            Command::Block(block::Command::Get {
+                block: block::BlockRef::Height(42)
+            })
+        ));
+        assert!(matches!(
+            by_id.command,
+            Command::Block(block::Command::Get {
+                block: block::BlockRef::Id(_)
+            })
+        ));
+    }
+}
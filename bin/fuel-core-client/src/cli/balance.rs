@@ -0,0 +1,61 @@
+use crate::cli::{
+    hex,
+    output::OutputFormat,
+    PaginationArgs,
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::fuel_tx::{
+    Address,
+    AssetId,
+};
+use serde_json::json;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// Get the balance of the owner, in the base asset if the asset is not specified
+    Get {
+        owner: Address,
+        #[clap(long = "asset-id")]
+        asset_id: Option<AssetId>,
+    },
+    /// List the balances of the owner in all assets
+    List {
+        owner: Address,
+        #[clap(flatten)]
+        pagination: PaginationArgs,
+    },
+}
+
+impl Command {
+    pub async fn exec(
+        self,
+        client: &FuelClient,
+        output: OutputFormat,
+    ) -> anyhow::Result<()> {
+        match self {
+            Command::Get { owner, asset_id } => {
+                let amount = client.balance(&owner, asset_id.as_ref()).await?;
+                output.print(&json!({
+                    "owner": hex(&owner),
+                    "asset_id": asset_id.as_ref().map(hex),
+                    "amount": amount,
+                }))
+            }
+            Command::List { owner, pagination } => {
+                let page = client.balances(&owner, pagination.into_request()).await?;
+                let results = page
+                    .results
+                    .iter()
+                    .map(|balance| {
+                        // The `u128` doesn't fit into the JSON number.
+                        json!({
+                            "asset_id": hex(&balance.asset_id),
+                            "amount": balance.amount.to_string(),
+                        })
+                    })
+                    .collect();
+                output.print_page(results, page.cursor, page.has_next_page)
+            }
+        }
+    }
+}
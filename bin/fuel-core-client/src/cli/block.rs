@@ -0,0 +1,114 @@
+use crate::cli::{
+    hex,
+    output::OutputFormat,
+    PaginationArgs,
+};
+use anyhow::Context;
+use fuel_core_client::client::{
+    types::{
+        block::Consensus,
+        Block,
+    },
+    FuelClient,
+};
+use fuel_core_types::fuel_tx::Bytes32;
+use serde_json::{
+    json,
+    Value,
+};
+use std::str::FromStr;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// Get the block by its height or id
+    Get { block: BlockRef },
+    /// Get the latest block of the chain
+    Latest,
+    /// List the blocks of the chain
+    List {
+        #[clap(flatten)]
+        pagination: PaginationArgs,
+    },
+}
+
+/// The block referenced either by the height or by the id.
+#[derive(Debug, Clone)]
+pub enum BlockRef {
+    Height(u32),
+    Id(Bytes32),
+}
+
+impl FromStr for BlockRef {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(height) = s.parse::<u32>() {
+            return Ok(BlockRef::Height(height))
+        }
+        Bytes32::from_str(s)
+            .map(BlockRef::Id)
+            .map_err(|_| format!("`{s}` is neither a block height nor a block id"))
+    }
+}
+
+impl core::fmt::Display for BlockRef {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BlockRef::Height(height) => write!(f, "at height {height}"),
+            BlockRef::Id(id) => write!(f, "{id:#x}"),
+        }
+    }
+}
+
+impl Command {
+    pub async fn exec(
+        self,
+        client: &FuelClient,
+        output: OutputFormat,
+    ) -> anyhow::Result<()> {
+        match self {
+            Command::Get { block } => {
+                let found = match &block {
+                    BlockRef::Height(height) => {
+                        client.block_by_height((*height).into()).await?
+                    }
+                    BlockRef::Id(id) => client.block(id).await?,
+                };
+                let block = found.with_context(|| format!("Block {block} not found"))?;
+                output.print(&block_to_json(&block))
+            }
+            Command::Latest => {
+                let chain = client.chain_info().await?;
+                output.print(&block_to_json(&chain.latest_block))
+            }
+            Command::List { pagination } => {
+                let page = client.blocks(pagination.into_request()).await?;
+                let results = page.results.iter().map(block_to_json).collect();
+                output.print_page(results, page.cursor, page.has_next_page)
+            }
+        }
+    }
+}
+
+pub fn block_to_json(block: &Block) -> Value {
+    let consensus = match &block.consensus {
+        Consensus::Genesis(_) => "genesis",
+        Consensus::PoAConsensus(_) => "poa",
+        Consensus::BftConsensus(_) => "bft",
+        Consensus::Unknown => "unknown",
+    };
+    json!({
+        "id": hex(&block.id),
+        "height": block.header.height,
+        "time": block.header.time.to_unix(),
+        "da_height": block.header.da_height,
+        "transactions_count": block.header.transactions_count,
+        "consensus": consensus,
+        "producer": block.block_producer.as_ref().map(hex),
+        "transactions": block
+            .transactions
+            .iter()
+            .map(hex)
+            .collect::<Vec<_>>(),
+    })
+}
@@ -0,0 +1,71 @@
+use crate::cli::{
+    hex,
+    output::OutputFormat,
+};
+use anyhow::Context;
+use fuel_core_client::client::FuelClient;
+use serde_json::json;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// Get the information about the chain
+    Info,
+    /// Get the information about the node
+    NodeInfo,
+    /// Check the health of the node
+    Health,
+    /// Get the consensus parameters of the particular version,
+    /// or the current ones if the version is not specified
+    ConsensusParameters {
+        #[clap(long = "version")]
+        version: Option<i32>,
+    },
+}
+
+impl Command {
+    pub async fn exec(
+        self,
+        client: &FuelClient,
+        output: OutputFormat,
+    ) -> anyhow::Result<()> {
+        match self {
+            Command::Info => {
+                let chain = client.chain_info().await?;
+                output.print(&json!({
+                    "name": chain.name,
+                    "chain_id": *chain.consensus_parameters.chain_id(),
+                    "base_asset_id": hex(chain.consensus_parameters.base_asset_id()),
+                    "da_height": chain.da_height,
+                    "latest_block_height": chain.latest_block.header.height,
+                    "latest_block_id": hex(&chain.latest_block.id),
+                }))
+            }
+            Command::NodeInfo => {
+                let node = client.node_info().await?;
+                output.print(&json!({
+                    "node_version": node.node_version,
+                    "utxo_validation": node.utxo_validation,
+                    "vm_backtrace": node.vm_backtrace,
+                    "max_tx": node.max_tx,
+                    "max_depth": node.max_depth,
+                }))
+            }
+            Command::Health => {
+                let healthy = client.health().await?;
+                output.print(&json!({ "healthy": healthy }))
+            }
+            Command::ConsensusParameters { version } => {
+                let parameters = match version {
+                    Some(version) => client
+                        .consensus_parameters(version)
+                        .await?
+                        .with_context(|| {
+                            format!("Consensus parameters of version {version} not found")
+                        })?,
+                    None => client.chain_info().await?.consensus_parameters,
+                };
+                output.print(&serde_json::to_value(parameters)?)
+            }
+        }
+    }
+}
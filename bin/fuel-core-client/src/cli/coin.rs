@@ -0,0 +1,74 @@
+use crate::cli::{
+    hex,
+    output::OutputFormat,
+    PaginationArgs,
+};
+use anyhow::Context;
+use fuel_core_client::client::{
+    types::Coin,
+    FuelClient,
+};
+use fuel_core_types::fuel_tx::{
+    Address,
+    AssetId,
+    UtxoId,
+};
+use serde_json::{
+    json,
+    Value,
+};
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// Get the coin by its UTXO id
+    Get { utxo_id: UtxoId },
+    /// List the coins of the owner
+    List {
+        owner: Address,
+        /// Only list the coins of this asset.
+        #[clap(long = "asset-id")]
+        asset_id: Option<AssetId>,
+        #[clap(flatten)]
+        pagination: PaginationArgs,
+    },
+}
+
+impl Command {
+    pub async fn exec(
+        self,
+        client: &FuelClient,
+        output: OutputFormat,
+    ) -> anyhow::Result<()> {
+        match self {
+            Command::Get { utxo_id } => {
+                let coin = client
+                    .coin(&utxo_id)
+                    .await?
+                    .with_context(|| format!("Coin {utxo_id:#x} not found"))?;
+                output.print(&coin_to_json(&coin))
+            }
+            Command::List {
+                owner,
+                asset_id,
+                pagination,
+            } => {
+                let page = client
+                    .coins(&owner, asset_id.as_ref(), pagination.into_request())
+                    .await?;
+                let results = page.results.iter().map(coin_to_json).collect();
+                output.print_page(results, page.cursor, page.has_next_page)
+            }
+        }
+    }
+}
+
+fn coin_to_json(coin: &Coin) -> Value {
+    json!({
+        "utxo_id": hex(&coin.utxo_id),
+        "owner": hex(&coin.owner),
+        "asset_id": hex(&coin.asset_id),
+        "amount": coin.amount,
+        "block_created": coin.block_created,
+        "tx_created_idx": coin.tx_created_idx,
+    })
+}
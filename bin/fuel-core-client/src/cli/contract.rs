@@ -0,0 +1,86 @@
+use crate::cli::{
+    hex,
+    hex_bytes,
+    output::OutputFormat,
+    PaginationArgs,
+};
+use anyhow::Context;
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::fuel_tx::{
+    AssetId,
+    ContractId,
+};
+use serde_json::json;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// Get the contract by its id
+    Get {
+        id: ContractId,
+        /// Include the bytecode of the contract into the output.
+        #[clap(long = "bytecode")]
+        bytecode: bool,
+    },
+    /// Get the balance of the contract, in the base asset if the asset is not specified
+    Balance {
+        id: ContractId,
+        #[clap(long = "asset-id")]
+        asset_id: Option<AssetId>,
+    },
+    /// List the balances of the contract in all assets
+    Balances {
+        id: ContractId,
+        #[clap(flatten)]
+        pagination: PaginationArgs,
+    },
+}
+
+impl Command {
+    pub async fn exec(
+        self,
+        client: &FuelClient,
+        output: OutputFormat,
+    ) -> anyhow::Result<()> {
+        match self {
+            Command::Get { id, bytecode } => {
+                let contract = client
+                    .contract(&id)
+                    .await?
+                    .with_context(|| format!("Contract {id:#x} not found"))?;
+                let mut value = json!({
+                    "id": hex(&contract.id),
+                    "salt": hex(&contract.salt),
+                    "bytecode_size": contract.bytecode.len(),
+                });
+                if bytecode {
+                    value["bytecode"] = json!(hex_bytes(&contract.bytecode));
+                }
+                output.print(&value)
+            }
+            Command::Balance { id, asset_id } => {
+                let amount = client.contract_balance(&id, asset_id.as_ref()).await?;
+                output.print(&json!({
+                    "contract": hex(&id),
+                    "asset_id": asset_id.as_ref().map(hex),
+                    "amount": amount,
+                }))
+            }
+            Command::Balances { id, pagination } => {
+                let page = client
+                    .contract_balances(&id, pagination.into_request())
+                    .await?;
+                let results = page
+                    .results
+                    .iter()
+                    .map(|balance| {
+                        json!({
+                            "asset_id": hex(&balance.asset_id),
+                            "amount": balance.amount,
+                        })
+                    })
+                    .collect();
+                output.print_page(results, page.cursor, page.has_next_page)
+            }
+        }
+    }
+}
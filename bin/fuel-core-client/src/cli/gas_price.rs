@@ -0,0 +1,64 @@
+use crate::cli::output::OutputFormat;
+use fuel_core_client::client::FuelClient;
+use serde_json::{
+    json,
+    Value,
+};
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// Get the gas price of the latest block
+    Latest,
+    /// Estimate the gas price for the inclusion in the next `block-horizon` blocks
+    Estimate {
+        #[clap(long = "block-horizon", default_value = "10")]
+        block_horizon: u32,
+    },
+    /// Get the gas prices of the blocks in the inclusive range of heights
+    History { from: u32, to: u32 },
+}
+
+impl Command {
+    pub async fn exec(
+        self,
+        client: &FuelClient,
+        output: OutputFormat,
+    ) -> anyhow::Result<()> {
+        match self {
+            Command::Latest => {
+                let latest = client.latest_gas_price().await?;
+                output.print(&json!({
+                    "gas_price": latest.gas_price,
+                    "block_height": *latest.block_height,
+                }))
+            }
+            Command::Estimate { block_horizon } => {
+                let estimate = client.estimate_gas_price(block_horizon).await?;
+                output.print(&json!({
+                    "gas_price": estimate.gas_price,
+                    "block_horizon": block_horizon,
+                }))
+            }
+            Command::History { from, to } => {
+                let history = client.gas_price_history(from.into(), to.into()).await?;
+                let entries = history
+                    .iter()
+                    .map(|entry| {
+                        json!({
+                            "block_height": *entry.block_height,
+                            "gas_price": entry.gas_price,
+                            "exec_gas_price": entry.exec_gas_price,
+                            "da_gas_price": entry.da_gas_price,
+                            "block_gas_used": entry.block_gas_used,
+                            "block_gas_capacity": entry.block_gas_capacity,
+                            "block_bytes": entry.block_bytes,
+                            // The `u128` doesn't fit into the JSON number.
+                            "recorded_da_cost": entry.recorded_da_cost.to_string(),
+                        })
+                    })
+                    .collect();
+                output.print(&Value::Array(entries))
+            }
+        }
+    }
+}
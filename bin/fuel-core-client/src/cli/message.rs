@@ -0,0 +1,133 @@
+use crate::cli::{
+    hex,
+    hex_bytes,
+    output::OutputFormat,
+    PaginationArgs,
+};
+use anyhow::Context;
+use fuel_core_client::client::{
+    types::{
+        message::MessageStatus,
+        MerkleProof,
+        Message,
+    },
+    FuelClient,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        Address,
+        Bytes32,
+        TxId,
+    },
+    fuel_types::Nonce,
+};
+use serde_json::{
+    json,
+    Value,
+};
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// Get the message by its nonce
+    Get { nonce: Nonce },
+    /// List the messages, optionally only the ones of the owner
+    List {
+        #[clap(long = "owner")]
+        owner: Option<Address>,
+        #[clap(flatten)]
+        pagination: PaginationArgs,
+    },
+    /// Get the status of the message by its nonce
+    Status { nonce: Nonce },
+    /// Get the proof of the message sent by the transaction
+    Proof {
+        tx_id: TxId,
+        nonce: Nonce,
+        /// The id of the block used as the commitment of the proof.
+        #[clap(long = "commit-block-id", conflicts_with = "commit_block_height")]
+        commit_block_id: Option<Bytes32>,
+        /// The height of the block used as the commitment of the proof.
+        #[clap(long = "commit-block-height")]
+        commit_block_height: Option<u32>,
+    },
+}
+
+impl Command {
+    pub async fn exec(
+        self,
+        client: &FuelClient,
+        output: OutputFormat,
+    ) -> anyhow::Result<()> {
+        match self {
+            Command::Get { nonce } => {
+                let message = client
+                    .message(&nonce)
+                    .await?
+                    .with_context(|| format!("Message {nonce:#x} not found"))?;
+                output.print(&message_to_json(&message))
+            }
+            Command::List { owner, pagination } => {
+                let page = client
+                    .messages(owner.as_ref(), pagination.into_request())
+                    .await?;
+                let results = page.results.iter().map(message_to_json).collect();
+                output.print_page(results, page.cursor, page.has_next_page)
+            }
+            Command::Status { nonce } => {
+                let status = match client.message_status(&nonce).await? {
+                    MessageStatus::Unspent => "unspent",
+                    MessageStatus::Spent => "spent",
+                    MessageStatus::NotFound => "not_found",
+                };
+                output.print(&json!({
+                    "nonce": hex(&nonce),
+                    "status": status,
+                }))
+            }
+            Command::Proof {
+                tx_id,
+                nonce,
+                commit_block_id,
+                commit_block_height,
+            } => {
+                let proof = client
+                    .message_proof(
+                        &tx_id,
+                        &nonce,
+                        commit_block_id.as_ref(),
+                        commit_block_height.map(Into::into),
+                    )
+                    .await?;
+                output.print(&json!({
+                    "sender": hex(&proof.sender),
+                    "recipient": hex(&proof.recipient),
+                    "nonce": hex(&proof.nonce),
+                    "amount": proof.amount,
+                    "data": hex_bytes(&proof.data),
+                    "message_block_height": proof.message_block_header.height,
+                    "commit_block_height": proof.commit_block_header.height,
+                    "message_proof": merkle_proof_to_json(&proof.message_proof),
+                    "block_proof": merkle_proof_to_json(&proof.block_proof),
+                }))
+            }
+        }
+    }
+}
+
+fn message_to_json(message: &Message) -> Value {
+    json!({
+        "nonce": hex(&message.nonce),
+        "sender": hex(&message.sender),
+        "recipient": hex(&message.recipient),
+        "amount": message.amount,
+        "da_height": message.da_height,
+        "data": hex_bytes(&message.data),
+    })
+}
+
+fn merkle_proof_to_json(proof: &MerkleProof) -> Value {
+    json!({
+        "proof_index": proof.proof_index,
+        "proof_set": proof.proof_set.iter().map(hex).collect::<Vec<_>>(),
+    })
+}
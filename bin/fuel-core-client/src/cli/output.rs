@@ -0,0 +1,165 @@
+use serde_json::{
+    json,
+    Map,
+    Value,
+};
+
+/// The format of the results printed by the commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Pretty-printed JSON.
+    Json,
+    /// Human-readable table.
+    Table,
+}
+
+impl OutputFormat {
+    /// Prints the `value` in the chosen format.
+    pub fn print(&self, value: &Value) -> anyhow::Result<()> {
+        let rendered = match self {
+            OutputFormat::Json => serde_json::to_string_pretty(value)?,
+            OutputFormat::Table => render_table(value),
+        };
+        println!("{rendered}");
+        Ok(())
+    }
+
+    /// Prints the page of the `results` with the cursor to fetch the next page.
+    pub fn print_page(
+        &self,
+        results: Vec<Value>,
+        cursor: Option<String>,
+        has_next_page: bool,
+    ) -> anyhow::Result<()> {
+        match self {
+            OutputFormat::Json => self.print(&json!({
+                "results": results,
+                "cursor": cursor,
+                "has_next_page": has_next_page,
+            })),
+            OutputFormat::Table => {
+                self.print(&Value::Array(results))?;
+                if let (true, Some(cursor)) = (has_next_page, cursor) {
+                    println!("\nNext page: --cursor {cursor}");
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Renders the object as the key-value rows and the array of objects
+/// as the table with a column per field.
+fn render_table(value: &Value) -> String {
+    match value {
+        Value::Object(object) => {
+            let rows = object
+                .iter()
+                .map(|(key, value)| vec![key.clone(), cell(value)])
+                .collect();
+            format_rows(vec!["FIELD".to_string(), "VALUE".to_string()], rows)
+        }
+        Value::Array(items) if items.iter().all(Value::is_object) => {
+            let mut columns: Vec<String> = vec![];
+            for item in items.iter().filter_map(Value::as_object) {
+                for key in item.keys() {
+                    if !columns.contains(key) {
+                        columns.push(key.clone());
+                    }
+                }
+            }
+            let rows = items
+                .iter()
+                .filter_map(Value::as_object)
+                .map(|item| row(item, &columns))
+                .collect();
+            let header = columns.iter().map(|column| column.to_uppercase()).collect();
+            format_rows(header, rows)
+        }
+        Value::Array(items) => items.iter().map(cell).collect::<Vec<_>>().join("\n"),
+        value => cell(value),
+    }
+}
+
+fn row(item: &Map<String, Value>, columns: &[String]) -> Vec<String> {
+    columns
+        .iter()
+        .map(|column| item.get(column).map(cell).unwrap_or_default())
+        .collect()
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(string) => string.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn format_rows(header: Vec<String>, rows: Vec<Vec<String>>) -> String {
+    let mut widths: Vec<usize> = header.iter().map(|cell| cell.chars().count()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let separator = widths.iter().map(|width| "-".repeat(*width)).collect();
+    core::iter::once(header)
+        .chain(core::iter::once(separator))
+        .chain(rows)
+        .map(|row| {
+            row.iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_table__array_of_objects_has_column_per_field() {
+        // Given
+        let value = json!([
+            { "height": 1, "id": "aa" },
+            { "height": 10, "id": "bb", "producer": null },
+        ]);
+
+        // When
+        let table = render_table(&value);
+
+        // Then
+        let expected = "\
+HEIGHT  ID  PRODUCER
+------  --  --------
+1       aa
+10      bb";
+        assert_eq!(table, expected);
+    }
+
+    #[test]
+    fn render_table__object_is_rendered_as_field_value_rows() {
+        // Given
+        let value = json!({ "heights": [1, 2], "name": "local" });
+
+        // When
+        let table = render_table(&value);
+
+        // Then
+        let expected = "\
+FIELD    VALUE
+-------  -----
+heights  [1,2]
+name     local";
+        assert_eq!(table, expected);
+    }
+}
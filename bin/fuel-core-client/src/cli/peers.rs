@@ -0,0 +1,52 @@
+use crate::cli::output::OutputFormat;
+use fuel_core_client::client::FuelClient;
+use serde_json::{
+    json,
+    Value,
+};
+use std::time::UNIX_EPOCH;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// List the peers connected to the node
+    List,
+}
+
+impl Command {
+    pub async fn exec(
+        self,
+        client: &FuelClient,
+        output: OutputFormat,
+    ) -> anyhow::Result<()> {
+        match self {
+            Command::List => {
+                let peers = client.connected_peers_info().await?;
+                let peers = peers
+                    .iter()
+                    .map(|peer| {
+                        let mut addresses: Vec<_> =
+                            peer.peer_addresses.iter().cloned().collect();
+                        addresses.sort();
+                        let last_heartbeat = peer
+                            .heartbeat_data
+                            .last_heartbeat
+                            .duration_since(UNIX_EPOCH)
+                            .map(|since_epoch| since_epoch.as_secs())
+                            .ok();
+                        let block_height =
+                            peer.heartbeat_data.block_height.map(|height| *height);
+                        json!({
+                            "id": peer.id.to_string(),
+                            "client_version": peer.client_version,
+                            "block_height": block_height,
+                            "last_heartbeat": last_heartbeat,
+                            "app_score": peer.app_score,
+                            "addresses": addresses,
+                        })
+                    })
+                    .collect();
+                output.print(&Value::Array(peers))
+            }
+        }
+    }
+}
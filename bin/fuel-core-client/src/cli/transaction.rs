@@ -0,0 +1,179 @@
+use crate::cli::{
+    hex,
+    output::OutputFormat,
+};
+use anyhow::Context;
+use fuel_core_client::client::{
+    types::TransactionStatus,
+    FuelClient,
+};
+use fuel_core_types::fuel_tx::{
+    Transaction,
+    TxId,
+};
+use futures::StreamExt;
+use serde_json::{
+    json,
+    Value,
+};
+
+pub mod call_script;
+pub mod deploy_contract;
+pub mod transfer;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// Submit a JSON encoded transaction for inclusion in a block
+    Submit { tx: String },
+    /// Submit a JSON encoded transaction for predicate estimation.
+    EstimatePredicates { tx: String },
+    /// Submit a JSON encoded transaction for a dry-run execution
+    DryRun { txs: Vec<String> },
+    /// Get the transactions associated with a particular transaction id
+    Get { id: TxId },
+    /// Get the receipts for a particular transaction id
+    Receipts { id: TxId },
+    /// Get the current status of a particular transaction id
+    Status { id: TxId },
+    /// Print the status updates of a particular transaction id until the final status
+    Watch { id: TxId },
+    /// Build, sign and submit the transfer of the coins to the recipient
+    Transfer(transfer::Transfer),
+    /// Build, sign and submit the deployment of the contract
+    DeployContract(deploy_contract::DeployContract),
+    /// Build, sign and submit the script, which may call the contracts
+    CallScript(call_script::CallScript),
+}
+
+impl Command {
+    pub async fn exec(
+        self,
+        client: &FuelClient,
+        output: OutputFormat,
+    ) -> anyhow::Result<()> {
+        match self {
+            Command::Submit { tx } => {
+                let tx = parse_transaction(&tx)?;
+                let id = client.submit(&tx).await?;
+                output.print(&json!({ "id": hex(&id) }))
+            }
+            Command::EstimatePredicates { tx } => {
+                let mut tx = parse_transaction(&tx)?;
+                client.estimate_predicates(&mut tx).await?;
+                output.print(&serde_json::to_value(&tx)?)
+            }
+            Command::DryRun { txs } => {
+                let txs = txs
+                    .iter()
+                    .map(|tx| parse_transaction(tx))
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                let statuses = client.dry_run(&txs).await?;
+                output.print(&serde_json::to_value(statuses)?)
+            }
+            Command::Get { id } => {
+                let tx = client
+                    .transaction(&id)
+                    .await?
+                    .with_context(|| format!("Transaction {id:#x} not found"))?;
+                output.print(&serde_json::to_value(tx)?)
+            }
+            Command::Receipts { id } => {
+                let receipts = client
+                    .receipts(&id)
+                    .await?
+                    .with_context(|| format!("Transaction {id:#x} has no receipts"))?;
+                output.print(&serde_json::to_value(receipts)?)
+            }
+            Command::Status { id } => {
+                let status = client.transaction_status(&id).await?;
+                output.print(&status_to_json(&status))
+            }
+            Command::Watch { id } => {
+                let mut statuses = client.subscribe_transaction_status(&id).await?;
+                while let Some(status) = statuses.next().await {
+                    let status = status?;
+                    output.print(&status_to_json(&status))?;
+                    if is_final(&status) {
+                        break
+                    }
+                }
+                Ok(())
+            }
+            Command::Transfer(command) => command.exec(client, output).await,
+            Command::DeployContract(command) => command.exec(client, output).await,
+            Command::CallScript(command) => command.exec(client, output).await,
+        }
+    }
+}
+
+fn parse_transaction(tx: &str) -> anyhow::Result<Transaction> {
+    serde_json::from_str(tx).context("Invalid transaction json")
+}
+
+/// Prints the id and the final status of the submitted transaction along with
+/// the `details`, and fails if the transaction isn't successful.
+fn report_submitted(
+    output: OutputFormat,
+    id: &TxId,
+    status: &TransactionStatus,
+    details: Value,
+) -> anyhow::Result<()> {
+    let mut report = json!({ "id": hex(id) });
+    for value in [status_to_json(status), details] {
+        if let (Some(report), Value::Object(fields)) = (report.as_object_mut(), value) {
+            report.extend(fields);
+        }
+    }
+    output.print(&report)?;
+    anyhow::ensure!(
+        matches!(status, TransactionStatus::Success { .. }),
+        "The transaction {id:#x} isn't successful"
+    );
+    Ok(())
+}
+
+fn is_final(status: &TransactionStatus) -> bool {
+    !matches!(status, TransactionStatus::Submitted { .. })
+}
+
+/// Summarizes the status without the receipts, they are available via `receipts`.
+fn status_to_json(status: &TransactionStatus) -> Value {
+    match status {
+        TransactionStatus::Submitted { submitted_at } => json!({
+            "status": "submitted",
+            "time": submitted_at.to_unix(),
+        }),
+        TransactionStatus::Success {
+            block_height,
+            time,
+            total_gas,
+            total_fee,
+            ..
+        } => json!({
+            "status": "success",
+            "block_height": **block_height,
+            "time": time.to_unix(),
+            "total_gas": total_gas,
+            "total_fee": total_fee,
+        }),
+        TransactionStatus::SqueezedOut { reason } => json!({
+            "status": "squeezed_out",
+            "reason": reason,
+        }),
+        TransactionStatus::Failure {
+            block_height,
+            time,
+            reason,
+            total_gas,
+            total_fee,
+            ..
+        } => json!({
+            "status": "failure",
+            "block_height": **block_height,
+            "time": time.to_unix(),
+            "reason": reason,
+            "total_gas": total_gas,
+            "total_fee": total_fee,
+        }),
+    }
+}
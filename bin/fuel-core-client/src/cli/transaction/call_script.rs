@@ -0,0 +1,167 @@
+use crate::cli::{
+    output::OutputFormat,
+    transaction::report_submitted,
+    wallet::{
+        SignerArgs,
+        Wallet,
+    },
+};
+use anyhow::Context;
+use fuel_core_client::client::{
+    types::TransactionStatus,
+    FuelClient,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        Input,
+        Output,
+        TransactionBuilder,
+    },
+    fuel_types::{
+        Address,
+        AssetId,
+        ContractId,
+    },
+};
+use serde_json::{
+    json,
+    Value,
+};
+use std::{
+    path::PathBuf,
+    str::FromStr,
+};
+
+#[derive(Debug, clap::Args)]
+pub struct CallScript {
+    /// The path to the bytecode of the script.
+    pub script: PathBuf,
+    /// The script data in hex format.
+    #[clap(long = "data", default_value = "")]
+    pub data: String,
+    /// The contract used by the script. Can be specified multiple times.
+    #[clap(long = "contract")]
+    pub contracts: Vec<ContractId>,
+    /// The coins available to the script in the `<asset_id>:<amount>` format.
+    /// Can be specified multiple times.
+    #[clap(long = "spend")]
+    pub spend: Vec<Spend>,
+    /// The number of the variable outputs available to the script.
+    #[clap(long = "variable-outputs", default_value = "0")]
+    pub variable_outputs: u16,
+    #[clap(flatten)]
+    pub signer: SignerArgs,
+}
+
+/// The amount of the asset spent by the script.
+#[derive(Debug, Clone, Copy)]
+pub struct Spend {
+    pub asset_id: AssetId,
+    pub amount: u64,
+}
+
+impl FromStr for Spend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (asset_id, amount) = s
+            .split_once(':')
+            .ok_or_else(|| format!("`{s}` is not in the `<asset_id>:<amount>` format"))?;
+        let asset_id = AssetId::from_str(asset_id).map_err(|e| e.to_string())?;
+        let amount = amount.parse().map_err(|e| format!("Invalid amount: {e}"))?;
+        Ok(Self { asset_id, amount })
+    }
+}
+
+impl CallScript {
+    pub async fn exec(
+        self,
+        client: &FuelClient,
+        output: OutputFormat,
+    ) -> anyhow::Result<()> {
+        let script = std::fs::read(&self.script).with_context(|| {
+            format!("Failed to read the script from {}", self.script.display())
+        })?;
+        let data = hex::decode(self.data.trim_start_matches("0x"))
+            .context("Invalid script data")?;
+        let spend: Vec<_> = self
+            .spend
+            .iter()
+            .map(|spend| (spend.asset_id, spend.amount))
+            .collect();
+
+        let wallet = Wallet::new(client, &self.signer).await?;
+        let tx = wallet
+            .fund(&spend, |funding| {
+                let mut builder =
+                    TransactionBuilder::script(script.clone(), data.clone());
+                builder.script_gas_limit(funding.script_gas_limit);
+                for (index, contract_id) in self.contracts.iter().enumerate() {
+                    builder.add_input(Input::contract(
+                        Default::default(),
+                        Default::default(),
+                        Default::default(),
+                        Default::default(),
+                        *contract_id,
+                    ));
+                    builder.add_output(Output::contract(
+                        u16::try_from(index)?,
+                        Default::default(),
+                        Default::default(),
+                    ));
+                }
+                for _ in 0..self.variable_outputs {
+                    builder.add_output(Output::variable(
+                        Address::zeroed(),
+                        0,
+                        AssetId::zeroed(),
+                    ));
+                }
+                wallet.add_funding(&mut builder, funding);
+                Ok(builder.finalize_as_transaction())
+            })
+            .await?;
+
+        let (id, status) = wallet.submit(&tx).await?;
+        let receipts = match &status {
+            TransactionStatus::Success { receipts, .. }
+            | TransactionStatus::Failure { receipts, .. } => {
+                serde_json::to_value(receipts)?
+            }
+            _ => Value::Null,
+        };
+        report_submitted(output, &id, &status, json!({ "receipts": receipts }))
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spend__from_str__parses_asset_id_and_amount() {
+        // Given
+        let asset_id = AssetId::from([1; 32]);
+        let arg = format!("{asset_id:#x}:100");
+
+        // When
+        let spend = Spend::from_str(&arg).unwrap();
+
+        // Then
+        assert_eq!(spend.asset_id, asset_id);
+        assert_eq!(spend.amount, 100);
+    }
+
+    #[test]
+    fn spend__from_str__fails_without_amount() {
+        // Given
+        let arg = format!("{:#x}", AssetId::from([1; 32]));
+
+        // When
+        let result = Spend::from_str(&arg);
+
+        // Then
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,96 @@
+use crate::cli::{
+    hex,
+    output::OutputFormat,
+    transaction::report_submitted,
+    wallet::{
+        SignerArgs,
+        Wallet,
+    },
+};
+use anyhow::Context;
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::{
+    fuel_crypto::rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    },
+    fuel_tx::{
+        Contract,
+        Output,
+        StorageSlot,
+        TransactionBuilder,
+    },
+    fuel_types::Salt,
+};
+use serde_json::json;
+use std::path::PathBuf;
+
+#[derive(Debug, clap::Args)]
+pub struct DeployContract {
+    /// The path to the bytecode of the contract.
+    pub bytecode: PathBuf,
+    /// The salt of the contract, random if not specified.
+    #[clap(long = "salt")]
+    pub salt: Option<Salt>,
+    /// The path to the JSON file with the initial storage slots of the contract.
+    #[clap(long = "storage-slots")]
+    pub storage_slots: Option<PathBuf>,
+    #[clap(flatten)]
+    pub signer: SignerArgs,
+}
+
+impl DeployContract {
+    pub async fn exec(
+        self,
+        client: &FuelClient,
+        output: OutputFormat,
+    ) -> anyhow::Result<()> {
+        let bytecode = std::fs::read(&self.bytecode).with_context(|| {
+            format!(
+                "Failed to read the bytecode from {}",
+                self.bytecode.display()
+            )
+        })?;
+        let mut storage_slots: Vec<StorageSlot> = match &self.storage_slots {
+            Some(path) => {
+                let file = std::fs::File::open(path).with_context(|| {
+                    format!("Failed to open the storage slots {}", path.display())
+                })?;
+                serde_json::from_reader(file).context("Invalid storage slots json")?
+            }
+            None => vec![],
+        };
+        storage_slots.sort_by_key(|slot| *slot.key());
+        let salt = self.salt.unwrap_or_else(|| StdRng::from_entropy().gen());
+
+        let contract = Contract::from(bytecode.clone());
+        let state_root = Contract::initial_state_root(storage_slots.iter());
+        let contract_id = contract.id(&salt, &contract.root(), &state_root);
+
+        let wallet = Wallet::new(client, &self.signer).await?;
+        let tx = wallet
+            .fund(&[], |funding| {
+                let mut builder = TransactionBuilder::create(
+                    bytecode.clone().into(),
+                    salt,
+                    storage_slots.clone(),
+                );
+                builder.add_output(Output::contract_created(contract_id, state_root));
+                wallet.add_funding(&mut builder, funding);
+                Ok(builder.finalize_as_transaction())
+            })
+            .await?;
+
+        let (id, status) = wallet.submit(&tx).await?;
+        report_submitted(
+            output,
+            &id,
+            &status,
+            json!({
+                "contract_id": hex(&contract_id),
+                "salt": hex(&salt),
+            }),
+        )
+    }
+}
@@ -0,0 +1,57 @@
+use crate::cli::{
+    output::OutputFormat,
+    transaction::report_submitted,
+    wallet::{
+        SignerArgs,
+        Wallet,
+    },
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::{
+    fuel_tx::{
+        Output,
+        TransactionBuilder,
+    },
+    fuel_types::{
+        Address,
+        AssetId,
+    },
+};
+use serde_json::json;
+
+#[derive(Debug, clap::Args)]
+pub struct Transfer {
+    /// The address of the recipient.
+    pub to: Address,
+    /// The amount of the coins to transfer.
+    pub amount: u64,
+    /// The asset of the coins to transfer, the base asset if not specified.
+    #[clap(long = "asset-id")]
+    pub asset_id: Option<AssetId>,
+    #[clap(flatten)]
+    pub signer: SignerArgs,
+}
+
+impl Transfer {
+    pub async fn exec(
+        self,
+        client: &FuelClient,
+        output: OutputFormat,
+    ) -> anyhow::Result<()> {
+        let wallet = Wallet::new(client, &self.signer).await?;
+        let asset_id = self.asset_id.unwrap_or(*wallet.params.base_asset_id());
+
+        let tx = wallet
+            .fund(&[(asset_id, self.amount)], |funding| {
+                let mut builder = TransactionBuilder::script(vec![], vec![]);
+                builder.script_gas_limit(funding.script_gas_limit);
+                builder.add_output(Output::coin(self.to, self.amount, asset_id));
+                wallet.add_funding(&mut builder, funding);
+                Ok(builder.finalize_as_transaction())
+            })
+            .await?;
+
+        let (id, status) = wallet.submit(&tx).await?;
+        report_submitted(output, &id, &status, json!({}))
+    }
+}
@@ -0,0 +1,299 @@
+//! Funding, signing and submission of the transactions built by the CLI.
+
+use anyhow::Context;
+use fuel_core_client::client::{
+    types::{
+        CoinType,
+        TransactionStatus,
+    },
+    FuelClient,
+};
+use fuel_core_keygen::{
+    keystore::{
+        decrypt_secret,
+        read_password_file,
+    },
+    parse_secret_key,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        Buildable,
+        Chargeable,
+        ConsensusParameters,
+        Input,
+        Output,
+        Receipt,
+        Transaction,
+        TransactionBuilder,
+        TransactionFee,
+        TxId,
+        UniqueIdentifier,
+    },
+    fuel_types::{
+        Address,
+        AssetId,
+    },
+    fuel_vm::SecretKey,
+    services::executor::TransactionExecutionResult,
+};
+use std::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+    },
+    path::PathBuf,
+};
+
+/// The number of attempts to select the coins covering the fee. The fee grows
+/// with the number of inputs, so the selected coins may not cover it on the first try.
+const FUNDING_ATTEMPTS: usize = 3;
+
+/// The key signing the transaction and the gas price estimation settings.
+#[derive(Debug, Clone, clap::Args)]
+pub struct SignerArgs {
+    /// The secret key in hex format used to sign the transaction.
+    #[clap(
+        long = "secret-key",
+        env = "FUEL_CLIENT_SECRET_KEY",
+        hide_env_values = true,
+        required_unless_present = "keystore",
+        conflicts_with = "keystore"
+    )]
+    pub secret_key: Option<String>,
+    /// The encrypted keystore file with the key used to sign the transaction.
+    #[clap(long = "keystore", requires = "password_file")]
+    pub keystore: Option<PathBuf>,
+    /// The file with the password of the keystore.
+    #[clap(long = "password-file")]
+    pub password_file: Option<PathBuf>,
+    /// The number of blocks in which the transaction is expected to be included.
+    /// It is used to estimate the gas price.
+    #[clap(long = "block-horizon", default_value = "10")]
+    pub block_horizon: u32,
+}
+
+impl SignerArgs {
+    fn secret(&self) -> anyhow::Result<SecretKey> {
+        match (&self.secret_key, &self.keystore, &self.password_file) {
+            (Some(secret_key), _, _) => parse_secret_key(secret_key),
+            (None, Some(keystore), Some(password_file)) => {
+                let password = read_password_file(password_file)?;
+                decrypt_secret(keystore, &password)
+            }
+            _ => anyhow::bail!("Either the secret key or the keystore is required"),
+        }
+    }
+}
+
+/// The coins and the limits the transaction is built with.
+pub struct Funding {
+    pub coins: Vec<CoinType>,
+    pub script_gas_limit: u64,
+    pub max_fee: u64,
+}
+
+/// Builds the transactions on behalf of the owner of the secret key.
+pub struct Wallet<'a> {
+    client: &'a FuelClient,
+    secret: SecretKey,
+    pub owner: Address,
+    pub params: ConsensusParameters,
+    gas_price: u64,
+}
+
+impl<'a> Wallet<'a> {
+    pub async fn new(client: &'a FuelClient, args: &SignerArgs) -> anyhow::Result<Self> {
+        let secret = args.secret()?;
+        let owner = Input::owner(&secret.public_key());
+        let params = client.chain_info().await?.consensus_parameters;
+        let gas_price = client
+            .estimate_gas_price(args.block_horizon)
+            .await?
+            .gas_price
+            .into();
+        Ok(Self {
+            client,
+            secret,
+            owner,
+            params,
+            gas_price,
+        })
+    }
+
+    /// Adds the `funding` coins signed by the wallet key and the change outputs
+    /// of the spent assets to the transaction.
+    pub fn add_funding<Tx: Buildable>(
+        &self,
+        builder: &mut TransactionBuilder<Tx>,
+        funding: &Funding,
+    ) {
+        let mut assets = BTreeSet::from([*self.params.base_asset_id()]);
+        for coin in &funding.coins {
+            match coin {
+                CoinType::Coin(coin) => {
+                    builder.add_unsigned_coin_input(
+                        self.secret,
+                        coin.utxo_id,
+                        coin.amount,
+                        coin.asset_id,
+                        Default::default(),
+                    );
+                    assets.insert(coin.asset_id);
+                }
+                CoinType::MessageCoin(message) => {
+                    builder.add_unsigned_message_input(
+                        self.secret,
+                        message.sender,
+                        message.nonce,
+                        message.amount,
+                        vec![],
+                    );
+                }
+                CoinType::Unknown => {}
+            }
+        }
+        for asset_id in assets {
+            builder.add_output(Output::change(self.owner, 0, asset_id));
+        }
+        builder.max_fee_limit(funding.max_fee);
+        builder.with_params(self.params.clone());
+    }
+
+    /// Selects the coins to cover the `spend` and the fee, estimates the predicates and
+    /// the script gas via the dry run, and returns the transaction signed by `build`.
+    pub async fn fund<F>(
+        &self,
+        spend: &[(AssetId, u64)],
+        build: F,
+    ) -> anyhow::Result<Transaction>
+    where
+        F: Fn(&Funding) -> anyhow::Result<Transaction>,
+    {
+        let max_gas_per_tx = self.params.tx_params().max_gas_per_tx();
+        let mut max_fee = 0;
+        for _ in 0..FUNDING_ATTEMPTS {
+            let coins = self.select_coins(spend, max_fee).await?;
+
+            // The dry run uses all gas available to the transaction.
+            let draft = build(&Funding {
+                coins: coins.clone(),
+                script_gas_limit: 0,
+                max_fee: 0,
+            })?;
+            let script_gas_limit =
+                max_gas_per_tx.saturating_sub(min_gas(&draft, &self.params)?);
+            let mut draft = build(&Funding {
+                coins: coins.clone(),
+                script_gas_limit,
+                max_fee: 0,
+            })?;
+            self.client.estimate_predicates(&mut draft).await?;
+            let gas_used = self.dry_run(draft).await?;
+
+            // Leaves the margin for the state changes until the execution.
+            let script_gas_limit = gas_used.saturating_add(gas_used.saturating_div(5));
+            let tx = build(&Funding {
+                coins: coins.clone(),
+                script_gas_limit,
+                max_fee: 0,
+            })?;
+            let required_fee = self.max_fee(&tx)?;
+            if required_fee <= max_fee {
+                let mut tx = build(&Funding {
+                    coins,
+                    script_gas_limit,
+                    max_fee,
+                })?;
+                self.client.estimate_predicates(&mut tx).await?;
+                return Ok(tx)
+            }
+            max_fee = required_fee;
+        }
+        anyhow::bail!("Failed to select the coins covering the fee of {max_fee}")
+    }
+
+    /// Submits the transaction and waits for its commitment.
+    pub async fn submit(
+        &self,
+        tx: &Transaction,
+    ) -> anyhow::Result<(TxId, TransactionStatus)> {
+        let id = tx.id(&self.params.chain_id());
+        let status = self.client.submit_and_await_commit(tx).await?;
+        Ok((id, status))
+    }
+
+    async fn select_coins(
+        &self,
+        spend: &[(AssetId, u64)],
+        max_fee: u64,
+    ) -> anyhow::Result<Vec<CoinType>> {
+        // At least one coin is required to pay for the transaction.
+        let mut amounts =
+            BTreeMap::from([(*self.params.base_asset_id(), max_fee.max(1))]);
+        for (asset_id, amount) in spend {
+            let total = amounts.entry(*asset_id).or_default();
+            *total = total.saturating_add(*amount);
+        }
+        let query = amounts
+            .into_iter()
+            .map(|(asset_id, amount)| (asset_id, amount, None))
+            .collect();
+        let coins = self
+            .client
+            .coins_to_spend(&self.owner, query, None)
+            .await
+            .context("Failed to select the coins to spend")?;
+        Ok(coins.into_iter().flatten().collect())
+    }
+
+    /// Returns the gas used by the script of the transaction in the dry run.
+    async fn dry_run(&self, tx: Transaction) -> anyhow::Result<u64> {
+        // The gas used doesn't depend on the gas price, the zero price
+        // allows the dry run before the fee is known.
+        let status = self
+            .client
+            .dry_run_opt(&[tx], None, Some(0))
+            .await?
+            .pop()
+            .context("The dry run didn't return the status of the transaction")?;
+        if let TransactionExecutionResult::Failed { result, .. } = &status.result {
+            anyhow::bail!("The transaction fails in the dry run: {result:?}")
+        }
+        let gas_used = status
+            .result
+            .receipts()
+            .iter()
+            .find_map(|receipt| match receipt {
+                Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+                _ => None,
+            })
+            .unwrap_or_default();
+        Ok(gas_used)
+    }
+
+    fn max_fee(&self, tx: &Transaction) -> anyhow::Result<u64> {
+        let gas_costs = self.params.gas_costs();
+        let fee_params = self.params.fee_params();
+        let fee = match tx {
+            Transaction::Script(tx) => {
+                TransactionFee::checked_from_tx(gas_costs, fee_params, tx, self.gas_price)
+            }
+            Transaction::Create(tx) => {
+                TransactionFee::checked_from_tx(gas_costs, fee_params, tx, self.gas_price)
+            }
+            _ => anyhow::bail!("Only the script and create transactions are supported"),
+        };
+        fee.map(|fee| fee.max_fee())
+            .context("The fee of the transaction overflows")
+    }
+}
+
+fn min_gas(tx: &Transaction, params: &ConsensusParameters) -> anyhow::Result<u64> {
+    let gas_costs = params.gas_costs();
+    let fee_params = params.fee_params();
+    match tx {
+        Transaction::Script(tx) => Ok(tx.min_gas(gas_costs, fee_params)),
+        Transaction::Create(tx) => Ok(tx.min_gas(gas_costs, fee_params)),
+        _ => anyhow::bail!("Only the script and create transactions are supported"),
+    }
+}
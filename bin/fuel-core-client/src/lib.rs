@@ -0,0 +1,8 @@
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::cast_possible_truncation)]
+#![deny(unused_crate_dependencies)]
+#![deny(warnings)]
+
+pub mod cli;
+
+use tokio as _; // Used only by the binary
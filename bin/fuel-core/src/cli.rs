@@ -27,8 +27,12 @@ pub fn default_db_path() -> PathBuf {
     dirs::home_dir().unwrap().join(".fuel").join("db")
 }
 
+#[cfg(feature = "rocksdb")]
+pub mod db;
 pub mod fee_contract;
 #[cfg(feature = "rocksdb")]
+pub mod reindex;
+#[cfg(feature = "rocksdb")]
 pub mod rollback;
 pub mod run;
 #[cfg(feature = "rocksdb")]
@@ -57,6 +61,10 @@ pub enum Fuel {
     Snapshot(snapshot::Command),
     #[cfg(feature = "rocksdb")]
     Rollback(rollback::Command),
+    #[cfg(feature = "rocksdb")]
+    Db(db::Command),
+    #[cfg(feature = "rocksdb")]
+    Reindex(reindex::Command),
     GenerateFeeContract(fee_contract::Command),
 }
 
@@ -139,6 +147,10 @@ pub async fn run_cli() -> anyhow::Result<()> {
             Fuel::Snapshot(command) => snapshot::exec(command).await,
             Fuel::GenerateFeeContract(command) => fee_contract::exec(command).await,
             Fuel::Rollback(command) => rollback::exec(command).await,
+            #[cfg(feature = "rocksdb")]
+            Fuel::Db(command) => db::exec(command).await,
+            #[cfg(feature = "rocksdb")]
+            Fuel::Reindex(command) => reindex::exec(command).await,
         },
         Err(e) => {
             // Prints the error and exits.
@@ -676,4 +688,117 @@ mod tests {
             };
         }
     }
+
+    mod db_tests {
+        use super::*;
+        use crate::cli::db;
+
+        #[test]
+        fn checkpoint_path_is_as_given() {
+            // given
+            let line = "./core db checkpoint --checkpoint-path ./some/path";
+
+            // when
+            let command = parse_cli(line, "")
+                .expect("should parse the db command")
+                .command;
+
+            // then
+            let Fuel::Db(db::Command {
+                subcommand: db::SubCommands::Checkpoint(checkpoint),
+            }) = command
+            else {
+                panic!("Expected a db checkpoint command")
+            };
+            assert_eq!(checkpoint.checkpoint_path, PathBuf::from("./some/path"));
+        }
+
+        #[test]
+        fn restore_requires_checkpoint_path() {
+            // given
+            let line = "./core db restore --db-path ./some/path";
+
+            // when
+            let result = parse_cli(line, "");
+
+            // then
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn inspect_key_requires_column() {
+            // given
+            let line = "./core db inspect --key 0x00";
+
+            // when
+            let result = parse_cli(line, "");
+
+            // then
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn verify_doesnt_repair_by_default() {
+            // given
+            let line = "./core db verify --json";
+
+            // when
+            let command = parse_cli(line, "")
+                .expect("should parse the db command")
+                .command;
+
+            // then
+            let Fuel::Db(db::Command {
+                subcommand: db::SubCommands::Verify(verify),
+            }) = command
+            else {
+                panic!("Expected a db verify command")
+            };
+            assert!(verify.json);
+            assert!(!verify.repair);
+        }
+    }
+
+    mod reindex_tests {
+        use super::*;
+        use crate::cli::{
+            reindex,
+            run,
+        };
+        use fuel_core::service::reindex::OffChainIndex;
+
+        #[test]
+        fn indexes_are_comma_separated() {
+            // given
+            let line = "./core reindex --index balances,coins";
+
+            // when
+            let command = parse_cli(line, "")
+                .expect("should parse the reindex command")
+                .command;
+
+            // then
+            let Fuel::Reindex(reindex::Command { indexes, .. }) = command else {
+                panic!("Expected a reindex command")
+            };
+            assert_eq!(indexes, vec![OffChainIndex::Balances, OffChainIndex::Coins]);
+        }
+
+        #[test]
+        fn run_reindex_without_values_rebuilds_everything() {
+            // given
+            let line = "./core run --reindex";
+
+            // when
+            let command = parse_cli(line, "")
+                .expect("should parse the run command")
+                .command;
+
+            // then
+            let Fuel::Run(run::Command { reindex, .. }) = command else {
+                panic!("Expected a run command")
+            };
+            assert_eq!(reindex, Some(vec![]));
+        }
+    }
 }
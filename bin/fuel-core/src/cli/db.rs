@@ -0,0 +1,51 @@
+use clap::{
+    Parser,
+    Subcommand,
+};
+use rlimit::{
+    getrlimit,
+    Resource,
+};
+
+mod checkpoint;
+mod inspect;
+mod restore;
+mod verify;
+
+/// Maintenance of the databases of the node.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    #[command(subcommand)]
+    pub subcommand: SubCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SubCommands {
+    /// Creates the checkpoint of the databases of the stopped node.
+    /// Use the `createDatabaseCheckpoint` mutation of the GraphQL API
+    /// to create the checkpoint of the running node.
+    Checkpoint(checkpoint::Command),
+    /// Validates the checkpoint and restores it into the database path.
+    Restore(restore::Command),
+    /// Prints the heights and the column statistics of the databases,
+    /// or decodes a single entry of the table.
+    Inspect(inspect::Command),
+    /// Verifies the off-chain indexes and the Merkle roots of the databases,
+    /// and optionally repairs the off-chain indexes.
+    Verify(verify::Command),
+}
+
+fn get_default_max_fds() -> i32 {
+    getrlimit(Resource::NOFILE)
+        .map(|(_, hard)| i32::try_from(hard.saturating_div(2)).unwrap_or(i32::MAX))
+        .expect("Our supported platforms should return max FD.")
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    match command.subcommand {
+        SubCommands::Checkpoint(command) => checkpoint::exec(command).await,
+        SubCommands::Restore(command) => restore::exec(command).await,
+        SubCommands::Inspect(command) => inspect::exec(command).await,
+        SubCommands::Verify(command) => verify::exec(command).await,
+    }
+}
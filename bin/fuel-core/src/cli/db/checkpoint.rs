@@ -0,0 +1,64 @@
+use super::get_default_max_fds;
+use crate::cli::default_db_path;
+use anyhow::Context;
+use clap::Parser;
+use fuel_core::{
+    combined_database::CombinedDatabase,
+    state::historical_rocksdb::StateRewindPolicy,
+};
+use std::{
+    path::PathBuf,
+    time::Duration,
+};
+
+/// Creates the checkpoint of the on-chain, off-chain, relayer and gas price databases.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = default_db_path().into_os_string()
+    )]
+    pub database_path: PathBuf,
+
+    /// Defines a specific number of file descriptors that RocksDB can use.
+    ///
+    /// If defined as -1 no limit will be applied and will use the OS limits.
+    /// If not defined the system default divided by two is used.
+    #[clap(
+        long = "rocksdb-max-fds",
+        env,
+        default_value = get_default_max_fds().to_string()
+    )]
+    pub rocksdb_max_fds: i32,
+
+    /// The path to the new checkpoint. It must not exist.
+    #[clap(long = "checkpoint-path")]
+    pub checkpoint_path: PathBuf,
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    let path = command.database_path.as_path();
+    let db = CombinedDatabase::open(
+        path,
+        64 * 1024 * 1024,
+        StateRewindPolicy::NoRewind,
+        command.rocksdb_max_fds,
+    )
+    .map_err(Into::<anyhow::Error>::into)
+    .context(format!(
+        "failed to open combined database at path {path:?}, \
+        the checkpoint of the running node is created by its GraphQL API"
+    ))?;
+
+    // Nobody writes to the databases of the stopped node, there is nothing to wait for.
+    let manifest = db.create_checkpoint(&command.checkpoint_path, Duration::ZERO)?;
+    tracing::info!(
+        "Created the checkpoint at {:?}: {manifest:?}",
+        command.checkpoint_path
+    );
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+use super::get_default_max_fds;
+use crate::cli::default_db_path;
+use clap::{
+    Parser,
+    ValueEnum,
+};
+use fuel_core::{
+    combined_database::{
+        inspect::{
+            DatabaseReport,
+            DecodedEntry,
+        },
+        CombinedDatabase,
+    },
+    database::database_description::{
+        off_chain::OffChain,
+        on_chain::OnChain,
+    },
+};
+use std::path::PathBuf;
+
+/// Prints the statistics of the databases or decodes a single entry.
+/// The databases are opened in the read-only mode, so the node can be running.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = default_db_path().into_os_string()
+    )]
+    pub database_path: PathBuf,
+
+    /// Defines a specific number of file descriptors that RocksDB can use.
+    ///
+    /// If defined as -1 no limit will be applied and will use the OS limits.
+    /// If not defined the system default divided by two is used.
+    #[clap(
+        long = "rocksdb-max-fds",
+        env,
+        default_value = get_default_max_fds().to_string()
+    )]
+    pub rocksdb_max_fds: i32,
+
+    /// Counts the exact number of keys in each column instead of the RocksDB
+    /// estimation. It iterates over the whole database and may take a while.
+    #[clap(long = "count-keys")]
+    pub count_keys: bool,
+
+    /// Prints the output as JSON.
+    #[clap(long = "json")]
+    pub json: bool,
+
+    /// The database of the `column` with the decoded entry.
+    #[clap(
+        long = "database",
+        default_value = "on-chain",
+        value_enum,
+        ignore_case = true
+    )]
+    pub database: Database,
+
+    /// The name of the column with the decoded entry, e.g. `Coins` or `OwnedCoins`.
+    #[clap(long = "column", requires = "key")]
+    pub column: Option<String>,
+
+    /// The hex encoded key of the decoded entry.
+    #[clap(long = "key", requires = "column")]
+    pub key: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Database {
+    OnChain,
+    OffChain,
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    let path = command.database_path.as_path();
+
+    if let (Some(column), Some(key)) = (&command.column, &command.key) {
+        let key = hex::decode(key.trim_start_matches("0x"))?;
+        let entry = match command.database {
+            Database::OnChain => CombinedDatabase::decode_entry::<OnChain>(
+                path,
+                command.rocksdb_max_fds,
+                column,
+                &key,
+            )?,
+            Database::OffChain => CombinedDatabase::decode_entry::<OffChain>(
+                path,
+                command.rocksdb_max_fds,
+                column,
+                &key,
+            )?,
+        };
+        if command.json {
+            println!("{}", serde_json::to_string_pretty(&entry)?);
+        } else {
+            print_entry(&entry);
+        }
+        return Ok(())
+    }
+
+    let report =
+        CombinedDatabase::inspect(path, command.rocksdb_max_fds, command.count_keys)?;
+    if command.json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(())
+    }
+
+    for (name, database) in [
+        ("on-chain", report.on_chain),
+        ("off-chain", report.off_chain),
+        ("relayer", report.relayer),
+        ("gas price", report.gas_price),
+    ] {
+        match database {
+            Some(database) => print_database(name, &database),
+            None => println!("The {name} database doesn't exist at {path:?}\n"),
+        }
+    }
+
+    Ok(())
+}
+
+fn print_database(name: &str, database: &DatabaseReport) {
+    println!("The {name} database `{}`", database.name);
+    match database.version {
+        Some(version) => println!("  version: {version}"),
+        None => println!("  version: unknown"),
+    }
+    match database.latest_height {
+        Some(height) => println!("  latest height: {height}"),
+        None => println!("  latest height: none"),
+    }
+    match database.modifications_history {
+        Some(range) => println!(
+            "  modifications history: {}..={}",
+            range.oldest, range.latest
+        ),
+        None => println!("  modifications history: empty"),
+    }
+
+    // The most of the historical columns are empty, so only the columns
+    // with data are printed. The JSON output contains all columns.
+    println!("  {:<56} {:>16} {:>16}", "column", "keys", "sst size");
+    for column in &database.columns {
+        let keys = column.keys.unwrap_or(column.estimated_keys);
+        if keys == 0 && column.sst_files_size == 0 {
+            continue
+        }
+        let keys = match column.keys {
+            Some(keys) => keys.to_string(),
+            None => format!("~{keys}"),
+        };
+        println!(
+            "  {:<56} {keys:>16} {:>16}",
+            column.name, column.sst_files_size
+        );
+    }
+    println!();
+}
+
+fn print_entry(entry: &DecodedEntry) {
+    match &entry.table {
+        Some(table) => println!("table: {table}"),
+        None => println!("table: unknown, the entry is hex encoded"),
+    }
+    println!("key: {}", entry.key);
+    match &entry.value {
+        Some(value) => println!("value: {value}"),
+        None => println!("value: not found"),
+    }
+}
@@ -0,0 +1,56 @@
+use super::get_default_max_fds;
+use crate::cli::default_db_path;
+use clap::Parser;
+use fuel_core::{
+    combined_database::CombinedDatabase,
+    state::historical_rocksdb::StateRewindPolicy,
+};
+use std::path::PathBuf;
+
+/// Restores the checkpoint created by `fuel-core db checkpoint` or by the GraphQL API.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database. It must not contain the databases.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = default_db_path().into_os_string()
+    )]
+    pub database_path: PathBuf,
+
+    /// Defines a specific number of file descriptors that RocksDB can use.
+    ///
+    /// If defined as -1 no limit will be applied and will use the OS limits.
+    /// If not defined the system default divided by two is used.
+    #[clap(
+        long = "rocksdb-max-fds",
+        env,
+        default_value = get_default_max_fds().to_string()
+    )]
+    pub rocksdb_max_fds: i32,
+
+    /// The path to the checkpoint.
+    #[clap(long = "checkpoint-path")]
+    pub checkpoint_path: PathBuf,
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use crate::cli::ShutdownListener;
+
+    let mut shutdown_listener = ShutdownListener::spawn();
+    let manifest = CombinedDatabase::restore_checkpoint(
+        &command.checkpoint_path,
+        &command.database_path,
+        64 * 1024 * 1024,
+        StateRewindPolicy::RewindFullRange,
+        command.rocksdb_max_fds,
+        &mut shutdown_listener,
+    )?;
+    tracing::info!(
+        "Restored the checkpoint into {:?}: {manifest:?}",
+        command.database_path
+    );
+
+    Ok(())
+}
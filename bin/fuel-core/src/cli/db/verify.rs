@@ -0,0 +1,154 @@
+use super::get_default_max_fds;
+use crate::cli::{
+    default_db_path,
+    ShutdownListener,
+};
+use clap::Parser;
+use fuel_core::{
+    combined_database::{
+        verify::VerificationReport,
+        CombinedDatabase,
+    },
+    fuel_core_graphql_api::worker_service::DaCompressionConfig,
+    service::reindex::{
+        reindex,
+        ReindexConfig,
+    },
+    state::historical_rocksdb::StateRewindPolicy,
+};
+use itertools::Itertools;
+use std::path::PathBuf;
+
+/// Verifies the off-chain indexes against the on-chain tables they are derived
+/// from and the Merkle roots of the blocks and the contract states.
+/// The databases are opened in the read-only mode, so the node can be running.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = default_db_path().into_os_string()
+    )]
+    pub database_path: PathBuf,
+
+    /// Defines a specific number of file descriptors that RocksDB can use.
+    ///
+    /// If defined as -1 no limit will be applied and will use the OS limits.
+    /// If not defined the system default divided by two is used.
+    #[clap(
+        long = "rocksdb-max-fds",
+        env,
+        default_value = get_default_max_fds().to_string()
+    )]
+    pub rocksdb_max_fds: i32,
+
+    /// Prints the report as JSON.
+    #[clap(long = "json")]
+    pub json: bool,
+
+    /// Rebuilds the off-chain indexes with mismatches, see the `reindex` command.
+    /// The node must be stopped, and the off-chain database must be synced with
+    /// the on-chain database.
+    #[clap(long = "repair")]
+    pub repair: bool,
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    let path = command.database_path.as_path();
+    let mut report = CombinedDatabase::verify(path, command.rocksdb_max_fds)?;
+
+    let indexes = report.indexes_to_repair();
+    if command.repair && !indexes.is_empty() {
+        // The JSON output contains only the report after the repair.
+        if !command.json {
+            print_report(&report, false)?;
+        }
+        // The reindex rebuilds the indexes at the height of the on-chain database.
+        if report.off_chain_height != report.on_chain_height {
+            anyhow::bail!(
+                "The off-chain database at the height {:?} is not synced with \
+                the on-chain database at the height {:?}, so the indexes can't be \
+                repaired. Let the node process all blocks before stopping it",
+                report.off_chain_height,
+                report.on_chain_height
+            )
+        }
+        tracing::info!("Repairing the {} indexes", indexes.iter().join(", "));
+        let config = ReindexConfig {
+            database_path: command.database_path.clone(),
+            capacity: 64 * 1024 * 1024,
+            // The indexes are rebuilt in place without the modifications history,
+            // so the off-chain database is opened without storing it.
+            state_rewind_policy: StateRewindPolicy::NoRewind,
+            max_fds: command.rocksdb_max_fds,
+            indexes,
+            executor: Default::default(),
+            da_compression: DaCompressionConfig::Disabled,
+            batch_size: ReindexConfig::DEFAULT_BATCH_SIZE,
+        };
+        reindex(config, ShutdownListener::spawn()).await?;
+
+        report = CombinedDatabase::verify(path, command.rocksdb_max_fds)?;
+    }
+    print_report(&report, command.json)?;
+
+    if !report.is_consistent() {
+        anyhow::bail!(
+            "The databases at {path:?} have {} mismatches",
+            report
+                .checks
+                .iter()
+                .map(|check| check.mismatches)
+                .fold(0u64, u64::saturating_add)
+        )
+    }
+
+    Ok(())
+}
+
+fn print_report(report: &VerificationReport, json: bool) -> anyhow::Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(report)?);
+        return Ok(())
+    }
+
+    let height = |height: Option<u64>| match height {
+        Some(height) => height.to_string(),
+        None => "none".to_string(),
+    };
+    println!("on-chain height: {}", height(report.on_chain_height));
+    println!("off-chain height: {}", height(report.off_chain_height));
+    println!("  {:<24} {:>16} {:>16}", "check", "verified", "mismatches");
+    for check in &report.checks {
+        match &check.skipped {
+            Some(reason) => {
+                println!("  {:<24} skipped: {reason}", check.check.to_string())
+            }
+            None => println!(
+                "  {:<24} {:>16} {:>16}",
+                check.check.to_string(),
+                check.verified,
+                check.mismatches
+            ),
+        }
+    }
+
+    for mismatch in &report.mismatches {
+        let value = |value: &Option<String>| match value {
+            Some(value) => value.clone(),
+            None => "missing".to_string(),
+        };
+        println!(
+            "{} {}: expected {}, actual {}",
+            mismatch.check,
+            mismatch.key,
+            value(&mismatch.expected),
+            value(&mismatch.actual)
+        );
+    }
+    println!();
+
+    Ok(())
+}
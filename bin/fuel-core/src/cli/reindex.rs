@@ -0,0 +1,111 @@
+use crate::cli::{
+    default_db_path,
+    run::state_rewind_policy_from_duration,
+    ShutdownListener,
+};
+use clap::Parser;
+use fuel_core::{
+    fuel_core_graphql_api::worker_service::DaCompressionConfig,
+    service::reindex::{
+        reindex,
+        OffChainIndex,
+        ReindexConfig,
+    },
+};
+use fuel_core_types::blockchain::header::StateTransitionBytecodeVersion;
+use rlimit::{
+    getrlimit,
+    Resource,
+};
+use std::path::PathBuf;
+
+/// Rebuilds the off-chain database, or only the selected indexes of it,
+/// from the on-chain database. The node must be stopped.
+///
+/// The interrupted reindex continues from the last processed group
+/// when the command is run again with the same arguments.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = default_db_path().into_os_string()
+    )]
+    pub database_path: PathBuf,
+
+    /// Defines a specific number of file descriptors that RocksDB can use.
+    ///
+    /// If defined as -1 no limit will be applied and will use the OS limits.
+    /// If not defined the system default divided by two is used.
+    #[clap(
+        long = "rocksdb-max-fds",
+        env,
+        default_value = get_default_max_fds().to_string()
+    )]
+    pub rocksdb_max_fds: i32,
+
+    /// The indexes rebuilt in place, e.g. `--index balances,coins`.
+    ///
+    /// If not set, the whole off-chain database is rebuilt by re-executing
+    /// the blocks. It requires the whole state history of the on-chain database,
+    /// see `--state-rewind-duration` of the `run` command.
+    #[clap(long = "index", value_enum, value_delimiter = ',')]
+    pub indexes: Vec<OffChainIndex>,
+
+    /// The number of blocks re-executed in parallel during the full rebuild.
+    #[clap(long = "batch-size", default_value_t = ReindexConfig::DEFAULT_BATCH_SIZE)]
+    pub batch_size: usize,
+
+    /// The state rewind duration of the rebuilt off-chain database.
+    /// Should match the `--state-rewind-duration` of the node.
+    #[clap(long = "state-rewind-duration", default_value = "7d", env)]
+    pub state_rewind_duration: humantime::Duration,
+
+    /// Enable full utxo stateful validation during the re-execution of the blocks.
+    /// Should match the `--utxo-validation` of the node.
+    #[arg(long = "utxo-validation", env)]
+    pub utxo_validation: bool,
+
+    /// Overrides the version of the native executor.
+    #[arg(long = "native-executor-version", env)]
+    pub native_executor_version: Option<StateTransitionBytecodeVersion>,
+
+    /// If given, the da-compressed blocks are rebuilt with the given retention time.
+    #[arg(long = "da-compression", env)]
+    pub da_compression: Option<humantime::Duration>,
+}
+
+fn get_default_max_fds() -> i32 {
+    getrlimit(Resource::NOFILE)
+        .map(|(_, hard)| i32::try_from(hard.saturating_div(2)).unwrap_or(i32::MAX))
+        .expect("Our supported platforms should return max FD.")
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    let da_compression = match command.da_compression {
+        Some(retention) => DaCompressionConfig::Enabled(fuel_core_compression::Config {
+            temporal_registry_retention: retention.into(),
+        }),
+        None => DaCompressionConfig::Disabled,
+    };
+    let config = ReindexConfig {
+        database_path: command.database_path,
+        capacity: 64 * 1024 * 1024,
+        state_rewind_policy: state_rewind_policy_from_duration(
+            command.state_rewind_duration,
+        )?,
+        max_fds: command.rocksdb_max_fds,
+        indexes: command.indexes,
+        executor: fuel_core::upgradable_executor::config::Config {
+            backtrace: false,
+            utxo_validation_default: command.utxo_validation,
+            native_executor_version: command.native_executor_version,
+        },
+        da_compression,
+        batch_size: command.batch_size,
+    };
+
+    reindex(config, ShutdownListener::spawn()).await
+}
@@ -4,7 +4,10 @@ use crate::{
     cli::{
         default_db_path,
         run::{
-            consensus::PoATriggerArgs,
+            consensus::{
+                PoATriggerArgs,
+                RemoteSignerArgs,
+            },
             graphql::GraphQLArgs,
             tx_pool::TxPoolArgs,
         },
@@ -31,6 +34,7 @@ use fuel_core::{
         genesis::NotifyCancel,
         Config,
         DbType,
+        GasPriceAlgorithm,
         RelayerConsensusConfig,
         VMConfig,
     },
@@ -39,6 +43,7 @@ use fuel_core::{
         Config as TxPoolConfig,
         HeavyWorkConfig,
         PoolLimits,
+        SenderLimits,
         ServiceChannelLimits,
     },
     types::{
@@ -51,11 +56,18 @@ use fuel_core_chain_config::{
     SnapshotMetadata,
     SnapshotReader,
 };
+use fuel_core_keygen::keystore::{
+    decrypt_secret,
+    read_password_file,
+};
 use fuel_core_metrics::config::{
     DisableConfig,
     Module,
 };
-use fuel_core_poa::signer::SignMode;
+use fuel_core_poa::signer::{
+    RemoteSigner,
+    SignMode,
+};
 use fuel_core_types::blockchain::header::StateTransitionBytecodeVersion;
 use pyroscope::{
     pyroscope::PyroscopeAgentRunning,
@@ -83,7 +95,13 @@ use tracing::{
 };
 
 #[cfg(feature = "rocksdb")]
-use fuel_core::state::historical_rocksdb::StateRewindPolicy;
+use fuel_core::{
+    service::reindex::{
+        OffChainIndex,
+        ReindexConfig,
+    },
+    state::historical_rocksdb::StateRewindPolicy,
+};
 
 use super::DEFAULT_DATABASE_CACHE_SIZE;
 
@@ -165,6 +183,43 @@ pub struct Command {
     #[arg(name = "DB_PRUNE", long = "db-prune", env, default_value = "false")]
     pub db_prune: bool,
 
+    #[cfg(feature = "rocksdb")]
+    /// Runs the node as the read replica of the node with the database at the path.
+    /// The replica follows the database of the primary node on the same host and
+    /// serves only the GraphQL API, the mutations are disabled.
+    ///
+    /// The `--db-path` is used for the files of the replica and must differ
+    /// from the database path of the primary node.
+    ///
+    /// The replica doesn't support snapshots, so the queries read the latest
+    /// state applied by the periodic catch-up with the primary. A query running
+    /// during the catch-up may observe the state of two different heights.
+    /// The queries requiring the consistent state should be sent to the primary.
+    #[clap(long = "read-replica-of", env)]
+    pub read_replica_of: Option<PathBuf>,
+
+    #[cfg(feature = "rocksdb")]
+    /// The interval between the catch-ups of the read replica with the primary node.
+    #[clap(long = "read-replica-catch-up-interval", default_value = "1s", env)]
+    pub read_replica_catch_up_interval: humantime::Duration,
+
+    #[cfg(feature = "rocksdb")]
+    /// Rebuilds the off-chain database from the on-chain database before
+    /// the start of the node. Without values, the whole off-chain database is
+    /// rebuilt by re-executing the blocks. Otherwise, only the given indexes
+    /// are rebuilt, e.g. `--reindex balances,coins`.
+    ///
+    /// The interrupted reindex continues on the next start with the same flag.
+    #[clap(
+        long = "reindex",
+        env,
+        num_args = 0..,
+        value_delimiter = ',',
+        value_enum,
+        conflicts_with = "read_replica_of"
+    )]
+    pub reindex: Option<Vec<OffChainIndex>>,
+
     /// The determines whether to continue the services on internal error or not.
     #[clap(long = "continue-services-on-error", default_value = "false", env)]
     pub continue_on_error: bool,
@@ -201,15 +256,86 @@ pub struct Command {
     #[arg(long = "min-gas-price", default_value = "0", env)]
     pub min_gas_price: u64,
 
-    /// The percentage threshold for gas price increase
+    /// The percentage threshold for gas price increase.
+    /// The `v1` algorithm uses it as the L2 block fullness threshold.
     #[arg(long = "gas-price-threshold-percent", default_value = "50", env)]
     pub gas_price_threshold_percent: u64,
 
+    /// The algorithm used to calculate the gas price.
+    /// The `v1` algorithm also covers the costs of the DA commitments.
+    #[clap(
+        long = "gas-price-algorithm",
+        default_value = "v0",
+        value_enum,
+        ignore_case = true,
+        env
+    )]
+    pub gas_price_algorithm: GasPriceAlgorithm,
+
+    /// The scale factor of the gas price used by the `v1` algorithm.
+    #[arg(long = "gas-price-factor", default_value = "100", env)]
+    pub gas_price_factor: NonZeroU64,
+
+    /// The minimum allowed DA gas price used by the `v1` algorithm.
+    #[arg(long = "min-da-gas-price", default_value = "0", env)]
+    pub min_da_gas_price: u64,
+
+    /// The maximum percentage change of the DA gas price per block used by the `v1` algorithm.
+    #[arg(
+        long = "da-gas-price-change-percent",
+        default_value_t = Config::DEFAULT_MAX_DA_GAS_PRICE_CHANGE_PERCENT,
+        env
+    )]
+    pub max_da_gas_price_change_percent: u16,
+
+    /// The proportional gain of the DA gas price controller used by the `v1` algorithm.
+    #[arg(
+        long = "da-gas-price-p-component",
+        default_value_t = Config::DEFAULT_DA_P_COMPONENT,
+        env
+    )]
+    pub da_p_component: i64,
+
+    /// The derivative gain of the DA gas price controller used by the `v1` algorithm.
+    #[arg(long = "da-gas-price-d-component", default_value = "0", env)]
+    pub da_d_component: i64,
+
+    /// The URL of the block committer API, which reports the costs of the DA commitments.
+    /// Required by the `v1` algorithm.
+    #[arg(
+        long = "da-committer-url",
+        required_if_eq("gas_price_algorithm", "v1"),
+        env
+    )]
+    pub da_committer_url: Option<String>,
+
+    /// The interval at which the costs are requested from the block committer.
+    #[arg(long = "da-poll-interval", env)]
+    pub da_poll_interval: Option<humantime::Duration>,
+
     /// The signing key used when producing blocks.
     /// Setting via the `CONSENSUS_KEY_SECRET` ENV var is preferred.
     #[arg(long = "consensus-key", env = "CONSENSUS_KEY_SECRET")]
     pub consensus_key: Option<String>,
 
+    /// The encrypted keystore file with the signing key used when producing blocks.
+    /// It can be created by the `fuel-core-keygen new --keystore`.
+    #[arg(
+        long = "consensus-keystore",
+        env,
+        conflicts_with = "consensus_key",
+        requires = "consensus_keystore_password_file"
+    )]
+    pub consensus_keystore: Option<PathBuf>,
+
+    /// The file with the password of the `consensus-keystore`.
+    #[arg(
+        long = "consensus-keystore-password-file",
+        env,
+        requires = "consensus_keystore"
+    )]
+    pub consensus_keystore_password_file: Option<PathBuf>,
+
     /// Use [AWS KMS](https://docs.aws.amazon.com/kms/latest/APIReference/Welcome.html)for signing blocks.
     /// Loads the AWS credentials and configuration from the environment.
     /// Takes key_id as an argument, e.g. key ARN works.
@@ -217,6 +343,9 @@ pub struct Command {
     #[cfg(feature = "aws-kms")]
     pub consensus_aws_kms: Option<String>,
 
+    #[clap(flatten)]
+    pub remote_signer: RemoteSignerArgs,
+
     /// If given, the node will produce and store da-compressed blocks
     /// with the given retention time.
     #[arg(long = "da-compression", env)]
@@ -293,6 +422,12 @@ impl Command {
             #[cfg(feature = "rocksdb")]
             state_rewind_duration,
             db_prune,
+            #[cfg(feature = "rocksdb")]
+            read_replica_of,
+            #[cfg(feature = "rocksdb")]
+            read_replica_catch_up_interval,
+            #[cfg(feature = "rocksdb")]
+            reindex: _,
             snapshot,
             continue_on_error,
             vm_backtrace,
@@ -303,9 +438,20 @@ impl Command {
             gas_price_change_percent,
             min_gas_price,
             gas_price_threshold_percent,
+            gas_price_algorithm,
+            gas_price_factor,
+            min_da_gas_price,
+            max_da_gas_price_change_percent,
+            da_p_component,
+            da_d_component,
+            da_committer_url,
+            da_poll_interval,
             consensus_key,
+            consensus_keystore,
+            consensus_keystore_password_file,
             #[cfg(feature = "aws-kms")]
             consensus_aws_kms,
+            remote_signer,
             da_compression,
             poa_trigger,
             predefined_blocks_path,
@@ -354,6 +500,8 @@ impl Command {
             chain_config.chain_name.clone(),
             disabled_metrics.is_enabled(Module::P2P),
         )?;
+        #[cfg(feature = "p2p")]
+        let state_sync = sync_args.state_sync_config();
 
         let trigger: Trigger = poa_trigger.into();
 
@@ -386,11 +534,33 @@ impl Command {
             };
         }
 
+        if let Some(config) = remote_signer.into_config() {
+            // The consensus key may come from the environment, so the conflict is
+            // checked here as well as by the parser.
+            anyhow::ensure!(
+                !consensus_signer.is_available()
+                    && consensus_key.is_none()
+                    && consensus_keystore.is_none(),
+                "the remote signer can't be combined with other signers"
+            );
+            let signer = RemoteSigner::connect(config)
+                .await
+                .context("failed to connect to the remote signer")?;
+            consensus_signer = SignMode::Remote(signer);
+        }
+
         if matches!(consensus_signer, SignMode::Unavailable) {
             if let Some(consensus_key) = consensus_key {
                 let key = SecretKey::from_str(&consensus_key)
                     .context("failed to parse consensus signing key")?;
                 consensus_signer = SignMode::Key(Secret::new(key.into()));
+            } else if let (Some(keystore), Some(password_file)) =
+                (consensus_keystore, consensus_keystore_password_file)
+            {
+                let password = read_password_file(&password_file)?;
+                let key = decrypt_secret(&keystore, &password)
+                    .context("failed to decrypt consensus signing key")?;
+                consensus_signer = SignMode::Key(Secret::new(key.into()));
             } else if debug {
                 // if consensus key is not configured, fallback to dev consensus key
                 let key = default_consensus_dev_key();
@@ -435,24 +605,19 @@ impl Command {
                 tracing::warn!("State rewind policy is only supported with RocksDB");
             }
 
-            let blocks = state_rewind_duration.as_secs();
-
-            if blocks == 0 {
-                StateRewindPolicy::NoRewind
-            } else {
-                let maximum_blocks: humantime::Duration = "136y".parse()?;
-
-                if blocks >= maximum_blocks.as_secs() {
-                    StateRewindPolicy::RewindFullRange
-                } else {
-                    StateRewindPolicy::RewindRange {
-                        size: NonZeroU64::new(blocks)
-                            .expect("The value is not zero above"),
-                    }
-                }
-            }
+            state_rewind_policy_from_duration(state_rewind_duration)?
         };
 
+        #[cfg(feature = "rocksdb")]
+        let read_replica = read_replica_of.map(|primary_path| {
+            fuel_core::combined_database::ReadReplicaConfig {
+                primary_path,
+                catch_up_interval: read_replica_catch_up_interval.into(),
+            }
+        });
+        #[cfg(not(feature = "rocksdb"))]
+        let read_replica = None;
+
         let combined_db_config = CombinedDatabaseConfig {
             database_path,
             database_type,
@@ -461,6 +626,7 @@ impl Command {
             state_rewind_policy,
             #[cfg(feature = "rocksdb")]
             max_fds: rocksdb_max_fds,
+            read_replica,
         };
 
         let block_importer = fuel_core::service::config::fuel_core_importer::Config::new(
@@ -482,6 +648,9 @@ impl Command {
             tx_max_number,
             tx_max_total_bytes,
             tx_max_total_gas,
+            tx_max_number_per_owner,
+            tx_max_total_gas_per_owner,
+            tx_max_pending_number_per_peer,
             tx_max_chain_count,
             tx_number_active_subscriptions,
             tx_blacklist_addresses,
@@ -494,6 +663,9 @@ impl Command {
             tx_size_of_p2p_sync_queue,
             tx_max_pending_read_requests,
             tx_max_pending_write_requests,
+            tx_pool_journal_path,
+            tx_selection_policy,
+            tx_max_txs_per_owner_per_block,
         } = tx_pool;
 
         let black_list = BlackList::new(
@@ -509,6 +681,12 @@ impl Command {
             max_bytes_size: tx_max_total_bytes,
         };
 
+        let sender_limits = SenderLimits {
+            max_txs_per_owner: tx_max_number_per_owner,
+            max_gas_per_owner: tx_max_total_gas_per_owner,
+            max_pending_txs_per_peer: tx_max_pending_number_per_peer,
+        };
+
         let pool_heavy_work_config = HeavyWorkConfig {
             number_threads_to_verify_transactions:
                 tx_number_threads_to_verify_transactions,
@@ -522,6 +700,7 @@ impl Command {
             max_pending_write_pool_requests: tx_max_pending_write_requests,
         };
 
+        let anonymous_role = graphql.anonymous_role(debug);
         let config = Config {
             graphql_config: GraphQLConfig {
                 addr,
@@ -534,8 +713,20 @@ impl Command {
                     .max_queries_resolver_recursive_depth,
                 max_queries_directives: graphql.max_queries_directives,
                 max_concurrent_queries: graphql.graphql_max_concurrent_queries,
+                max_subscriptions_per_connection: graphql
+                    .graphql_max_subscriptions_per_connection,
                 request_body_bytes_limit: graphql.graphql_request_body_bytes_limit,
                 api_request_timeout: graphql.api_request_timeout.into(),
+                admin_api_token: graphql.admin_api_token,
+                api_keys: graphql
+                    .api_keys
+                    .into_iter()
+                    .map(|api_key| (api_key.key, api_key.role))
+                    .collect(),
+                jwt_secret: graphql.api_jwt_secret,
+                anonymous_role,
+                cors_allowed_origins: graphql.api_cors_origins,
+                admin_addr: graphql.admin_addr,
                 query_log_threshold_time: graphql.query_log_threshold_time.into(),
                 costs: Costs {
                     balance_query: graphql.costs.balance_query,
@@ -546,6 +737,7 @@ impl Command {
                     submit: graphql.costs.submit,
                     submit_and_await: graphql.costs.submit_and_await,
                     status_change: graphql.costs.status_change,
+                    blocks_subscription: graphql.costs.blocks_subscription,
                     storage_read: graphql.costs.storage_read,
                     tx_get: graphql.costs.tx_get,
                     tx_status_read: graphql.costs.tx_status_read,
@@ -559,6 +751,7 @@ impl Command {
                         .costs
                         .state_transition_bytecode_read,
                     da_compressed_block_read: graphql.costs.da_compressed_block_read,
+                    database_checkpoint: graphql.costs.database_checkpoint,
                 },
             },
             combined_db_config,
@@ -580,9 +773,13 @@ impl Command {
                 max_tx_update_subscriptions: tx_number_active_subscriptions,
                 black_list,
                 pool_limits,
+                sender_limits,
                 heavy_work: pool_heavy_work_config,
                 service_channel_limits,
                 metrics: disabled_metrics.is_enabled(Module::TxPool),
+                journal_path: tx_pool_journal_path,
+                selection_policy: tx_selection_policy
+                    .into_selection_policy(tx_max_txs_per_owner_per_block),
             },
             block_producer: ProducerConfig {
                 coinbase_recipient,
@@ -592,6 +789,14 @@ impl Command {
             gas_price_change_percent,
             min_gas_price,
             gas_price_threshold_percent,
+            gas_price_algorithm,
+            gas_price_factor,
+            min_da_gas_price,
+            max_da_gas_price_change_percent,
+            da_p_component,
+            da_d_component,
+            da_committer_url,
+            da_poll_interval: da_poll_interval.map(Into::into),
             block_importer,
             da_compression,
             #[cfg(feature = "relayer")]
@@ -600,7 +805,10 @@ impl Command {
             p2p: p2p_cfg,
             #[cfg(feature = "p2p")]
             sync: sync_args.into(),
+            #[cfg(feature = "p2p")]
+            state_sync,
             consensus_signer,
+            bft_timeouts: Default::default(),
             name,
             relayer_consensus_config: verifier,
             min_connected_reserved_peers,
@@ -611,6 +819,29 @@ impl Command {
     }
 }
 
+/// Converts the state rewind duration into the policy, assuming each block
+/// requires one second to produce.
+#[cfg(feature = "rocksdb")]
+pub(crate) fn state_rewind_policy_from_duration(
+    state_rewind_duration: humantime::Duration,
+) -> anyhow::Result<StateRewindPolicy> {
+    let blocks = state_rewind_duration.as_secs();
+
+    if blocks == 0 {
+        return Ok(StateRewindPolicy::NoRewind)
+    }
+
+    let maximum_blocks: humantime::Duration = "136y".parse()?;
+
+    if blocks >= maximum_blocks.as_secs() {
+        Ok(StateRewindPolicy::RewindFullRange)
+    } else {
+        Ok(StateRewindPolicy::RewindRange {
+            size: NonZeroU64::new(blocks).expect("The value is not zero above"),
+        })
+    }
+}
+
 #[cfg(feature = "rocksdb")]
 fn get_default_max_fds() -> i32 {
     getrlimit(Resource::NOFILE)
@@ -627,6 +858,8 @@ pub async fn get_service_with_shutdown_listeners(
     }
 
     let profiling = command.profiling.clone();
+    #[cfg(feature = "rocksdb")]
+    let reindex = command.reindex.clone();
     let config = command.get_config().await?;
 
     // start profiling agent if url is configured
@@ -635,10 +868,20 @@ pub async fn get_service_with_shutdown_listeners(
     // log fuel-core version
     info!("Fuel Core version v{}", env!("CARGO_PKG_VERSION"));
     trace!("Initializing in TRACE mode.");
+    let mut shutdown_listener = ShutdownListener::spawn();
+
+    #[cfg(feature = "rocksdb")]
+    if let Some(indexes) = reindex {
+        let reindex_config = ReindexConfig::from_node_config(&config, indexes)?;
+        fuel_core::service::reindex::reindex(reindex_config, shutdown_listener.clone())
+            .await?;
+    }
+
     // initialize the server
     let combined_database = CombinedDatabase::from_config(&config.combined_db_config)?;
-
-    let mut shutdown_listener = ShutdownListener::spawn();
+    #[cfg(feature = "p2p")]
+    fuel_core::service::genesis::sync_state_from_peers(&config, &combined_database)
+        .await?;
 
     Ok((
         FuelService::new(combined_database, config, &mut shutdown_listener)?,
@@ -709,7 +952,9 @@ fn start_pyroscope_agent(
 #[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
+    use fuel_core::fuel_core_graphql_api::Role;
     use strum::IntoEnumIterator;
+    use test_case::test_case;
 
     fn parse_command(args: &[&str]) -> anyhow::Result<Command> {
         Ok(Command::try_parse_from([""].iter().chain(args))?)
@@ -784,4 +1029,229 @@ mod tests {
             \n\nFor more information, try '--help'.\n"
         );
     }
+
+    #[test]
+    fn parse_gas_price_algorithm__v1_requires_committer_url() {
+        // Given
+        let args = ["--gas-price-algorithm", "v1"];
+
+        // When
+        let command = parse_command(&args);
+
+        // Then
+        command.expect_err("should fail to parse");
+    }
+
+    #[test]
+    fn parse_gas_price_algorithm__v1_with_committer_url() {
+        // Given
+        let args = [
+            "--gas-price-algorithm",
+            "v1",
+            "--da-committer-url",
+            "http://localhost:8080",
+        ];
+
+        // When
+        let command = parse_command(&args).unwrap();
+
+        // Then
+        assert_eq!(command.gas_price_algorithm, GasPriceAlgorithm::V1);
+        assert_eq!(
+            command.da_committer_url,
+            Some("http://localhost:8080".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_consensus_keystore__requires_password_file() {
+        // Given
+        let args = ["--consensus-keystore", "key.json"];
+
+        // When
+        let command = parse_command(&args);
+
+        // Then
+        command.expect_err("should fail to parse");
+    }
+
+    #[test]
+    fn parse_consensus_keystore__conflicts_with_consensus_key() {
+        // Given
+        let args = [
+            "--consensus-keystore",
+            "key.json",
+            "--consensus-keystore-password-file",
+            "password",
+            "--consensus-key",
+            "0x01",
+        ];
+
+        // When
+        let command = parse_command(&args);
+
+        // Then
+        command.expect_err("should fail to parse");
+    }
+
+    #[test]
+    fn parse_consensus_keystore__with_password_file() {
+        // Given
+        let args = [
+            "--consensus-keystore",
+            "key.json",
+            "--consensus-keystore-password-file",
+            "password",
+        ];
+
+        // When
+        let command = parse_command(&args).unwrap();
+
+        // Then
+        assert_eq!(command.consensus_keystore, Some(PathBuf::from("key.json")));
+        assert_eq!(
+            command.consensus_keystore_password_file,
+            Some(PathBuf::from("password"))
+        );
+    }
+
+    #[test]
+    fn parse_remote_signer__conflicts_with_consensus_key() {
+        // Given
+        let args = [
+            "--consensus-remote-signer",
+            "http://127.0.0.1:4500",
+            "--consensus-key",
+            "0x01",
+        ];
+
+        // When
+        let command = parse_command(&args);
+
+        // Then
+        command.expect_err("should fail to parse");
+    }
+
+    #[test]
+    fn parse_remote_signer__conflicts_with_consensus_keystore() {
+        // Given
+        let args = [
+            "--consensus-remote-signer",
+            "http://127.0.0.1:4500",
+            "--consensus-keystore",
+            "key.json",
+            "--consensus-keystore-password-file",
+            "password",
+        ];
+
+        // When
+        let command = parse_command(&args);
+
+        // Then
+        command.expect_err("should fail to parse");
+    }
+
+    #[test]
+    fn parse_remote_signer__with_defaults() {
+        // Given
+        let args = ["--consensus-remote-signer", "http://127.0.0.1:4500"];
+
+        // When
+        let command = parse_command(&args).unwrap();
+
+        // Then
+        let config = command.remote_signer.into_config().unwrap();
+        assert_eq!(config.url, "http://127.0.0.1:4500");
+        assert_eq!(config.timeout, std::time::Duration::from_secs(5));
+        assert_eq!(config.retries, 3);
+    }
+
+    #[test]
+    fn parse_api_keys__parses_roles_and_keys() {
+        // Given
+        let args = [
+            "--api-key",
+            "submit:first",
+            "--api-key",
+            "admin:second:with:colons",
+        ];
+
+        // When
+        let command = parse_command(&args).unwrap();
+
+        // Then
+        let api_keys: Vec<_> = command
+            .graphql
+            .api_keys
+            .into_iter()
+            .map(|api_key| (api_key.role, api_key.key))
+            .collect();
+        assert_eq!(
+            api_keys,
+            vec![
+                (Role::Submit, "first".to_string()),
+                (Role::Admin, "second:with:colons".to_string()),
+            ]
+        );
+    }
+
+    #[test_case(&[] => Role::Submit; "submit by default")]
+    #[test_case(&["--debug"] => Role::Admin; "admin in debug mode")]
+    #[test_case(&["--api-key", "submit:key"] => Role::Read; "read with api keys")]
+    #[test_case(&["--api-jwt-secret", "secret"] => Role::Read; "read with jwt")]
+    #[test_case(
+        &["--debug", "--api-key", "submit:key"] => Role::Read;
+        "read with api keys in debug mode"
+    )]
+    #[test_case(
+        &["--api-key", "submit:key", "--api-anonymous-role", "submit"] => Role::Submit;
+        "explicit role"
+    )]
+    fn graphql_anonymous_role__depends_on_authentication(args: &[&str]) -> Role {
+        let command = parse_command(args).unwrap();
+        command.graphql.anonymous_role(command.debug)
+    }
+
+    #[test]
+    fn parse_api_keys__fails_with_unknown_role() {
+        // Given
+        let args = ["--api-key", "owner:key"];
+
+        // When
+        let command = parse_command(&args);
+
+        // Then
+        command.expect_err("should fail to parse");
+    }
+
+    #[cfg(feature = "rocksdb")]
+    #[tokio::test]
+    async fn get_config__read_replica_uses_primary_path() {
+        // Given
+        let args = [
+            "--db-path",
+            "/tmp/replica",
+            "--read-replica-of",
+            "/tmp/primary",
+            "--read-replica-catch-up-interval",
+            "500ms",
+        ];
+
+        // When
+        let config = parse_command(&args).unwrap().get_config().await.unwrap();
+
+        // Then
+        assert!(config.is_read_replica());
+        assert_eq!(
+            config.combined_db_config.read_replica,
+            Some(fuel_core::combined_database::ReadReplicaConfig {
+                primary_path: "/tmp/primary".into(),
+                catch_up_interval: std::time::Duration::from_millis(500),
+            })
+        );
+        assert_eq!(
+            config.combined_db_config.database_path,
+            PathBuf::from("/tmp/replica")
+        );
+    }
 }
@@ -5,6 +5,7 @@ use clap::{
     ValueEnum,
 };
 use fuel_core::service::config::Trigger as PoATrigger;
+use fuel_core_poa::signer::RemoteSignerConfig;
 use humantime::Duration;
 
 #[derive(Debug, Clone, clap::Args)]
@@ -63,6 +64,48 @@ struct Interval {
     pub period: Option<Duration>,
 }
 
+/// The remote signer holding the block production key.
+#[derive(Debug, Clone, clap::Args)]
+pub struct RemoteSignerArgs {
+    /// The base URL of the remote signer used to sign the blocks.
+    /// The signer must implement the HTTP/JSON API of the `fuel-core-mock-signer`.
+    #[arg(
+        long = "consensus-remote-signer",
+        env,
+        conflicts_with_all = ["consensus_key", "consensus_keystore"]
+    )]
+    pub url: Option<String>,
+
+    /// The timeout of a single request to the remote signer.
+    #[arg(long = "consensus-remote-signer-timeout", default_value = "5s", env)]
+    pub timeout: Duration,
+
+    /// The number of retries of the failed request to the remote signer.
+    #[arg(long = "consensus-remote-signer-retries", default_value = "3", env)]
+    pub retries: usize,
+
+    /// The interval between the health checks of the remote signer.
+    /// Blocks are not produced while the remote signer is unhealthy.
+    #[arg(
+        long = "consensus-remote-signer-health-interval",
+        default_value = "5s",
+        env
+    )]
+    pub health_check_interval: Duration,
+}
+
+impl RemoteSignerArgs {
+    pub fn into_config(self) -> Option<RemoteSignerConfig> {
+        let url = self.url?;
+        Some(RemoteSignerConfig {
+            url,
+            timeout: self.timeout.into(),
+            retries: self.retries,
+            health_check_interval: self.health_check_interval.into(),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
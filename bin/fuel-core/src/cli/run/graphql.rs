@@ -1,8 +1,14 @@
 //! Clap configuration related to GraphQL service.
 
-use std::net;
+use std::{
+    net,
+    str::FromStr,
+};
 
-use fuel_core::fuel_core_graphql_api::DEFAULT_QUERY_COSTS;
+use fuel_core::fuel_core_graphql_api::{
+    Role,
+    DEFAULT_QUERY_COSTS,
+};
 
 #[derive(Debug, Clone, clap::Args)]
 pub struct GraphQLArgs {
@@ -50,6 +56,16 @@ pub struct GraphQLArgs {
     #[clap(long = "graphql-max-concurrent-queries", default_value = "1024", env)]
     pub graphql_max_concurrent_queries: usize,
 
+    /// The max number of active subscriptions over one WebSocket connection.
+    /// It prevents a single client from taking all transaction status subscriptions
+    /// allowed by the `--tx-number-active-subscriptions`.
+    #[clap(
+        long = "graphql-max-subscriptions-per-connection",
+        default_value = "32",
+        env
+    )]
+    pub graphql_max_subscriptions_per_connection: usize,
+
     /// The max body limit of the GraphQL query.
     #[clap(
         long = "graphql-request-body-bytes-limit",
@@ -66,10 +82,85 @@ pub struct GraphQLArgs {
     #[clap(long = "api-request-timeout", default_value = "30s", env)]
     pub api_request_timeout: humantime::Duration,
 
+    /// The bearer token granting the admin role, required by the admin API like
+    /// the management of the TxPool blacklist.
+    #[clap(long = "admin-api-token", env)]
+    pub admin_api_token: Option<String>,
+
+    /// The API key accepted as the bearer token in the `<role>:<key>` format,
+    /// where the role is `read`, `submit` or `admin`. Can be specified multiple times.
+    #[clap(long = "api-key", value_delimiter = ',', env = "API_KEYS")]
+    pub api_keys: Vec<ApiKey>,
+
+    /// The secret used to verify the HS256 JWT bearer tokens.
+    /// The role is taken from the `role` claim of the token.
+    #[clap(long = "api-jwt-secret", env)]
+    pub api_jwt_secret: Option<String>,
+
+    /// The role of the requests without a bearer token.
+    /// Defaults to `read` if the `--api-key` or the `--api-jwt-secret` is set,
+    /// to `admin` in the `--debug` mode and to `submit` otherwise.
+    #[clap(long = "api-anonymous-role", env)]
+    pub api_anonymous_role: Option<Role>,
+
+    /// The origin allowed to make the cross-origin requests to the API.
+    /// Any origin is allowed if it is not specified. Can be specified multiple times.
+    #[clap(
+        long = "api-cors-origin",
+        value_delimiter = ',',
+        env = "API_CORS_ORIGINS"
+    )]
+    pub api_cors_origins: Vec<String>,
+
+    /// The address of the separate listener serving the admin operations and
+    /// the metrics. If it is set, they are not served on the main address.
+    #[clap(long = "admin-addr", env)]
+    pub admin_addr: Option<net::SocketAddr>,
+
     #[clap(flatten)]
     pub costs: QueryCosts,
 }
 
+impl GraphQLArgs {
+    /// Returns the role of the requests without a bearer token.
+    pub fn anonymous_role(&self, debug: bool) -> Role {
+        // The callers authenticated by the API keys or the JWT shouldn't be
+        // bypassed by the anonymous requests.
+        let is_authenticated = !self.api_keys.is_empty() || self.api_jwt_secret.is_some();
+        match self.api_anonymous_role {
+            Some(role) => role,
+            None if is_authenticated => Role::Read,
+            None if debug => Role::Admin,
+            None => Role::Submit,
+        }
+    }
+}
+
+/// The API key and the role granted by it.
+#[derive(Debug, Clone)]
+pub struct ApiKey {
+    pub role: Role,
+    pub key: String,
+}
+
+impl FromStr for ApiKey {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (role, key) = s
+            .split_once(':')
+            .ok_or("The API key should be in the `<role>:<key>` format")?;
+        let role = Role::from_str(role).map_err(|_| format!("Unknown role `{role}`"))?;
+        if key.is_empty() {
+            return Err("The API key is empty".to_string())
+        }
+        Ok(Self {
+            role,
+            key: key.to_string(),
+        })
+    }
+}
+
 /// Costs for individual graphql queries.
 #[derive(Debug, Clone, clap::Args)]
 pub struct QueryCosts {
@@ -136,6 +227,14 @@ pub struct QueryCosts {
     )]
     pub status_change: usize,
 
+    /// Query costs for the blocks subscription.
+    #[clap(
+        long = "query-cost-blocks-subscription",
+        default_value = DEFAULT_QUERY_COSTS.blocks_subscription.to_string(),
+        env
+    )]
+    pub blocks_subscription: usize,
+
     /// Query costs for reading from storage.
     #[clap(
         long = "query-cost-storage-read",
@@ -223,4 +322,12 @@ pub struct QueryCosts {
         env
     )]
     pub da_compressed_block_read: usize,
+
+    /// Query costs for creating a database checkpoint.
+    #[clap(
+        long = "query-cost-database-checkpoint",
+        default_value = DEFAULT_QUERY_COSTS.database_checkpoint.to_string(),
+        env
+    )]
+    pub database_checkpoint: usize,
 }
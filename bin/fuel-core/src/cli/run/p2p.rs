@@ -15,6 +15,7 @@ use fuel_core::{
         heartbeat,
         Multiaddr,
     },
+    service::config::StateSyncConfig,
     types::{
         fuel_crypto,
         fuel_crypto::SecretKey,
@@ -207,6 +208,27 @@ pub struct SyncArgs {
     /// The maximum number of headers to request in a single batch.
     #[clap(long = "sync-header-batch-size", default_value = "10", env)]
     pub header_batch_size: u32,
+    /// Initializes the empty database from the state of peers instead of
+    /// the genesis state from the snapshot. The chain config must contain
+    /// the current consensus parameters of the network.
+    /// Only the history of blocks is verified, the rest of the state,
+    /// including contract states and balances, is trusted from the reserved
+    /// nodes, so at least one reserved node is required.
+    #[clap(long = "sync-state-from-peers", env)]
+    pub state_from_peers: bool,
+    /// The number of blocks behind the tip of peers at which the state is synced.
+    /// Peers must still keep the history of the state at that height.
+    #[clap(long = "sync-state-blocks-behind", default_value = "10", env)]
+    pub state_blocks_behind: u32,
+}
+
+impl SyncArgs {
+    pub fn state_sync_config(&self) -> Option<StateSyncConfig> {
+        self.state_from_peers.then(|| StateSyncConfig {
+            blocks_behind_tip: self.state_blocks_behind,
+            ..Default::default()
+        })
+    }
 }
 
 #[derive(Clone, Debug)]
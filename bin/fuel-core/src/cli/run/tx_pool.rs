@@ -1,5 +1,10 @@
 //! Clap configuration related to TxPool service.
 
+use clap::ValueEnum;
+use fuel_core::txpool::config::{
+    Config,
+    SelectionPolicy,
+};
 use fuel_core_types::{
     fuel_tx::{
         Address,
@@ -8,6 +13,7 @@ use fuel_core_types::{
     },
     fuel_types::Nonce,
 };
+use std::path::PathBuf;
 
 #[derive(Debug, Clone, clap::Args)]
 pub struct TxPoolArgs {
@@ -31,6 +37,30 @@ pub struct TxPoolArgs {
     #[clap(long = "tx-max-total-bytes", default_value = "131072000", env)]
     pub tx_max_total_bytes: usize,
 
+    /// The max number of transactions of one owner that the `TxPool` can simultaneously store.
+    #[clap(
+        long = "tx-max-number-per-owner",
+        default_value_t = Config::DEFAULT_MAX_TXS_PER_OWNER,
+        env
+    )]
+    pub tx_max_number_per_owner: usize,
+
+    /// The max number of gas of one owner that the `TxPool` can simultaneously store.
+    #[clap(
+        long = "tx-max-total-gas-per-owner",
+        default_value_t = Config::DEFAULT_MAX_GAS_PER_OWNER,
+        env
+    )]
+    pub tx_max_total_gas_per_owner: u64,
+
+    /// The max number of gossiped transactions of one peer waiting for verification.
+    #[clap(
+        long = "tx-max-pending-number-per-peer",
+        default_value_t = Config::DEFAULT_MAX_PENDING_TXS_PER_PEER,
+        env
+    )]
+    pub tx_max_pending_number_per_peer: usize,
+
     /// The max number of tx in a chain of dependent transactions that supported by the `TxPool`.
     #[clap(long = "tx-max-depth", default_value = "32", env)]
     pub tx_max_chain_count: usize,
@@ -82,6 +112,52 @@ pub struct TxPoolArgs {
     /// Maximum number of pending read requests in the service.
     #[clap(long = "tx-max-pending-read-requests", default_value = "1000", env)]
     pub tx_max_pending_read_requests: usize,
+
+    /// The path to the file journaling the transactions accepted by the `TxPool`.
+    /// When set, the transactions are restored from it after the restart of the node.
+    #[clap(long = "tx-pool-journal-path", env)]
+    pub tx_pool_journal_path: Option<PathBuf>,
+
+    /// The policy used to select transactions from the `TxPool` for the block.
+    #[clap(
+        long = "tx-selection-policy",
+        default_value = "ratio-tip-gas",
+        value_enum,
+        ignore_case = true,
+        env
+    )]
+    pub tx_selection_policy: TxSelectionPolicy,
+
+    /// The max number of transactions of the same owner included in one block
+    /// by the `fairness` selection policy.
+    #[clap(long = "tx-max-txs-per-owner-per-block", default_value = "10", env)]
+    pub tx_max_txs_per_owner_per_block: usize,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum TxSelectionPolicy {
+    /// Selects transactions with the highest tip/gas ratio first.
+    RatioTipGas,
+    /// Selects transactions in the order of their submission.
+    Fifo,
+    /// Selects transactions with the highest tip/byte ratio first.
+    TipPerByte,
+    /// Selects transactions with the highest tip/gas ratio first, limiting
+    /// the number of transactions of the same owner in the block.
+    Fairness,
+}
+
+impl TxSelectionPolicy {
+    pub fn into_selection_policy(self, max_txs_per_owner: usize) -> SelectionPolicy {
+        match self {
+            TxSelectionPolicy::RatioTipGas => SelectionPolicy::RatioTipGas,
+            TxSelectionPolicy::Fifo => SelectionPolicy::Fifo,
+            TxSelectionPolicy::TipPerByte => SelectionPolicy::TipPerByte,
+            TxSelectionPolicy::Fairness => {
+                SelectionPolicy::Fairness { max_txs_per_owner }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -141,4 +217,21 @@ mod tests {
 
         Ok(blacklist)
     }
+
+    #[test_case(&[""] => Ok(SelectionPolicy::RatioTipGas); "defaults to ratio tip gas")]
+    #[test_case(&["", "--tx-selection-policy=fifo"] => Ok(SelectionPolicy::Fifo); "fifo works")]
+    #[test_case(&["", "--tx-selection-policy=TIP-PER-BYTE"] => Ok(SelectionPolicy::TipPerByte); "tip per byte works")]
+    #[test_case(&["", "--tx-selection-policy=fairness"]
+        => Ok(SelectionPolicy::Fairness { max_txs_per_owner: 10 }); "fairness works")]
+    #[test_case(&["", "--tx-selection-policy=fairness", "--tx-max-txs-per-owner-per-block=3"]
+        => Ok(SelectionPolicy::Fairness { max_txs_per_owner: 3 }); "fairness with limit works")]
+    fn parse_selection_policy(args: &[&str]) -> Result<SelectionPolicy, String> {
+        let command: Command =
+            Command::try_parse_from(args).map_err(|e| e.to_string())?;
+        let args = command.tx_pool;
+
+        Ok(args
+            .tx_selection_policy
+            .into_selection_policy(args.tx_max_txs_per_owner_per_block))
+    }
 }
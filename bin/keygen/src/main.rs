@@ -1,19 +1,34 @@
 //! A simple keygen cli utility tool for configuring fuel-core
+use anyhow::Context;
 use atty::Stream;
 use clap::Parser;
 use crossterm::terminal;
 use fuel_core_keygen::{
+    export_secret,
+    import_secret,
+    keystore::{
+        change_password,
+        read_password_file,
+    },
     new_key,
+    new_keystore,
     parse_secret,
     KeyType,
 };
-use std::io::{
-    stdin,
-    stdout,
-    Read,
-    Write,
+use std::{
+    io::{
+        stdin,
+        stdout,
+        BufRead,
+        Read,
+        Write,
+    },
+    path::PathBuf,
+};
+use termion::{
+    input::TermRead,
+    screen::IntoAlternateScreen,
 };
-use termion::screen::IntoAlternateScreen;
 
 /// Parse a secret key to view the associated public key
 #[derive(Debug, clap::Args)]
@@ -47,6 +62,95 @@ pub struct NewKey {
         default_value = <KeyType as std::convert::Into<&'static str>>::into(KeyType::BlockProduction),
     )]
     pub key_type: KeyType,
+    /// Store the secret in the encrypted keystore file in this directory
+    /// instead of printing it.
+    #[clap(long = "keystore")]
+    pub keystore: Option<PathBuf>,
+    /// The name of the keystore file. A random UUID is used if not specified.
+    #[clap(long = "name", requires = "keystore")]
+    pub name: Option<String>,
+    #[clap(flatten)]
+    pub password: PasswordArgs,
+}
+
+/// Encrypt an existing secret key into the keystore file.
+/// The secret is read from the standard input.
+#[derive(Debug, clap::Args)]
+pub struct ImportKey {
+    /// The directory to store the keystore file in.
+    #[clap(long = "keystore")]
+    pub keystore: PathBuf,
+    /// The name of the keystore file. A random UUID is used if not specified.
+    #[clap(long = "name")]
+    pub name: Option<String>,
+    /// Print the JSON in pretty format
+    #[clap(long = "pretty", short = 'p')]
+    pub pretty: bool,
+    /// Key type of the imported secret. It can either be `block-production` or `peering`.
+    #[clap(
+        long = "key-type",
+        short = 'k',
+        value_enum,
+        default_value = <KeyType as std::convert::Into<&'static str>>::into(KeyType::BlockProduction),
+    )]
+    pub key_type: KeyType,
+    #[clap(flatten)]
+    pub password: PasswordArgs,
+}
+
+/// Decrypt the secret key from the keystore file
+#[derive(Debug, clap::Args)]
+pub struct ExportKey {
+    /// The path to the keystore file.
+    pub keystore: PathBuf,
+    /// Print the JSON in pretty format
+    #[clap(long = "pretty", short = 'p')]
+    pub pretty: bool,
+    /// Key type of the exported secret. It can either be `block-production` or `peering`.
+    #[clap(
+        long = "key-type",
+        short = 'k',
+        value_enum,
+        default_value = <KeyType as std::convert::Into<&'static str>>::into(KeyType::BlockProduction),
+    )]
+    pub key_type: KeyType,
+    #[clap(flatten)]
+    pub password: PasswordArgs,
+}
+
+/// Re-encrypt the keystore file with a new password
+#[derive(Debug, clap::Args)]
+pub struct ChangePassword {
+    /// The path to the keystore file.
+    pub keystore: PathBuf,
+    #[clap(flatten)]
+    pub password: PasswordArgs,
+    /// The file with the new password. The password is prompted if not specified.
+    #[clap(long = "new-password-file")]
+    pub new_password_file: Option<PathBuf>,
+}
+
+#[derive(Debug, clap::Args)]
+pub struct PasswordArgs {
+    /// The file with the keystore password. The password is prompted if not specified.
+    #[clap(long = "password-file")]
+    pub password_file: Option<PathBuf>,
+}
+
+impl PasswordArgs {
+    fn password(&self) -> anyhow::Result<String> {
+        match &self.password_file {
+            Some(path) => read_password_file(path),
+            None => read_hidden("Password"),
+        }
+    }
+
+    fn new_password(&self) -> anyhow::Result<String> {
+        match &self.password_file {
+            Some(path) => read_password_file(path),
+            None => read_new_password(),
+        }
+    }
 }
 
 /// Key management utilities for configuring fuel-core
@@ -55,26 +159,119 @@ pub struct NewKey {
 pub(crate) enum Command {
     New(NewKey),
     Parse(ParseSecret),
+    Import(ImportKey),
+    Export(ExportKey),
+    ChangePassword(ChangePassword),
+}
+
+/// The result of the command to print.
+pub(crate) struct Output {
+    value: serde_json::Value,
+    pretty: bool,
+    /// The output contains the secret and is displayed discreetly.
+    secret: bool,
 }
 
 impl Command {
-    pub(crate) fn exec(&self) -> anyhow::Result<(serde_json::Value, bool)> {
+    pub(crate) fn exec(&self) -> anyhow::Result<Output> {
         match self {
-            Command::New(cmd) => {
-                Ok((serde_json::to_value(new_key(cmd.key_type)?)?, cmd.pretty))
+            Command::New(cmd) => match &cmd.keystore {
+                Some(dir) => {
+                    let password = cmd.password.new_password()?;
+                    let response =
+                        new_keystore(cmd.key_type, dir, cmd.name.as_deref(), &password)?;
+                    Ok(Output {
+                        value: serde_json::to_value(response)?,
+                        pretty: cmd.pretty,
+                        secret: false,
+                    })
+                }
+                None => Ok(Output {
+                    value: serde_json::to_value(new_key(cmd.key_type)?)?,
+                    pretty: cmd.pretty,
+                    secret: true,
+                }),
+            },
+            Command::Parse(cmd) => Ok(Output {
+                value: serde_json::to_value(parse_secret(cmd.key_type, &cmd.secret)?)?,
+                pretty: cmd.pretty,
+                secret: true,
+            }),
+            Command::Import(cmd) => {
+                let secret = read_hidden("Secret key")?;
+                let password = cmd.password.new_password()?;
+                let response = import_secret(
+                    cmd.key_type,
+                    &secret,
+                    &cmd.keystore,
+                    cmd.name.as_deref(),
+                    &password,
+                )?;
+                Ok(Output {
+                    value: serde_json::to_value(response)?,
+                    pretty: cmd.pretty,
+                    secret: false,
+                })
+            }
+            Command::Export(cmd) => {
+                let password = cmd.password.password()?;
+                let response = export_secret(cmd.key_type, &cmd.keystore, &password)?;
+                Ok(Output {
+                    value: serde_json::to_value(response)?,
+                    pretty: cmd.pretty,
+                    secret: true,
+                })
+            }
+            Command::ChangePassword(cmd) => {
+                let password = cmd.password.password()?;
+                let new_password = match &cmd.new_password_file {
+                    Some(path) => read_password_file(path)?,
+                    None => read_new_password()?,
+                };
+                change_password(&cmd.keystore, &password, &new_password)?;
+                Ok(Output {
+                    value: serde_json::json!({ "keystore": cmd.keystore }),
+                    pretty: false,
+                    secret: false,
+                })
             }
-            Command::Parse(cmd) => Ok((
-                serde_json::to_value(parse_secret(cmd.key_type, &cmd.secret)?)?,
-                cmd.pretty,
-            )),
         }
     }
 }
 
 fn main() -> anyhow::Result<()> {
     let cmd = Command::parse();
-    let (result, is_pretty) = cmd.exec()?;
-    print_value(result, is_pretty)
+    let output = cmd.exec()?;
+    if output.secret {
+        print_value(output.value, output.pretty)
+    } else {
+        print_json(output.value, output.pretty)
+    }
+}
+
+/// Reads the line without echoing it if the standard input is a terminal.
+fn read_hidden(prompt: &str) -> anyhow::Result<String> {
+    if atty::is(Stream::Stdin) {
+        let mut stdout = stdout().lock();
+        write!(stdout, "{prompt}: ")?;
+        stdout.flush()?;
+        let line = stdin().lock().read_passwd(&mut stdout)?;
+        writeln!(stdout)?;
+        line.context("the input is interrupted")
+    } else {
+        let mut line = String::new();
+        BufRead::read_line(&mut stdin().lock(), &mut line)?;
+        Ok(line.trim_end_matches(['\r', '\n']).to_string())
+    }
+}
+
+fn read_new_password() -> anyhow::Result<String> {
+    let password = read_hidden("New password")?;
+    if atty::is(Stream::Stdin) {
+        let confirmation = read_hidden("Repeat the new password")?;
+        anyhow::ensure!(password == confirmation, "the passwords don't match");
+    }
+    Ok(password)
 }
 
 fn wait_for_keypress() {
@@ -100,6 +297,16 @@ fn display_string_discreetly(
     Ok(())
 }
 
+fn print_json(output: serde_json::Value, pretty: bool) -> anyhow::Result<()> {
+    let output = if pretty {
+        serde_json::to_string_pretty(&output)
+    } else {
+        serde_json::to_string(&output)
+    }?;
+    println!("{output}");
+    Ok(())
+}
+
 fn print_value(output: serde_json::Value, pretty: bool) -> anyhow::Result<()> {
     let output = if pretty {
         serde_json::to_string_pretty(&output)
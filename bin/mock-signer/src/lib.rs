@@ -0,0 +1,149 @@
+//! The reference implementation of the remote signer API used by the block
+//! producer, see `fuel_core_poa::signer::remote`. The secret key is held in memory,
+//! so it is only intended for tests and as an example for the real integrations.
+
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::cast_possible_truncation)]
+#![deny(warnings)]
+
+use axum::{
+    extract::Extension,
+    http::StatusCode,
+    routing::{
+        get,
+        post,
+    },
+    Json,
+    Router,
+};
+use fuel_core_types::{
+    fuel_crypto::{
+        Message,
+        PublicKey,
+        SecretKey,
+        Signature,
+    },
+    fuel_types::{
+        Bytes32,
+        Bytes64,
+    },
+};
+use serde::Deserialize;
+use serde_json::{
+    json,
+    Value,
+};
+use std::{
+    net::{
+        SocketAddr,
+        TcpListener,
+    },
+    str::FromStr,
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Arc,
+    },
+};
+use tokio::sync::oneshot;
+
+struct State {
+    secret: SecretKey,
+    healthy: AtomicBool,
+}
+
+#[derive(Deserialize)]
+struct SignRequest {
+    digest: String,
+}
+
+/// The running mock signer. The server is stopped when it is dropped.
+pub struct MockSigner {
+    state: Arc<State>,
+    address: SocketAddr,
+    _shutdown: oneshot::Sender<()>,
+}
+
+impl MockSigner {
+    /// Starts the server signing with the `secret` on the `address`.
+    pub async fn spawn(secret: SecretKey, address: SocketAddr) -> anyhow::Result<Self> {
+        let state = Arc::new(State {
+            secret,
+            healthy: AtomicBool::new(true),
+        });
+        let listener = TcpListener::bind(address)?;
+        let address = listener.local_addr()?;
+        let (shutdown, stopped) = oneshot::channel::<()>();
+
+        let router = Router::new()
+            .route("/v1/public-key", get(public_key))
+            .route("/v1/health", get(health))
+            .route("/v1/sign", post(sign))
+            .layer(Extension(state.clone()));
+        let server = axum::Server::from_tcp(listener)?
+            .serve(router.into_make_service())
+            .with_graceful_shutdown(async move {
+                let _ = stopped.await;
+            });
+        tokio::spawn(server);
+
+        Ok(Self {
+            state,
+            address,
+            _shutdown: shutdown,
+        })
+    }
+
+    /// The address the server is listening on.
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// The base URL of the remote signer API.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// The public key of the signing key.
+    pub fn public_key(&self) -> PublicKey {
+        self.state.secret.public_key()
+    }
+
+    /// Makes the health check and signing succeed or fail, simulating the outage.
+    pub fn set_healthy(&self, healthy: bool) {
+        self.state.healthy.store(healthy, Ordering::Relaxed);
+    }
+}
+
+async fn public_key(Extension(state): Extension<Arc<State>>) -> Json<Value> {
+    Json(json!({ "public_key": state.secret.public_key().to_string() }))
+}
+
+async fn health(Extension(state): Extension<Arc<State>>) -> StatusCode {
+    if state.healthy.load(Ordering::Relaxed) {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    }
+}
+
+async fn sign(
+    Extension(state): Extension<Arc<State>>,
+    Json(request): Json<SignRequest>,
+) -> Result<Json<Value>, (StatusCode, String)> {
+    if !state.healthy.load(Ordering::Relaxed) {
+        return Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            "signer is unhealthy".into(),
+        ))
+    }
+    let digest = Bytes32::from_str(&request.digest)
+        .map_err(|_| (StatusCode::BAD_REQUEST, "invalid digest".to_string()))?;
+    let message = Message::from_bytes(*digest);
+    let signature = Signature::sign(&state.secret, &message);
+    Ok(Json(
+        json!({ "signature": format!("{:x}", Bytes64::from(*signature)) }),
+    ))
+}
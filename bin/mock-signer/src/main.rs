@@ -0,0 +1,39 @@
+//! Runs the mock remote signer for the block production.
+use clap::Parser;
+use fuel_core_mock_signer::MockSigner;
+use fuel_core_types::fuel_crypto::SecretKey;
+use std::{
+    net::SocketAddr,
+    str::FromStr,
+};
+
+/// The remote signer holding the block production key in memory. For tests only.
+#[derive(Debug, Parser)]
+#[clap(name = "fuel-core-mock-signer", author, version, about)]
+struct Cli {
+    /// The secret key in hex format used to sign the blocks.
+    #[clap(
+        long = "secret-key",
+        env = "MOCK_SIGNER_SECRET_KEY",
+        hide_env_values = true
+    )]
+    secret_key: String,
+    /// The address to listen on.
+    #[clap(long = "address", default_value = "127.0.0.1:4500")]
+    address: SocketAddr,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    let secret = SecretKey::from_str(&cli.secret_key)
+        .map_err(|_| anyhow::anyhow!("invalid secret key"))?;
+    let signer = MockSigner::spawn(secret, cli.address).await?;
+    println!(
+        "Listening on {} with the public key {}",
+        signer.url(),
+        signer.public_key()
+    );
+    tokio::signal::ctrl_c().await?;
+    Ok(())
+}
@@ -16,8 +16,18 @@ use fuel_core_chain_config::default_consensus_dev_key;
 
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 pub enum ConsensusConfig {
-    PoA { signing_key: Address },
+    PoA {
+        signing_key: Address,
+    },
     PoAV2(PoAV2),
+    /// Tendermint-style consensus of multiple validators.
+    Bft {
+        /// Addresses of the validators. The proposer of the round is
+        /// selected from the list in the round-robin order.
+        validators: Vec<Address>,
+        /// The number of validators' signatures required to commit the block.
+        threshold: u32,
+    },
 }
 
 impl ConsensusConfig {
@@ -27,6 +37,42 @@ impl ConsensusConfig {
             signing_key_overrides: Default::default(),
         })
     }
+
+    /// Creates the BFT config where the threshold is `2f + 1`, the minimal
+    /// quorum tolerating `f` faulty validators out of `3f + 1`.
+    pub fn bft(validators: Vec<Address>) -> Self {
+        let total = u32::try_from(validators.len()).unwrap_or(u32::MAX);
+        let faulty = total.saturating_sub(1) / 3;
+        ConsensusConfig::Bft {
+            validators,
+            threshold: total.saturating_sub(faulty),
+        }
+    }
+
+    /// Validates the BFT config. The threshold must be more than two thirds of
+    /// the validators, so two conflicting quorums can't be formed with less than
+    /// `f + 1` faulty validators, and reachable by the validators.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if let ConsensusConfig::Bft {
+            validators,
+            threshold,
+        } = self
+        {
+            let total = u64::try_from(validators.len()).unwrap_or(u64::MAX);
+            let threshold = u64::from(*threshold);
+            if threshold.saturating_mul(3) <= total.saturating_mul(2) {
+                anyhow::bail!(
+                    "The BFT threshold {threshold} is not above 2/3 of {total} validators"
+                )
+            }
+            if threshold > total {
+                anyhow::bail!(
+                    "The BFT threshold {threshold} exceeds {total} validators"
+                )
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
@@ -73,6 +119,8 @@ impl PoAV2 {
 
 #[cfg(test)]
 mod tests {
+    #![allow(non_snake_case)]
+
     use super::*;
 
     #[test]
@@ -108,4 +156,65 @@ mod tests {
             signing_key_after_30
         );
     }
+
+    #[test]
+    fn bft__threshold_is_two_thirds_plus_one_of_validators() {
+        let validators = |count: u8| (0..count).map(|i| Address::from([i; 32])).collect();
+
+        for (count, expected_threshold) in [(1, 1), (3, 3), (4, 3), (7, 5), (10, 7)] {
+            // When
+            let config = ConsensusConfig::bft(validators(count));
+
+            // Then
+            let ConsensusConfig::Bft { threshold, .. } = config else {
+                panic!("Expected BFT config");
+            };
+            assert_eq!(threshold, expected_threshold, "{count} validators");
+        }
+    }
+
+    #[test]
+    fn validate__accepts_default_bft_threshold() {
+        for count in [1, 3, 4, 7, 10] {
+            // Given
+            let validators = (0..count).map(|i| Address::from([i; 32])).collect();
+            let config = ConsensusConfig::bft(validators);
+
+            // When
+            let result = config.validate();
+
+            // Then
+            assert!(result.is_ok(), "{count} validators");
+        }
+    }
+
+    #[test]
+    fn validate__rejects_bft_threshold_of_two_thirds_or_less() {
+        // Given
+        let config = ConsensusConfig::Bft {
+            validators: (0..3).map(|i| Address::from([i; 32])).collect(),
+            threshold: 2,
+        };
+
+        // When
+        let result = config.validate();
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn validate__rejects_bft_threshold_above_validators_count() {
+        // Given
+        let config = ConsensusConfig::Bft {
+            validators: (0..4).map(|i| Address::from([i; 32])).collect(),
+            threshold: 5,
+        };
+
+        // When
+        let result = config.validate();
+
+        // Then
+        assert!(result.is_err());
+    }
 }
@@ -679,6 +679,8 @@ pub const MAX_GROUP_SIZE: usize = usize::MAX;
 mod tests {
     use std::path::Path;
 
+    use fuel_core_storage::kv_store::StorageColumn;
+
     use crate::{
         ChainConfig,
         Randomize,
@@ -862,6 +864,50 @@ mod tests {
         assert_eq!(coins.into_iter().count(), 0);
     }
 
+    #[test]
+    fn encoded_reader_decodes_groups_of_table_entries() {
+        // given
+        let mut rng = StdRng::seed_from_u64(0);
+        let coins = std::iter::repeat_with(|| TableEntry::<Coins>::randomize(&mut rng))
+            .take(5)
+            .collect_vec();
+        let encoded = coins
+            .chunks(2)
+            .map(|group| {
+                group
+                    .iter()
+                    .map(|entry| postcard::to_allocvec(entry).unwrap())
+                    .collect_vec()
+            })
+            .collect_vec();
+        let tables = [(Coins::column().name(), encoded)].into_iter().collect();
+        let block_config = LastBlockConfig {
+            block_height: 13u32.into(),
+            da_block_height: 14u64.into(),
+            consensus_parameters_version: 1,
+            state_transition_version: 2,
+            blocks_root: Bytes32::from([123; 32]),
+        };
+
+        // when
+        let reader = SnapshotReader::new_encoded(
+            ChainConfig::local_testnet(),
+            tables,
+            Some(block_config),
+        );
+
+        // then
+        let read_coins = reader
+            .read::<Coins>()
+            .unwrap()
+            .into_iter()
+            .map(Result::unwrap)
+            .collect_vec();
+        assert_eq!(read_coins, coins.chunks(2).map(<[_]>::to_vec).collect_vec());
+        assert_eq!(reader.read::<Messages>().unwrap().into_iter().count(), 0);
+        assert_eq!(reader.last_block_config(), Some(&block_config));
+    }
+
     fn assert_roundtrip<T>(
         writer: impl FnOnce(&Path) -> SnapshotWriter,
         reader: impl FnOnce(SnapshotMetadata, usize) -> SnapshotReader,
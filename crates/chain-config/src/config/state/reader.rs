@@ -1,6 +1,8 @@
 use std::fmt::Debug;
 
+use alloc::collections::BTreeMap;
 use fuel_core_storage::{
+    kv_store::StorageColumn,
     structured_storage::TableWithBlueprint,
     Mappable,
 };
@@ -113,6 +115,10 @@ enum DataSource {
         state: StateConfig,
         group_size: usize,
     },
+    Encoded {
+        tables: BTreeMap<String, Vec<Vec<Vec<u8>>>>,
+        latest_block_config: Option<LastBlockConfig>,
+    },
 }
 
 #[derive(Clone, Debug)]
@@ -132,6 +138,23 @@ impl SnapshotReader {
         }
     }
 
+    /// Creates a reader over the groups of postcard-encoded table entries,
+    /// keyed by the name of the table column. It is used to import the state
+    /// received from other nodes.
+    pub fn new_encoded(
+        chain_config: ChainConfig,
+        tables: BTreeMap<String, Vec<Vec<Vec<u8>>>>,
+        latest_block_config: Option<LastBlockConfig>,
+    ) -> Self {
+        Self {
+            chain_config,
+            data_source: DataSource::Encoded {
+                tables,
+                latest_block_config,
+            },
+        }
+    }
+
     #[cfg(feature = "test-helpers")]
     pub fn local_testnet() -> Self {
         let state = StateConfig::local_testnet();
@@ -251,7 +274,6 @@ impl SnapshotReader {
             #[cfg(feature = "parquet")]
             DataSource::Parquet { tables, .. } => {
                 use anyhow::Context;
-                use fuel_core_storage::kv_store::StorageColumn;
                 let name = T::column().name();
                 let Some(path) = tables.get(name.as_str()) else {
                     return Ok(Groups {
@@ -280,6 +302,28 @@ impl SnapshotReader {
                     groups: collection.into_iter(),
                 }
             }
+            DataSource::Encoded { tables, .. } => {
+                let groups = tables
+                    .get(T::column().name().as_str())
+                    .map(|groups| {
+                        groups
+                            .iter()
+                            .map(|group| {
+                                group
+                                    .iter()
+                                    .map(|entry| {
+                                        postcard::from_bytes(entry)
+                                            .map_err(|e| anyhow::anyhow!(e))
+                                    })
+                                    .collect()
+                            })
+                            .collect_vec()
+                    })
+                    .unwrap_or_default();
+                GroupIter::InMemory {
+                    groups: groups.into_iter(),
+                }
+            }
         };
 
         Ok(Groups { iter })
@@ -292,6 +336,10 @@ impl SnapshotReader {
     pub fn last_block_config(&self) -> Option<&LastBlockConfig> {
         match &self.data_source {
             DataSource::InMemory { state, .. } => state.last_block.as_ref(),
+            DataSource::Encoded {
+                latest_block_config: block,
+                ..
+            } => block.as_ref(),
             #[cfg(feature = "parquet")]
             DataSource::Parquet {
                 latest_block_config: block,
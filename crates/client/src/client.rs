@@ -16,7 +16,10 @@ use crate::client::{
         TransactionId,
     },
     types::{
-        gas_price::LatestGasPrice,
+        gas_price::{
+            GasPriceHistoryEntry,
+            LatestGasPrice,
+        },
         message::MessageStatus,
         primitives::{
             Address,
@@ -68,6 +71,7 @@ use fuel_core_types::{
 };
 #[cfg(feature = "subscriptions")]
 use futures::{
+    stream::BoxStream,
     Stream,
     StreamExt,
 };
@@ -78,19 +82,33 @@ use pagination::{
     PaginationRequest,
 };
 use schema::{
-    balance::BalanceArgs,
+    balance::{
+        BalanceArgs,
+        BalanceAtHeightArgs,
+    },
     blob::BlobByIdArgs,
-    block::BlockByIdArgs,
+    block::{
+        BlockByIdArgs,
+        BlocksSubscriptionArgs,
+    },
     coins::{
         CoinByIdArgs,
         CoinsConnectionArgs,
     },
     contract::{
+        ContractBalanceAtHeightQueryArgs,
+        ContractBalanceProofArgs,
         ContractBalancesConnectionArgs,
         ContractByIdArgs,
+        ContractStateProofArgs,
+        ContractStorageSlotsConnectionArgs,
+        ContractStorageValuesArgs,
     },
     da_compressed::DaCompressedBlockByHeightArgs,
-    gas_price::BlockHorizonArgs,
+    gas_price::{
+        BlockHorizonArgs,
+        GasPriceHistoryArgs,
+    },
     tx::{
         TransactionsByOwnerConnectionArgs,
         TxArg,
@@ -115,7 +133,10 @@ use schema::{
     U64,
 };
 #[cfg(feature = "subscriptions")]
-use std::future;
+use std::{
+    future,
+    time::Duration,
+};
 use std::{
     convert::TryInto,
     io::{
@@ -149,12 +170,45 @@ pub mod types;
 
 type RegisterId = u32;
 
+/// The number of attempts to restore the subscription in a row before giving up.
+#[cfg(feature = "subscriptions")]
+const MAX_RECONNECT_ATTEMPTS: usize = 5;
+
+/// The delay before the first attempt to restore the subscription.
+/// It doubles with each next attempt in a row up to `RECONNECT_MAX_DELAY`.
+#[cfg(feature = "subscriptions")]
+const RECONNECT_BASE_DELAY: Duration = Duration::from_millis(100);
+
+#[cfg(feature = "subscriptions")]
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(5);
+
+/// Returns the delay before the `attempt` to restore the subscription,
+/// starting from the first one.
+#[cfg(feature = "subscriptions")]
+fn reconnect_delay(attempt: usize) -> Duration {
+    let factor = u32::try_from(attempt.saturating_sub(1))
+        .ok()
+        .and_then(|exponent| 2u32.checked_pow(exponent))
+        .unwrap_or(u32::MAX);
+    RECONNECT_BASE_DELAY.saturating_mul(factor).min(RECONNECT_MAX_DELAY)
+}
+
+#[cfg(feature = "subscriptions")]
+struct BlocksSubscriptionState<'a> {
+    /// The height of the next expected block.
+    next_height: BlockHeight,
+    stream: Option<BoxStream<'a, io::Result<types::FullBlock>>>,
+    reconnect_attempts: usize,
+    finished: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct FuelClient {
     client: reqwest::Client,
     #[cfg(feature = "subscriptions")]
     cookie: std::sync::Arc<reqwest::cookie::Jar>,
     url: reqwest::Url,
+    bearer_token: Option<String>,
 }
 
 impl FromStr for FuelClient {
@@ -182,13 +236,18 @@ impl FromStr for FuelClient {
                 client,
                 cookie,
                 url,
+                bearer_token: None,
             })
         }
 
         #[cfg(not(feature = "subscriptions"))]
         {
             let client = reqwest::Client::new();
-            Ok(Self { client, url })
+            Ok(Self {
+                client,
+                url,
+                bearer_token: None,
+            })
         }
     }
 }
@@ -221,6 +280,13 @@ impl FuelClient {
         Self::from_str(url.as_ref())
     }
 
+    /// Sends the `token` in the `Authorization: Bearer` header of the queries.
+    /// It is required by the admin API of the node.
+    pub fn with_bearer_token(mut self, token: impl Into<String>) -> Self {
+        self.bearer_token = Some(token.into());
+        self
+    }
+
     /// Send the GraphQL query to the client.
     pub async fn query<ResponseData, Vars>(
         &self,
@@ -230,9 +296,11 @@ impl FuelClient {
         Vars: serde::Serialize,
         ResponseData: serde::de::DeserializeOwned + 'static,
     {
-        let response = self
-            .client
-            .post(self.url.clone())
+        let mut request = self.client.post(self.url.clone());
+        if let Some(token) = &self.bearer_token {
+            request = request.bearer_auth(token);
+        }
+        let response = request
             .run_graphql(q)
             .await
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
@@ -402,6 +470,20 @@ impl FuelClient {
         self.query(query).await.map(|r| r.estimate_gas_price)
     }
 
+    /// Returns the gas prices calculated for the blocks in the `[from_height, to_height]` range.
+    pub async fn gas_price_history(
+        &self,
+        from_height: BlockHeight,
+        to_height: BlockHeight,
+    ) -> io::Result<Vec<GasPriceHistoryEntry>> {
+        let query = schema::gas_price::QueryGasPriceHistory::build(GasPriceHistoryArgs {
+            from_height: from_height.into(),
+            to_height: to_height.into(),
+        });
+        let history = self.query(query).await?.gas_price_history;
+        Ok(history.into_iter().map(Into::into).collect())
+    }
+
     #[cfg(feature = "std")]
     pub async fn connected_peers_info(
         &self,
@@ -774,6 +856,160 @@ impl FuelClient {
         Ok(stream)
     }
 
+    /// Subscribes to the stream of blocks with their transactions and statuses.
+    /// The stream starts from the `from_height` if it is specified,
+    /// otherwise from the block after the latest one.
+    ///
+    /// If the connection drops, the stream reconnects to the node with
+    /// the exponential backoff and continues from the block after the last
+    /// received one, so no blocks are skipped or repeated.
+    #[cfg(feature = "subscriptions")]
+    pub async fn subscribe_blocks(
+        &self,
+        from_height: Option<BlockHeight>,
+    ) -> io::Result<impl Stream<Item = io::Result<types::FullBlock>> + '_> {
+        // The start height is fixed before subscribing, so the blocks produced
+        // before the first one is received are not skipped after the reconnect.
+        let from_height = match from_height {
+            Some(height) => height,
+            None => {
+                let latest_height = self.chain_info().await?.latest_block.header.height;
+                latest_height.saturating_add(1).into()
+            }
+        };
+        let stream = self.blocks_subscription(Some(from_height)).await?;
+        let state = BlocksSubscriptionState {
+            next_height: from_height,
+            stream: Some(stream),
+            reconnect_attempts: 0,
+            finished: false,
+        };
+
+        Ok(futures::stream::unfold(
+            state,
+            move |mut state| async move {
+                if state.finished {
+                    return None
+                }
+
+                loop {
+                    let mut stream = match state.stream.take() {
+                        Some(stream) => stream,
+                        None => {
+                            let delay = reconnect_delay(state.reconnect_attempts);
+                            tokio::time::sleep(delay).await;
+                            let from_height = Some(state.next_height);
+                            match self.blocks_subscription(from_height).await {
+                                Ok(stream) => stream,
+                                Err(err) => {
+                                    if state.reconnect_attempts
+                                        >= MAX_RECONNECT_ATTEMPTS
+                                    {
+                                        state.finished = true;
+                                        return Some((Err(err), state))
+                                    }
+                                    state.reconnect_attempts =
+                                        state.reconnect_attempts.saturating_add(1);
+                                    continue
+                                }
+                            }
+                        }
+                    };
+
+                    let next = stream.next().await;
+                    match next {
+                        Some(Ok(block)) => {
+                            state.reconnect_attempts = 0;
+                            state.stream = Some(stream);
+                            let height = BlockHeight::from(block.block.header.height);
+                            if height < state.next_height {
+                                // The block is already returned before the reconnect.
+                                continue
+                            }
+                            state.next_height = height.succ().unwrap_or(height);
+                            return Some((Ok(block), state))
+                        }
+                        Some(Err(err)) => {
+                            tracing::warn!("The blocks subscription failed: {err:?}");
+                            if state.reconnect_attempts >= MAX_RECONNECT_ATTEMPTS {
+                                state.finished = true;
+                                return Some((Err(err), state))
+                            }
+                        }
+                        None => {
+                            tracing::debug!(
+                                "The blocks subscription was closed by the node"
+                            );
+                            if state.reconnect_attempts >= MAX_RECONNECT_ATTEMPTS {
+                                return None
+                            }
+                        }
+                    }
+
+                    state.reconnect_attempts =
+                        state.reconnect_attempts.saturating_add(1);
+                }
+            },
+        ))
+    }
+
+    /// Subscribes to the stream of the gas prices calculated after each new block.
+    #[cfg(feature = "subscriptions")]
+    pub async fn subscribe_gas_prices(
+        &self,
+    ) -> io::Result<impl Stream<Item = io::Result<GasPriceHistoryEntry>>> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::gas_price::GasPricesSubscription::build(());
+
+        let stream = self.subscribe(s).await?.map(
+            |r: io::Result<schema::gas_price::GasPricesSubscription>| {
+                let entry: GasPriceHistoryEntry = r?.gas_prices.into();
+                Result::<_, io::Error>::Ok(entry)
+            },
+        );
+
+        Ok(stream)
+    }
+
+    /// Subscribes to the stream of the transactions accepted by the transaction pool.
+    #[cfg(feature = "subscriptions")]
+    pub async fn subscribe_new_pending_transactions(
+        &self,
+    ) -> io::Result<impl Stream<Item = io::Result<types::PendingTransaction>>> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::tx_pool::NewPendingTransactionsSubscription::build(());
+
+        let stream = self.subscribe(s).await?.map(
+            |r: io::Result<schema::tx_pool::NewPendingTransactionsSubscription>| {
+                let tx: types::PendingTransaction =
+                    r?.new_pending_transactions.try_into()?;
+                Result::<_, io::Error>::Ok(tx)
+            },
+        );
+
+        Ok(stream)
+    }
+
+    #[cfg(feature = "subscriptions")]
+    async fn blocks_subscription(
+        &self,
+        from_height: Option<BlockHeight>,
+    ) -> io::Result<BoxStream<'_, io::Result<types::FullBlock>>> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::block::BlocksSubscription::build(BlocksSubscriptionArgs {
+            from_height: from_height.map(|height| U32(height.into())),
+        });
+
+        let stream = self.subscribe(s).await?.map(
+            |r: io::Result<schema::block::BlocksSubscription>| {
+                let block: types::FullBlock = r?.blocks.try_into()?;
+                Result::<_, io::Error>::Ok(block)
+            },
+        );
+
+        Ok(stream.boxed())
+    }
+
     #[cfg(feature = "subscriptions")]
     /// Awaits for the transaction to be committed into a block
     ///
@@ -829,6 +1065,76 @@ impl FuelClient {
         Ok(transactions)
     }
 
+    /// Returns the current usage of the transaction pool along with its limits.
+    pub async fn tx_pool_stats(&self) -> io::Result<types::TxPoolStats> {
+        let query = schema::tx_pool::TxPoolStatsQuery::build(());
+        let stats = self.query(query).await?.tx_pool_stats.into();
+        Ok(stats)
+    }
+
+    /// Returns the blacklist of the transaction pool.
+    /// Requires the admin API token, see [`FuelClient::with_bearer_token`].
+    pub async fn tx_pool_blacklist(&self) -> io::Result<types::TxPoolBlackList> {
+        let query = schema::tx_pool::TxPoolBlackListQuery::build(());
+        let blacklist = self.query(query).await?.tx_pool_blacklist.into();
+        Ok(blacklist)
+    }
+
+    /// Adds the entry to the blacklist of the transaction pool.
+    /// Returns the ids of the transactions evicted from the pool.
+    /// Requires the admin API token, see [`FuelClient::with_bearer_token`].
+    pub async fn add_to_tx_pool_blacklist(
+        &self,
+        entry: types::TxPoolBlackListEntry,
+    ) -> io::Result<Vec<TxId>> {
+        let query = schema::tx_pool::AddToTxPoolBlackListMutation::build(
+            schema::tx_pool::TxPoolBlackListEntryArgs {
+                entry: entry.into(),
+            },
+        );
+        let evicted = self.query(query).await?.add_to_tx_pool_blacklist;
+        Ok(evicted.into_iter().map(Into::into).collect())
+    }
+
+    /// Removes the entry from the blacklist of the transaction pool.
+    /// Returns `false` if the entry was not blacklisted.
+    /// Requires the admin API token, see [`FuelClient::with_bearer_token`].
+    pub async fn remove_from_tx_pool_blacklist(
+        &self,
+        entry: types::TxPoolBlackListEntry,
+    ) -> io::Result<bool> {
+        let query = schema::tx_pool::RemoveFromTxPoolBlackListMutation::build(
+            schema::tx_pool::TxPoolBlackListEntryArgs {
+                entry: entry.into(),
+            },
+        );
+        let removed = self.query(query).await?.remove_from_tx_pool_blacklist;
+        Ok(removed)
+    }
+
+    /// Returns a paginated set of transactions from the transaction pool sorted by their ids.
+    /// Transactions can be filtered by the owner of the inputs, the spent or created asset
+    /// and the minimal tip.
+    pub async fn pending_transactions(
+        &self,
+        owner: Option<&Address>,
+        asset_id: Option<&AssetId>,
+        min_tip: Option<u64>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::PendingTransaction, String>> {
+        let filter = schema::tx_pool::PendingTransactionsFilterInput {
+            owner: owner.map(|owner| (*owner).into()),
+            asset_id: asset_id.map(|asset_id| (*asset_id).into()),
+            min_tip: min_tip.map(Into::into),
+        };
+        let args =
+            schema::tx_pool::PendingTransactionsConnectionArgs::from((filter, request));
+        let query = schema::tx_pool::PendingTransactionsQuery::build(args);
+
+        let transactions = self.query(query).await?.pending_transactions.try_into()?;
+        Ok(transactions)
+    }
+
     pub async fn receipts(&self, id: &TxId) -> io::Result<Option<Vec<Receipt>>> {
         let query =
             schema::tx::TransactionStatusQuery::build(TxIdArgs { id: (*id).into() });
@@ -1065,6 +1371,32 @@ impl FuelClient {
         Ok(balance.amount)
     }
 
+    /// Returns the balance of the contract at the block `height`.
+    /// The node should keep the history for the `height` to answer the query.
+    pub async fn contract_balance_at_height(
+        &self,
+        id: &ContractId,
+        asset: Option<&AssetId>,
+        height: BlockHeight,
+    ) -> io::Result<u64> {
+        let asset_id: schema::AssetId = match asset {
+            Some(asset) => (*asset).into(),
+            None => schema::AssetId::default(),
+        };
+
+        let query = schema::contract::ContractBalanceAtHeightQuery::build(
+            ContractBalanceAtHeightQueryArgs {
+                id: (*id).into(),
+                asset: asset_id,
+                at_height: Some((*height).into()),
+            },
+        );
+
+        let balance: types::ContractBalance =
+            self.query(query).await?.contract_balance.into();
+        Ok(balance.amount)
+    }
+
     pub async fn balance(
         &self,
         owner: &Address,
@@ -1080,6 +1412,28 @@ impl FuelClient {
         Ok(balance.amount.try_into().unwrap_or(u64::MAX))
     }
 
+    /// Returns the balance of the `owner` at the block `height`.
+    /// The node should keep the history for the `height` to answer the query.
+    pub async fn balance_at_height(
+        &self,
+        owner: &Address,
+        asset_id: Option<&AssetId>,
+        height: BlockHeight,
+    ) -> io::Result<u64> {
+        let owner: schema::Address = (*owner).into();
+        let asset_id: schema::AssetId = match asset_id {
+            Some(asset_id) => (*asset_id).into(),
+            None => schema::AssetId::default(),
+        };
+        let query = schema::balance::BalanceAtHeightQuery::build(BalanceAtHeightArgs {
+            owner,
+            asset_id,
+            at_height: Some((*height).into()),
+        });
+        let balance: types::Balance = self.query(query).await?.balance.into();
+        Ok(balance.amount.try_into().unwrap_or(u64::MAX))
+    }
+
     // Retrieve a page of balances by their owner
     pub async fn balances(
         &self,
@@ -1108,6 +1462,83 @@ impl FuelClient {
         Ok(balances)
     }
 
+    /// Retrieve a page of the contract's storage slots.
+    pub async fn contract_storage_slots(
+        &self,
+        contract: &ContractId,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::ContractStorageSlot, String>> {
+        let contract_id: schema::ContractId = (*contract).into();
+        let args = ContractStorageSlotsConnectionArgs::from((contract_id, request));
+        let query = schema::contract::ContractStorageSlotsQuery::build(args);
+
+        let slots = self.query(query).await?.contract_storage_slots.into();
+
+        Ok(slots)
+    }
+
+    /// Retrieve the values of the contract's storage slots by their `keys`.
+    /// Slots without a value are not returned.
+    pub async fn contract_storage_values(
+        &self,
+        contract: &ContractId,
+        keys: &[Bytes32],
+    ) -> io::Result<Vec<types::ContractStorageSlot>> {
+        let query = schema::contract::ContractStorageValuesQuery::build(
+            ContractStorageValuesArgs {
+                contract: (*contract).into(),
+                keys: keys.iter().map(|key| (*key).into()).collect(),
+            },
+        );
+
+        let slots = self
+            .query(query)
+            .await?
+            .contract_storage_values
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Ok(slots)
+    }
+
+    /// Returns the value of the contract's storage slot along with the proof
+    /// of it in the contract's state tree. Use `ContractStateProof::verify`
+    /// to check the value against the trusted state root of the contract.
+    pub async fn contract_state_proof(
+        &self,
+        contract: &ContractId,
+        key: &Bytes32,
+    ) -> io::Result<types::ContractStateProof> {
+        let query =
+            schema::contract::ContractStateProofQuery::build(ContractStateProofArgs {
+                contract: (*contract).into(),
+                key: (*key).into(),
+            });
+
+        let proof = self.query(query).await?.contract_state_proof.into();
+        Ok(proof)
+    }
+
+    /// Returns the contract's balance of the `asset` along with the proof
+    /// of it in the contract's balances tree. Use `ContractBalanceProof::verify`
+    /// to check the balance against the trusted balance root of the contract.
+    pub async fn contract_balance_proof(
+        &self,
+        contract: &ContractId,
+        asset: &AssetId,
+    ) -> io::Result<types::ContractBalanceProof> {
+        let query = schema::contract::ContractBalanceProofQuery::build(
+            ContractBalanceProofArgs {
+                contract: (*contract).into(),
+                asset: (*asset).into(),
+            },
+        );
+
+        let proof = self.query(query).await?.contract_balance_proof.into();
+        Ok(proof)
+    }
+
     // Retrieve a message by its nonce
     pub async fn message(&self, nonce: &Nonce) -> io::Result<Option<types::Message>> {
         let query = schema::message::MessageQuery::build(NonceArgs {
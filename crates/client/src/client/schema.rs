@@ -40,6 +40,7 @@ pub mod upgrades;
 pub mod gas_price;
 pub mod primitives;
 pub mod tx;
+pub mod tx_pool;
 
 pub mod relayed_tx;
 
@@ -1,6 +1,10 @@
 use super::Bytes32;
 use crate::client::schema::{
     schema,
+    tx::{
+        OpaqueTransaction,
+        TransactionStatus,
+    },
     BlockId,
     ConnectionArgsFields,
     PageInfo,
@@ -153,6 +157,7 @@ pub struct Header {
 pub enum Consensus {
     Genesis(Genesis),
     PoAConsensus(PoAConsensus),
+    BftConsensus(BftConsensus),
     #[cynic(fallback)]
     Unknown,
 }
@@ -173,6 +178,44 @@ pub struct PoAConsensus {
     pub signature: Signature,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BftConsensus {
+    pub round: U32,
+    pub signatures: Vec<Signature>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BlocksSubscriptionArgs {
+    pub from_height: Option<U32>,
+}
+
+/// Block with all its transactions and their statuses
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct FullBlock {
+    pub block: Block,
+    pub transactions: Vec<FullTransaction>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct FullTransaction {
+    pub transaction: OpaqueTransaction,
+    pub status: TransactionStatus,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "BlocksSubscriptionArgs"
+)]
+pub struct BlocksSubscription {
+    #[arguments(fromHeight: $from_height)]
+    pub blocks: FullBlock,
+}
+
 impl Block {
     /// Returns the block producer public key, if any.
     pub fn block_producer(&self) -> Option<fuel_crypto::PublicKey> {
@@ -184,7 +227,8 @@ impl Block {
                 let producer_pub_key = signature.recover(&message);
                 producer_pub_key.ok()
             }
-            Consensus::Unknown => None,
+            // The block is sealed by a quorum of validators instead of one producer.
+            Consensus::BftConsensus(_) | Consensus::Unknown => None,
         }
     }
 }
@@ -227,6 +271,16 @@ mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn blocks_subscription_gql_output() {
+        use cynic::SubscriptionBuilder;
+        let operation = BlocksSubscription::build(BlocksSubscriptionArgs {
+            from_height: Some(U32(0)),
+        });
+        let operation = serde_json::to_value(operation).unwrap();
+        insta::assert_snapshot!(operation["query"].as_str().unwrap())
+    }
+
     #[test]
     fn blocks_connection_query_gql_output() {
         use cynic::QueryBuilder;
@@ -2,10 +2,13 @@ use crate::client::{
     schema::{
         schema,
         AssetId,
+        Bytes32,
         ContractId,
         HexString,
         PageInfo,
         Salt,
+        UtxoId,
+        U32,
         U64,
     },
     PageDirection,
@@ -53,6 +56,24 @@ pub struct ContractBalanceQuery {
     pub contract_balance: ContractBalance,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractBalanceAtHeightQueryArgs {
+    pub id: ContractId,
+    pub asset: AssetId,
+    pub at_height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractBalanceAtHeightQueryArgs"
+)]
+pub struct ContractBalanceAtHeightQuery {
+    #[arguments(contract: $id, asset: $asset, atHeight: $at_height)]
+    pub contract_balance: ContractBalance,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct Contract {
@@ -135,6 +156,157 @@ impl From<(ContractId, PaginationRequest<String>)> for ContractBalancesConnectio
     }
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStorageSlot {
+    pub key: Bytes32,
+    pub value: HexString,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractStorageSlotsConnectionArgs {
+    /// The contract that owns the storage slots
+    pub contract: ContractId,
+    /// Skip until storage slot key (forward pagination)
+    pub after: Option<String>,
+    /// Skip until storage slot key (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n storage slots in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n storage slots in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStorageSlotEdge {
+    pub cursor: String,
+    pub node: ContractStorageSlot,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStorageSlotConnection {
+    pub edges: Vec<ContractStorageSlotEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractStorageSlotsConnectionArgs"
+)]
+pub struct ContractStorageSlotsQuery {
+    #[arguments(contract: $contract, after: $after, before: $before, first: $first, last: $last)]
+    pub contract_storage_slots: ContractStorageSlotConnection,
+}
+
+impl From<(ContractId, PaginationRequest<String>)>
+    for ContractStorageSlotsConnectionArgs
+{
+    fn from(r: (ContractId, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => ContractStorageSlotsConnectionArgs {
+                contract: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results),
+                last: None,
+            },
+            PageDirection::Backward => ContractStorageSlotsConnectionArgs {
+                contract: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractStorageValuesArgs {
+    pub contract: ContractId,
+    pub keys: Vec<Bytes32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractStorageValuesArgs"
+)]
+pub struct ContractStorageValuesQuery {
+    #[arguments(contract: $contract, keys: $keys)]
+    pub contract_storage_values: Vec<ContractStorageSlot>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct SparseMerkleProof {
+    pub root: Bytes32,
+    pub proof_set: Vec<Bytes32>,
+    pub inclusion: bool,
+    pub leaf_key: Option<Bytes32>,
+    pub leaf_value: Option<Bytes32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStateProof {
+    pub contract: ContractId,
+    pub key: Bytes32,
+    pub value: Option<HexString>,
+    pub utxo_id: UtxoId,
+    pub proof: SparseMerkleProof,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractStateProofArgs {
+    pub contract: ContractId,
+    pub key: Bytes32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractStateProofArgs"
+)]
+pub struct ContractStateProofQuery {
+    #[arguments(contract: $contract, key: $key)]
+    pub contract_state_proof: ContractStateProof,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractBalanceProof {
+    pub contract: ContractId,
+    pub asset_id: AssetId,
+    pub amount: Option<U64>,
+    pub utxo_id: UtxoId,
+    pub proof: SparseMerkleProof,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractBalanceProofArgs {
+    pub contract: ContractId,
+    pub asset: AssetId,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractBalanceProofArgs"
+)]
+pub struct ContractBalanceProofQuery {
+    #[arguments(contract: $contract, asset: $asset)]
+    pub contract_balance_proof: ContractBalanceProof,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -147,4 +319,48 @@ mod tests {
         });
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn contract_storage_slots_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation =
+            ContractStorageSlotsQuery::build(ContractStorageSlotsConnectionArgs {
+                contract: ContractId::default(),
+                after: None,
+                before: None,
+                first: Some(100),
+                last: None,
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn contract_storage_values_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ContractStorageValuesQuery::build(ContractStorageValuesArgs {
+            contract: ContractId::default(),
+            keys: vec![Bytes32::default()],
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn contract_state_proof_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ContractStateProofQuery::build(ContractStateProofArgs {
+            contract: ContractId::default(),
+            key: Bytes32::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn contract_balance_proof_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ContractBalanceProofQuery::build(ContractBalanceProofArgs {
+            contract: ContractId::default(),
+            asset: AssetId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
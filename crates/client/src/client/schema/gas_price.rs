@@ -1,5 +1,6 @@
 use crate::client::schema::{
     schema,
+    U128,
     U32,
     U64,
 };
@@ -47,6 +48,42 @@ pub struct QueryEstimateGasPrice {
     pub estimate_gas_price: EstimateGasPrice,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct GasPriceHistoryEntry {
+    pub block_height: U32,
+    pub gas_price: U64,
+    pub exec_gas_price: U64,
+    pub da_gas_price: U64,
+    pub block_gas_used: U64,
+    pub block_gas_capacity: U64,
+    pub block_bytes: U64,
+    pub recorded_da_cost: U128,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct GasPriceHistoryArgs {
+    pub from_height: U32,
+    pub to_height: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "GasPriceHistoryArgs"
+)]
+pub struct QueryGasPriceHistory {
+    #[arguments(fromHeight: $from_height, toHeight: $to_height)]
+    pub gas_price_history: Vec<GasPriceHistoryEntry>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Subscription")]
+pub struct GasPricesSubscription {
+    pub gas_prices: GasPriceHistoryEntry,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -68,4 +105,23 @@ mod tests {
         let operation = QueryEstimateGasPrice::build(args);
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn gas_price_history_query_gql_output() {
+        use cynic::QueryBuilder;
+        let args = GasPriceHistoryArgs {
+            from_height: 1.into(),
+            to_height: 10.into(),
+        };
+        let operation = QueryGasPriceHistory::build(args);
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn gas_prices_subscription_gql_output() {
+        use cynic::SubscriptionBuilder;
+        let operation = GasPricesSubscription::build(());
+        let operation = serde_json::to_value(operation).unwrap();
+        insta::assert_snapshot!(operation["query"].as_str().unwrap())
+    }
 }
@@ -0,0 +1,271 @@
+use crate::client::{
+    schema::{
+        schema,
+        tx::OpaqueTransaction,
+        Address,
+        AssetId,
+        ContractId,
+        ConversionError,
+        Nonce,
+        PageInfo,
+        Tai64Timestamp,
+        TransactionId,
+        UtxoId,
+        U32,
+        U64,
+    },
+    types::PendingTransaction as ClientPendingTransaction,
+    PageDirection,
+    PaginatedResult,
+    PaginationRequest,
+};
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TxPoolStats {
+    pub tx_count: U64,
+    pub total_gas: U64,
+    pub total_bytes: U64,
+    pub max_txs: U64,
+    pub max_gas: U64,
+    pub max_bytes: U64,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct TxPoolStatsQuery {
+    pub tx_pool_stats: TxPoolStats,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TxPoolBlackList {
+    pub owners: Vec<Address>,
+    pub coins: Vec<UtxoId>,
+    pub messages: Vec<Nonce>,
+    pub contracts: Vec<ContractId>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct TxPoolBlackListQuery {
+    pub tx_pool_blacklist: TxPoolBlackList,
+}
+
+#[derive(cynic::InputObject, Clone, Debug, Default)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TxPoolBlackListEntryInput {
+    /// Blacklists all inputs owned by the `owner`
+    pub owner: Option<Address>,
+    /// Blacklists the coin
+    pub coin: Option<UtxoId>,
+    /// Blacklists the message
+    pub message: Option<Nonce>,
+    /// Blacklists the contract
+    pub contract: Option<ContractId>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TxPoolBlackListEntryArgs {
+    pub entry: TxPoolBlackListEntryInput,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "TxPoolBlackListEntryArgs",
+    graphql_type = "Mutation"
+)]
+pub struct AddToTxPoolBlackListMutation {
+    #[arguments(entry: $entry)]
+    pub add_to_tx_pool_blacklist: Vec<TransactionId>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "TxPoolBlackListEntryArgs",
+    graphql_type = "Mutation"
+)]
+pub struct RemoveFromTxPoolBlackListMutation {
+    #[arguments(entry: $entry)]
+    pub remove_from_tx_pool_blacklist: bool,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PendingTransaction {
+    pub id: TransactionId,
+    pub transaction: OpaqueTransaction,
+    pub tip: U64,
+    pub max_gas: U64,
+    pub submitted_at: Tai64Timestamp,
+    pub dependencies_depth: U32,
+}
+
+#[derive(cynic::InputObject, Clone, Debug, Default)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PendingTransactionsFilterInput {
+    /// Filter transactions that spend inputs owned by the `owner`
+    pub owner: Option<Address>,
+    /// Filter transactions that spend or create the `asset_id`
+    pub asset_id: Option<AssetId>,
+    /// Filter transactions with the tip greater than or equal to `min_tip`
+    pub min_tip: Option<U64>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct PendingTransactionsConnectionArgs {
+    /// Filter transactions based on a filter
+    pub filter: Option<PendingTransactionsFilterInput>,
+    /// Skip until transaction id (forward pagination)
+    pub after: Option<String>,
+    /// Skip until transaction id (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n transactions in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n transactions in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(PendingTransactionsFilterInput, PaginationRequest<String>)>
+    for PendingTransactionsConnectionArgs
+{
+    fn from(r: (PendingTransactionsFilterInput, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => PendingTransactionsConnectionArgs {
+                filter: Some(r.0),
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results),
+                last: None,
+            },
+            PageDirection::Backward => PendingTransactionsConnectionArgs {
+                filter: Some(r.0),
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "PendingTransactionsConnectionArgs"
+)]
+pub struct PendingTransactionsQuery {
+    #[arguments(filter: $filter, after: $after, before: $before, first: $first, last: $last)]
+    pub pending_transactions: PendingTransactionConnection,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PendingTransactionConnection {
+    pub edges: Vec<PendingTransactionEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PendingTransactionEdge {
+    pub cursor: String,
+    pub node: PendingTransaction,
+}
+
+impl TryFrom<PendingTransactionConnection>
+    for PaginatedResult<ClientPendingTransaction, String>
+{
+    type Error = ConversionError;
+
+    fn try_from(conn: PendingTransactionConnection) -> Result<Self, Self::Error> {
+        let results: Result<Vec<ClientPendingTransaction>, Self::Error> =
+            conn.edges.into_iter().map(|e| e.node.try_into()).collect();
+
+        Ok(PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: results?,
+        })
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Subscription")]
+pub struct NewPendingTransactionsSubscription {
+    pub new_pending_transactions: PendingTransaction,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tx_pool_stats_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TxPoolStatsQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn tx_pool_blacklist_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TxPoolBlackListQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn add_to_tx_pool_blacklist_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = AddToTxPoolBlackListMutation::build(TxPoolBlackListEntryArgs {
+            entry: TxPoolBlackListEntryInput {
+                owner: Some(Address::default()),
+                ..Default::default()
+            },
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn remove_from_tx_pool_blacklist_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation =
+            RemoveFromTxPoolBlackListMutation::build(TxPoolBlackListEntryArgs {
+                entry: TxPoolBlackListEntryInput {
+                    owner: Some(Address::default()),
+                    ..Default::default()
+                },
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn pending_transactions_query_gql_output() {
+        use cynic::QueryBuilder;
+        let args = PendingTransactionsConnectionArgs {
+            filter: Some(PendingTransactionsFilterInput {
+                min_tip: Some(10u64.into()),
+                ..Default::default()
+            }),
+            after: None,
+            before: None,
+            first: Some(10),
+            last: None,
+        };
+        let operation = PendingTransactionsQuery::build(args);
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn new_pending_transactions_subscription_gql_output() {
+        use cynic::SubscriptionBuilder;
+        let operation = NewPendingTransactionsSubscription::build(());
+        let operation = serde_json::to_value(operation).unwrap();
+        insta::assert_snapshot!(operation["query"].as_str().unwrap())
+    }
+}
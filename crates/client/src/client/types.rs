@@ -11,12 +11,14 @@ pub mod gas_price;
 pub mod merkle_proof;
 pub mod message;
 pub mod node_info;
+pub mod tx_pool;
 
 pub use balance::Balance;
 pub use blob::Blob;
 pub use block::{
     Block,
     Consensus,
+    FullBlock,
 };
 pub use chain_info::ChainInfo;
 pub use coins::{
@@ -27,19 +29,33 @@ pub use coins::{
 pub use contract::{
     Contract,
     ContractBalance,
+    ContractBalanceProof,
+    ContractStateProof,
+    ContractStorageSlot,
 };
 pub use gas_costs::{
     DependentCost,
     GasCosts,
 };
-pub use merkle_proof::MerkleProof;
+pub use merkle_proof::{
+    MerkleProof,
+    SparseMerkleProof,
+    SparseMerkleProofKind,
+};
 pub use message::{
     Message,
     MessageProof,
 };
 pub use node_info::NodeInfo;
+pub use tx_pool::{
+    PendingTransaction,
+    TxPoolBlackList,
+    TxPoolBlackListEntry,
+    TxPoolStats,
+};
 
 use crate::client::schema::{
+    block::FullTransaction as SchemaFullTransaction,
     relayed_tx::RelayedTransactionStatus as SchemaRelayedTransactionStatus,
     tx::{
         OpaqueTransactionWithStatus,
@@ -264,6 +280,22 @@ impl TryFrom<OpaqueTransactionWithStatus> for TransactionResponse {
     }
 }
 
+impl TryFrom<SchemaFullTransaction> for TransactionResponse {
+    type Error = ConversionError;
+
+    fn try_from(value: SchemaFullTransaction) -> Result<Self, Self::Error> {
+        let bytes = value.transaction.raw_payload.0 .0;
+        let tx: TransactionType = Transaction::from_bytes(bytes.as_slice())
+            .map(Into::into)
+            .unwrap_or(TransactionType::Unknown);
+
+        Ok(Self {
+            transaction: tx,
+            status: value.status.try_into()?,
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RelayedTransactionStatus {
     Failed {
@@ -1,13 +1,16 @@
 use crate::client::{
     schema,
     schema::ConversionError,
-    types::primitives::{
-        BlockId,
-        Hash,
-        MerkleRoot,
-        PublicKey,
-        Signature,
-        TransactionId,
+    types::{
+        primitives::{
+            BlockId,
+            Hash,
+            MerkleRoot,
+            PublicKey,
+            Signature,
+            TransactionId,
+        },
+        TransactionResponse,
     },
     PaginatedResult,
 };
@@ -33,6 +36,13 @@ impl Block {
     }
 }
 
+/// The block with all its transactions and their statuses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FullBlock {
+    pub block: Block,
+    pub transactions: Vec<TransactionResponse>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Header {
     pub id: BlockId,
@@ -54,6 +64,7 @@ pub struct Header {
 pub enum Consensus {
     Genesis(Genesis),
     PoAConsensus(PoAConsensus),
+    BftConsensus(BftConsensus),
     Unknown,
 }
 
@@ -71,6 +82,12 @@ pub struct PoAConsensus {
     pub signature: Signature,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BftConsensus {
+    pub round: u32,
+    pub signatures: Vec<Signature>,
+}
+
 // GraphQL Translation
 
 impl TryFrom<schema::block::Header> for Header {
@@ -109,6 +126,9 @@ impl From<schema::block::Consensus> for Consensus {
             schema::block::Consensus::PoAConsensus(poa) => {
                 Consensus::PoAConsensus(poa.into())
             }
+            schema::block::Consensus::BftConsensus(bft) => {
+                Consensus::BftConsensus(bft.into())
+            }
             schema::block::Consensus::Unknown => Consensus::Unknown,
         }
     }
@@ -135,6 +155,22 @@ impl From<schema::block::PoAConsensus> for PoAConsensus {
     }
 }
 
+impl From<schema::block::BftConsensus> for BftConsensus {
+    fn from(value: schema::block::BftConsensus) -> Self {
+        Self {
+            round: value.round.into(),
+            signatures: value
+                .signatures
+                .into_iter()
+                .map(|signature| {
+                    let bytes: [u8; 64] = signature.0 .0.into();
+                    Signature::from_bytes(bytes)
+                })
+                .collect(),
+        }
+    }
+}
+
 impl TryFrom<schema::block::Block> for Block {
     type Error = ConversionError;
 
@@ -163,6 +199,21 @@ impl TryFrom<schema::block::Block> for Block {
     }
 }
 
+impl TryFrom<schema::block::FullBlock> for FullBlock {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::block::FullBlock) -> Result<Self, Self::Error> {
+        Ok(Self {
+            block: value.block.try_into()?,
+            transactions: value
+                .transactions
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
 impl TryFrom<schema::block::BlockConnection> for PaginatedResult<Block, String> {
     type Error = ConversionError;
 
@@ -1,10 +1,16 @@
 use crate::client::{
     schema,
-    types::primitives::{
-        AssetId,
-        Bytes,
-        ContractId,
-        Salt,
+    types::{
+        primitives::{
+            AssetId,
+            Bytes,
+            Bytes32,
+            ContractId,
+            MerkleRoot,
+            Salt,
+            UtxoId,
+        },
+        SparseMerkleProof,
     },
     PaginatedResult,
 };
@@ -22,6 +28,76 @@ pub struct ContractBalance {
     pub asset_id: AssetId,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractStorageSlot {
+    pub key: Bytes32,
+    pub value: Bytes,
+}
+
+/// The proof of the value of the contract's storage slot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractStateProof {
+    pub contract: ContractId,
+    pub key: Bytes32,
+    /// The value of the storage slot, `None` if the slot is empty.
+    pub value: Option<Bytes>,
+    /// The latest UTXO of the contract. The state root of its contract output
+    /// is equal to the root of the proof.
+    pub utxo_id: UtxoId,
+    pub proof: SparseMerkleProof,
+}
+
+impl ContractStateProof {
+    /// Verifies the value of the storage slot against the `state_root` of the contract.
+    ///
+    /// The root of the proof is returned by the same node as the value, so the
+    /// `state_root` must come from a trusted source, like the contract output
+    /// of the `utxo_id` transaction from a verified block.
+    pub fn verify(&self, state_root: &MerkleRoot) -> bool {
+        if &self.proof.root != state_root {
+            return false
+        }
+
+        let key = [self.contract.as_ref(), self.key.as_ref()].concat();
+        match &self.value {
+            Some(value) => self.proof.verify_inclusion(&key, value),
+            None => self.proof.verify_exclusion(&key),
+        }
+    }
+}
+
+/// The proof of the contract's balance of the asset.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractBalanceProof {
+    pub contract: ContractId,
+    pub asset_id: AssetId,
+    /// The balance of the asset, `None` if the contract doesn't have the balance entry.
+    pub amount: Option<u64>,
+    /// The latest UTXO of the contract. The balance root of its contract output
+    /// is equal to the root of the proof.
+    pub utxo_id: UtxoId,
+    pub proof: SparseMerkleProof,
+}
+
+impl ContractBalanceProof {
+    /// Verifies the balance of the asset against the `balance_root` of the contract.
+    ///
+    /// The root of the proof is returned by the same node as the balance, so the
+    /// `balance_root` must come from a trusted source, like the contract output
+    /// of the `utxo_id` transaction from a verified block.
+    pub fn verify(&self, balance_root: &MerkleRoot) -> bool {
+        if &self.proof.root != balance_root {
+            return false
+        }
+
+        let key = [self.contract.as_ref(), self.asset_id.as_ref()].concat();
+        match self.amount {
+            Some(amount) => self.proof.verify_inclusion(&key, &amount.to_be_bytes()),
+            None => self.proof.verify_exclusion(&key),
+        }
+    }
+}
+
 // GraphQL Translation
 
 impl From<schema::contract::Contract> for Contract {
@@ -56,3 +132,49 @@ impl From<schema::contract::ContractBalanceConnection>
         }
     }
 }
+
+impl From<schema::contract::ContractStorageSlot> for ContractStorageSlot {
+    fn from(value: schema::contract::ContractStorageSlot) -> Self {
+        Self {
+            key: value.key.into(),
+            value: value.value.into(),
+        }
+    }
+}
+
+impl From<schema::contract::ContractStorageSlotConnection>
+    for PaginatedResult<ContractStorageSlot, String>
+{
+    fn from(conn: schema::contract::ContractStorageSlotConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
+
+impl From<schema::contract::ContractStateProof> for ContractStateProof {
+    fn from(value: schema::contract::ContractStateProof) -> Self {
+        Self {
+            contract: value.contract.into(),
+            key: value.key.into(),
+            value: value.value.map(Into::into),
+            utxo_id: value.utxo_id.into(),
+            proof: value.proof.into(),
+        }
+    }
+}
+
+impl From<schema::contract::ContractBalanceProof> for ContractBalanceProof {
+    fn from(value: schema::contract::ContractBalanceProof) -> Self {
+        Self {
+            contract: value.contract.into(),
+            asset_id: value.asset_id.into(),
+            amount: value.amount.map(Into::into),
+            utxo_id: value.utxo_id.into(),
+            proof: value.proof.into(),
+        }
+    }
+}
@@ -28,3 +28,30 @@ impl From<schema::gas_price::EstimateGasPrice> for EstimateGasPrice {
         }
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasPriceHistoryEntry {
+    pub block_height: BlockHeight,
+    pub gas_price: u64,
+    pub exec_gas_price: u64,
+    pub da_gas_price: u64,
+    pub block_gas_used: u64,
+    pub block_gas_capacity: u64,
+    pub block_bytes: u64,
+    pub recorded_da_cost: u128,
+}
+
+impl From<schema::gas_price::GasPriceHistoryEntry> for GasPriceHistoryEntry {
+    fn from(value: schema::gas_price::GasPriceHistoryEntry) -> Self {
+        Self {
+            block_height: BlockHeight::new(value.block_height.into()),
+            gas_price: value.gas_price.into(),
+            exec_gas_price: value.exec_gas_price.into(),
+            da_gas_price: value.da_gas_price.into(),
+            block_gas_used: value.block_gas_used.into(),
+            block_gas_capacity: value.block_gas_capacity.into(),
+            block_bytes: value.block_bytes.into(),
+            recorded_da_cost: value.recorded_da_cost.into(),
+        }
+    }
+}
@@ -1,7 +1,11 @@
 use crate::client::{
     schema,
-    types::primitives::MerkleRoot,
+    types::primitives::{
+        Bytes32,
+        MerkleRoot,
+    },
 };
+use fuel_core_types::fuel_crypto::Hasher;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MerkleProof {
@@ -11,6 +15,108 @@ pub struct MerkleProof {
     pub proof_index: u64,
 }
 
+/// The proof of inclusion or exclusion of the key in the sparse Merkle tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseMerkleProof {
+    /// The root of the sparse Merkle tree.
+    pub root: MerkleRoot,
+    /// The side nodes of the path from the leaf to the root.
+    pub proof_set: Vec<Bytes32>,
+    /// The proved fact about the key.
+    pub kind: SparseMerkleProofKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SparseMerkleProofKind {
+    /// The key is present in the tree.
+    Inclusion,
+    /// The key is absent in the tree. The path of the key ends in an empty subtree.
+    ExclusionPlaceholder,
+    /// The key is absent in the tree. The path of the key ends in the leaf of another key.
+    ExclusionLeaf {
+        /// The hashed key of the found leaf.
+        leaf_key: Bytes32,
+        /// The hash of the value of the found leaf.
+        leaf_value: Bytes32,
+    },
+}
+
+const SPARSE_LEAF_PREFIX: u8 = 0;
+const SPARSE_NODE_PREFIX: u8 = 1;
+
+impl SparseMerkleProof {
+    /// Verifies that the tree with the root of the proof contains the `value`
+    /// under the `key`. The `key` and the `value` are encoded in the same way as
+    /// the node stores them.
+    pub fn verify_inclusion(&self, key: &[u8], value: &[u8]) -> bool {
+        if self.kind != SparseMerkleProofKind::Inclusion {
+            return false
+        }
+
+        let key = Hasher::hash(key);
+        let leaf = sparse_leaf_hash(&key, &Hasher::hash(value));
+        self.verify_path(&key, leaf)
+    }
+
+    /// Verifies that the tree with the root of the proof doesn't contain the `key`.
+    /// The `key` is encoded in the same way as the node stores it.
+    pub fn verify_exclusion(&self, key: &[u8]) -> bool {
+        let key = Hasher::hash(key);
+        let leaf = match &self.kind {
+            SparseMerkleProofKind::Inclusion => return false,
+            SparseMerkleProofKind::ExclusionPlaceholder => Bytes32::zeroed(),
+            SparseMerkleProofKind::ExclusionLeaf {
+                leaf_key,
+                leaf_value,
+            } => {
+                if leaf_key == &key {
+                    return false
+                }
+                sparse_leaf_hash(leaf_key, leaf_value)
+            }
+        };
+        self.verify_path(&key, leaf)
+    }
+
+    fn verify_path(&self, key: &Bytes32, leaf: Bytes32) -> bool {
+        let depth = self.proof_set.len();
+        if depth > Bytes32::LEN * 8 {
+            return false
+        }
+
+        let mut current = leaf;
+        // The side nodes go from the leaf to the root, while the bits
+        // of the key define the path from the root to the leaf.
+        for (side_node, bit_index) in self.proof_set.iter().zip((0..depth).rev()) {
+            let byte = key[bit_index / 8];
+            let is_right = (byte >> 7usize.saturating_sub(bit_index % 8)) & 1 == 1;
+            current = if is_right {
+                sparse_node_hash(side_node, &current)
+            } else {
+                sparse_node_hash(&current, side_node)
+            };
+        }
+
+        current == self.root
+    }
+}
+
+fn sparse_leaf_hash(key: &Bytes32, value_hash: &Bytes32) -> Bytes32 {
+    Hasher::default()
+        .chain([SPARSE_LEAF_PREFIX])
+        .chain(key)
+        .chain(value_hash)
+        .finalize()
+}
+
+fn sparse_node_hash(left: &Bytes32, right: &Bytes32) -> Bytes32 {
+    Hasher::default()
+        .chain([SPARSE_NODE_PREFIX])
+        .chain(left)
+        .chain(right)
+        .finalize()
+}
+
 // GraphQL Translation
 
 impl From<schema::message::MerkleProof> for MerkleProof {
@@ -28,3 +134,23 @@ impl From<schema::message::MerkleProof> for MerkleProof {
         }
     }
 }
+
+impl From<schema::contract::SparseMerkleProof> for SparseMerkleProof {
+    fn from(value: schema::contract::SparseMerkleProof) -> Self {
+        let kind = match (value.inclusion, value.leaf_key, value.leaf_value) {
+            (true, _, _) => SparseMerkleProofKind::Inclusion,
+            (false, Some(leaf_key), Some(leaf_value)) => {
+                SparseMerkleProofKind::ExclusionLeaf {
+                    leaf_key: leaf_key.into(),
+                    leaf_value: leaf_value.into(),
+                }
+            }
+            (false, _, _) => SparseMerkleProofKind::ExclusionPlaceholder,
+        };
+        Self {
+            root: value.root.into(),
+            proof_set: value.proof_set.into_iter().map(Into::into).collect(),
+            kind,
+        }
+    }
+}
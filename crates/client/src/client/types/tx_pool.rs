@@ -0,0 +1,117 @@
+use crate::client::{
+    schema::{
+        self,
+        ConversionError,
+    },
+    types::primitives::TransactionId,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        Address,
+        ContractId,
+        Transaction,
+        UtxoId,
+    },
+    fuel_types::Nonce,
+};
+use tai64::Tai64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TxPoolStats {
+    pub tx_count: u64,
+    pub total_gas: u64,
+    pub total_bytes: u64,
+    pub max_txs: u64,
+    pub max_gas: u64,
+    pub max_bytes: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingTransaction {
+    pub id: TransactionId,
+    pub transaction: Transaction,
+    pub tip: u64,
+    pub max_gas: u64,
+    pub submitted_at: Tai64,
+    pub dependencies_depth: u32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TxPoolBlackList {
+    pub owners: Vec<Address>,
+    pub coins: Vec<UtxoId>,
+    pub messages: Vec<Nonce>,
+    pub contracts: Vec<ContractId>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxPoolBlackListEntry {
+    Owner(Address),
+    Coin(UtxoId),
+    Message(Nonce),
+    Contract(ContractId),
+}
+
+// GraphQL Translation
+
+impl From<schema::tx_pool::TxPoolStats> for TxPoolStats {
+    fn from(value: schema::tx_pool::TxPoolStats) -> Self {
+        Self {
+            tx_count: value.tx_count.into(),
+            total_gas: value.total_gas.into(),
+            total_bytes: value.total_bytes.into(),
+            max_txs: value.max_txs.into(),
+            max_gas: value.max_gas.into(),
+            max_bytes: value.max_bytes.into(),
+        }
+    }
+}
+
+impl From<schema::tx_pool::TxPoolBlackList> for TxPoolBlackList {
+    fn from(value: schema::tx_pool::TxPoolBlackList) -> Self {
+        Self {
+            owners: value.owners.into_iter().map(Into::into).collect(),
+            coins: value.coins.into_iter().map(Into::into).collect(),
+            messages: value.messages.into_iter().map(Into::into).collect(),
+            contracts: value.contracts.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<TxPoolBlackListEntry> for schema::tx_pool::TxPoolBlackListEntryInput {
+    fn from(value: TxPoolBlackListEntry) -> Self {
+        match value {
+            TxPoolBlackListEntry::Owner(owner) => Self {
+                owner: Some(owner.into()),
+                ..Default::default()
+            },
+            TxPoolBlackListEntry::Coin(utxo_id) => Self {
+                coin: Some(utxo_id.into()),
+                ..Default::default()
+            },
+            TxPoolBlackListEntry::Message(nonce) => Self {
+                message: Some(nonce.into()),
+                ..Default::default()
+            },
+            TxPoolBlackListEntry::Contract(contract_id) => Self {
+                contract: Some(contract_id.into()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl TryFrom<schema::tx_pool::PendingTransaction> for PendingTransaction {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::tx_pool::PendingTransaction) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id.into(),
+            transaction: value.transaction.try_into()?,
+            tip: value.tip.into(),
+            max_gas: value.max_gas.into(),
+            submitted_at: value.submitted_at.0,
+            dependencies_depth: value.dependencies_depth.into(),
+        })
+    }
+}
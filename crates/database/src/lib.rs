@@ -71,6 +71,16 @@ pub enum Error {
     },
     #[display(fmt = "Reached the end of the history")]
     ReachedEndOfHistory,
+    /// The requested height is not yet available in the database.
+    #[display(
+        fmt = "The requested height {requested_height:#x} is above the latest available height {latest_height:#x}"
+    )]
+    HeightIsNotAvailableYet {
+        /// The height requested by the caller.
+        requested_height: u64,
+        /// The latest height known by the database.
+        latest_height: u64,
+    },
 
     /// Not related to database error.
     #[from]
@@ -30,7 +30,16 @@ use fuel_core_storage::tables::{
 };
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_types::fuel_types::BlockHeight;
-use std::path::PathBuf;
+use std::{
+    path::PathBuf,
+    time::Duration,
+};
+
+pub mod checkpoint;
+#[cfg(feature = "rocksdb")]
+pub mod inspect;
+#[cfg(feature = "rocksdb")]
+pub mod verify;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct CombinedDatabaseConfig {
@@ -41,6 +50,18 @@ pub struct CombinedDatabaseConfig {
     pub state_rewind_policy: StateRewindPolicy,
     #[cfg(feature = "rocksdb")]
     pub max_fds: i32,
+    /// Opens the databases as the read replica of another node.
+    /// The `database_path` is used for the files of the replica.
+    pub read_replica: Option<ReadReplicaConfig>,
+}
+
+/// The configuration of the read replica following the databases of the primary node.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReadReplicaConfig {
+    /// The path to the databases of the primary node.
+    pub primary_path: PathBuf,
+    /// The interval between catch-ups with the primary node.
+    pub catch_up_interval: Duration,
 }
 
 /// A database that combines the on-chain, off-chain and relayer databases into one entity.
@@ -105,6 +126,58 @@ impl CombinedDatabase {
         })
     }
 
+    /// Opens the databases as secondary instances following the databases of
+    /// the primary node at the `path`. The files of the secondary instances are
+    /// stored at the `secondary_path`, it must differ from the `path`.
+    #[cfg(feature = "rocksdb")]
+    pub fn open_secondary(
+        path: &std::path::Path,
+        secondary_path: &std::path::Path,
+        capacity: usize,
+        max_fds: i32,
+    ) -> crate::database::Result<Self> {
+        if path == secondary_path {
+            return Err(anyhow::anyhow!(
+                "The read replica can't use the database path of the primary node"
+            )
+            .into());
+        }
+        std::fs::create_dir_all(secondary_path)
+            .map_err(|e| crate::database::Error::Other(e.into()))?;
+
+        // Split the fds in equitable manner between the databases
+        let max_fds = match max_fds {
+            -1 => -1,
+            _ => max_fds.saturating_div(4),
+        };
+        let on_chain =
+            Database::open_rocksdb_secondary(path, secondary_path, capacity, max_fds)?;
+        let off_chain =
+            Database::open_rocksdb_secondary(path, secondary_path, capacity, max_fds)?;
+        let relayer =
+            Database::open_rocksdb_secondary(path, secondary_path, capacity, max_fds)?;
+        let gas_price =
+            Database::open_rocksdb_secondary(path, secondary_path, capacity, max_fds)?;
+        Ok(Self {
+            on_chain,
+            off_chain,
+            relayer,
+            gas_price,
+        })
+    }
+
+    /// Applies the changes made by the primary node to all databases.
+    /// It is a no-op if the databases are not secondary instances.
+    pub fn catch_up_with_primary(&self) -> StorageResult<()> {
+        // The primary updates the auxiliary databases after the on-chain one,
+        // catching up with them first keeps them from being ahead of the on-chain.
+        self.off_chain.catch_up_with_primary()?;
+        self.gas_price.catch_up_with_primary()?;
+        self.relayer.catch_up_with_primary()?;
+        self.on_chain.catch_up_with_primary()?;
+        Ok(())
+    }
+
     /// A test-only temporary rocksdb database with given rewind policy.
     #[cfg(feature = "rocksdb")]
     pub fn temp_database_with_state_rewind_policy(
@@ -119,11 +192,35 @@ impl CombinedDatabase {
     }
 
     pub fn from_config(config: &CombinedDatabaseConfig) -> DatabaseResult<Self> {
+        if config.read_replica.is_some() && config.database_type != DbType::RocksDb {
+            return Err(
+                anyhow::anyhow!("The read replica requires the RocksDB database").into(),
+            );
+        }
+
         let combined_database = match config.database_type {
             #[cfg(feature = "rocksdb")]
             DbType::RocksDb => {
-                // use a default tmp rocksdb if no path is provided
-                if config.database_path.as_os_str().is_empty() {
+                if let Some(read_replica) = &config.read_replica {
+                    if config.database_path.as_os_str().is_empty() {
+                        return Err(anyhow::anyhow!(
+                            "The read replica requires its own database path"
+                        )
+                        .into());
+                    }
+                    tracing::info!(
+                        "Opening the read replica of the database {:?} at {:?}",
+                        read_replica.primary_path,
+                        config.database_path,
+                    );
+                    CombinedDatabase::open_secondary(
+                        &read_replica.primary_path,
+                        &config.database_path,
+                        config.max_database_cache_size,
+                        config.max_fds,
+                    )?
+                } else if config.database_path.as_os_str().is_empty() {
+                    // use a default tmp rocksdb if no path is provided
                     tracing::warn!(
                         "No RocksDB path configured, initializing database with a tmp directory"
                     );
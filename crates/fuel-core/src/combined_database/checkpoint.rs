@@ -0,0 +1,395 @@
+//! The checkpoints of the [`CombinedDatabase`] back up the databases of the
+//! running node. Each database is checkpointed by RocksDB, which hard-links
+//! the SST files, so creating the checkpoint is cheap and doesn't stop the node.
+
+use super::CombinedDatabase;
+#[cfg(feature = "rocksdb")]
+use super::ShutdownListener;
+use crate::database::{
+    database_description::DatabaseDescription,
+    Database,
+};
+#[cfg(feature = "rocksdb")]
+use crate::{
+    database::database_description::{
+        gas_price::GasPriceDatabase,
+        off_chain::OffChain,
+        on_chain::OnChain,
+        relayer::Relayer,
+    },
+    state::historical_rocksdb::StateRewindPolicy,
+};
+use fuel_core_storage::transactional::HistoricalView;
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    fuel_types::BlockHeight,
+};
+use std::{
+    path::Path,
+    time::{
+        Duration,
+        Instant,
+        SystemTime,
+    },
+};
+
+/// The name of the manifest file in the directory of the checkpoint.
+pub const MANIFEST_FILE_NAME: &str = "manifest.json";
+
+/// The current version of the manifest format.
+pub const MANIFEST_VERSION: u32 = 1;
+
+/// The manifest describes the databases stored in the checkpoint.
+/// The checkpoint without the manifest is incomplete.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct CheckpointManifest {
+    /// The version of the manifest format.
+    pub version: u32,
+    /// The UNIX timestamp of the checkpoint creation in seconds.
+    pub created_at: u64,
+    /// The height of the on-chain database.
+    pub on_chain_height: Option<BlockHeight>,
+    /// The height of the off-chain database.
+    pub off_chain_height: Option<BlockHeight>,
+    /// The height of the gas price database.
+    pub gas_price_height: Option<BlockHeight>,
+    /// The DA height of the relayer database.
+    pub relayer_height: Option<DaBlockHeight>,
+}
+
+impl CheckpointManifest {
+    /// Reads the manifest of the checkpoint at the `path`.
+    pub fn read(path: &Path) -> anyhow::Result<Self> {
+        let file = path.join(MANIFEST_FILE_NAME);
+        let bytes = std::fs::read(&file).map_err(|e| {
+            anyhow::anyhow!("Failed to read the checkpoint manifest {file:?}: {e}")
+        })?;
+        let manifest: Self = serde_json::from_slice(&bytes)?;
+        if manifest.version != MANIFEST_VERSION {
+            return Err(anyhow::anyhow!(
+                "The checkpoint manifest version {} is not supported, expected {}",
+                manifest.version,
+                MANIFEST_VERSION
+            ))
+        }
+        Ok(manifest)
+    }
+
+    fn write(&self, path: &Path) -> anyhow::Result<()> {
+        let bytes = serde_json::to_vec_pretty(self)?;
+        std::fs::write(path.join(MANIFEST_FILE_NAME), bytes)?;
+        Ok(())
+    }
+}
+
+impl CombinedDatabase {
+    /// Creates the checkpoints of all databases in the `path` along with the manifest.
+    ///
+    /// The on-chain database doesn't accept new blocks only while its checkpoint
+    /// is created. After that, the off-chain and gas price databases have up to
+    /// `sync_timeout` to process the same block, and their checkpoints are created
+    /// without blocking the on-chain database. The manifest records the height of
+    /// each checkpoint. On restore, the databases ahead of the on-chain database
+    /// are rolled back, and the databases lagging behind are synced by the node.
+    pub fn create_checkpoint(
+        &self,
+        path: &Path,
+        sync_timeout: Duration,
+    ) -> anyhow::Result<CheckpointManifest> {
+        if path.exists() {
+            return Err(anyhow::anyhow!(
+                "The checkpoint path {path:?} already exists"
+            ))
+        }
+        std::fs::create_dir_all(path)?;
+
+        let result = self.create_checkpoint_at(path, sync_timeout);
+        if result.is_err() {
+            let _ = std::fs::remove_dir_all(path);
+        }
+        result
+    }
+
+    fn create_checkpoint_at(
+        &self,
+        path: &Path,
+        sync_timeout: Duration,
+    ) -> anyhow::Result<CheckpointManifest> {
+        let on_chain_height = self.on_chain.create_checkpoint(path)?;
+        let started = Instant::now();
+        wait_for_height(&self.off_chain, on_chain_height, started, sync_timeout);
+        let off_chain_height = self.off_chain.create_checkpoint(path)?;
+        wait_for_height(&self.gas_price, on_chain_height, started, sync_timeout);
+        let gas_price_height = self.gas_price.create_checkpoint(path)?;
+        let relayer_height = self.relayer.create_checkpoint(path)?;
+
+        let manifest = CheckpointManifest {
+            version: MANIFEST_VERSION,
+            created_at: SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            on_chain_height,
+            off_chain_height,
+            gas_price_height,
+            relayer_height,
+        };
+
+        for (name, height) in [
+            ("off-chain", manifest.off_chain_height),
+            ("gas price", manifest.gas_price_height),
+        ] {
+            if height.is_some() && height < manifest.on_chain_height {
+                tracing::warn!(
+                    "The {name} database of the checkpoint is behind the on-chain \
+                    database: {height:?} < {:?}",
+                    manifest.on_chain_height
+                );
+            }
+        }
+
+        manifest.write(path)?;
+        Ok(manifest)
+    }
+
+    /// Restores the checkpoint created by [`CombinedDatabase::create_checkpoint`]
+    /// into the `path`, which must not contain the databases.
+    ///
+    /// The restored databases are validated the same way as on the start of
+    /// the node: the versions must be supported, and the auxiliary databases
+    /// ahead of the on-chain database are rolled back. The restored files
+    /// are removed if the validation fails.
+    #[cfg(feature = "rocksdb")]
+    pub fn restore_checkpoint<S>(
+        checkpoint: &Path,
+        path: &Path,
+        capacity: usize,
+        state_rewind_policy: StateRewindPolicy,
+        max_fds: i32,
+        shutdown_listener: &mut S,
+    ) -> anyhow::Result<CheckpointManifest>
+    where
+        S: ShutdownListener,
+    {
+        let manifest = CheckpointManifest::read(checkpoint)?;
+        let names = [
+            OnChain::name(),
+            OffChain::name(),
+            Relayer::name(),
+            GasPriceDatabase::name(),
+        ];
+        for name in &names {
+            if path.join(name).exists() {
+                return Err(anyhow::anyhow!(
+                    "The database `{name}` already exists at {path:?}"
+                ))
+            }
+        }
+
+        let result = names
+            .iter()
+            .try_for_each(|name| copy_database(&checkpoint.join(name), &path.join(name)))
+            .and_then(|_| {
+                let database = Self::open(path, capacity, state_rewind_policy, max_fds)?;
+                database.validate_checkpoint(&manifest, shutdown_listener)
+            });
+
+        if let Err(err) = result {
+            if let Err(prune_err) = Self::prune(path) {
+                tracing::error!(
+                    "Failed to remove the restored databases at {path:?}: {prune_err}"
+                );
+            }
+            return Err(err)
+        }
+
+        Ok(manifest)
+    }
+
+    #[cfg(feature = "rocksdb")]
+    fn validate_checkpoint<S>(
+        &self,
+        manifest: &CheckpointManifest,
+        shutdown_listener: &mut S,
+    ) -> anyhow::Result<()>
+    where
+        S: ShutdownListener,
+    {
+        self.check_version()?;
+
+        let on_chain_height = self.on_chain().latest_height_from_metadata()?;
+        let off_chain_height = self.off_chain().latest_height_from_metadata()?;
+        let gas_price_height = self.gas_price().latest_height_from_metadata()?;
+        let relayer_height = self.relayer().latest_height_from_metadata()?;
+        if on_chain_height != manifest.on_chain_height
+            || off_chain_height != manifest.off_chain_height
+            || gas_price_height != manifest.gas_price_height
+            || relayer_height != manifest.relayer_height
+        {
+            return Err(anyhow::anyhow!(
+                "The heights of the databases don't match the checkpoint manifest"
+            ))
+        }
+
+        self.sync_aux_db_heights(shutdown_listener)
+    }
+}
+
+/// Waits until the `database` reaches the `target` height or the `timeout` expires.
+/// The databases without a height are not waited for.
+fn wait_for_height<Description>(
+    database: &Database<Description>,
+    target: Option<BlockHeight>,
+    started: Instant,
+    timeout: Duration,
+) where
+    Description: DatabaseDescription<Height = BlockHeight>,
+{
+    while started.elapsed() < timeout {
+        match database.latest_height() {
+            Some(height) if Some(height) < target => {
+                std::thread::sleep(Duration::from_millis(10));
+            }
+            _ => break,
+        }
+    }
+}
+
+/// Copies the files of the database checkpoint. The SST files are immutable,
+/// so they are hard-linked when possible.
+#[cfg(feature = "rocksdb")]
+fn copy_database(from: &Path, to: &Path) -> anyhow::Result<()> {
+    if !from.is_dir() {
+        return Err(anyhow::anyhow!(
+            "The checkpoint doesn't contain the database {from:?}"
+        ))
+    }
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let source = entry?.path();
+        let Some(file_name) = source.file_name() else {
+            continue
+        };
+        let target = to.join(file_name);
+        let is_sst = source.extension().map_or(false, |ext| ext == "sst");
+        if !is_sst || std::fs::hard_link(&source, &target).is_err() {
+            std::fs::copy(&source, &target)?;
+        }
+    }
+    Ok(())
+}
+
+#[allow(non_snake_case)]
+#[cfg(feature = "rocksdb")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::database_description::on_chain::OnChain;
+    use fuel_core_storage::{
+        tables::FuelBlocks,
+        StorageAsMut,
+    };
+    use fuel_core_types::blockchain::block::CompressedBlock;
+    use tempfile::TempDir;
+
+    struct NeverCancelled;
+
+    impl ShutdownListener for NeverCancelled {
+        fn is_cancelled(&self) -> bool {
+            false
+        }
+    }
+
+    fn open(path: &Path) -> CombinedDatabase {
+        CombinedDatabase::open(path, 1024 * 1024, StateRewindPolicy::RewindFullRange, 512)
+            .unwrap()
+    }
+
+    fn add_block(database: &mut Database<OnChain>, height: u32) {
+        database
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&height.into(), &CompressedBlock::default())
+            .unwrap();
+    }
+
+    #[test]
+    fn restore_checkpoint__restores_databases_at_checkpoint_height() {
+        let db_dir = TempDir::new().unwrap();
+        let checkpoint_dir = TempDir::new().unwrap();
+        let checkpoint_path = checkpoint_dir.path().join("checkpoint");
+        let restore_dir = TempDir::new().unwrap();
+        let mut database = open(db_dir.path());
+        add_block(database.on_chain_mut(), 0);
+
+        // Given
+        let manifest = database
+            .create_checkpoint(&checkpoint_path, Duration::ZERO)
+            .unwrap();
+        add_block(database.on_chain_mut(), 1);
+
+        // When
+        let restored = CombinedDatabase::restore_checkpoint(
+            &checkpoint_path,
+            restore_dir.path(),
+            1024 * 1024,
+            StateRewindPolicy::RewindFullRange,
+            512,
+            &mut NeverCancelled,
+        )
+        .unwrap();
+
+        // Then
+        assert_eq!(restored, manifest);
+        assert_eq!(manifest.on_chain_height, Some(0u32.into()));
+        let restored_database = open(restore_dir.path());
+        assert_eq!(
+            restored_database
+                .on_chain()
+                .latest_height_from_metadata()
+                .unwrap(),
+            Some(0u32.into())
+        );
+    }
+
+    #[test]
+    fn restore_checkpoint__fails_without_manifest() {
+        let db_dir = TempDir::new().unwrap();
+        let checkpoint_dir = TempDir::new().unwrap();
+        let checkpoint_path = checkpoint_dir.path().join("checkpoint");
+        let restore_dir = TempDir::new().unwrap();
+        let database = open(db_dir.path());
+        database
+            .create_checkpoint(&checkpoint_path, Duration::ZERO)
+            .unwrap();
+
+        // Given
+        std::fs::remove_file(checkpoint_path.join(MANIFEST_FILE_NAME)).unwrap();
+
+        // When
+        let result = CombinedDatabase::restore_checkpoint(
+            &checkpoint_path,
+            restore_dir.path(),
+            1024 * 1024,
+            StateRewindPolicy::RewindFullRange,
+            512,
+            &mut NeverCancelled,
+        );
+
+        // Then
+        assert!(result.is_err());
+        assert!(!restore_dir.path().join(OnChain::name()).exists());
+    }
+
+    #[test]
+    fn create_checkpoint__fails_if_path_exists() {
+        let db_dir = TempDir::new().unwrap();
+        let checkpoint_dir = TempDir::new().unwrap();
+        let database = open(db_dir.path());
+
+        // When
+        let result = database.create_checkpoint(checkpoint_dir.path(), Duration::ZERO);
+
+        // Then
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,492 @@
+//! The read-only inspection of the databases of the [`CombinedDatabase`].
+//! The databases are opened in the read-only mode, so they can be inspected
+//! while the node is running without affecting it.
+
+use super::CombinedDatabase;
+use crate::{
+    database::{
+        database_description::{
+            gas_price::GasPriceDatabase,
+            off_chain::OffChain,
+            on_chain::OnChain,
+            relayer::Relayer,
+            DatabaseDescription,
+            DatabaseHeight,
+        },
+        metadata::MetadataTable,
+    },
+    graphql_api::storage::{
+        balances::{
+            CoinBalances,
+            MessageBalances,
+        },
+        blocks::FuelBlockIdsToHeights,
+        coins::OwnedCoins,
+        contracts::ContractsInfo,
+        da_compression::DaCompressedBlocks,
+        messages::{
+            OwnedMessageIds,
+            SpentMessages,
+        },
+        old::{
+            OldFuelBlockConsensus,
+            OldFuelBlocks,
+            OldTransactions,
+        },
+        relayed_transactions::RelayedTransactionStatuses,
+        transactions::{
+            OwnedTransactions,
+            TransactionStatuses,
+        },
+        tx_pool_blacklist::TxPoolBlackList,
+    },
+    state::{
+        historical_rocksdb::{
+            description::Column,
+            HistoricalRocksDB,
+        },
+        rocks_db::RocksDb,
+    },
+};
+use fuel_core_storage::{
+    blueprint::BlueprintInspect,
+    codec::Decode,
+    kv_store::{
+        KeyValueInspect,
+        StorageColumn,
+    },
+    structured_storage::{
+        StructuredStorage,
+        TableWithBlueprint,
+    },
+    tables::{
+        BlobData,
+        Coins,
+        ConsensusParametersVersions,
+        ContractsAssets,
+        ContractsLatestUtxo,
+        ContractsRawCode,
+        ContractsState,
+        FuelBlocks,
+        Messages,
+        ProcessedTransactions,
+        SealedBlockConsensus,
+        StateTransitionBytecodeVersions,
+        Transactions,
+        UploadedBytecodes,
+    },
+    Error as StorageError,
+    StorageAsRef,
+    StorageInspect,
+};
+use std::{
+    fmt::Debug,
+    path::Path,
+};
+
+type KeyCodec<T, Description> = <<T as TableWithBlueprint>::Blueprint as BlueprintInspect<
+    T,
+    RocksDb<Description>,
+>>::KeyCodec;
+type ValueCodec<T, Description> = <<T as TableWithBlueprint>::Blueprint as BlueprintInspect<
+    T,
+    RocksDb<Description>,
+>>::ValueCodec;
+
+/// The statistics of the column of the database.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct ColumnReport {
+    /// The id of the column.
+    pub id: u32,
+    /// The name of the column.
+    pub name: String,
+    /// The number of keys estimated by RocksDB.
+    pub estimated_keys: u64,
+    /// The exact number of keys, if they were counted.
+    pub keys: Option<u64>,
+    /// The total size of the SST files of the column in bytes.
+    pub sst_files_size: u64,
+}
+
+/// The range of heights covered by the modifications history.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct HeightRange {
+    pub oldest: u64,
+    pub latest: u64,
+}
+
+/// The state of the database and the statistics of its columns.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct DatabaseReport {
+    /// The name of the database.
+    pub name: String,
+    /// The version of the database from its metadata.
+    pub version: Option<u32>,
+    /// The latest height of the database from its metadata.
+    pub latest_height: Option<u64>,
+    /// The heights the database can be rolled back to.
+    pub modifications_history: Option<HeightRange>,
+    /// The statistics of the original and the historical columns.
+    pub columns: Vec<ColumnReport>,
+}
+
+/// The reports of the databases. The database is `None` if it doesn't exist.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct InspectionReport {
+    pub on_chain: Option<DatabaseReport>,
+    pub off_chain: Option<DatabaseReport>,
+    pub relayer: Option<DatabaseReport>,
+    pub gas_price: Option<DatabaseReport>,
+}
+
+/// The entry of the database decoded with the codecs of the table.
+/// The entries of the columns without a known table are printed as hex.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct DecodedEntry {
+    /// The table used to decode the entry.
+    pub table: Option<String>,
+    /// The decoded key.
+    pub key: String,
+    /// The decoded value, or `None` if the key doesn't exist.
+    pub value: Option<String>,
+}
+
+/// The database with the tables known to [`CombinedDatabase::decode_entry`].
+pub trait DecodeEntry: DatabaseDescription {
+    /// Decodes the entry with the `key` of the `column` using the blueprint
+    /// codecs of the table stored in the `column`.
+    fn decode_entry(
+        storage: &RocksDb<Self>,
+        column: Self::Column,
+        key: &[u8],
+    ) -> anyhow::Result<DecodedEntry>;
+}
+
+impl CombinedDatabase {
+    /// Inspects the databases at the `path`. The exact number of keys
+    /// requires iteration over all columns, so it is counted only if `count_keys`.
+    pub fn inspect(
+        path: &Path,
+        max_fds: i32,
+        count_keys: bool,
+    ) -> anyhow::Result<InspectionReport> {
+        Ok(InspectionReport {
+            on_chain: inspect_database::<OnChain>(path, max_fds, count_keys)?,
+            off_chain: inspect_database::<OffChain>(path, max_fds, count_keys)?,
+            relayer: inspect_database::<Relayer>(path, max_fds, count_keys)?,
+            gas_price: inspect_database::<GasPriceDatabase>(path, max_fds, count_keys)?,
+        })
+    }
+
+    /// Decodes the entry with the `key` of the `column` of the latest state
+    /// of the database at the `path`.
+    pub fn decode_entry<Description>(
+        path: &Path,
+        max_fds: i32,
+        column: &str,
+        key: &[u8],
+    ) -> anyhow::Result<DecodedEntry>
+    where
+        Description: DecodeEntry,
+        <MetadataTable<Description> as TableWithBlueprint>::Blueprint:
+            BlueprintInspect<MetadataTable<Description>, RocksDb<Description>>,
+    {
+        let Some(column) = enum_iterator::all::<Description::Column>()
+            .find(|candidate| candidate.name() == column)
+        else {
+            let columns = enum_iterator::all::<Description::Column>()
+                .map(|column| column.name())
+                .collect::<Vec<_>>();
+            return Err(anyhow::anyhow!(
+                "The {} database doesn't have the column `{column}`, \
+                the available columns are: {}",
+                Description::name(),
+                columns.join(", ")
+            ))
+        };
+
+        let db = HistoricalRocksDB::<Description>::open_read_only(path, None, max_fds)?;
+        let storage = db.latest_view();
+        if column.id() == Description::metadata_column().id() {
+            return decode_table_entry::<MetadataTable<Description>, _>(
+                "MetadataTable",
+                &storage,
+                key,
+            )
+        }
+        Description::decode_entry(&storage, column, key)
+    }
+}
+
+fn inspect_database<Description>(
+    path: &Path,
+    max_fds: i32,
+    count_keys: bool,
+) -> anyhow::Result<Option<DatabaseReport>>
+where
+    Description: DatabaseDescription,
+    StructuredStorage<RocksDb<Description>>:
+        StorageInspect<MetadataTable<Description>, Error = StorageError>,
+{
+    if !path.join(Description::name()).exists() {
+        return Ok(None)
+    }
+
+    let db = HistoricalRocksDB::<Description>::open_read_only(path, None, max_fds)?;
+    let metadata = StructuredStorage::new(db.latest_view())
+        .storage::<MetadataTable<Description>>()
+        .get(&())?
+        .map(|metadata| metadata.into_owned());
+    let modifications_history = db
+        .modifications_history_range()?
+        .map(|(oldest, latest)| HeightRange { oldest, latest });
+
+    let columns = enum_iterator::all::<Column<Description>>()
+        .map(|column| {
+            let statistics = db.column_statistics(column, count_keys)?;
+            Ok::<_, anyhow::Error>(ColumnReport {
+                id: column.id(),
+                name: column.name(),
+                estimated_keys: statistics.estimated_keys,
+                keys: statistics.keys,
+                sst_files_size: statistics.sst_files_size,
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(Some(DatabaseReport {
+        name: Description::name(),
+        version: metadata.as_ref().map(|metadata| metadata.version()),
+        latest_height: metadata.map(|metadata| metadata.height().as_u64()),
+        modifications_history,
+        columns,
+    }))
+}
+
+fn decode_table_entry<T, Description>(
+    table: &str,
+    storage: &RocksDb<Description>,
+    key: &[u8],
+) -> anyhow::Result<DecodedEntry>
+where
+    Description: DatabaseDescription,
+    T: TableWithBlueprint<Column = Description::Column>,
+    T::Blueprint: BlueprintInspect<T, RocksDb<Description>>,
+    T::OwnedKey: Debug,
+    T::OwnedValue: Debug,
+{
+    let decoded_key = <KeyCodec<T, Description> as Decode<T::OwnedKey>>::decode(key)?;
+    let value = storage
+        .get(key, T::column())?
+        .map(<ValueCodec<T, Description> as Decode<T::OwnedValue>>::decode_from_value)
+        .transpose()?;
+
+    Ok(DecodedEntry {
+        table: Some(table.to_string()),
+        key: format!("{decoded_key:?}"),
+        value: value.map(|value| format!("{value:#?}")),
+    })
+}
+
+fn raw_entry<Description>(
+    storage: &RocksDb<Description>,
+    column: Description::Column,
+    key: &[u8],
+) -> anyhow::Result<DecodedEntry>
+where
+    Description: DatabaseDescription,
+{
+    let value = storage.get(key, column)?;
+    Ok(DecodedEntry {
+        table: None,
+        key: hex::encode(key),
+        value: value.map(hex::encode),
+    })
+}
+
+/// Decodes the entry with the first table from the list stored in the `column`.
+/// The entry is printed as hex if none of the tables is stored in the `column`.
+macro_rules! decode_with_tables {
+    ($storage:expr, $column:expr, $key:expr, [$($table:ident),* $(,)?]) => {{
+        $(
+            if $column.id() == <$table as TableWithBlueprint>::column().id() {
+                return decode_table_entry::<$table, _>(stringify!($table), $storage, $key)
+            }
+        )*
+        raw_entry($storage, $column, $key)
+    }};
+}
+
+impl DecodeEntry for OnChain {
+    fn decode_entry(
+        storage: &RocksDb<Self>,
+        column: Self::Column,
+        key: &[u8],
+    ) -> anyhow::Result<DecodedEntry> {
+        decode_with_tables!(
+            storage,
+            column,
+            key,
+            [
+                ContractsRawCode,
+                ContractsState,
+                ContractsLatestUtxo,
+                ContractsAssets,
+                Coins,
+                Transactions,
+                FuelBlocks,
+                Messages,
+                SealedBlockConsensus,
+                ProcessedTransactions,
+                ConsensusParametersVersions,
+                StateTransitionBytecodeVersions,
+                UploadedBytecodes,
+                BlobData,
+            ]
+        )
+    }
+}
+
+impl DecodeEntry for OffChain {
+    fn decode_entry(
+        storage: &RocksDb<Self>,
+        column: Self::Column,
+        key: &[u8],
+    ) -> anyhow::Result<DecodedEntry> {
+        decode_with_tables!(
+            storage,
+            column,
+            key,
+            [
+                OwnedCoins,
+                TransactionStatuses,
+                OwnedTransactions,
+                OwnedMessageIds,
+                FuelBlockIdsToHeights,
+                ContractsInfo,
+                OldFuelBlocks,
+                OldFuelBlockConsensus,
+                OldTransactions,
+                RelayedTransactionStatuses,
+                SpentMessages,
+                DaCompressedBlocks,
+                CoinBalances,
+                MessageBalances,
+                TxPoolBlackList,
+            ]
+        )
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::historical_rocksdb::StateRewindPolicy;
+    use fuel_core_storage::{
+        column::Column as OnChainColumn,
+        StorageAsMut,
+    };
+    use fuel_core_types::{
+        blockchain::block::CompressedBlock,
+        fuel_types::BlockHeight,
+    };
+    use tempfile::TempDir;
+
+    fn database_with_blocks(path: &Path, blocks: u32) -> CombinedDatabase {
+        let mut database = CombinedDatabase::open(
+            path,
+            1024 * 1024,
+            StateRewindPolicy::RewindFullRange,
+            512,
+        )
+        .unwrap();
+        for height in 0..blocks {
+            database
+                .on_chain_mut()
+                .storage_as_mut::<FuelBlocks>()
+                .insert(&height.into(), &CompressedBlock::default())
+                .unwrap();
+        }
+        database
+    }
+
+    #[test]
+    fn inspect__reports_heights_history_and_keys_of_running_database() {
+        let db_dir = TempDir::new().unwrap();
+
+        // Given
+        let _database = database_with_blocks(db_dir.path(), 3);
+
+        // When
+        let report = CombinedDatabase::inspect(db_dir.path(), 512, true).unwrap();
+
+        // Then
+        let on_chain = report.on_chain.unwrap();
+        assert_eq!(on_chain.latest_height, Some(2));
+        assert_eq!(
+            on_chain.modifications_history,
+            Some(HeightRange {
+                oldest: 0,
+                latest: 2
+            })
+        );
+        let blocks = on_chain
+            .columns
+            .iter()
+            .find(|column| column.id == OnChainColumn::FuelBlocks.id())
+            .unwrap();
+        assert_eq!(blocks.keys, Some(3));
+    }
+
+    #[test]
+    fn inspect__skips_missing_databases() {
+        let db_dir = TempDir::new().unwrap();
+
+        // When
+        let report = CombinedDatabase::inspect(db_dir.path(), 512, false).unwrap();
+
+        // Then
+        assert_eq!(report.on_chain, None);
+        assert!(!db_dir.path().join(OnChain::name()).exists());
+    }
+
+    #[test]
+    fn decode_entry__uses_codecs_of_the_table() {
+        let db_dir = TempDir::new().unwrap();
+        let _database = database_with_blocks(db_dir.path(), 2);
+
+        // Given
+        let key = 1u32.to_be_bytes();
+
+        // When
+        let entry = CombinedDatabase::decode_entry::<OnChain>(
+            db_dir.path(),
+            512,
+            "FuelBlocks",
+            &key,
+        )
+        .unwrap();
+
+        // Then
+        assert_eq!(entry.table.as_deref(), Some("FuelBlocks"));
+        assert_eq!(entry.key, format!("{:?}", BlockHeight::from(1u32)));
+        assert_eq!(
+            entry.value,
+            Some(format!("{:#?}", CompressedBlock::default()))
+        );
+    }
+
+    #[test]
+    fn decode_entry__fails_for_unknown_column() {
+        let db_dir = TempDir::new().unwrap();
+        let _database = database_with_blocks(db_dir.path(), 1);
+
+        // When
+        let result =
+            CombinedDatabase::decode_entry::<OnChain>(db_dir.path(), 512, "Unknown", &[]);
+
+        // Then
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,754 @@
+//! The consistency verification of the databases of the [`CombinedDatabase`].
+//! The off-chain indexes are recomputed from the on-chain tables they are
+//! derived from, and the Merkle roots stored in the on-chain database are
+//! recomputed from the Merkle trees and the leaves. The databases are opened
+//! in the read-only mode, so they can be verified while the node is running.
+
+use super::CombinedDatabase;
+use crate::{
+    database::{
+        database_description::{
+            off_chain::OffChain,
+            on_chain::OnChain,
+            DatabaseDescription,
+            DatabaseHeight,
+            IndexationKind,
+        },
+        Database,
+        OffChainIterableKeyValueView,
+        OnChainIterableKeyValueView,
+    },
+    graphql_api::storage::{
+        balances::{
+            CoinBalances,
+            MessageBalance,
+            MessageBalances,
+        },
+        coins::{
+            owner_coin_id_key,
+            OwnedCoins,
+        },
+        messages::{
+            OwnedMessageIds,
+            OwnedMessageKey,
+        },
+    },
+    service::reindex::OffChainIndex,
+};
+use anyhow::bail;
+use fuel_core_storage::{
+    codec::{
+        primitive::Primitive,
+        Decode,
+    },
+    iter::{
+        IterDirection,
+        IteratorOverTable,
+    },
+    tables::{
+        merkle::{
+            DenseMetadataKey,
+            FuelBlockMerkleData,
+            FuelBlockMerkleMetadata,
+        },
+        Coins,
+        FuelBlocks,
+        Messages,
+    },
+    transactional::{
+        AtomicView,
+        HistoricalView,
+    },
+    StorageAsRef,
+};
+use fuel_core_types::{
+    fuel_merkle::binary::MerkleTree,
+    fuel_tx::{
+        Address,
+        AssetId,
+        Bytes32,
+        TxId,
+        UtxoId,
+    },
+};
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    fmt::Debug,
+    path::Path,
+};
+
+/// The maximum number of mismatches listed in the report for each check.
+/// The total number of mismatches is reported by the [`CheckReport`].
+pub const MAX_LISTED_MISMATCHES: usize = 1000;
+
+/// The consistency check of the database.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, strum_macros::Display, serde::Serialize)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum Check {
+    /// The `CoinBalances` recomputed from the on-chain `Coins`.
+    CoinBalances,
+    /// The `MessageBalances` recomputed from the on-chain `Messages`.
+    MessageBalances,
+    /// The `OwnedCoins` compared with the owners of the on-chain `Coins`.
+    OwnedCoins,
+    /// The `OwnedMessageIds` compared with the recipients of the on-chain `Messages`.
+    OwnedMessageIds,
+    /// The roots of the `FuelBlockMerkleMetadata` re-derived from the
+    /// `FuelBlockMerkleData` and compared with the `prev_root` of the next block.
+    BlockMerkleRoots,
+    /// The roots of the `ContractsStateMerkleMetadata` recomputed
+    /// from the `ContractsState`.
+    ContractStateRoots,
+}
+
+impl Check {
+    /// Returns the off-chain index that repairs the mismatches of the check
+    /// when rebuilt, or `None` if the checked table is not derivable.
+    pub fn index(&self) -> Option<OffChainIndex> {
+        match self {
+            Check::CoinBalances | Check::MessageBalances => Some(OffChainIndex::Balances),
+            Check::OwnedCoins => Some(OffChainIndex::Coins),
+            Check::OwnedMessageIds => Some(OffChainIndex::Messages),
+            Check::BlockMerkleRoots | Check::ContractStateRoots => None,
+        }
+    }
+}
+
+/// The entry that doesn't match the value derived from the source tables.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct Mismatch {
+    /// The check that found the mismatch.
+    pub check: Check,
+    /// The key of the entry.
+    pub key: String,
+    /// The value derived from the source tables, or `None` if the entry shouldn't exist.
+    pub expected: Option<String>,
+    /// The stored value, or `None` if the entry doesn't exist.
+    pub actual: Option<String>,
+}
+
+/// The outcome of the check.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct CheckReport {
+    pub check: Check,
+    /// The number of compared entries.
+    pub verified: u64,
+    /// The total number of mismatches.
+    pub mismatches: u64,
+    /// The reason why the check was skipped, if it was.
+    pub skipped: Option<String>,
+}
+
+/// The outcome of the verification of the databases.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct VerificationReport {
+    /// The latest height of the on-chain database.
+    pub on_chain_height: Option<u64>,
+    /// The latest height of the off-chain database. The off-chain indexes are
+    /// compared with the on-chain tables at this height.
+    pub off_chain_height: Option<u64>,
+    pub checks: Vec<CheckReport>,
+    /// The mismatches, at most [`MAX_LISTED_MISMATCHES`] for each check.
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl VerificationReport {
+    /// Returns `true` if none of the checks found a mismatch.
+    pub fn is_consistent(&self) -> bool {
+        self.checks.iter().all(|check| check.mismatches == 0)
+    }
+
+    /// Returns the off-chain indexes to rebuild to repair the found mismatches.
+    pub fn indexes_to_repair(&self) -> Vec<OffChainIndex> {
+        let mut indexes = vec![];
+        for check in &self.checks {
+            if check.mismatches == 0 {
+                continue
+            }
+            if let Some(index) = check.check.index() {
+                if !indexes.contains(&index) {
+                    indexes.push(index);
+                }
+            }
+        }
+        indexes
+    }
+
+    fn add(&mut self, recorder: CheckRecorder) {
+        self.checks.push(CheckReport {
+            check: recorder.check,
+            verified: recorder.verified,
+            mismatches: recorder.mismatches,
+            skipped: None,
+        });
+        self.mismatches.extend(recorder.listed);
+    }
+
+    fn skip(&mut self, check: Check, reason: &str) {
+        self.checks.push(CheckReport {
+            check,
+            verified: 0,
+            mismatches: 0,
+            skipped: Some(reason.to_string()),
+        });
+    }
+}
+
+/// Collects the outcome of the comparisons of a single check.
+struct CheckRecorder {
+    check: Check,
+    verified: u64,
+    mismatches: u64,
+    listed: Vec<Mismatch>,
+}
+
+impl CheckRecorder {
+    fn new(check: Check) -> Self {
+        Self {
+            check,
+            verified: 0,
+            mismatches: 0,
+            listed: vec![],
+        }
+    }
+
+    fn compare<V>(&mut self, key: String, expected: Option<V>, actual: Option<V>)
+    where
+        V: PartialEq + Debug,
+    {
+        self.verified = self.verified.saturating_add(1);
+        if expected == actual {
+            return
+        }
+
+        self.mismatches = self.mismatches.saturating_add(1);
+        if self.listed.len() < MAX_LISTED_MISMATCHES {
+            self.listed.push(Mismatch {
+                check: self.check,
+                key,
+                expected: expected.map(|value| format!("{value:?}")),
+                actual: actual.map(|value| format!("{value:?}")),
+            });
+        }
+    }
+}
+
+impl CombinedDatabase {
+    /// Verifies the consistency of the on-chain and off-chain databases
+    /// at the `path`. The databases are not modified, the mismatches of
+    /// the off-chain indexes can be repaired by the reindex.
+    pub fn verify(path: &Path, max_fds: i32) -> anyhow::Result<VerificationReport> {
+        for name in [OnChain::name(), OffChain::name()] {
+            if !path.join(&name).exists() {
+                bail!("The {name} database doesn't exist at {path:?}")
+            }
+        }
+
+        let on_chain = Database::<OnChain>::open_rocksdb_read_only(path, max_fds)?;
+        let off_chain = Database::<OffChain>::open_rocksdb_read_only(path, max_fds)?;
+        let on_chain_height = HistoricalView::latest_height(&on_chain);
+        let off_chain_height = HistoricalView::latest_height(&off_chain);
+        let on_chain_view = on_chain.latest_view()?;
+        let off_chain_view = off_chain.latest_view()?;
+
+        let mut report = VerificationReport {
+            on_chain_height: on_chain_height.map(|height| height.as_u64()),
+            off_chain_height: off_chain_height.map(|height| height.as_u64()),
+            checks: vec![],
+            mismatches: vec![],
+        };
+
+        // The off-chain database is behind the on-chain database while the node
+        // processes the blocks, so the indexes are compared with the on-chain
+        // state at the height of the off-chain database.
+        let source = match (on_chain_height, off_chain_height) {
+            (Some(on_chain_height), Some(off_chain_height))
+                if off_chain_height < on_chain_height =>
+            {
+                on_chain.view_at(&off_chain_height).map_err(|err| {
+                    format!(
+                        "the on-chain state at the off-chain height \
+                        {off_chain_height} is unavailable: {err}"
+                    )
+                })
+            }
+            (Some(on_chain_height), Some(off_chain_height))
+                if off_chain_height > on_chain_height =>
+            {
+                Err(format!(
+                    "the off-chain database at the height {off_chain_height} is \
+                    ahead of the on-chain database at the height {on_chain_height}"
+                ))
+            }
+            _ => Ok(on_chain_view.clone()),
+        };
+
+        match source {
+            Ok(source) => {
+                if off_chain.indexation_available(IndexationKind::Balances)? {
+                    report.add(verify_coin_balances(&source, &off_chain_view)?);
+                    report.add(verify_message_balances(&source, &off_chain_view)?);
+                } else {
+                    let reason = "the balances indexation is unavailable";
+                    report.skip(Check::CoinBalances, reason);
+                    report.skip(Check::MessageBalances, reason);
+                }
+                report.add(verify_owned_coins(&source, &off_chain_view)?);
+                report.add(verify_owned_message_ids(&source, &off_chain_view)?);
+            }
+            Err(reason) => {
+                for check in [
+                    Check::CoinBalances,
+                    Check::MessageBalances,
+                    Check::OwnedCoins,
+                    Check::OwnedMessageIds,
+                ] {
+                    report.skip(check, &reason);
+                }
+            }
+        }
+
+        report.add(verify_block_merkle_roots(&on_chain_view)?);
+        #[cfg(feature = "smt")]
+        report.add(verify_contract_state_roots(&on_chain_view)?);
+        #[cfg(not(feature = "smt"))]
+        report.skip(
+            Check::ContractStateRoots,
+            "the node is built without the `smt` feature",
+        );
+
+        Ok(report)
+    }
+}
+
+fn verify_coin_balances(
+    on_chain: &OnChainIterableKeyValueView,
+    off_chain: &OffChainIterableKeyValueView,
+) -> anyhow::Result<CheckRecorder> {
+    let mut recorder = CheckRecorder::new(Check::CoinBalances);
+
+    let mut expected_balances = BTreeMap::<(Address, AssetId), u128>::new();
+    for coin in on_chain.iter_all::<Coins>(None) {
+        let (_, coin) = coin?;
+        let balance = expected_balances
+            .entry((*coin.owner(), *coin.asset_id()))
+            .or_default();
+        *balance = balance.saturating_add(u128::from(*coin.amount()));
+    }
+
+    // The spent balances are stored as zero, so the missing entry
+    // and the zero balance are equivalent.
+    for entry in off_chain.iter_all::<CoinBalances>(None) {
+        let (key, actual) = entry?;
+        let expected = expected_balances
+            .remove(&(*key.address(), *key.asset_id()))
+            .unwrap_or_default();
+        recorder.compare(key.to_string(), Some(expected), Some(actual));
+    }
+    for ((owner, asset_id), expected) in expected_balances {
+        recorder.compare(
+            format!("address={owner} asset_id={asset_id}"),
+            Some(expected),
+            Some(0),
+        );
+    }
+
+    Ok(recorder)
+}
+
+fn verify_message_balances(
+    on_chain: &OnChainIterableKeyValueView,
+    off_chain: &OffChainIterableKeyValueView,
+) -> anyhow::Result<CheckRecorder> {
+    let mut recorder = CheckRecorder::new(Check::MessageBalances);
+
+    let mut expected_balances = BTreeMap::<Address, MessageBalance>::new();
+    for message in on_chain.iter_all::<Messages>(None) {
+        let (_, message) = message?;
+        let balance = expected_balances.entry(*message.recipient()).or_default();
+        let amount = u128::from(message.amount());
+        if message.is_retryable_message() {
+            balance.retryable = balance.retryable.saturating_add(amount);
+        } else {
+            balance.non_retryable = balance.non_retryable.saturating_add(amount);
+        }
+    }
+
+    for entry in off_chain.iter_all::<MessageBalances>(None) {
+        let (owner, actual) = entry?;
+        let expected = expected_balances.remove(&owner).unwrap_or_default();
+        recorder.compare(format!("address={owner}"), Some(expected), Some(actual));
+    }
+    for (owner, expected) in expected_balances {
+        recorder.compare(
+            format!("address={owner}"),
+            Some(expected),
+            Some(MessageBalance::default()),
+        );
+    }
+
+    Ok(recorder)
+}
+
+fn verify_owned_coins(
+    on_chain: &OnChainIterableKeyValueView,
+    off_chain: &OffChainIterableKeyValueView,
+) -> anyhow::Result<CheckRecorder> {
+    let mut recorder = CheckRecorder::new(Check::OwnedCoins);
+
+    // Each coin must be indexed by its owner.
+    for coin in on_chain.iter_all::<Coins>(None) {
+        let (utxo_id, coin) = coin?;
+        let owner = *coin.owner();
+        let indexed = off_chain
+            .storage::<OwnedCoins>()
+            .contains_key(&owner_coin_id_key(&owner, &utxo_id))?;
+        recorder.compare(
+            format!("utxo_id={utxo_id}"),
+            Some(owner),
+            indexed.then_some(owner),
+        );
+    }
+
+    // Each indexed coin must exist and belong to the owner. The matching
+    // entries were compared above.
+    for key in off_chain.iter_all_keys::<OwnedCoins>(None) {
+        let key = key?;
+        let (owner, utxo_id) = key.split_at(Address::LEN);
+        let owner = Address::try_from(owner)?;
+        let utxo_id = <Primitive<{ TxId::LEN + 2 }> as Decode<UtxoId>>::decode(utxo_id)?;
+        let expected = on_chain
+            .storage::<Coins>()
+            .get(&utxo_id)?
+            .map(|coin| *coin.owner());
+        if expected != Some(owner) {
+            recorder.compare(format!("utxo_id={utxo_id}"), expected, Some(owner));
+        }
+    }
+
+    Ok(recorder)
+}
+
+fn verify_owned_message_ids(
+    on_chain: &OnChainIterableKeyValueView,
+    off_chain: &OffChainIterableKeyValueView,
+) -> anyhow::Result<CheckRecorder> {
+    let mut recorder = CheckRecorder::new(Check::OwnedMessageIds);
+
+    // Each message must be indexed by its recipient.
+    for message in on_chain.iter_all::<Messages>(None) {
+        let (nonce, message) = message?;
+        let recipient = *message.recipient();
+        let indexed = off_chain
+            .storage::<OwnedMessageIds>()
+            .contains_key(&OwnedMessageKey::new(&recipient, &nonce))?;
+        recorder.compare(
+            format!("nonce={nonce}"),
+            Some(recipient),
+            indexed.then_some(recipient),
+        );
+    }
+
+    // Each indexed message must exist and belong to the recipient. The matching
+    // entries were compared above.
+    for key in off_chain.iter_all_keys::<OwnedMessageIds>(None) {
+        let key = key?;
+        let expected = on_chain
+            .storage::<Messages>()
+            .get(key.nonce())?
+            .map(|message| *message.recipient());
+        if expected.as_ref() != Some(key.address()) {
+            recorder.compare(
+                format!("nonce={}", key.nonce()),
+                expected,
+                Some(*key.address()),
+            );
+        }
+    }
+
+    Ok(recorder)
+}
+
+fn verify_block_merkle_roots(
+    on_chain: &OnChainIterableKeyValueView,
+) -> anyhow::Result<CheckRecorder> {
+    let mut recorder = CheckRecorder::new(Check::BlockMerkleRoots);
+
+    let Some(latest_height) = on_chain
+        .iter_all_keys::<FuelBlocks>(Some(IterDirection::Reverse))
+        .next()
+        .transpose()?
+    else {
+        return Ok(recorder)
+    };
+    let latest_version = on_chain
+        .storage::<FuelBlockMerkleMetadata>()
+        .get(&DenseMetadataKey::Latest)?
+        .map(|metadata| metadata.version());
+
+    // Each block adds one leaf to the tree, so the number of leaves
+    // of the tree at the height follows from the latest metadata.
+    let derived_root = |height: u32| {
+        let version = latest_version?
+            .checked_sub(u64::from(u32::from(latest_height).checked_sub(height)?))?;
+        let tree = MerkleTree::<FuelBlockMerkleData, _>::load(on_chain, version).ok()?;
+        Some(Bytes32::from(tree.root()))
+    };
+
+    let blocks = on_chain.iter_all::<FuelBlocks>(Some(IterDirection::Forward));
+    for (index, block) in blocks.enumerate() {
+        let (height, block) = block?;
+        let derived = derived_root(u32::from(height));
+        let stored = on_chain
+            .storage::<FuelBlockMerkleMetadata>()
+            .get(&DenseMetadataKey::Primary(height))?
+            .map(Cow::into_owned)
+            .map(|metadata| Bytes32::from(*metadata.root()));
+        recorder.compare(format!("height={height}"), derived, stored);
+
+        // The block commits to the root of the tree of the previous blocks.
+        // The genesis block after a regenesis commits to the tree of the previous
+        // network, which doesn't exist locally, so it is skipped.
+        let is_genesis = index == 0;
+        if let Some(previous_height) = height.pred().filter(|_| !is_genesis) {
+            recorder.compare(
+                format!("height={height} prev_root"),
+                derived_root(u32::from(previous_height)),
+                Some(*block.header().prev_root()),
+            );
+        }
+    }
+
+    Ok(recorder)
+}
+
+#[cfg(feature = "smt")]
+fn verify_contract_state_roots(
+    on_chain: &OnChainIterableKeyValueView,
+) -> anyhow::Result<CheckRecorder> {
+    use fuel_core_storage::tables::{
+        merkle::ContractsStateMerkleMetadata,
+        ContractsState,
+    };
+    use fuel_core_types::{
+        fuel_merkle::sparse::{
+            self,
+            MerkleTreeKey,
+        },
+        fuel_tx::ContractId,
+    };
+
+    let mut recorder = CheckRecorder::new(Check::ContractStateRoots);
+    let empty_root = Bytes32::from(sparse::in_memory::MerkleTree::new().root());
+    let stored_root = |contract_id: &ContractId| {
+        let root = on_chain
+            .storage::<ContractsStateMerkleMetadata>()
+            .get(contract_id)?
+            .map(|metadata| Bytes32::from(*metadata.root()));
+        Ok::<_, anyhow::Error>(root)
+    };
+
+    // The slots are sorted by the contract id, so the state of each contract
+    // is collected separately.
+    let mut slots = on_chain.iter_all::<ContractsState>(None).peekable();
+    while let Some(slot) = slots.next() {
+        let (key, value) = slot?;
+        let contract_id = *key.contract_id();
+        let mut state = vec![(key, value)];
+        while let Some(Ok((key, _))) = slots.peek() {
+            if key.contract_id() != &contract_id {
+                break
+            }
+            if let Some(Ok(slot)) = slots.next() {
+                state.push(slot);
+            }
+        }
+        let expected = sparse::in_memory::MerkleTree::root_from_set(
+            state
+                .iter()
+                .map(|(key, value)| (MerkleTreeKey::new(*key), value)),
+        );
+        recorder.compare(
+            format!("contract_id={contract_id}"),
+            Some(Bytes32::from(expected)),
+            stored_root(&contract_id)?,
+        );
+    }
+
+    // The metadata is removed with the last slot of the contract.
+    for contract_id in on_chain.iter_all_keys::<ContractsStateMerkleMetadata>(None) {
+        let contract_id = contract_id?;
+        let has_state = on_chain
+            .iter_all_filtered_keys::<ContractsState, _>(Some(contract_id), None, None)
+            .next()
+            .is_some();
+        if !has_state {
+            recorder.compare(
+                format!("contract_id={contract_id}"),
+                Some(empty_root),
+                stored_root(&contract_id)?,
+            );
+        }
+    }
+
+    Ok(recorder)
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        graphql_api::storage::{
+            balances::CoinBalancesKey,
+            blocks::FuelBlockIdsToHeights,
+        },
+        state::historical_rocksdb::StateRewindPolicy,
+    };
+    use fuel_core_storage::{
+        transactional::ReadTransaction,
+        StorageAsMut,
+    };
+    use fuel_core_types::{
+        blockchain::block::CompressedBlock,
+        entities::coins::coin::CompressedCoin,
+    };
+    use tempfile::TempDir;
+
+    const OWNER: Address = Address::new([1; 32]);
+
+    fn database_with_coin(path: &Path, amount: u64) -> CombinedDatabase {
+        let mut database = CombinedDatabase::open(
+            path,
+            1024 * 1024,
+            StateRewindPolicy::RewindFullRange,
+            512,
+        )
+        .unwrap();
+        let utxo_id = UtxoId::new(TxId::new([2; 32]), 0);
+        let mut coin = CompressedCoin::default();
+        coin.set_owner(OWNER);
+        coin.set_amount(amount);
+
+        database
+            .on_chain_mut()
+            .storage_as_mut::<Coins>()
+            .insert(&utxo_id, &coin)
+            .unwrap();
+        database
+            .on_chain_mut()
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&0u32.into(), &CompressedBlock::default())
+            .unwrap();
+
+        let off_chain = database.off_chain_mut();
+        off_chain
+            .storage_as_mut::<OwnedCoins>()
+            .insert(&owner_coin_id_key(&OWNER, &utxo_id), &())
+            .unwrap();
+        off_chain
+            .storage_as_mut::<CoinBalances>()
+            .insert(
+                &CoinBalancesKey::new(&OWNER, &AssetId::default()),
+                &u128::from(amount),
+            )
+            .unwrap();
+        off_chain
+            .storage_as_mut::<FuelBlockIdsToHeights>()
+            .insert(&Default::default(), &0u32.into())
+            .unwrap();
+        database
+    }
+
+    #[test]
+    fn verify__reports_consistent_database() {
+        let db_dir = TempDir::new().unwrap();
+
+        // Given
+        let _database = database_with_coin(db_dir.path(), 100);
+
+        // When
+        let report = CombinedDatabase::verify(db_dir.path(), 512).unwrap();
+
+        // Then
+        assert!(report.is_consistent(), "{report:#?}");
+        assert_eq!(report.on_chain_height, Some(0));
+        assert_eq!(report.off_chain_height, Some(0));
+        let owned_coins = report
+            .checks
+            .iter()
+            .find(|check| check.check == Check::OwnedCoins)
+            .unwrap();
+        assert_eq!(owned_coins.verified, 1);
+    }
+
+    #[test]
+    fn verify__reports_corrupted_balance_and_index_to_repair() {
+        let db_dir = TempDir::new().unwrap();
+        let database = database_with_coin(db_dir.path(), 100);
+
+        // Given
+        let key = CoinBalancesKey::new(&OWNER, &AssetId::default());
+        let off_chain = database.off_chain();
+        let mut transaction = off_chain.read_transaction();
+        transaction
+            .storage_as_mut::<CoinBalances>()
+            .insert(&key, &42)
+            .unwrap();
+        off_chain
+            .commit_changes_without_height(transaction.into_changes())
+            .unwrap();
+
+        // When
+        let report = CombinedDatabase::verify(db_dir.path(), 512).unwrap();
+
+        // Then
+        assert!(!report.is_consistent());
+        assert_eq!(
+            report.mismatches,
+            vec![Mismatch {
+                check: Check::CoinBalances,
+                key: key.to_string(),
+                expected: Some("100".to_string()),
+                actual: Some("42".to_string()),
+            }]
+        );
+        assert_eq!(report.indexes_to_repair(), vec![OffChainIndex::Balances]);
+    }
+
+    #[test]
+    fn verify__skips_prev_root_of_regenesis_block() {
+        let db_dir = TempDir::new().unwrap();
+        let mut database = CombinedDatabase::open(
+            db_dir.path(),
+            1024 * 1024,
+            StateRewindPolicy::RewindFullRange,
+            512,
+        )
+        .unwrap();
+
+        // Given
+        let mut block = CompressedBlock::default();
+        block.header_mut().set_block_height(5u32.into());
+        block.header_mut().set_previous_root(Bytes32::new([7; 32]));
+        database
+            .on_chain_mut()
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&5u32.into(), &block)
+            .unwrap();
+        drop(database);
+
+        // When
+        let report = CombinedDatabase::verify(db_dir.path(), 512).unwrap();
+
+        // Then
+        let roots = report
+            .checks
+            .iter()
+            .find(|check| check.check == Check::BlockMerkleRoots)
+            .unwrap();
+        assert_eq!(roots.mismatches, 0, "{report:#?}");
+    }
+}
@@ -21,7 +21,6 @@ use crate::{
         in_memory::memory_store::MemoryStore,
         ColumnType,
         IterableKeyValueView,
-        KeyValueView,
     },
 };
 use fuel_core_chain_config::TableEntry;
@@ -223,6 +222,64 @@ where
         Ok(Self::new(Arc::new(db)))
     }
 
+    /// Opens the secondary instance following the database of the primary node
+    /// at the `path`. The secondary instance is read-only and requires
+    /// [`Self::catch_up_with_primary`] to see the new changes of the primary.
+    #[cfg(feature = "rocksdb")]
+    pub fn open_rocksdb_secondary(
+        path: &Path,
+        secondary_path: &Path,
+        capacity: impl Into<Option<usize>>,
+        max_fds: i32,
+    ) -> Result<Self> {
+        use anyhow::Context;
+        let db = HistoricalRocksDB::<Description>::open_secondary(
+            path,
+            secondary_path,
+            capacity.into(),
+            max_fds,
+        )
+        .map_err(Into::<anyhow::Error>::into)
+        .with_context(|| {
+            format!("Failed to open the secondary instance of the rocksdb at {path:?}")
+        })?;
+
+        Ok(Self::new(Arc::new(db)))
+    }
+
+    /// Opens the database at the `path` in the read-only mode.
+    /// The database can be opened while the node uses it.
+    #[cfg(feature = "rocksdb")]
+    pub fn open_rocksdb_read_only(path: &Path, max_fds: i32) -> Result<Self> {
+        use anyhow::Context;
+        let db = HistoricalRocksDB::<Description>::open_read_only(path, None, max_fds)
+            .map_err(Into::<anyhow::Error>::into)
+            .with_context(|| {
+                format!("Failed to open rocksdb at {path:?} in the read-only mode")
+            })?;
+
+        Ok(Self::new(Arc::new(db)))
+    }
+
+    /// Applies the changes of the primary instance and updates the cached height.
+    pub fn catch_up_with_primary(&self) -> StorageResult<()> {
+        let mut lock = self.inner_storage().stage.height.lock();
+        self.inner_storage().data.catch_up_with_primary()?;
+        *lock = self.latest_height_from_metadata()?;
+        Ok(())
+    }
+
+    /// Creates the checkpoint of the database in the `path` and returns its height.
+    /// The database doesn't accept new heights only while the checkpoint is created.
+    pub fn create_checkpoint(
+        &self,
+        path: &std::path::Path,
+    ) -> StorageResult<Option<Description::Height>> {
+        let lock = self.inner_storage().stage.height.lock();
+        self.inner_storage().data.create_checkpoint(path)?;
+        Ok(*lock)
+    }
+
     /// Converts the regular database to an unchecked database.
     ///
     /// Returns an error in the case regular database is initialized with the `GenesisDatabase`,
@@ -326,7 +383,7 @@ where
     Description: DatabaseDescription,
 {
     type Height = Description::Height;
-    type ViewAtHeight = KeyValueView<ColumnType<Description>>;
+    type ViewAtHeight = IterableKeyValueView<ColumnType<Description>>;
 
     fn latest_height(&self) -> Option<Self::Height> {
         *self.inner_storage().stage.height.lock()
@@ -336,9 +393,9 @@ where
         let lock = self.inner_storage().stage.height.lock();
 
         match *lock {
-            None => return self.latest_view().map(|view| view.into_key_value_view()),
+            None => return self.latest_view(),
             Some(current_height) if &current_height == height => {
-                return self.latest_view().map(|view| view.into_key_value_view())
+                return self.latest_view()
             }
             _ => {}
         };
@@ -366,6 +423,16 @@ impl Modifiable for Database<OffChain> {
     }
 }
 
+impl Database<OffChain> {
+    /// Commits the changes that don't belong to any block, like the runtime
+    /// configuration of the node. The changes are not a part of the history,
+    /// so the rollback doesn't revert them.
+    pub fn commit_changes_without_height(&self, changes: Changes) -> StorageResult<()> {
+        let _guard = self.inner_storage().stage.height.lock();
+        self.inner_storage().data.commit_changes(None, changes)
+    }
+}
+
 impl Modifiable for Database<GasPriceDatabase> {
     fn commit_changes(&mut self, changes: Changes) -> StorageResult<()> {
         commit_changes_with_height_update(self, changes, |iter| {
@@ -13,11 +13,13 @@ use fuel_core_storage::{
         ContractsState,
     },
     ContractsAssetKey,
+    ContractsStateKey,
     Result as StorageResult,
     StorageAsRef,
 };
 use fuel_core_types::fuel_types::{
     AssetId,
+    Bytes32,
     ContractId,
 };
 use itertools::Itertools;
@@ -92,6 +94,21 @@ impl OnChainIterableKeyValueView {
         )
         .map_ok(|(key, value)| TableEntry { key, value })
     }
+
+    pub fn filter_contract_storage_slots(
+        &self,
+        contract: ContractId,
+        start_slot: Option<Bytes32>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<TableEntry<ContractsState>>> + '_ {
+        let start_slot = start_slot.map(|slot| ContractsStateKey::new(&contract, &slot));
+        self.iter_all_filtered::<ContractsState, _>(
+            Some(contract),
+            start_slot.as_ref(),
+            direction,
+        )
+        .map_ok(|(key, value)| TableEntry { key, value })
+    }
 }
 
 #[cfg(test)]
@@ -4,6 +4,7 @@ use super::database_description::{
 };
 use crate::database::{
     database_description::{
+        off_chain::OffChain,
         DatabaseDescription,
         DatabaseMetadata,
     },
@@ -14,12 +15,15 @@ use fuel_core_storage::{
     blueprint::plain::Plain,
     codec::postcard::Postcard,
     structured_storage::TableWithBlueprint,
+    transactional::ReadTransaction,
     Error as StorageError,
     Mappable,
     Result as StorageResult,
+    StorageAsMut,
     StorageAsRef,
     StorageInspect,
 };
+use std::collections::HashSet;
 
 /// The table that stores all metadata about the database.
 pub struct MetadataTable<Description>(core::marker::PhantomData<Description>);
@@ -89,3 +93,46 @@ where
         Ok(indexation_availability.contains(&kind))
     }
 }
+
+impl Database<OffChain> {
+    /// Sets the availability of the indexation `kind` in the metadata.
+    /// The indexation is available only when its tables are complete,
+    /// so it is enabled after the tables are rebuilt by the reindex.
+    ///
+    /// Does nothing for the empty database without metadata,
+    /// all indexations are available for the new database.
+    pub fn set_indexation_availability(
+        &self,
+        kind: IndexationKind,
+        available: bool,
+    ) -> StorageResult<()> {
+        let Some(metadata) = self.storage::<MetadataTable<OffChain>>().get(&())? else {
+            return Ok(());
+        };
+
+        let (version, height, mut indexation_availability) = match metadata.into_owned() {
+            DatabaseMetadata::V1 { version, height } => (version, height, HashSet::new()),
+            DatabaseMetadata::V2 {
+                version,
+                height,
+                indexation_availability,
+            } => (version, height, indexation_availability),
+        };
+        if available {
+            indexation_availability.insert(kind);
+        } else {
+            indexation_availability.remove(&kind);
+        }
+        let metadata = DatabaseMetadata::V2 {
+            version,
+            height,
+            indexation_availability,
+        };
+
+        let mut transaction = self.read_transaction();
+        transaction
+            .storage_as_mut::<MetadataTable<OffChain>>()
+            .insert(&(), &metadata)?;
+        self.commit_changes_without_height(transaction.into_changes())
+    }
+}
@@ -9,6 +9,7 @@ use std::{
 };
 
 pub mod api_service;
+pub(crate) mod auth_extension;
 pub mod da_compression;
 pub mod database;
 pub(crate) mod indexation;
@@ -17,8 +18,14 @@ pub mod ports;
 pub mod storage;
 pub(crate) mod validation_extension;
 pub(crate) mod view_extension;
+pub mod websocket;
 pub mod worker_service;
 
+pub use auth_extension::{
+    ApiKeys,
+    Role,
+};
+
 #[derive(Clone, Debug)]
 pub struct Config {
     pub config: ServiceConfig,
@@ -28,6 +35,9 @@ pub struct Config {
     pub max_tx: usize,
     pub max_txpool_dependency_chain_length: usize,
     pub chain_name: String,
+    /// Rejects the mutations and the submission of the transactions,
+    /// used by the read replica.
+    pub read_only: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -41,14 +51,41 @@ pub struct ServiceConfig {
     pub max_queries_resolver_recursive_depth: usize,
     pub max_queries_directives: usize,
     pub max_concurrent_queries: usize,
+    /// The max number of active subscriptions over one WebSocket connection.
+    pub max_subscriptions_per_connection: usize,
     pub request_body_bytes_limit: usize,
     /// Time to wait after submitting a query before debug info will be logged about query.
     pub query_log_threshold_time: Duration,
     pub api_request_timeout: Duration,
+    /// The bearer token granting the admin role.
+    pub admin_api_token: Option<String>,
+    /// The API keys accepted as the bearer tokens and the roles granted by them.
+    pub api_keys: ApiKeys,
+    /// The secret of the HS256 JWT bearer tokens.
+    /// The role is taken from the `role` claim.
+    pub jwt_secret: Option<String>,
+    /// The role of the requests without a bearer token.
+    pub anonymous_role: Role,
+    /// The origins allowed to make the cross-origin requests.
+    /// Any origin is allowed if empty.
+    pub cors_allowed_origins: Vec<String>,
+    /// The address of the separate listener for the admin operations.
+    /// If it is set, the admin operations and the metrics are not served
+    /// by the main listener.
+    pub admin_addr: Option<SocketAddr>,
     /// Configurable cost parameters to limit graphql queries complexity
     pub costs: Costs,
 }
 
+impl ServiceConfig {
+    /// Returns `true` if any bearer token is accepted by the API.
+    pub fn is_auth_enabled(&self) -> bool {
+        self.admin_api_token.is_some()
+            || !self.api_keys.is_empty()
+            || self.jwt_secret.is_some()
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Costs {
     pub balance_query: usize,
@@ -59,6 +96,7 @@ pub struct Costs {
     pub submit: usize,
     pub submit_and_await: usize,
     pub status_change: usize,
+    pub blocks_subscription: usize,
     pub storage_read: usize,
     pub tx_get: usize,
     pub tx_status_read: usize,
@@ -70,6 +108,7 @@ pub struct Costs {
     pub bytecode_read: usize,
     pub state_transition_bytecode_read: usize,
     pub da_compressed_block_read: usize,
+    pub database_checkpoint: usize,
 }
 
 #[cfg(feature = "test-helpers")]
@@ -91,6 +130,7 @@ pub const DEFAULT_QUERY_COSTS: Costs = Costs {
     submit: 40001,
     submit_and_await: 40001,
     status_change: 40001,
+    blocks_subscription: 40001,
     storage_read: 40,
     tx_get: 50,
     tx_status_read: 50,
@@ -102,6 +142,7 @@ pub const DEFAULT_QUERY_COSTS: Costs = Costs {
     bytecode_read: 8000,
     state_transition_bytecode_read: 76_000,
     da_compressed_block_read: 4000,
+    database_checkpoint: 40001,
 };
 
 pub fn query_costs() -> &'static Costs {
@@ -1,19 +1,34 @@
 use crate::{
     fuel_core_graphql_api::{
+        auth_extension::{
+            authenticate,
+            AdminListener,
+            AuthExtension,
+            Role,
+        },
         metrics_extension::MetricsExtension,
         ports::{
+            BlockImporterPort,
             BlockProducerPort,
             ConsensusModulePort,
             ConsensusProvider as ConsensusProviderTrait,
+            DatabaseCheckpointPort,
             GasPriceEstimate,
+            GasPriceHistoryPort,
             OffChainDatabase,
             OnChainDatabase,
             P2pPort,
+            TxPoolBlackListPort,
             TxPoolPort,
         },
         validation_extension::ValidationExtension,
         view_extension::ViewExtension,
+        websocket::{
+            graphql_ws_handler,
+            WebSocketLimits,
+        },
         Config,
+        ServiceConfig,
     },
     graphql_api,
     schema::{
@@ -31,17 +46,16 @@ use async_graphql::{
     Response,
 };
 use axum::{
+    body::Body,
     extract::{
         DefaultBodyLimit,
         Extension,
     },
     http::{
-        header::{
-            ACCESS_CONTROL_ALLOW_HEADERS,
-            ACCESS_CONTROL_ALLOW_METHODS,
-            ACCESS_CONTROL_ALLOW_ORIGIN,
-        },
+        header::AUTHORIZATION,
+        HeaderMap,
         HeaderValue,
+        StatusCode,
     },
     response::{
         sse::Event,
@@ -63,7 +77,7 @@ use fuel_core_services::{
     StateWatcher,
     TaskNextAction,
 };
-use fuel_core_storage::transactional::AtomicView;
+use fuel_core_storage::transactional::HistoricalView;
 use fuel_core_types::fuel_types::BlockHeight;
 use futures::Stream;
 use hyper::rt::Executor;
@@ -80,7 +94,12 @@ use std::{
 use tokio_stream::StreamExt;
 use tower::limit::ConcurrencyLimitLayer;
 use tower_http::{
-    set_header::SetResponseHeaderLayer,
+    cors::{
+        AllowHeaders,
+        AllowMethods,
+        AllowOrigin,
+        CorsLayer,
+    },
     timeout::TimeoutLayer,
     trace::TraceLayer,
 };
@@ -94,25 +113,38 @@ pub type BlockProducer = Box<dyn BlockProducerPort>;
 // In the future GraphQL should not be aware of `TxPool`. It should
 //  use only `Database` to receive all information about transactions.
 pub type TxPool = Box<dyn TxPoolPort>;
+pub type TxPoolBlackListManager = Box<dyn TxPoolBlackListPort>;
 pub type ConsensusModule = Box<dyn ConsensusModulePort>;
+pub type BlockImporter = Box<dyn BlockImporterPort>;
 pub type P2pService = Box<dyn P2pPort>;
 
 pub type GasPriceProvider = Box<dyn GasPriceEstimate>;
 
+pub type GasPriceHistory = Box<dyn GasPriceHistoryPort>;
+
+pub type DatabaseCheckpoint = Box<dyn DatabaseCheckpointPort>;
+
 pub type ConsensusProvider = Box<dyn ConsensusProviderTrait>;
 
+/// The token from the `Authorization: Bearer <token>` header of the request.
+pub struct BearerToken(pub String);
+
 #[derive(Clone)]
 pub struct SharedState {
     pub bound_address: SocketAddr,
+    /// The address of the listener serving the admin operations, if it is separate.
+    pub admin_bound_address: Option<SocketAddr>,
 }
 
 pub struct GraphqlService {
     bound_address: SocketAddr,
+    admin_bound_address: Option<SocketAddr>,
 }
 
 pub struct ServerParams {
     router: Router,
     listener: TcpListener,
+    admin: Option<(Router, TcpListener)>,
     number_of_threads: usize,
 }
 
@@ -151,6 +183,7 @@ impl RunnableService for GraphqlService {
     fn shared_data(&self) -> Self::SharedData {
         SharedState {
             bound_address: self.bound_address,
+            admin_bound_address: self.admin_bound_address,
         }
     }
 
@@ -159,10 +192,10 @@ impl RunnableService for GraphqlService {
         state: &StateWatcher,
         params: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
-        let mut state = state.clone();
         let ServerParams {
             router,
             listener,
+            admin,
             number_of_threads,
         } = params;
 
@@ -176,23 +209,42 @@ impl RunnableService for GraphqlService {
             processor: Arc::new(processor),
         };
 
-        let server = axum::Server::from_tcp(listener)
-            .unwrap()
-            .executor(executor)
-            .serve(router.into_make_service())
-            .with_graceful_shutdown(async move {
-                state
-                    .while_started()
-                    .await
-                    .expect("The service is destroyed");
-            });
-
-        Ok(Task {
-            server: Box::pin(server),
-        })
+        let server = serve(listener, router, executor.clone(), state.clone());
+        let server: Pin<Box<dyn Future<Output = hyper::Result<()>> + Send>> = match admin
+        {
+            Some((admin_router, admin_listener)) => {
+                let admin_server =
+                    serve(admin_listener, admin_router, executor, state.clone());
+                Box::pin(async move {
+                    futures::future::try_join(server, admin_server).await?;
+                    Ok(())
+                })
+            }
+            None => Box::pin(server),
+        };
+
+        Ok(Task { server })
     }
 }
 
+fn serve(
+    listener: TcpListener,
+    router: Router,
+    executor: ExecutorWithMetrics,
+    mut state: StateWatcher,
+) -> impl Future<Output = hyper::Result<()>> + Send + 'static {
+    axum::Server::from_tcp(listener)
+        .unwrap()
+        .executor(executor)
+        .serve(router.into_make_service())
+        .with_graceful_shutdown(async move {
+            state
+                .while_started()
+                .await
+                .expect("The service is destroyed");
+        })
+}
+
 #[async_trait::async_trait]
 impl RunnableTask for Task {
     async fn run(&mut self, _: &mut StateWatcher) -> TaskNextAction {
@@ -223,22 +275,30 @@ pub fn new_service<OnChain, OffChain>(
     on_database: OnChain,
     off_database: OffChain,
     txpool: TxPool,
+    txpool_blacklist: TxPoolBlackListManager,
     producer: BlockProducer,
     consensus_module: ConsensusModule,
+    block_importer: BlockImporter,
     p2p_service: P2pService,
     gas_price_provider: GasPriceProvider,
+    gas_price_history: GasPriceHistory,
+    database_checkpoint: DatabaseCheckpoint,
     consensus_parameters_provider: ConsensusProvider,
     memory_pool: SharedMemoryPool,
 ) -> anyhow::Result<Service>
 where
-    OnChain: AtomicView + 'static,
-    OffChain: AtomicView + worker::OffChainDatabase + 'static,
+    OnChain: HistoricalView<Height = BlockHeight> + 'static,
+    OffChain: HistoricalView<Height = BlockHeight> + worker::OffChainDatabase + 'static,
     OnChain::LatestView: OnChainDatabase,
+    OnChain::ViewAtHeight: OnChainDatabase,
     OffChain::LatestView: OffChainDatabase,
+    OffChain::ViewAtHeight: OffChainDatabase,
 {
     graphql_api::initialize_query_costs(config.config.costs.clone())?;
 
     let network_addr = config.config.addr;
+    let admin_addr = config.config.admin_addr;
+    let cors = cors_layer(&config.config.cors_allowed_origins)?;
     let combined_read_database = ReadDatabase::new(
         config.config.database_batch_size,
         genesis_block_height,
@@ -247,11 +307,16 @@ where
     )?;
     let request_timeout = config.config.api_request_timeout;
     let concurrency_limit = config.config.max_concurrent_queries;
+    let websocket_limits = WebSocketLimits::new(
+        concurrency_limit,
+        config.config.max_subscriptions_per_connection,
+    );
     let body_limit = config.config.request_body_bytes_limit;
     let max_queries_resolver_recursive_depth =
         config.config.max_queries_resolver_recursive_depth;
     let number_of_threads = config.config.number_of_threads;
 
+    let auth_config = Arc::new(config.config.clone());
     let schema = schema
         .limit_complexity(config.config.max_queries_complexity)
         .limit_depth(config.config.max_queries_depth)
@@ -263,72 +328,119 @@ where
         .data(config)
         .data(combined_read_database)
         .data(txpool)
+        .data(txpool_blacklist)
         .data(producer)
         .data(consensus_module)
+        .data(block_importer)
         .data(p2p_service)
         .data(gas_price_provider)
+        .data(gas_price_history)
+        .data(database_checkpoint)
         .data(consensus_parameters_provider)
         .data(memory_pool)
         .extension(ValidationExtension::new(
             max_queries_resolver_recursive_depth,
         ))
+        .extension(AuthExtension::new())
         .extension(async_graphql::extensions::Tracing)
         .extension(ViewExtension::new())
         .finish();
 
     let graphql_endpoint = "/v1/graphql";
     let graphql_subscription_endpoint = "/v1/graphql-sub";
+    let graphql_websocket_endpoint = "/v1/graphql-ws";
 
     let graphql_playground =
         || render_graphql_playground(graphql_endpoint, graphql_subscription_endpoint);
 
-    let router = Router::new()
-        .route("/v1/playground", get(graphql_playground))
-        .route(
-            graphql_endpoint,
-            post(graphql_handler)
-                .layer(ConcurrencyLimitLayer::new(concurrency_limit))
-                .options(ok),
-        )
-        .route(
-            graphql_subscription_endpoint,
-            post(graphql_subscription_handler).options(ok),
-        )
-        .route("/v1/metrics", get(metrics))
-        .route("/v1/health", get(health))
-        .route("/health", get(health))
-        .layer(Extension(schema))
-        .layer(TraceLayer::new_for_http())
-        .layer(TimeoutLayer::new(request_timeout))
-        .layer(SetResponseHeaderLayer::<_>::overriding(
-            ACCESS_CONTROL_ALLOW_ORIGIN,
-            HeaderValue::from_static("*"),
-        ))
-        .layer(SetResponseHeaderLayer::<_>::overriding(
-            ACCESS_CONTROL_ALLOW_METHODS,
-            HeaderValue::from_static("*"),
-        ))
-        .layer(SetResponseHeaderLayer::<_>::overriding(
-            ACCESS_CONTROL_ALLOW_HEADERS,
-            HeaderValue::from_static("*"),
-        ))
-        .layer(DefaultBodyLimit::max(body_limit));
+    let router = |admin: bool| {
+        let router = Router::new()
+            .route("/v1/playground", get(graphql_playground))
+            .route(
+                graphql_endpoint,
+                post(graphql_handler)
+                    .layer(ConcurrencyLimitLayer::new(concurrency_limit))
+                    .options(ok),
+            )
+            .route(
+                graphql_subscription_endpoint,
+                post(graphql_subscription_handler).options(ok),
+            )
+            .route(graphql_websocket_endpoint, get(graphql_ws_handler))
+            .route("/v1/health", get(health))
+            .route("/health", get(health));
+        // The metrics are served by the admin listener if it is separate.
+        let router = if admin {
+            router.route("/v1/metrics", get(admin_metrics))
+        } else {
+            router
+        };
+        router
+            .layer(Extension(schema.clone()))
+            .layer(Extension(auth_config.clone()))
+            .layer(Extension(websocket_limits.clone()))
+            .layer(Extension(AdminListener(admin)))
+            .layer(TraceLayer::new_for_http())
+            .layer(TimeoutLayer::new(request_timeout))
+            .layer(cors.clone())
+            .layer(DefaultBodyLimit::max(body_limit))
+    };
 
     let listener = TcpListener::bind(network_addr)?;
     let bound_address = listener.local_addr()?;
-
     tracing::info!("Binding GraphQL provider to {}", bound_address);
 
+    let admin = match admin_addr {
+        Some(admin_addr) => {
+            let admin_listener = TcpListener::bind(admin_addr)?;
+            tracing::info!(
+                "Binding GraphQL admin provider to {}",
+                admin_listener.local_addr()?
+            );
+            Some((router(true), admin_listener))
+        }
+        None => None,
+    };
+    let admin_bound_address = admin
+        .as_ref()
+        .map(|(_, listener)| listener.local_addr())
+        .transpose()?;
+    let router = router(admin.is_none());
+
     Ok(Service::new_with_params(
-        GraphqlService { bound_address },
+        GraphqlService {
+            bound_address,
+            admin_bound_address,
+        },
         ServerParams {
             router,
             listener,
+            admin,
             number_of_threads,
         },
     ))
 }
 
+/// Allows the cross-origin requests from the `origins`,
+/// or from any origin if it is empty.
+fn cors_layer(origins: &[String]) -> anyhow::Result<CorsLayer> {
+    let allow_origin = if origins.is_empty() {
+        AllowOrigin::any()
+    } else {
+        let origins = origins
+            .iter()
+            .map(|origin| HeaderValue::from_str(origin))
+            .collect::<Result<Vec<_>, _>>()?;
+        AllowOrigin::list(origins)
+    };
+    // The `Authorization` header is not covered by the wildcard,
+    // so the requested headers are mirrored.
+    Ok(CorsLayer::new()
+        .allow_origin(allow_origin)
+        .allow_methods(AllowMethods::mirror_request())
+        .allow_headers(AllowHeaders::mirror_request()))
+}
+
 async fn render_graphql_playground(
     endpoint: &str,
     subscription_endpoint: &str,
@@ -346,19 +458,59 @@ async fn health() -> Json<serde_json::Value> {
     Json(json!({ "up": true }))
 }
 
+/// Serves the metrics to the callers with the admin role.
+async fn admin_metrics(
+    config: Extension<Arc<ServiceConfig>>,
+    headers: HeaderMap,
+    req: axum::http::Request<Body>,
+) -> axum::response::Response {
+    let token = bearer_token(&headers).map(BearerToken);
+    let role = authenticate(&config, token.as_ref())
+        .ok()
+        .map(|role| role.unwrap_or(config.anonymous_role));
+    if role != Some(Role::Admin) {
+        return StatusCode::UNAUTHORIZED.into_response()
+    }
+    metrics(req).await.into_response()
+}
+
 async fn graphql_handler(
     schema: Extension<CoreSchema>,
+    admin_listener: Extension<AdminListener>,
+    headers: HeaderMap,
     req: Json<Request>,
 ) -> Json<Response> {
-    schema.execute(req.0).await.into()
+    let request = with_credentials(req.0, &headers, admin_listener.0);
+    schema.execute(request).await.into()
+}
+
+/// Adds the bearer token and the kind of the listener to the request,
+/// they are checked by the `AuthExtension`.
+fn with_credentials(
+    mut request: Request,
+    headers: &HeaderMap,
+    admin_listener: AdminListener,
+) -> Request {
+    if let Some(token) = bearer_token(headers) {
+        request = request.data(BearerToken(token));
+    }
+    request.data(admin_listener)
+}
+
+pub(crate) fn bearer_token(headers: &HeaderMap) -> Option<String> {
+    let value = headers.get(AUTHORIZATION)?.to_str().ok()?;
+    value.strip_prefix("Bearer ").map(ToString::to_string)
 }
 
 async fn graphql_subscription_handler(
     schema: Extension<CoreSchema>,
+    admin_listener: Extension<AdminListener>,
+    headers: HeaderMap,
     req: Json<Request>,
 ) -> Sse<impl Stream<Item = anyhow::Result<Event, serde_json::Error>>> {
+    let request = with_credentials(req.0, &headers, admin_listener.0);
     let stream = schema
-        .execute_stream(req.0)
+        .execute_stream(request)
         .map(|r| Event::default().json_data(r));
     Sse::new(stream)
         .keep_alive(axum::response::sse::KeepAlive::new().text("keep-alive-text"))
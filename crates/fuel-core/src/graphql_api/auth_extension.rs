@@ -0,0 +1,468 @@
+use crate::fuel_core_graphql_api::{
+    api_service::BearerToken,
+    Config,
+    ServiceConfig,
+};
+use async_graphql::{
+    extensions::{
+        Extension,
+        ExtensionContext,
+        ExtensionFactory,
+        NextParseQuery,
+    },
+    parser::types::{
+        ExecutableDocument,
+        OperationType,
+        Selection,
+        SelectionSet,
+    },
+    ServerError,
+    ServerResult,
+    Variables,
+};
+use fuel_core_types::{
+    fuel_crypto::Hasher,
+    fuel_types::Bytes32,
+};
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    sync::Arc,
+};
+use subtle::ConstantTimeEq;
+
+/// The role of the API caller. Each role includes the permissions of the previous ones.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Deserialize,
+    strum_macros::Display,
+    strum_macros::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum Role {
+    /// Queries and subscriptions of the chain state.
+    Read,
+    /// Submission of the transactions.
+    Submit,
+    /// Block production, the debugger and the management of the node.
+    Admin,
+}
+
+/// The API keys accepted as the bearer tokens and the roles granted by them.
+/// The keys are stored by their hashes, so looking up the token doesn't
+/// reveal the keys through the timing of the comparisons.
+#[derive(Clone, Debug, Default)]
+pub struct ApiKeys(HashMap<Bytes32, Role>);
+
+impl ApiKeys {
+    /// Adds the `key` granting the `role`. Returns the previous role of the key.
+    pub fn insert(&mut self, key: &str, role: Role) -> Option<Role> {
+        self.0.insert(Hasher::hash(key.as_bytes()), role)
+    }
+
+    /// Returns the role granted by the `token`.
+    pub fn get(&self, token: &str) -> Option<Role> {
+        self.0.get(&Hasher::hash(token.as_bytes())).copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<Key> FromIterator<(Key, Role)> for ApiKeys
+where
+    Key: AsRef<str>,
+{
+    fn from_iter<I: IntoIterator<Item = (Key, Role)>>(iter: I) -> Self {
+        let mut api_keys = Self::default();
+        for (key, role) in iter {
+            api_keys.insert(key.as_ref(), role);
+        }
+        api_keys
+    }
+}
+
+/// Whether the listener that accepted the request serves the admin operations.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct AdminListener(pub bool);
+
+/// The claims of the JWT bearer token.
+#[derive(serde::Deserialize)]
+struct Claims {
+    role: Role,
+}
+
+/// Returns the role granted by the bearer token, or `None` for the anonymous request.
+pub(crate) fn authenticate(
+    config: &ServiceConfig,
+    token: Option<&BearerToken>,
+) -> Result<Option<Role>, String> {
+    let Some(BearerToken(token)) = token else {
+        return Ok(None)
+    };
+
+    let is_admin = config
+        .admin_api_token
+        .as_ref()
+        .is_some_and(|admin| bool::from(admin.as_bytes().ct_eq(token.as_bytes())));
+    if is_admin {
+        return Ok(Some(Role::Admin))
+    }
+    if let Some(role) = config.api_keys.get(token) {
+        return Ok(Some(role))
+    }
+    if let Some(secret) = &config.jwt_secret {
+        let key = jsonwebtoken::DecodingKey::from_secret(secret.as_bytes());
+        let validation = jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::HS256);
+        return jsonwebtoken::decode::<Claims>(token, &key, &validation)
+            .map(|data| Some(data.claims.role))
+            .map_err(|e| format!("Invalid bearer token: {e}"))
+    }
+
+    // Tokens are ignored if the authentication is not configured,
+    // they may be addressed to the proxy in front of the node.
+    if config.is_auth_enabled() {
+        Err("Invalid bearer token".to_string())
+    } else {
+        Ok(None)
+    }
+}
+
+/// Returns the role required to execute all operations of the document.
+fn required_role(document: &ExecutableDocument) -> Role {
+    let mut role = Role::Read;
+    for (_, operation) in document.operations.iter() {
+        let ty = operation.node.ty;
+        let mut visited = HashSet::new();
+        let mut fields = vec![];
+        root_fields(
+            document,
+            &operation.node.selection_set.node,
+            &mut visited,
+            &mut fields,
+        );
+        for field in fields {
+            role = role.max(field_role(ty, field));
+        }
+    }
+    role
+}
+
+/// Returns `true` if the document contains any mutation.
+fn has_mutation(document: &ExecutableDocument) -> bool {
+    document
+        .operations
+        .iter()
+        .any(|(_, operation)| operation.node.ty == OperationType::Mutation)
+}
+
+/// Collects the names of the root fields, including the fields of the fragments.
+fn root_fields<'a>(
+    document: &'a ExecutableDocument,
+    selection_set: &'a SelectionSet,
+    visited: &mut HashSet<&'a str>,
+    fields: &mut Vec<&'a str>,
+) {
+    for selection in &selection_set.items {
+        match &selection.node {
+            Selection::Field(field) => fields.push(field.node.name.node.as_str()),
+            Selection::FragmentSpread(spread) => {
+                let name = &spread.node.fragment_name.node;
+                // The cycles of the fragments are rejected later by the validation.
+                if !visited.insert(name.as_str()) {
+                    continue
+                }
+                if let Some(fragment) = document.fragments.get(name) {
+                    root_fields(
+                        document,
+                        &fragment.node.selection_set.node,
+                        visited,
+                        fields,
+                    );
+                }
+            }
+            Selection::InlineFragment(fragment) => {
+                root_fields(document, &fragment.node.selection_set.node, visited, fields)
+            }
+        }
+    }
+}
+
+/// The roles required by the root fields of the queries.
+const QUERY_ROLES: &[(&str, Role)] = &[
+    ("allReceipts", Role::Read),
+    ("balance", Role::Read),
+    ("balances", Role::Read),
+    ("blob", Role::Read),
+    ("block", Role::Read),
+    ("blocks", Role::Read),
+    ("chain", Role::Read),
+    ("coin", Role::Read),
+    ("coins", Role::Read),
+    ("coinsToSpend", Role::Read),
+    ("consensusParameters", Role::Read),
+    ("contract", Role::Read),
+    ("contractBalance", Role::Read),
+    ("contractBalanceProof", Role::Read),
+    ("contractBalances", Role::Read),
+    ("contractStateProof", Role::Read),
+    ("contractStorageSlots", Role::Read),
+    ("contractStorageValues", Role::Read),
+    ("daCompressedBlock", Role::Read),
+    ("estimateGasPrice", Role::Read),
+    ("estimatePredicates", Role::Read),
+    ("gasPriceHistory", Role::Read),
+    ("health", Role::Read),
+    ("latestGasPrice", Role::Read),
+    ("memory", Role::Admin),
+    ("message", Role::Read),
+    ("messageProof", Role::Read),
+    ("messageStatus", Role::Read),
+    ("messages", Role::Read),
+    ("nodeInfo", Role::Read),
+    ("pendingTransactions", Role::Read),
+    ("register", Role::Admin),
+    ("relayedTransactionStatus", Role::Read),
+    ("stateTransitionBytecodeByRoot", Role::Read),
+    ("stateTransitionBytecodeByVersion", Role::Read),
+    ("transaction", Role::Read),
+    ("transactions", Role::Read),
+    ("transactionsByOwner", Role::Read),
+    ("txPoolBlacklist", Role::Admin),
+    ("txPoolStats", Role::Read),
+];
+
+/// The roles required by the root fields of the mutations.
+const MUTATION_ROLES: &[(&str, Role)] = &[
+    ("addToTxPoolBlacklist", Role::Admin),
+    ("continueTx", Role::Admin),
+    ("createDatabaseCheckpoint", Role::Admin),
+    ("dryRun", Role::Read),
+    ("endSession", Role::Admin),
+    ("execute", Role::Admin),
+    ("produceBlocks", Role::Admin),
+    ("removeFromTxPoolBlacklist", Role::Admin),
+    ("reset", Role::Admin),
+    ("setBreakpoint", Role::Admin),
+    ("setSingleStepping", Role::Admin),
+    ("startSession", Role::Admin),
+    ("startTx", Role::Admin),
+    ("submit", Role::Submit),
+];
+
+/// The roles required by the root fields of the subscriptions.
+const SUBSCRIPTION_ROLES: &[(&str, Role)] = &[
+    ("blocks", Role::Read),
+    ("gasPrices", Role::Read),
+    ("newPendingTransactions", Role::Read),
+    ("statusChange", Role::Read),
+    ("submitAndAwait", Role::Submit),
+    ("submitAndAwaitStatus", Role::Submit),
+];
+
+fn operation_roles(ty: OperationType) -> &'static [(&'static str, Role)] {
+    match ty {
+        OperationType::Query => QUERY_ROLES,
+        OperationType::Mutation => MUTATION_ROLES,
+        OperationType::Subscription => SUBSCRIPTION_ROLES,
+    }
+}
+
+/// Returns the role required by the root field. The introspection is public,
+/// while the fields missing in the tables require the admin role, so the new
+/// fields are not exposed before they are classified.
+fn field_role(ty: OperationType, field: &str) -> Role {
+    if field.starts_with("__") {
+        return Role::Read
+    }
+    operation_roles(ty)
+        .iter()
+        .find(|(name, _)| *name == field)
+        .map_or(Role::Admin, |(_, role)| *role)
+}
+
+/// The extension rejects the operations not allowed for the role of the caller.
+pub(crate) struct AuthExtension;
+
+impl AuthExtension {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ExtensionFactory for AuthExtension {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(AuthExtension::new())
+    }
+}
+
+#[async_trait::async_trait]
+impl Extension for AuthExtension {
+    async fn parse_query(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        query: &str,
+        variables: &Variables,
+        next: NextParseQuery<'_>,
+    ) -> ServerResult<ExecutableDocument> {
+        let document = next.run(ctx, query, variables).await?;
+        let graphql_config = ctx.data_unchecked::<Config>();
+        let config = &graphql_config.config;
+        let required = required_role(&document);
+
+        if graphql_config.read_only && (required > Role::Read || has_mutation(&document))
+        {
+            return Err(ServerError::new(
+                "The node is a read replica, the operation is not available",
+                None,
+            ))
+        }
+
+        let admin_listener = ctx.data_opt::<AdminListener>().map_or(true, |l| l.0);
+        if required == Role::Admin && !admin_listener {
+            return Err(ServerError::new(
+                "The operation is only available on the admin listener",
+                None,
+            ))
+        }
+
+        let role = authenticate(config, ctx.data_opt::<BearerToken>())
+            .map_err(|e| ServerError::new(e, None))?
+            .unwrap_or(config.anonymous_role);
+        if role < required {
+            return Err(ServerError::new(
+                format!("The operation requires the `{required}` role"),
+                None,
+            ))
+        }
+
+        Ok(document)
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_graphql::parser::{
+        parse_query,
+        parse_schema,
+        types::{
+            TypeKind,
+            TypeSystemDefinition,
+        },
+    };
+    use test_case::test_case;
+
+    #[test_case("{ chain { name } }" => Role::Read; "queries are public")]
+    #[test_case("mutation { dryRun(txs: []) { id } }" => Role::Read; "dry run is public")]
+    #[test_case("mutation { submit(tx: \"0x\") { id } }" => Role::Submit; "submit")]
+    #[test_case(
+        "subscription { submitAndAwait(tx: \"0x\") { __typename } }" => Role::Submit;
+        "submit and await"
+    )]
+    #[test_case(
+        "mutation { produceBlocks(blocksToProduce: \"1\") }" => Role::Admin;
+        "block production"
+    )]
+    #[test_case("{ memory(id: \"1\", start: 0, size: 1) }" => Role::Admin; "debugger")]
+    #[test_case("{ __schema { queryType { name } } }" => Role::Read; "introspection")]
+    #[test_case("{ unknownField }" => Role::Admin; "unclassified fields")]
+    #[test_case(
+        "mutation { ...F } fragment F on Mutation { startSession }" => Role::Admin;
+        "fragments are followed"
+    )]
+    #[test_case(
+        "{ chain { name } } mutation M { submit(tx: \"0x\") { id } }" => Role::Submit;
+        "highest role of all operations"
+    )]
+    fn required_role__matches_operation(query: &str) -> Role {
+        let document = parse_query(query).unwrap();
+        required_role(&document)
+    }
+
+    fn service_config() -> ServiceConfig {
+        let mut config = crate::service::Config::local_node().graphql_config.config;
+        config.admin_api_token = Some("admin".to_string());
+        config.api_keys = [("submitter", Role::Submit)].into_iter().collect();
+        config
+    }
+
+    #[test_case("admin" => Ok(Some(Role::Admin)); "admin token")]
+    #[test_case("submitter" => Ok(Some(Role::Submit)); "api key")]
+    #[test_case("admi" => matches Err(_); "prefix of admin token")]
+    #[test_case("submitter2" => matches Err(_); "unknown api key")]
+    fn authenticate__grants_role_of_token(token: &str) -> Result<Option<Role>, String> {
+        let config = service_config();
+        authenticate(&config, Some(&BearerToken(token.to_string())))
+    }
+
+    #[test]
+    fn required_role__handles_cyclic_fragments() {
+        // Given
+        let query = "{ ...A } fragment A on Query { ...B } fragment B on Query { ...A }";
+        let document = parse_query(query).unwrap();
+
+        // When
+        let role = required_role(&document);
+
+        // Then
+        assert_eq!(role, Role::Read);
+    }
+
+    fn schema_root_fields(type_name: &str) -> Vec<String> {
+        let sdl = crate::schema::build_schema().finish().sdl();
+        let document = parse_schema(sdl).unwrap();
+        document
+            .definitions
+            .into_iter()
+            .filter_map(|definition| match definition {
+                TypeSystemDefinition::Type(ty) if ty.node.name.node == type_name => {
+                    match ty.node.kind {
+                        TypeKind::Object(object) => Some(object.fields),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+            .flatten()
+            .map(|field| field.node.name.node.to_string())
+            .collect()
+    }
+
+    #[test_case(OperationType::Query, "Query"; "query")]
+    #[test_case(OperationType::Mutation, "Mutation"; "mutation")]
+    #[test_case(OperationType::Subscription, "Subscription"; "subscription")]
+    fn field_role__classifies_every_root_field_of_schema(
+        ty: OperationType,
+        type_name: &str,
+    ) {
+        // Given
+        let mut fields = schema_root_fields(type_name);
+        fields.sort();
+
+        // When
+        let mut classified: Vec<_> = operation_roles(ty)
+            .iter()
+            .map(|(name, _)| name.to_string())
+            .collect();
+        classified.sort();
+
+        // Then
+        assert_eq!(
+            fields, classified,
+            "Every root field of `{type_name}` must be classified by a role"
+        );
+    }
+}
@@ -1,8 +1,11 @@
-use crate::fuel_core_graphql_api::{
-    database::arc_wrapper::ArcWrapper,
-    ports::{
-        OffChainDatabase,
-        OnChainDatabase,
+use crate::{
+    database::Error as DatabaseError,
+    fuel_core_graphql_api::{
+        database::arc_wrapper::ArcWrapper,
+        ports::{
+            OffChainDatabase,
+            OnChainDatabase,
+        },
     },
 };
 use fuel_core_services::yield_stream::StreamYieldExt;
@@ -14,12 +17,13 @@ use fuel_core_storage::{
     },
     not_found,
     tables::Transactions,
-    transactional::AtomicView,
+    transactional::HistoricalView,
     Error as StorageError,
     IsNotFound,
     Mappable,
     PredicateStorageRequirements,
     Result as StorageResult,
+    SparseMerkleProof,
     StorageInspect,
     StorageRead,
     StorageSize,
@@ -77,6 +81,23 @@ pub type OnChainView = Arc<dyn OnChainDatabase>;
 /// The off-chain view of the database used by the [`ReadView`] to fetch off-chain data.
 pub type OffChainView = Arc<dyn OffChainDatabase>;
 
+/// The provider of the on-chain views at the latest and historical heights.
+type OnChainViewProvider = Box<
+    dyn HistoricalView<
+        LatestView = OnChainView,
+        Height = BlockHeight,
+        ViewAtHeight = OnChainView,
+    >,
+>;
+/// The provider of the off-chain views at the latest and historical heights.
+type OffChainViewProvider = Box<
+    dyn HistoricalView<
+        LatestView = OffChainView,
+        Height = BlockHeight,
+        ViewAtHeight = OffChainView,
+    >,
+>;
+
 /// The container of the on-chain and off-chain database view provides.
 /// It is used only by `ViewExtension` to create a [`ReadView`].
 pub struct ReadDatabase {
@@ -85,9 +106,9 @@ pub struct ReadDatabase {
     /// The height of the genesis block.
     genesis_height: BlockHeight,
     /// The on-chain database view provider.
-    on_chain: Box<dyn AtomicView<LatestView = OnChainView>>,
+    on_chain: OnChainViewProvider,
     /// The off-chain database view provider.
-    off_chain: Box<dyn AtomicView<LatestView = OffChainView>>,
+    off_chain: OffChainViewProvider,
     /// The flag that indicates whether the Balances cache table is enabled.
     balances_enabled: bool,
 }
@@ -101,10 +122,13 @@ impl ReadDatabase {
         off_chain: OffChain,
     ) -> Result<Self, StorageError>
     where
-        OnChain: AtomicView + 'static,
-        OffChain: AtomicView + worker::OffChainDatabase + 'static,
+        OnChain: HistoricalView<Height = BlockHeight> + 'static,
+        OffChain:
+            HistoricalView<Height = BlockHeight> + worker::OffChainDatabase + 'static,
         OnChain::LatestView: OnChainDatabase,
+        OnChain::ViewAtHeight: OnChainDatabase,
         OffChain::LatestView: OffChainDatabase,
+        OffChain::ViewAtHeight: OffChainDatabase,
     {
         let balances_enabled = off_chain.balances_enabled()?;
 
@@ -119,9 +143,6 @@ impl ReadDatabase {
 
     /// Creates a consistent view of the database.
     pub fn view(&self) -> StorageResult<ReadView> {
-        // TODO: Use the same height for both views to guarantee consistency.
-        //  It is not possible to implement until `view_at` is implemented for the `AtomicView`.
-        //  https://github.com/FuelLabs/fuel-core/issues/1582
         Ok(ReadView {
             batch_size: self.batch_size,
             genesis_height: self.genesis_height,
@@ -131,6 +152,34 @@ impl ReadDatabase {
         })
     }
 
+    /// Creates a consistent view of the database at the given `height`.
+    ///
+    /// Fails if any of the databases didn't reach the `height` yet, or if the
+    /// state rewind policy doesn't keep the history for the `height` anymore.
+    pub fn view_at(&self, height: &BlockHeight) -> StorageResult<ReadView> {
+        let latest_height = self
+            .on_chain
+            .latest_height()
+            .min(self.off_chain.latest_height())
+            .ok_or(DatabaseError::NoHistoryIsAvailable)?;
+
+        if *height > latest_height {
+            return Err(DatabaseError::HeightIsNotAvailableYet {
+                requested_height: u64::from(u32::from(*height)),
+                latest_height: u64::from(u32::from(latest_height)),
+            }
+            .into());
+        }
+
+        Ok(ReadView {
+            batch_size: self.batch_size,
+            genesis_height: self.genesis_height,
+            on_chain: self.on_chain.view_at(height)?,
+            off_chain: self.off_chain.view_at(height)?,
+            balances_enabled: self.balances_enabled,
+        })
+    }
+
     #[cfg(feature = "test-helpers")]
     pub fn test_view(&self) -> ReadView {
         self.view().expect("The latest view always should exist")
@@ -311,6 +360,35 @@ impl ReadView {
         .yield_each(self.batch_size)
     }
 
+    pub fn contract_storage_slots(
+        &self,
+        contract: ContractId,
+        start_slot: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<(Bytes32, Vec<u8>)>> + '_ {
+        futures::stream::iter(
+            self.on_chain
+                .contract_storage_slots(contract, start_slot, direction),
+        )
+        .yield_each(self.batch_size)
+    }
+
+    pub fn contract_state_proof(
+        &self,
+        contract: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<SparseMerkleProof> {
+        self.on_chain.contract_state_proof(contract, key)
+    }
+
+    pub fn contract_balance_proof(
+        &self,
+        contract: ContractId,
+        asset: AssetId,
+    ) -> StorageResult<SparseMerkleProof> {
+        self.on_chain.contract_balance_proof(contract, asset)
+    }
+
     pub fn da_height(&self) -> StorageResult<DaBlockHeight> {
         self.on_chain.da_height()
     }
@@ -1,4 +1,6 @@
+use crate::combined_database::checkpoint::CheckpointManifest;
 use async_trait::async_trait;
+use fuel_core_gas_price_service::common::gas_price_history::GasPriceHistoryEntry;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::{
     iter::{
@@ -9,17 +11,29 @@ use fuel_core_storage::{
         BlobData,
         Coins,
         ContractsAssets,
+        ContractsLatestUtxo,
         ContractsRawCode,
+        ContractsState,
         Messages,
         StateTransitionBytecodeVersions,
         UploadedBytecodes,
     },
     Error as StorageError,
     Result as StorageResult,
+    SparseMerkleProof,
     StorageInspect,
     StorageRead,
 };
-use fuel_core_txpool::TxStatusMessage;
+use fuel_core_txpool::{
+    config::{
+        BlackList,
+        BlackListEntry,
+    },
+    PendingTxsQuery,
+    TxInfo,
+    TxPoolStats,
+    TxStatusMessage,
+};
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
@@ -55,6 +69,7 @@ use fuel_core_types::{
     },
     fuel_vm::interpreter::Memory,
     services::{
+        block_importer::SharedImportResult,
         executor::TransactionExecutionStatus,
         graphql_api::ContractBalance,
         p2p::PeerInfo,
@@ -62,7 +77,10 @@ use fuel_core_types::{
     },
     tai64::Tai64,
 };
-use std::sync::Arc;
+use std::{
+    path::PathBuf,
+    sync::Arc,
+};
 
 use super::storage::balances::TotalBalanceAmount;
 
@@ -196,6 +214,8 @@ pub trait DatabaseRelayedTransactions {
 pub trait DatabaseContracts:
     StorageInspect<ContractsRawCode, Error = StorageError>
     + StorageInspect<ContractsAssets, Error = StorageError>
+    + StorageInspect<ContractsState, Error = StorageError>
+    + StorageInspect<ContractsLatestUtxo, Error = StorageError>
 {
     fn contract_balances(
         &self,
@@ -203,6 +223,30 @@ pub trait DatabaseContracts:
         start_asset: Option<AssetId>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    /// Returns the storage slots of the `contract` with their values.
+    fn contract_storage_slots(
+        &self,
+        contract: ContractId,
+        start_slot: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(Bytes32, Vec<u8>)>>;
+
+    /// Returns the proof of inclusion or exclusion of the `key` storage slot
+    /// in the state sparse Merkle tree of the `contract`.
+    fn contract_state_proof(
+        &self,
+        contract: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<SparseMerkleProof>;
+
+    /// Returns the proof of inclusion or exclusion of the `asset` balance
+    /// in the balances sparse Merkle tree of the `contract`.
+    fn contract_balance_proof(
+        &self,
+        contract: ContractId,
+        asset: AssetId,
+    ) -> StorageResult<SparseMerkleProof>;
 }
 
 /// Trait that specifies all the getters required for chain metadata.
@@ -222,6 +266,36 @@ pub trait TxPoolPort: Send + Sync {
         &self,
         tx_id: TxId,
     ) -> anyhow::Result<BoxStream<TxStatusMessage>>;
+
+    /// Returns the current usage of the pool along with its limits.
+    async fn stats(&self) -> anyhow::Result<TxPoolStats>;
+
+    /// Returns the page of the transactions that are currently in the pool.
+    async fn pending_transactions(
+        &self,
+        query: PendingTxsQuery,
+    ) -> anyhow::Result<Vec<TxInfo>>;
+
+    /// Returns the transaction from the pool along with the pool-related information.
+    async fn pending_transaction(&self, id: TxId) -> anyhow::Result<Option<TxInfo>>;
+
+    /// Returns the stream of ids of the transactions accepted by the pool.
+    fn new_pending_transactions_subscribe(&self) -> BoxStream<TxId>;
+}
+
+/// Manages the blacklist of the `TxPool` at runtime.
+#[async_trait]
+pub trait TxPoolBlackListPort: Send + Sync {
+    /// Returns the current blacklist of the pool.
+    async fn blacklist(&self) -> anyhow::Result<BlackList>;
+
+    /// Adds the entry to the blacklist and evicts the transactions that use it.
+    /// Returns the ids of the evicted transactions.
+    async fn add(&self, entry: BlackListEntry) -> anyhow::Result<Vec<TxId>>;
+
+    /// Removes the entry from the blacklist.
+    /// Returns `false` if the entry was not blacklisted.
+    async fn remove(&self, entry: BlackListEntry) -> anyhow::Result<bool>;
 }
 
 #[async_trait]
@@ -245,6 +319,11 @@ pub trait ConsensusModulePort: Send + Sync {
     ) -> anyhow::Result<()>;
 }
 
+pub trait BlockImporterPort: Send + Sync {
+    /// Returns a stream of the blocks imported after the call.
+    fn block_events(&self) -> BoxStream<SharedImportResult>;
+}
+
 /// Trait that specifies queries supported by the database.
 pub trait DatabaseMessageProof: Send + Sync {
     /// Gets the [`MerkleProof`] for the message block at `message_block_height` height
@@ -268,6 +347,31 @@ pub trait GasPriceEstimate: Send + Sync {
     async fn worst_case_gas_price(&self, height: BlockHeight) -> Option<u64>;
 }
 
+/// Trait for reading the history of the gas prices calculated by the gas price service.
+pub trait GasPriceHistoryPort: Send + Sync {
+    /// Returns the history entries starting from the `height` in ascending order.
+    fn gas_price_history(
+        &self,
+        height: BlockHeight,
+    ) -> BoxedIter<'_, StorageResult<GasPriceHistoryEntry>>;
+
+    /// Returns the history entry for the block at the `height`, if any.
+    fn gas_price_history_at(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<GasPriceHistoryEntry>>;
+}
+
+/// Creates the checkpoints of the databases of the node.
+#[async_trait]
+pub trait DatabaseCheckpointPort: Send + Sync {
+    /// Creates the checkpoints of all databases in the `path` and returns the manifest.
+    async fn create_checkpoint(
+        &self,
+        path: PathBuf,
+    ) -> anyhow::Result<CheckpointManifest>;
+}
+
 /// Trait for getting VM memory.
 #[async_trait::async_trait]
 pub trait MemoryPool {
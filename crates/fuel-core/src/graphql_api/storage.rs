@@ -45,6 +45,7 @@ pub mod messages;
 pub mod old;
 pub mod statistic;
 pub mod transactions;
+pub mod tx_pool_blacklist;
 
 pub mod relayed_transactions;
 /// Tracks the total number of transactions written to the chain
@@ -118,6 +119,8 @@ pub enum Column {
     CoinBalances = 23,
     /// Message balances per account.
     MessageBalances = 24,
+    /// See [`TxPoolBlackList`](tx_pool_blacklist::TxPoolBlackList)
+    TxPoolBlackList = 25,
 }
 
 impl Column {
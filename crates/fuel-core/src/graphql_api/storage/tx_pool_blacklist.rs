@@ -0,0 +1,45 @@
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::postcard::Postcard,
+    structured_storage::TableWithBlueprint,
+    Mappable,
+};
+use fuel_core_txpool::config::BlackListEntry;
+
+/// The table stores the entries of the TxPool blacklist added at runtime.
+/// It is used to restore the blacklist after the restart of the node.
+pub struct TxPoolBlackList;
+
+impl Mappable for TxPoolBlackList {
+    type Key = BlackListEntry;
+    type OwnedKey = Self::Key;
+    type Value = ();
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for TxPoolBlackList {
+    type Blueprint = Plain<Postcard, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::TxPoolBlackList
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::Rng;
+
+    fn random_entry<R: Rng>(rng: &mut R) -> BlackListEntry {
+        BlackListEntry::Owner(rng.gen())
+    }
+
+    fuel_core_storage::basic_storage_tests!(
+        TxPoolBlackList,
+        BlackListEntry::Owner(Default::default()),
+        (),
+        (),
+        random_entry
+    );
+}
@@ -0,0 +1,315 @@
+//! The WebSocket transport for the GraphQL API. It supports `graphql-transport-ws`
+//! and the legacy `graphql-ws` protocols.
+
+use crate::{
+    fuel_core_graphql_api::{
+        api_service::{
+            bearer_token,
+            BearerToken,
+        },
+        auth_extension::AdminListener,
+    },
+    schema::CoreSchema,
+};
+use async_graphql::{
+    http::{
+        WebSocket,
+        WebSocketProtocols,
+        WsMessage,
+        ALL_WEBSOCKET_PROTOCOLS,
+    },
+    Data,
+};
+use axum::{
+    extract::{
+        ws::{
+            CloseFrame,
+            Message,
+            WebSocket as AxumWebSocket,
+            WebSocketUpgrade,
+        },
+        Extension,
+    },
+    http::{
+        HeaderMap,
+        StatusCode,
+    },
+    response::{
+        IntoResponse,
+        Response,
+    },
+};
+use futures::{
+    SinkExt,
+    StreamExt,
+};
+use serde::Deserialize;
+use std::{
+    collections::HashSet,
+    str::FromStr,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+use tokio::sync::{
+    OwnedSemaphorePermit,
+    Semaphore,
+};
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+/// The payload of the `connection_init` message sent by the client.
+/// It is available for resolvers through the context data.
+#[derive(Clone, Debug)]
+pub struct ConnectionInitPayload(pub serde_json::Value);
+
+#[derive(Clone)]
+pub(crate) struct WebSocketLimits {
+    /// Limits the number of simultaneously opened connections.
+    connections: Arc<Semaphore>,
+    /// The maximum number of active operations per connection.
+    max_subscriptions_per_connection: usize,
+}
+
+impl WebSocketLimits {
+    pub(crate) fn new(
+        max_connections: usize,
+        max_subscriptions_per_connection: usize,
+    ) -> Self {
+        Self {
+            connections: Arc::new(Semaphore::new(max_connections)),
+            max_subscriptions_per_connection,
+        }
+    }
+}
+
+pub(crate) async fn graphql_ws_handler(
+    schema: Extension<CoreSchema>,
+    limits: Extension<WebSocketLimits>,
+    admin_listener: Extension<AdminListener>,
+    headers: HeaderMap,
+    upgrade: WebSocketUpgrade,
+) -> Response {
+    let protocol = headers
+        .get("sec-websocket-protocol")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|protocols| {
+            protocols
+                .split(',')
+                .find_map(|p| WebSocketProtocols::from_str(p.trim()).ok())
+        })
+        .unwrap_or(WebSocketProtocols::GraphQLWS);
+
+    let Ok(permit) = limits.connections.clone().try_acquire_owned() else {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Too many concurrent WebSocket connections",
+        )
+            .into_response()
+    };
+
+    let max_subscriptions = limits.max_subscriptions_per_connection;
+    let schema = schema.0;
+    let credentials = Credentials {
+        token: bearer_token(&headers),
+        admin_listener: admin_listener.0,
+    };
+    upgrade
+        .protocols(ALL_WEBSOCKET_PROTOCOLS)
+        .on_upgrade(move |socket| {
+            serve(
+                socket,
+                schema,
+                protocol,
+                max_subscriptions,
+                credentials,
+                permit,
+            )
+        })
+        .into_response()
+}
+
+/// The credentials of the connection. The bearer token can be passed in the
+/// `Authorization` header of the upgrade request or in the `connection_init` payload.
+struct Credentials {
+    token: Option<String>,
+    admin_listener: AdminListener,
+}
+
+impl Credentials {
+    fn into_data(self, payload: serde_json::Value) -> Data {
+        let token = payload
+            .get("Authorization")
+            .and_then(|value| value.as_str())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(ToString::to_string)
+            .or(self.token);
+
+        let mut data = Data::default();
+        if let Some(token) = token {
+            data.insert(BearerToken(token));
+        }
+        data.insert(self.admin_listener);
+        data.insert(ConnectionInitPayload(payload));
+        data
+    }
+}
+
+async fn serve(
+    socket: AxumWebSocket,
+    schema: CoreSchema,
+    protocol: WebSocketProtocols,
+    max_subscriptions: usize,
+    credentials: Credentials,
+    _permit: OwnedSemaphorePermit,
+) {
+    let (mut sink, stream) = socket.split();
+    let operations = Arc::new(Mutex::new(ActiveOperations::new(max_subscriptions)));
+    let (rejections_sender, rejections) = tokio::sync::mpsc::unbounded_channel();
+
+    let input = {
+        let operations = operations.clone();
+        stream
+            .take_while(|message| futures::future::ready(message.is_ok()))
+            .filter_map(move |message| {
+                let data = match message {
+                    Ok(Message::Text(text)) => text.into_bytes(),
+                    Ok(Message::Binary(data)) => data,
+                    _ => return futures::future::ready(None),
+                };
+
+                let mut operations = operations.lock().expect("The lock is not poisoned");
+                let data = match OperationMessage::parse(&data) {
+                    Some(message) if message.starts_operation() => {
+                        let id = message.id.unwrap_or_default();
+                        if operations.start(id.clone()) {
+                            Some(data)
+                        } else {
+                            let _ = rejections_sender.send(rejection(
+                                protocol,
+                                &id,
+                                max_subscriptions,
+                            ));
+                            None
+                        }
+                    }
+                    Some(message) if message.stops_operation() => {
+                        if let Some(id) = &message.id {
+                            operations.finish(id);
+                        }
+                        Some(data)
+                    }
+                    _ => Some(data),
+                };
+                futures::future::ready(data)
+            })
+    };
+
+    let output = WebSocket::new(schema, input, protocol)
+        .on_connection_init(
+            move |payload| async move { Ok(credentials.into_data(payload)) },
+        )
+        .map(move |message| match message {
+            WsMessage::Text(text) => {
+                if let Some(message) = OperationMessage::parse(text.as_bytes()) {
+                    if message.finishes_operation() {
+                        if let Some(id) = &message.id {
+                            operations
+                                .lock()
+                                .expect("The lock is not poisoned")
+                                .finish(id);
+                        }
+                    }
+                }
+                Message::Text(text)
+            }
+            WsMessage::Close(code, reason) => Message::Close(Some(CloseFrame {
+                code,
+                reason: reason.into(),
+            })),
+        });
+
+    let rejections = UnboundedReceiverStream::new(rejections).map(Message::Text);
+    let mut messages = futures::stream::select(output, rejections);
+
+    while let Some(message) = messages.next().await {
+        if sink.send(message).await.is_err() {
+            break
+        }
+    }
+}
+
+/// Identifiers of operations that are running over one connection.
+struct ActiveOperations {
+    ids: HashSet<String>,
+    limit: usize,
+}
+
+impl ActiveOperations {
+    fn new(limit: usize) -> Self {
+        Self {
+            ids: HashSet::new(),
+            limit,
+        }
+    }
+
+    /// Returns `false` if the connection already reached the limit of operations.
+    fn start(&mut self, id: String) -> bool {
+        if self.ids.len() >= self.limit && !self.ids.contains(&id) {
+            return false
+        }
+        self.ids.insert(id);
+        true
+    }
+
+    fn finish(&mut self, id: &str) {
+        self.ids.remove(id);
+    }
+}
+
+/// The common part of the messages of `graphql-transport-ws` and `graphql-ws` protocols.
+#[derive(Deserialize)]
+struct OperationMessage {
+    #[serde(rename = "type")]
+    ty: String,
+    id: Option<String>,
+}
+
+impl OperationMessage {
+    fn parse(data: &[u8]) -> Option<Self> {
+        serde_json::from_slice(data).ok()
+    }
+
+    /// `subscribe` for `graphql-transport-ws` and `start` for `graphql-ws`.
+    fn starts_operation(&self) -> bool {
+        matches!(self.ty.as_str(), "subscribe" | "start")
+    }
+
+    /// `complete` for `graphql-transport-ws` and `stop` for `graphql-ws`.
+    fn stops_operation(&self) -> bool {
+        matches!(self.ty.as_str(), "complete" | "stop")
+    }
+
+    /// The server's messages that end the operation.
+    fn finishes_operation(&self) -> bool {
+        matches!(self.ty.as_str(), "complete" | "error")
+    }
+}
+
+fn rejection(protocol: WebSocketProtocols, id: &str, limit: usize) -> String {
+    let error = serde_json::json!({
+        "message": format!(
+            "The connection reached the limit of {limit} concurrent subscriptions"
+        )
+    });
+    let payload = match protocol {
+        WebSocketProtocols::GraphQLWS => serde_json::json!([error]),
+        WebSocketProtocols::SubscriptionsTransportWS => error,
+    };
+    serde_json::json!({
+        "type": "error",
+        "id": id,
+        "payload": payload,
+    })
+    .to_string()
+}
@@ -143,40 +143,18 @@ where
     fn process_block(&mut self, result: SharedImportResult) -> anyhow::Result<()> {
         let block = &result.sealed_block.entity;
         let mut transaction = self.database.transaction();
-        // save the status for every transaction using the finalized block id
-        persist_transaction_status(&result, &mut transaction)?;
 
-        // save the associated owner for each transaction in the block
-        index_tx_owners_for_block(block, &mut transaction, &self.chain_id)?;
-
-        // save the transaction related information
-        process_transactions(block.transactions().iter(), &mut transaction)?;
-
-        let height = block.header().height();
-        let block_id = block.id();
-        transaction
-            .storage_as_mut::<FuelBlockIdsToHeights>()
-            .insert(&block_id, height)?;
-
-        let total_tx_count = transaction
-            .increase_tx_count(block.transactions().len() as u64)
-            .unwrap_or_default();
-
-        process_executor_events(
-            result.events.iter().map(Cow::Borrowed),
+        let total_tx_count = process_block(
+            &result,
             &mut transaction,
+            &self.chain_id,
+            &self.da_compression_config,
             self.balances_enabled,
         )?;
 
-        match self.da_compression_config {
-            DaCompressionConfig::Disabled => {}
-            DaCompressionConfig::Enabled(config) => {
-                da_compress_block(config, block, &result.events, &mut transaction)?;
-            }
-        }
-
         transaction.commit()?;
 
+        let height = block.header().height();
         for status in result.tx_status.iter() {
             let tx_id = status.id;
             let status = from_executor_to_status(block, status.result.clone());
@@ -190,6 +168,57 @@ where
     }
 }
 
+/// Updates the off-chain indexes with the imported block within the `transaction`.
+/// Returns the total number of transactions after the block.
+///
+/// It is used by the off-chain worker and by the reindex of the off-chain database,
+/// which replays the blocks from the on-chain database.
+pub fn process_block<T>(
+    result: &ImportResult,
+    transaction: &mut T,
+    chain_id: &ChainId,
+    da_compression_config: &DaCompressionConfig,
+    balances_enabled: bool,
+) -> anyhow::Result<u64>
+where
+    T: OffChainDatabaseTransaction,
+{
+    let block = &result.sealed_block.entity;
+    // save the status for every transaction using the finalized block id
+    persist_transaction_status(result, transaction)?;
+
+    // save the associated owner for each transaction in the block
+    index_tx_owners_for_block(block, transaction, chain_id)?;
+
+    // save the transaction related information
+    process_transactions(block.transactions().iter(), transaction)?;
+
+    let height = block.header().height();
+    let block_id = block.id();
+    transaction
+        .storage_as_mut::<FuelBlockIdsToHeights>()
+        .insert(&block_id, height)?;
+
+    let total_tx_count = transaction
+        .increase_tx_count(block.transactions().len() as u64)
+        .unwrap_or_default();
+
+    process_executor_events(
+        result.events.iter().map(Cow::Borrowed),
+        transaction,
+        balances_enabled,
+    )?;
+
+    match da_compression_config {
+        DaCompressionConfig::Disabled => {}
+        DaCompressionConfig::Enabled(config) => {
+            da_compress_block(*config, block, &result.events, transaction)?;
+        }
+    }
+
+    Ok(total_tx_count)
+}
+
 /// Process the executor events and update the indexes for the messages and coins.
 pub fn process_executor_events<'a, Iter, T>(
     events: Iter,
@@ -269,7 +298,7 @@ where
 }
 
 /// Associate all transactions within a block to their respective UTXO owners
-fn index_tx_owners_for_block<T>(
+pub(crate) fn index_tx_owners_for_block<T>(
     block: &Block,
     block_st_transaction: &mut T,
     chain_id: &ChainId,
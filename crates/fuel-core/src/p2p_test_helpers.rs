@@ -405,6 +405,52 @@ pub async fn make_nodes(
     }
 }
 
+/// Creates a network of BFT validators signing with the `secrets`.
+/// Validators discover each other through a single bootstrap node.
+pub async fn make_bft_nodes(
+    secrets: impl IntoIterator<Item = SecretKey>,
+    config: Option<Config>,
+) -> Nodes {
+    let secrets: Vec<_> = secrets.into_iter().collect();
+    let mut config = config.unwrap_or_else(Config::local_node);
+
+    let validators = secrets
+        .iter()
+        .map(|secret| Input::owner(&secret.public_key()))
+        .collect();
+    let mut chain_config = config.snapshot_reader.chain_config().clone();
+    chain_config.consensus = ConsensusConfig::bft(validators);
+    config.snapshot_reader = config
+        .snapshot_reader
+        .clone()
+        .with_chain_config(chain_config);
+
+    let bootstrap = Bootstrap::new(&make_config("b:0".to_string(), config.clone()))
+        .await
+        .expect("Failed to create bootstrap node");
+    let boots = bootstrap.listeners();
+
+    let mut validators = Vec::with_capacity(secrets.len());
+    for (i, secret) in secrets.into_iter().enumerate() {
+        let mut node_config = make_config(format!("bft:{i}"), config.clone());
+        node_config.block_production = Trigger::Never;
+        node_config
+            .p2p
+            .as_mut()
+            .unwrap()
+            .bootstrap_nodes
+            .clone_from(&boots);
+        node_config.consensus_signer = SignMode::Key(Secret::new(secret.into()));
+        validators.push(make_node(node_config, Vec::with_capacity(0)).await);
+    }
+
+    Nodes {
+        bootstrap_nodes: vec![bootstrap],
+        producers: vec![],
+        validators,
+    }
+}
+
 fn update_signing_key(config: &mut Config, key: Address) {
     let snapshot_reader = &config.snapshot_reader;
 
@@ -416,6 +462,9 @@ fn update_signing_key(config: &mut Config, key: Address) {
         ConsensusConfig::PoAV2(poa) => {
             poa.set_genesis_signing_key(key);
         }
+        consensus @ ConsensusConfig::Bft { .. } => {
+            *consensus = ConsensusConfig::bft(vec![key]);
+        }
     }
     config.snapshot_reader = snapshot_reader.clone().with_chain_config(chain_config)
 }
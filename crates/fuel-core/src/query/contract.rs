@@ -3,14 +3,18 @@ use fuel_core_storage::{
     not_found,
     tables::{
         ContractsAssets,
+        ContractsLatestUtxo,
         ContractsRawCode,
+        ContractsState,
     },
     Result as StorageResult,
     StorageAsRef,
 };
 use fuel_core_types::{
+    fuel_tx::UtxoId,
     fuel_types::{
         AssetId,
+        Bytes32,
         ContractId,
     },
     services::graphql_api::ContractBalance,
@@ -55,4 +59,41 @@ impl ReadView {
             asset_id,
         })
     }
+
+    /// Returns the values of the `keys` storage slots of the `contract`.
+    /// Slots without a value are skipped.
+    pub fn contract_storage_values<'a>(
+        &'a self,
+        contract_id: ContractId,
+        keys: impl IntoIterator<Item = Bytes32> + 'a,
+    ) -> impl Iterator<Item = StorageResult<(Bytes32, Vec<u8>)>> + 'a {
+        keys.into_iter().filter_map(move |key| {
+            let value = self
+                .on_chain
+                .as_ref()
+                .storage::<ContractsState>()
+                .get(&(&contract_id, &key).into())
+                .transpose()?;
+
+            Some(value.map(|value| (key, value.into_owned().0)))
+        })
+    }
+
+    /// Returns the latest UTXO of the contract. The contract output of the
+    /// transaction that created it commits to the latest state and balance roots.
+    pub fn contract_latest_utxo_id(
+        &self,
+        contract_id: ContractId,
+    ) -> StorageResult<UtxoId> {
+        let utxo_id = self
+            .on_chain
+            .as_ref()
+            .storage::<ContractsLatestUtxo>()
+            .get(&contract_id)?
+            .ok_or(not_found!(ContractsLatestUtxo))?
+            .utxo_id()
+            .to_owned();
+
+        Ok(utxo_id)
+    }
 }
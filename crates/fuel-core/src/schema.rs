@@ -23,6 +23,7 @@ use fuel_core_storage::{
     iter::IterDirection,
     Result as StorageResult,
 };
+use fuel_core_types::fuel_types::BlockHeight;
 use futures::{
     Stream,
     TryStreamExt,
@@ -34,6 +35,7 @@ pub mod balance;
 pub mod blob;
 pub mod block;
 pub mod chain;
+pub mod checkpoint;
 pub mod coins;
 pub mod contract;
 pub mod da_compressed;
@@ -46,6 +48,7 @@ pub mod upgrades;
 pub mod gas_price;
 pub mod scalars;
 pub mod tx;
+pub mod tx_pool;
 
 pub mod relayed_tx;
 
@@ -57,24 +60,38 @@ pub struct Query(
     block::BlockQuery,
     chain::ChainQuery,
     tx::TxQuery,
+    tx_pool::TxPoolQuery,
     health::HealthQuery,
     coins::CoinQuery,
     da_compressed::DaCompressedBlockQuery,
     contract::ContractQuery,
     contract::ContractBalanceQuery,
+    contract::ContractStorageQuery,
     node_info::NodeQuery,
     gas_price::LatestGasPriceQuery,
     gas_price::EstimateGasPriceQuery,
+    gas_price::GasPriceHistoryQuery,
     message::MessageQuery,
     relayed_tx::RelayedTransactionQuery,
     upgrades::UpgradeQuery,
 );
 
 #[derive(MergedObject, Default)]
-pub struct Mutation(dap::DapMutation, tx::TxMutation, block::BlockMutation);
+pub struct Mutation(
+    dap::DapMutation,
+    tx::TxMutation,
+    block::BlockMutation,
+    tx_pool::TxPoolMutation,
+    checkpoint::DatabaseCheckpointMutation,
+);
 
 #[derive(MergedSubscription, Default)]
-pub struct Subscription(tx::TxStatusSubscription);
+pub struct Subscription(
+    tx::TxStatusSubscription,
+    tx_pool::TxPoolSubscription,
+    block::BlockSubscription,
+    gas_price::GasPriceSubscription,
+);
 
 pub type CoreSchema = Schema<Query, Mutation, Subscription>;
 pub type CoreSchemaBuilder = SchemaBuilder<Query, Mutation, Subscription>;
@@ -216,6 +233,10 @@ where
 pub trait ReadViewProvider {
     /// Returns the read view for the current operation.
     fn read_view(&self) -> StorageResult<Cow<ReadView>>;
+
+    /// Returns the read view at the `height` if it is specified,
+    /// otherwise returns the read view for the current operation.
+    fn read_view_at(&self, height: Option<BlockHeight>) -> StorageResult<Cow<ReadView>>;
 }
 
 impl<'a> ReadViewProvider for Context<'a> {
@@ -232,4 +253,14 @@ impl<'a> ReadViewProvider for Context<'a> {
             Ok(Cow::Borrowed(read_view))
         }
     }
+
+    fn read_view_at(&self, height: Option<BlockHeight>) -> StorageResult<Cow<ReadView>> {
+        match height {
+            Some(height) => {
+                let database: &ReadDatabase = self.data_unchecked();
+                database.view_at(&height).map(Cow::Owned)
+            }
+            None => self.read_view(),
+        }
+    }
 }
@@ -25,7 +25,10 @@ use async_graphql::{
 use fuel_core_types::services::graphql_api;
 use futures::StreamExt;
 
-use super::scalars::U64;
+use super::scalars::{
+    U32,
+    U64,
+};
 
 pub struct Balance(graphql_api::AddressBalance);
 
@@ -66,8 +69,12 @@ impl BalanceQuery {
         ctx: &Context<'_>,
         #[graphql(desc = "address of the owner")] owner: Address,
         #[graphql(desc = "asset_id of the coin")] asset_id: AssetId,
+        #[graphql(
+            desc = "The height of the block at which to query the balance. The latest height is used if not specified."
+        )]
+        at_height: Option<U32>,
     ) -> async_graphql::Result<Balance> {
-        let query = ctx.read_view()?;
+        let query = ctx.read_view_at(at_height.map(|height| height.0.into()))?;
         let base_asset_id = *ctx
             .data_unchecked::<ConsensusProvider>()
             .latest_consensus_params()
@@ -5,6 +5,7 @@ use crate::{
         scalars::{
             BlobId,
             HexString,
+            U32,
         },
         ReadViewProvider,
     },
@@ -17,29 +18,39 @@ use fuel_core_storage::{
     not_found,
     tables::BlobData,
 };
-use fuel_core_types::fuel_types;
+use fuel_core_types::fuel_types::{
+    self,
+    BlockHeight,
+};
 
-pub struct Blob(fuel_types::BlobId);
+pub struct Blob {
+    id: fuel_types::BlobId,
+    /// The height at which the blob is queried, the latest height if `None`.
+    at_height: Option<BlockHeight>,
+}
 
 #[Object]
 impl Blob {
     async fn id(&self) -> BlobId {
-        self.0.into()
+        self.id.into()
     }
 
     #[graphql(complexity = "query_costs().bytecode_read")]
     async fn bytecode(&self, ctx: &Context<'_>) -> async_graphql::Result<HexString> {
-        let query = ctx.read_view()?;
+        let query = ctx.read_view_at(self.at_height)?;
         query
-            .blob_bytecode(self.0)
+            .blob_bytecode(self.id)
             .map(HexString)
             .map_err(async_graphql::Error::from)
     }
 }
 
 impl From<fuel_types::BlobId> for Blob {
-    fn from(value: fuel_types::BlobId) -> Self {
-        Self(value)
+    fn from(id: fuel_types::BlobId) -> Self {
+        Self {
+            id,
+            at_height: None,
+        }
     }
 }
 
@@ -53,13 +64,21 @@ impl BlobQuery {
         &self,
         ctx: &Context<'_>,
         #[graphql(desc = "ID of the Blob")] id: BlobId,
+        #[graphql(
+            desc = "The height of the block at which to query the blob. The latest height is used if not specified."
+        )]
+        at_height: Option<U32>,
     ) -> async_graphql::Result<Option<Blob>> {
-        let query = ctx.read_view()?;
+        let at_height = at_height.map(|height| height.0.into());
+        let query = ctx.read_view_at(at_height)?;
         query
             .blob_exists(id.0)
             .and_then(|blob_exists| {
                 if blob_exists {
-                    Ok(id.0)
+                    Ok(Blob {
+                        id: id.0,
+                        at_height,
+                    })
                 } else {
                     Err(not_found!(BlobData))
                 }
@@ -5,8 +5,15 @@ use super::scalars::{
 };
 use crate::{
     fuel_core_graphql_api::{
-        api_service::ConsensusModule,
-        database::ReadView,
+        api_service::{
+            BlockImporter,
+            ConsensusModule,
+            ConsensusProvider,
+        },
+        database::{
+            ReadDatabase,
+            ReadView,
+        },
         query_costs,
         Config as GraphQLConfig,
         IntoApiResult,
@@ -19,7 +26,10 @@ use crate::{
             U32,
             U64,
         },
-        tx::types::Transaction,
+        tx::types::{
+            Transaction,
+            TransactionStatus,
+        },
         ReadViewProvider,
     },
 };
@@ -33,10 +43,13 @@ use async_graphql::{
     Enum,
     Object,
     SimpleObject,
+    Subscription,
     Union,
 };
+use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::{
     iter::IterDirection,
+    Error as StorageError,
     Result as StorageResult,
 };
 use fuel_core_types::{
@@ -48,6 +61,11 @@ use fuel_core_types::{
     fuel_types::{
         self,
         BlockHeight,
+        ChainId,
+    },
+    services::{
+        block_importer::SharedImportResult,
+        txpool::from_executor_to_status,
     },
 };
 use futures::{
@@ -55,6 +73,7 @@ use futures::{
     StreamExt,
     TryStreamExt,
 };
+use std::time::Duration;
 
 pub struct Block(pub(crate) CompressedBlock);
 
@@ -65,6 +84,7 @@ pub struct Header(pub(crate) BlockHeader);
 pub enum Consensus {
     Genesis(Genesis),
     PoA(PoAConsensus),
+    Bft(BftConsensus),
 }
 
 type CoreGenesis = fuel_core_types::blockchain::consensus::Genesis;
@@ -89,6 +109,11 @@ pub struct PoAConsensus {
     signature: Signature,
 }
 
+pub struct BftConsensus {
+    round: u32,
+    signatures: Vec<Signature>,
+}
+
 #[derive(Clone, Copy, Debug, Enum, Eq, PartialEq)]
 pub enum BlockVersion {
     V1,
@@ -252,6 +277,19 @@ impl PoAConsensus {
     }
 }
 
+#[Object]
+impl BftConsensus {
+    /// The round of the `BFT` consensus in which validators committed the block.
+    async fn round(&self) -> U32 {
+        self.round.into()
+    }
+
+    /// Gets the signatures of the validators' precommits for the block.
+    async fn signatures(&self) -> Vec<Signature> {
+        self.signatures.clone()
+    }
+}
+
 #[derive(Default)]
 pub struct BlockQuery;
 
@@ -402,6 +440,218 @@ impl BlockMutation {
     }
 }
 
+/// The block with all its transactions and their execution statuses.
+pub struct FullBlock {
+    block: Block,
+    transactions: Vec<FullTransaction>,
+}
+
+pub struct FullTransaction {
+    transaction: Transaction,
+    status: TransactionStatus,
+}
+
+#[Object]
+impl FullBlock {
+    async fn block(&self) -> &Block {
+        &self.block
+    }
+
+    async fn transactions(&self) -> &Vec<FullTransaction> {
+        &self.transactions
+    }
+}
+
+#[Object]
+impl FullTransaction {
+    async fn transaction(&self) -> &Transaction {
+        &self.transaction
+    }
+
+    async fn status(&self) -> &TransactionStatus {
+        &self.status
+    }
+}
+
+impl FullBlock {
+    fn from_import_result(result: &SharedImportResult, chain_id: &ChainId) -> Self {
+        let block = &result.sealed_block.entity;
+        let transactions = block
+            .transactions()
+            .iter()
+            .zip(result.tx_status.iter())
+            .map(|(tx, status)| FullTransaction {
+                transaction: Transaction::from_tx(status.id, tx.clone()),
+                status: TransactionStatus::new(
+                    status.id,
+                    from_executor_to_status(block, status.result.clone()),
+                ),
+            })
+            .collect();
+
+        Self {
+            block: block.compress(chain_id).into(),
+            transactions,
+        }
+    }
+
+    fn from_storage(query: &ReadView, height: &BlockHeight) -> StorageResult<Self> {
+        let block = query.block(height)?;
+        let transactions = block
+            .transactions()
+            .iter()
+            .map(|tx_id| {
+                let transaction = query.transaction(tx_id)?;
+                let status = query.tx_status(tx_id)?;
+                Ok(FullTransaction {
+                    transaction: Transaction::from_tx(*tx_id, transaction),
+                    status: TransactionStatus::new(*tx_id, status),
+                })
+            })
+            .collect::<StorageResult<Vec<_>>>()?;
+
+        Ok(Self {
+            block: block.into(),
+            transactions,
+        })
+    }
+}
+
+/// The off-chain database processes the block after its import, so it can lag
+/// behind the importer. The backfill waits for it to not return incomplete blocks.
+const OFF_CHAIN_SYNC_INTERVAL: Duration = Duration::from_millis(50);
+const OFF_CHAIN_SYNC_ATTEMPTS: usize = 100;
+
+async fn block_from_storage(
+    database: &ReadDatabase,
+    height: BlockHeight,
+) -> anyhow::Result<FullBlock> {
+    for _ in 0..OFF_CHAIN_SYNC_ATTEMPTS {
+        match FullBlock::from_storage(&database.view()?, &height) {
+            Err(StorageError::NotFound(_, _)) => {
+                tokio::time::sleep(OFF_CHAIN_SYNC_INTERVAL).await;
+            }
+            result => return Ok(result?),
+        }
+    }
+
+    Err(anyhow!(
+        "The block at height {height} is not available in the database"
+    ))
+}
+
+struct BlocksStreamState<'a> {
+    database: &'a ReadDatabase,
+    chain_id: ChainId,
+    events: BoxStream<SharedImportResult>,
+    /// The height of the next block to return.
+    next_height: BlockHeight,
+    /// The last height that should be read from the storage instead of the events.
+    backfill_until: Option<BlockHeight>,
+    /// The event that should be returned after the backfill.
+    pending_event: Option<SharedImportResult>,
+    failed: bool,
+}
+
+impl<'a> BlocksStreamState<'a> {
+    async fn next_block(&mut self) -> Option<anyhow::Result<FullBlock>> {
+        if self.failed {
+            return None
+        }
+
+        loop {
+            if let Some(backfill_until) = self.backfill_until {
+                if self.next_height <= backfill_until {
+                    let height = self.next_height;
+                    self.next_height = next_height(&height);
+                    let result = block_from_storage(self.database, height).await;
+                    self.failed = result.is_err();
+                    return Some(result)
+                }
+                self.backfill_until = None;
+            }
+
+            if let Some(event) = self.pending_event.take() {
+                let height = *event.sealed_block.entity.header().height();
+                self.next_height = next_height(&height);
+                return Some(Ok(FullBlock::from_import_result(&event, &self.chain_id)))
+            }
+
+            let event = self.events.next().await?;
+            let height = *event.sealed_block.entity.header().height();
+
+            if height < self.next_height {
+                // The block was already returned from the storage.
+                continue
+            }
+
+            if height > self.next_height {
+                // The stream skipped some events(or the subscription started in the past),
+                // fill the gap from the storage.
+                self.backfill_until = Some(u32::from(height).saturating_sub(1).into());
+            }
+            self.pending_event = Some(event);
+        }
+    }
+}
+
+fn next_height(height: &BlockHeight) -> BlockHeight {
+    u32::from(*height).saturating_add(1).into()
+}
+
+#[derive(Default)]
+pub struct BlockSubscription;
+
+#[Subscription]
+impl BlockSubscription {
+    /// Returns a stream of blocks with their transactions, statuses and receipts.
+    /// If `from_height` is in the past, the stream starts with the blocks from the
+    /// database and continues with newly imported blocks without gaps.
+    /// If `from_height` is not specified, the stream starts from the next block.
+    #[graphql(complexity = "query_costs().blocks_subscription + child_complexity")]
+    async fn blocks<'a>(
+        &self,
+        ctx: &'a Context<'a>,
+        #[graphql(desc = "The height of the first block in the stream")]
+        from_height: Option<U32>,
+    ) -> async_graphql::Result<impl Stream<Item = async_graphql::Result<FullBlock>> + 'a>
+    {
+        let database: &ReadDatabase = ctx.data_unchecked();
+        let chain_id = ctx
+            .data_unchecked::<ConsensusProvider>()
+            .latest_consensus_params()
+            .chain_id();
+
+        // Subscribe before reading the latest height to not miss blocks
+        // imported in between.
+        let events = ctx.data_unchecked::<BlockImporter>().block_events();
+        let latest_height = database.view()?.latest_block_height()?;
+
+        let (next_height, backfill_until) = match from_height {
+            Some(from_height) => {
+                let from_height: BlockHeight = u32::from(from_height).into();
+                (from_height, Some(latest_height))
+            }
+            None => (next_height(&latest_height), None),
+        };
+
+        let state = BlocksStreamState {
+            database,
+            chain_id,
+            events,
+            next_height,
+            backfill_until,
+            pending_event: None,
+            failed: false,
+        };
+
+        Ok(futures::stream::unfold(state, |mut state| async move {
+            let block = state.next_block().await?;
+            Some((block.map_err(async_graphql::Error::from), state))
+        }))
+    }
+}
+
 impl From<CompressedBlock> for Block {
     fn from(block: CompressedBlock) -> Self {
         Block(block)
@@ -441,6 +691,10 @@ impl TryFrom<CoreConsensus> for Consensus {
             CoreConsensus::PoA(poa) => Ok(Consensus::PoA(PoAConsensus {
                 signature: poa.signature.into(),
             })),
+            CoreConsensus::Bft(bft) => Ok(Consensus::Bft(BftConsensus {
+                round: bft.round,
+                signatures: bft.signatures.into_iter().map(Into::into).collect(),
+            })),
             _ => Err(format!("Unknown consensus type: {:?}", consensus)),
         }
     }
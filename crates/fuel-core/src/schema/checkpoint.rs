@@ -0,0 +1,70 @@
+use crate::{
+    combined_database::checkpoint::CheckpointManifest,
+    fuel_core_graphql_api::{
+        api_service::DatabaseCheckpoint as DatabaseCheckpointProvider,
+        query_costs,
+    },
+    schema::scalars::{
+        Tai64Timestamp,
+        U32,
+        U64,
+    },
+};
+use async_graphql::{
+    Context,
+    Object,
+};
+use fuel_core_types::tai64::Tai64;
+use std::path::PathBuf;
+
+pub struct DatabaseCheckpoint(CheckpointManifest);
+
+#[Object]
+impl DatabaseCheckpoint {
+    /// The time when the checkpoint was created.
+    async fn created_at(&self) -> Tai64Timestamp {
+        let seconds = i64::try_from(self.0.created_at).unwrap_or(i64::MAX);
+        Tai64Timestamp(Tai64::from_unix(seconds))
+    }
+
+    /// The height of the on-chain database.
+    async fn on_chain_height(&self) -> Option<U32> {
+        self.0.on_chain_height.map(Into::into)
+    }
+
+    /// The height of the off-chain database.
+    async fn off_chain_height(&self) -> Option<U32> {
+        self.0.off_chain_height.map(Into::into)
+    }
+
+    /// The height of the gas price database.
+    async fn gas_price_height(&self) -> Option<U32> {
+        self.0.gas_price_height.map(Into::into)
+    }
+
+    /// The DA height of the relayer database.
+    async fn relayer_height(&self) -> Option<U64> {
+        self.0.relayer_height.map(|height| height.0.into())
+    }
+}
+
+#[derive(Default)]
+pub struct DatabaseCheckpointMutation;
+
+#[Object]
+impl DatabaseCheckpointMutation {
+    /// Creates the checkpoints of all databases of the node in the `path` on
+    /// the node's filesystem. The `path` must not exist. The checkpoint can be
+    /// restored with the `fuel-core db restore` command.
+    /// Requires the admin role.
+    #[graphql(complexity = "query_costs().database_checkpoint + child_complexity")]
+    async fn create_database_checkpoint(
+        &self,
+        ctx: &Context<'_>,
+        path: String,
+    ) -> async_graphql::Result<DatabaseCheckpoint> {
+        let checkpoint = ctx.data_unchecked::<DatabaseCheckpointProvider>();
+        let manifest = checkpoint.create_checkpoint(PathBuf::from(path)).await?;
+        Ok(DatabaseCheckpoint(manifest))
+    }
+}
@@ -173,8 +173,12 @@ impl CoinQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
+        #[graphql(
+            desc = "The height of the block at which to query the coins. The latest height is used if not specified."
+        )]
+        at_height: Option<U32>,
     ) -> async_graphql::Result<Connection<UtxoId, Coin, EmptyFields, EmptyFields>> {
-        let query = ctx.read_view()?;
+        let query = ctx.read_view_at(at_height.map(|height| height.0.into()))?;
         let owner: fuel_tx::Address = filter.owner.into();
         crate::schema::query_pagination(after, before, first, last, |start, direction| {
             let coins = query
@@ -6,9 +6,12 @@ use crate::{
     schema::{
         scalars::{
             AssetId,
+            Bytes32,
             ContractId,
             HexString,
             Salt,
+            UtxoId,
+            U32,
             U64,
         },
         ReadViewProvider,
@@ -28,39 +31,53 @@ use fuel_core_storage::{
     tables::ContractsRawCode,
 };
 use fuel_core_types::{
-    fuel_types,
+    fuel_merkle::sparse::proof::{
+        ExclusionLeaf,
+        Proof,
+    },
+    fuel_types::{
+        self,
+        BlockHeight,
+    },
     services::graphql_api,
 };
 use futures::StreamExt;
 
-pub struct Contract(pub(crate) fuel_types::ContractId);
+pub struct Contract {
+    pub(crate) id: fuel_types::ContractId,
+    /// The height at which the contract is queried, the latest height if `None`.
+    pub(crate) at_height: Option<BlockHeight>,
+}
 
 impl From<fuel_types::ContractId> for Contract {
     fn from(id: fuel_types::ContractId) -> Self {
-        Self(id)
+        Self {
+            id,
+            at_height: None,
+        }
     }
 }
 
 #[Object]
 impl Contract {
     async fn id(&self) -> ContractId {
-        self.0.into()
+        self.id.into()
     }
 
     #[graphql(complexity = "query_costs().bytecode_read")]
     async fn bytecode(&self, ctx: &Context<'_>) -> async_graphql::Result<HexString> {
-        let query = ctx.read_view()?;
+        let query = ctx.read_view_at(self.at_height)?;
         query
-            .contract_bytecode(self.0)
+            .contract_bytecode(self.id)
             .map(HexString)
             .map_err(Into::into)
     }
 
     #[graphql(complexity = "query_costs().storage_read")]
     async fn salt(&self, ctx: &Context<'_>) -> async_graphql::Result<Salt> {
-        let query = ctx.read_view()?;
+        let query = ctx.read_view_at(self.at_height)?;
         query
-            .contract_salt(&self.0)
+            .contract_salt(&self.id)
             .map(Into::into)
             .map_err(Into::into)
     }
@@ -76,13 +93,21 @@ impl ContractQuery {
         &self,
         ctx: &Context<'_>,
         #[graphql(desc = "ID of the Contract")] id: ContractId,
+        #[graphql(
+            desc = "The height of the block at which to query the contract. The latest height is used if not specified."
+        )]
+        at_height: Option<U32>,
     ) -> async_graphql::Result<Option<Contract>> {
-        let query = ctx.read_view()?;
+        let at_height = at_height.map(|height| height.0.into());
+        let query = ctx.read_view_at(at_height)?;
         query
             .contract_exists(id.0)
             .and_then(|contract_exists| {
                 if contract_exists {
-                    Ok(id.0)
+                    Ok(Contract {
+                        id: id.0,
+                        at_height,
+                    })
                 } else {
                     Err(not_found!(ContractsRawCode))
                 }
@@ -125,10 +150,14 @@ impl ContractBalanceQuery {
         ctx: &Context<'_>,
         contract: ContractId,
         asset: AssetId,
+        #[graphql(
+            desc = "The height of the block at which to query the balance. The latest height is used if not specified."
+        )]
+        at_height: Option<U32>,
     ) -> async_graphql::Result<ContractBalance> {
         let contract_id = contract.into();
         let asset_id = asset.into();
-        let query = ctx.read_view()?;
+        let query = ctx.read_view_at(at_height.map(|height| height.0.into()))?;
         query
             .contract_balance(contract_id, asset_id)
             .into_api_result()
@@ -180,6 +209,33 @@ impl ContractBalanceQuery {
         })
         .await
     }
+
+    // 256 * QUERY_COSTS.storage_read because the depth of the Merkle tree in the worst case is 256
+    #[graphql(complexity = "256 * query_costs().storage_read + child_complexity")]
+    async fn contract_balance_proof(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "ID of the Contract")] contract: ContractId,
+        #[graphql(desc = "ID of the Asset")] asset: AssetId,
+    ) -> async_graphql::Result<ContractBalanceProof> {
+        let query = ctx.read_view()?;
+        let contract_id = contract.into();
+        let asset_id = asset.into();
+        let utxo_id = query.contract_latest_utxo_id(contract_id)?;
+        let amount = query
+            .contract_balance(contract_id, asset_id)
+            .into_api_result::<graphql_api::ContractBalance, async_graphql::Error>()?
+            .map(|balance| balance.amount);
+        let proof = query.contract_balance_proof(contract_id, asset_id)?;
+
+        Ok(ContractBalanceProof {
+            contract_id,
+            asset_id,
+            amount,
+            utxo_id,
+            proof: SparseMerkleProof(proof),
+        })
+    }
 }
 
 impl From<graphql_api::ContractBalance> for ContractBalance {
@@ -187,3 +243,231 @@ impl From<graphql_api::ContractBalance> for ContractBalance {
         ContractBalance(balance)
     }
 }
+
+pub struct ContractStorageSlot {
+    key: fuel_types::Bytes32,
+    value: Vec<u8>,
+}
+
+#[Object]
+impl ContractStorageSlot {
+    async fn key(&self) -> Bytes32 {
+        self.key.into()
+    }
+
+    async fn value(&self) -> HexString {
+        HexString(self.value.clone())
+    }
+}
+
+impl From<(fuel_types::Bytes32, Vec<u8>)> for ContractStorageSlot {
+    fn from((key, value): (fuel_types::Bytes32, Vec<u8>)) -> Self {
+        Self { key, value }
+    }
+}
+
+#[derive(Default)]
+pub struct ContractStorageQuery;
+
+#[Object]
+impl ContractStorageQuery {
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator\
+        + (query_costs().storage_read + first.unwrap_or_default() as usize) * child_complexity \
+        + (query_costs().storage_read + last.unwrap_or_default() as usize) * child_complexity\
+    }")]
+    async fn contract_storage_slots(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "ID of the Contract")] contract: ContractId,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<Bytes32, ContractStorageSlot, EmptyFields, EmptyFields>,
+    > {
+        let query = ctx.read_view()?;
+
+        crate::schema::query_pagination(after, before, first, last, |start, direction| {
+            let slots = query
+                .contract_storage_slots(
+                    contract.into(),
+                    (*start).map(Into::into),
+                    direction,
+                )
+                .map(|slot| {
+                    let (key, value) = slot?;
+
+                    Ok((key.into(), (key, value).into()))
+                });
+
+            Ok(slots)
+        })
+        .await
+    }
+
+    #[graphql(complexity = "query_costs().storage_read * keys.len()")]
+    async fn contract_storage_values(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "ID of the Contract")] contract: ContractId,
+        #[graphql(desc = "Keys of the storage slots")] keys: Vec<Bytes32>,
+    ) -> async_graphql::Result<Vec<ContractStorageSlot>> {
+        let query = ctx.read_view()?;
+        let slots = query
+            .contract_storage_values(contract.into(), keys.into_iter().map(Into::into))
+            .map(|slot| slot.map(Into::into))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(slots)
+    }
+
+    // 256 * QUERY_COSTS.storage_read because the depth of the Merkle tree in the worst case is 256
+    #[graphql(complexity = "256 * query_costs().storage_read + child_complexity")]
+    async fn contract_state_proof(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "ID of the Contract")] contract: ContractId,
+        #[graphql(desc = "Key of the storage slot")] key: Bytes32,
+    ) -> async_graphql::Result<ContractStateProof> {
+        let query = ctx.read_view()?;
+        let contract_id = contract.into();
+        let key = key.into();
+        let utxo_id = query.contract_latest_utxo_id(contract_id)?;
+        let value = query
+            .contract_storage_values(contract_id, [key])
+            .next()
+            .transpose()?
+            .map(|(_, value)| value);
+        let proof = query.contract_state_proof(contract_id, key)?;
+
+        Ok(ContractStateProof {
+            contract_id,
+            key,
+            value,
+            utxo_id,
+            proof: SparseMerkleProof(proof),
+        })
+    }
+}
+
+pub struct SparseMerkleProof(Proof);
+
+#[Object]
+impl SparseMerkleProof {
+    /// The root of the sparse Merkle tree.
+    async fn root(&self) -> Bytes32 {
+        match &self.0 {
+            Proof::Inclusion(proof) => proof.root.into(),
+            Proof::Exclusion(proof) => proof.root.into(),
+        }
+    }
+
+    /// The side nodes of the path from the leaf to the root.
+    async fn proof_set(&self) -> Vec<Bytes32> {
+        let proof_set = match &self.0 {
+            Proof::Inclusion(proof) => &proof.proof_set,
+            Proof::Exclusion(proof) => &proof.proof_set,
+        };
+        proof_set.iter().map(|node| (*node).into()).collect()
+    }
+
+    /// `true` if the proof proves the inclusion of the key into the tree,
+    /// `false` if it proves the exclusion.
+    async fn inclusion(&self) -> bool {
+        matches!(self.0, Proof::Inclusion(_))
+    }
+
+    /// The key of the leaf found instead of the excluded key.
+    /// `null` for inclusion proofs and for exclusion proofs ending in an empty subtree.
+    async fn leaf_key(&self) -> Option<Bytes32> {
+        match &self.0 {
+            Proof::Exclusion(proof) => match &proof.leaf {
+                ExclusionLeaf::Leaf(leaf) => Some(leaf.leaf_key.into()),
+                ExclusionLeaf::Placeholder => None,
+            },
+            Proof::Inclusion(_) => None,
+        }
+    }
+
+    /// The hash of the value of the leaf found instead of the excluded key.
+    /// `null` for inclusion proofs and for exclusion proofs ending in an empty subtree.
+    async fn leaf_value(&self) -> Option<Bytes32> {
+        match &self.0 {
+            Proof::Exclusion(proof) => match &proof.leaf {
+                ExclusionLeaf::Leaf(leaf) => Some(leaf.leaf_value.into()),
+                ExclusionLeaf::Placeholder => None,
+            },
+            Proof::Inclusion(_) => None,
+        }
+    }
+}
+
+pub struct ContractStateProof {
+    contract_id: fuel_types::ContractId,
+    key: fuel_types::Bytes32,
+    value: Option<Vec<u8>>,
+    utxo_id: fuel_core_types::fuel_tx::UtxoId,
+    proof: SparseMerkleProof,
+}
+
+#[Object]
+impl ContractStateProof {
+    async fn contract(&self) -> ContractId {
+        self.contract_id.into()
+    }
+
+    async fn key(&self) -> Bytes32 {
+        self.key.into()
+    }
+
+    /// The value of the storage slot, `null` if the slot is empty.
+    async fn value(&self) -> Option<HexString> {
+        self.value.clone().map(HexString)
+    }
+
+    /// The latest UTXO of the contract. The state root of its contract output
+    /// is equal to the root of the proof.
+    async fn utxo_id(&self) -> UtxoId {
+        self.utxo_id.into()
+    }
+
+    async fn proof(&self) -> &SparseMerkleProof {
+        &self.proof
+    }
+}
+
+pub struct ContractBalanceProof {
+    contract_id: fuel_types::ContractId,
+    asset_id: fuel_types::AssetId,
+    amount: Option<u64>,
+    utxo_id: fuel_core_types::fuel_tx::UtxoId,
+    proof: SparseMerkleProof,
+}
+
+#[Object]
+impl ContractBalanceProof {
+    async fn contract(&self) -> ContractId {
+        self.contract_id.into()
+    }
+
+    async fn asset_id(&self) -> AssetId {
+        self.asset_id.into()
+    }
+
+    /// The balance of the asset, `null` if the contract doesn't have the balance entry.
+    async fn amount(&self) -> Option<U64> {
+        self.amount.map(Into::into)
+    }
+
+    /// The latest UTXO of the contract. The balance root of its contract output
+    /// is equal to the root of the proof.
+    async fn utxo_id(&self) -> UtxoId {
+        self.utxo_id.into()
+    }
+
+    async fn proof(&self) -> &SparseMerkleProof {
+        &self.proof
+    }
+}
@@ -1,25 +1,39 @@
 use super::scalars::{
+    U128,
     U32,
     U64,
 };
 use crate::{
     graphql_api::{
-        api_service::GasPriceProvider,
+        api_service::{
+            BlockImporter,
+            GasPriceHistory,
+            GasPriceProvider,
+        },
         query_costs,
     },
     schema::ReadViewProvider,
 };
+use anyhow::anyhow;
 use async_graphql::{
     Context,
     Object,
+    Subscription,
 };
+use fuel_core_gas_price_service::common::gas_price_history;
 use fuel_core_types::{
     blockchain::block::Block,
     fuel_tx::{
         field::MintGasPrice,
         Transaction,
     },
+    fuel_types::BlockHeight,
 };
+use futures::{
+    Stream,
+    StreamExt,
+};
+use std::time::Duration;
 
 pub struct LatestGasPrice {
     pub gas_price: U64,
@@ -111,3 +125,136 @@ impl EstimateGasPriceQuery {
         })
     }
 }
+
+pub struct GasPriceHistoryEntry(pub(crate) gas_price_history::GasPriceHistoryEntry);
+
+#[Object]
+impl GasPriceHistoryEntry {
+    /// The height of the block the gas prices were calculated from.
+    async fn block_height(&self) -> U32 {
+        self.0.l2_block_height.into()
+    }
+
+    /// The sum of the execution and DA gas prices.
+    async fn gas_price(&self) -> U64 {
+        self.0
+            .exec_gas_price
+            .saturating_add(self.0.da_gas_price)
+            .into()
+    }
+
+    /// The execution gas price calculated for the next block.
+    async fn exec_gas_price(&self) -> U64 {
+        self.0.exec_gas_price.into()
+    }
+
+    /// The DA gas price calculated for the next block.
+    async fn da_gas_price(&self) -> U64 {
+        self.0.da_gas_price.into()
+    }
+
+    async fn block_gas_used(&self) -> U64 {
+        self.0.block_gas_used.into()
+    }
+
+    async fn block_gas_capacity(&self) -> U64 {
+        self.0.block_gas_capacity.into()
+    }
+
+    async fn block_bytes(&self) -> U64 {
+        self.0.block_bytes.into()
+    }
+
+    /// The cost of the DA records applied by the algorithm at this block.
+    async fn recorded_da_cost(&self) -> U128 {
+        self.0.recorded_da_cost.into()
+    }
+}
+
+impl From<gas_price_history::GasPriceHistoryEntry> for GasPriceHistoryEntry {
+    fn from(entry: gas_price_history::GasPriceHistoryEntry) -> Self {
+        Self(entry)
+    }
+}
+
+#[derive(Default)]
+pub struct GasPriceHistoryQuery {}
+
+#[Object]
+impl GasPriceHistoryQuery {
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator \
+        + (to_height.0.saturating_sub(from_height.0) as usize).saturating_add(1) \
+        * (query_costs().storage_read + child_complexity) \
+    }")]
+    async fn gas_price_history(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The height of the first block in the range")] from_height: U32,
+        #[graphql(desc = "The height of the last block in the range")] to_height: U32,
+    ) -> async_graphql::Result<Vec<GasPriceHistoryEntry>> {
+        if from_height.0 > to_height.0 {
+            return Err(async_graphql::Error::new(
+                "`fromHeight` should be less than or equal to `toHeight`",
+            ))
+        }
+
+        let history = ctx.data_unchecked::<GasPriceHistory>();
+        let to_height: BlockHeight = to_height.0.into();
+        let entries = history
+            .gas_price_history(from_height.0.into())
+            .take_while(|entry| match entry {
+                Ok(entry) => entry.l2_block_height() <= to_height,
+                Err(_) => true,
+            })
+            .map(|entry| entry.map(Into::into))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(entries)
+    }
+}
+
+const GAS_PRICE_SYNC_INTERVAL: Duration = Duration::from_millis(50);
+const GAS_PRICE_SYNC_ATTEMPTS: usize = 100;
+
+/// The gas price service processes the block after it is imported, so the entry
+/// may appear in the storage with a small delay.
+async fn history_entry_from_storage(
+    history: &GasPriceHistory,
+    height: BlockHeight,
+) -> anyhow::Result<GasPriceHistoryEntry> {
+    for _ in 0..GAS_PRICE_SYNC_ATTEMPTS {
+        if let Some(entry) = history.gas_price_history_at(&height)? {
+            return Ok(entry.into())
+        }
+        tokio::time::sleep(GAS_PRICE_SYNC_INTERVAL).await;
+    }
+
+    Err(anyhow!(
+        "The gas prices for the block at height {height} are not available"
+    ))
+}
+
+#[derive(Default)]
+pub struct GasPriceSubscription;
+
+#[Subscription]
+impl GasPriceSubscription {
+    /// Returns a stream of the gas prices calculated after each new block.
+    #[graphql(complexity = "query_costs().storage_read + child_complexity")]
+    async fn gas_prices<'a>(
+        &self,
+        ctx: &'a Context<'a>,
+    ) -> async_graphql::Result<
+        impl Stream<Item = async_graphql::Result<GasPriceHistoryEntry>> + 'a,
+    > {
+        let history = ctx.data_unchecked::<GasPriceHistory>();
+        let events = ctx.data_unchecked::<BlockImporter>().block_events();
+
+        Ok(events.then(move |event| async move {
+            let height = *event.sealed_block.entity.header().height();
+            history_entry_from_storage(history, height)
+                .await
+                .map_err(async_graphql::Error::from)
+        }))
+    }
+}
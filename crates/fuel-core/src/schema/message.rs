@@ -90,9 +90,13 @@ impl MessageQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
+        #[graphql(
+            desc = "The height of the block at which to query the messages. The latest height is used if not specified."
+        )]
+        at_height: Option<U32>,
     ) -> async_graphql::Result<Connection<HexString, Message, EmptyFields, EmptyFields>>
     {
-        let query = ctx.read_view()?;
+        let query = ctx.read_view_at(at_height.map(|height| height.0.into()))?;
         let owner = owner.map(|owner| owner.0);
         let owner_ref = owner.as_ref();
         crate::schema::query_pagination(
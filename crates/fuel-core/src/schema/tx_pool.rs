@@ -0,0 +1,401 @@
+use crate::{
+    fuel_core_graphql_api::{
+        api_service::{
+            ConsensusProvider,
+            TxPool,
+            TxPoolBlackListManager,
+        },
+        query_costs,
+    },
+    schema::{
+        scalars::{
+            Address,
+            AssetId,
+            ContractId,
+            Nonce,
+            Tai64Timestamp,
+            TransactionId,
+            UtxoId,
+            U32,
+            U64,
+        },
+        tx::types::Transaction,
+    },
+};
+use async_graphql::{
+    connection::{
+        Connection,
+        EmptyFields,
+    },
+    Context,
+    Object,
+    Subscription,
+};
+use fuel_core_storage::Error as StorageError;
+use fuel_core_txpool::{
+    config::{
+        BlackList,
+        BlackListEntry,
+    },
+    PendingTxsQuery,
+    TxInfo,
+    TxPoolStats as TxPoolStatsInfo,
+};
+use fuel_core_types::{
+    fuel_tx,
+    services::txpool::PoolTransaction,
+    tai64::Tai64,
+};
+use futures::{
+    Stream,
+    StreamExt,
+    TryStreamExt,
+};
+use std::ops::Deref;
+
+pub struct TxPoolStats(TxPoolStatsInfo);
+
+#[Object]
+impl TxPoolStats {
+    /// The number of transactions in the pool.
+    async fn tx_count(&self) -> U64 {
+        (self.0.tx_count as u64).into()
+    }
+
+    /// The total gas of the transactions in the pool.
+    async fn total_gas(&self) -> U64 {
+        self.0.total_gas.into()
+    }
+
+    /// The total size of the transactions in the pool in bytes.
+    async fn total_bytes(&self) -> U64 {
+        (self.0.total_bytes_size as u64).into()
+    }
+
+    /// The maximum number of transactions the pool can store.
+    async fn max_txs(&self) -> U64 {
+        (self.0.max_txs as u64).into()
+    }
+
+    /// The maximum gas the pool can store.
+    async fn max_gas(&self) -> U64 {
+        self.0.max_gas.into()
+    }
+
+    /// The maximum size of the transactions the pool can store in bytes.
+    async fn max_bytes(&self) -> U64 {
+        (self.0.max_bytes_size as u64).into()
+    }
+}
+
+pub struct PendingTransaction(TxInfo);
+
+#[Object]
+impl PendingTransaction {
+    async fn id(&self) -> TransactionId {
+        TransactionId(self.0.tx().id())
+    }
+
+    async fn transaction(&self) -> Transaction {
+        let tx = self.0.tx();
+        Transaction::from_tx(tx.id(), tx.deref().into())
+    }
+
+    async fn tip(&self) -> U64 {
+        self.0.tx().tip().into()
+    }
+
+    async fn max_gas(&self) -> U64 {
+        self.0.tx().max_gas().into()
+    }
+
+    /// The time when the transaction was accepted by the pool.
+    async fn submitted_at(&self) -> Tai64Timestamp {
+        let seconds = self
+            .0
+            .creation_instant()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        Tai64Timestamp(Tai64::from_unix(seconds as i64))
+    }
+
+    /// The length of the longest chain of the transaction's dependencies in the pool.
+    /// The transaction without dependencies in the pool is executable and has a depth of `0`.
+    async fn dependencies_depth(&self) -> U32 {
+        u32::try_from(self.0.dependencies_depth())
+            .unwrap_or(u32::MAX)
+            .into()
+    }
+}
+
+impl From<TxInfo> for PendingTransaction {
+    fn from(info: TxInfo) -> Self {
+        Self(info)
+    }
+}
+
+#[derive(async_graphql::InputObject)]
+struct PendingTransactionsFilterInput {
+    /// Returns transactions that spend inputs owned by the `owner`.
+    owner: Option<Address>,
+    /// Returns transactions that spend or create the `asset_id`.
+    asset_id: Option<AssetId>,
+    /// Returns transactions with the tip greater than or equal to `min_tip`.
+    min_tip: Option<U64>,
+}
+
+impl PendingTransactionsFilterInput {
+    fn matches(&self, tx: &PoolTransaction, base_asset_id: &fuel_tx::AssetId) -> bool {
+        if let Some(min_tip) = &self.min_tip {
+            if tx.tip() < min_tip.0 {
+                return false
+            }
+        }
+        if let Some(owner) = &self.owner {
+            let owned = tx
+                .inputs()
+                .iter()
+                .any(|input| input.input_owner() == Some(&owner.0));
+            if !owned {
+                return false
+            }
+        }
+        if let Some(asset_id) = &self.asset_id {
+            let spent = tx
+                .inputs()
+                .iter()
+                .any(|input| input.asset_id(base_asset_id) == Some(&asset_id.0));
+            let created = tx
+                .outputs()
+                .iter()
+                .any(|output| output.asset_id() == Some(&asset_id.0));
+            if !spent && !created {
+                return false
+            }
+        }
+        true
+    }
+}
+
+pub struct TxPoolBlackList(BlackList);
+
+#[Object]
+impl TxPoolBlackList {
+    /// Blacklisted owners of the inputs.
+    async fn owners(&self) -> Vec<Address> {
+        let mut owners: Vec<_> = self.0.owners.iter().copied().collect();
+        owners.sort();
+        owners.into_iter().map(Into::into).collect()
+    }
+
+    /// Blacklisted coins.
+    async fn coins(&self) -> Vec<UtxoId> {
+        let mut coins: Vec<_> = self.0.coins.iter().copied().collect();
+        coins.sort();
+        coins.into_iter().map(Into::into).collect()
+    }
+
+    /// Blacklisted messages.
+    async fn messages(&self) -> Vec<Nonce> {
+        let mut messages: Vec<_> = self.0.messages.iter().copied().collect();
+        messages.sort();
+        messages.into_iter().map(Into::into).collect()
+    }
+
+    /// Blacklisted contracts.
+    async fn contracts(&self) -> Vec<ContractId> {
+        let mut contracts: Vec<_> = self.0.contracts.iter().copied().collect();
+        contracts.sort();
+        contracts.into_iter().map(Into::into).collect()
+    }
+}
+
+/// The entry of the blacklist. Exactly one field should be set.
+#[derive(async_graphql::InputObject)]
+struct TxPoolBlackListEntryInput {
+    /// Blacklists all inputs owned by the `owner`.
+    owner: Option<Address>,
+    /// Blacklists the coin.
+    coin: Option<UtxoId>,
+    /// Blacklists the message.
+    message: Option<Nonce>,
+    /// Blacklists the contract.
+    contract: Option<ContractId>,
+}
+
+impl TryFrom<TxPoolBlackListEntryInput> for BlackListEntry {
+    type Error = async_graphql::Error;
+
+    fn try_from(input: TxPoolBlackListEntryInput) -> Result<Self, Self::Error> {
+        let TxPoolBlackListEntryInput {
+            owner,
+            coin,
+            message,
+            contract,
+        } = input;
+        let entries = [
+            owner.map(|owner| BlackListEntry::Owner(owner.0)),
+            coin.map(|coin| BlackListEntry::Coin(coin.0)),
+            message.map(|message| BlackListEntry::Message(message.0)),
+            contract.map(|contract| BlackListEntry::Contract(contract.0)),
+        ];
+        let mut entries = entries.into_iter().flatten();
+
+        match (entries.next(), entries.next()) {
+            (Some(entry), None) => Ok(entry),
+            _ => Err(async_graphql::Error::new(
+                "Exactly one field of the blacklist entry should be set",
+            )),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct TxPoolQuery;
+
+#[Object]
+impl TxPoolQuery {
+    /// Returns the current usage of the transaction pool along with its limits.
+    #[graphql(complexity = "query_costs().storage_read + child_complexity")]
+    async fn tx_pool_stats(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<TxPoolStats> {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        Ok(TxPoolStats(txpool.stats().await?))
+    }
+
+    /// Returns the blacklist of the transaction pool.
+    /// Requires the admin role.
+    #[graphql(complexity = "query_costs().storage_read + child_complexity")]
+    async fn tx_pool_blacklist(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<TxPoolBlackList> {
+        let blacklist = ctx.data_unchecked::<TxPoolBlackListManager>();
+        Ok(TxPoolBlackList(blacklist.blacklist().await?))
+    }
+
+    /// Returns the transactions from the pool that are not yet included in the block,
+    /// sorted by their ids.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator\
+        + (query_costs().tx_get + first.unwrap_or_default() as usize) * child_complexity \
+        + (query_costs().tx_get + last.unwrap_or_default() as usize) * child_complexity\
+    }")]
+    async fn pending_transactions(
+        &self,
+        ctx: &Context<'_>,
+        filter: Option<PendingTransactionsFilterInput>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<TransactionId, PendingTransaction, EmptyFields, EmptyFields>,
+    > {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let base_asset_id = *ctx
+            .data_unchecked::<ConsensusProvider>()
+            .latest_consensus_params()
+            .base_asset_id();
+
+        // The page includes the transaction at the cursor and one more transaction
+        // to know whether there is the next page.
+        let max_txs = first
+            .or(last)
+            .and_then(|count| usize::try_from(count).ok())
+            .unwrap_or_default()
+            .saturating_add(2);
+
+        crate::schema::query_pagination(after, before, first, last, |start, direction| {
+            let query = PendingTxsQuery {
+                start: start.as_ref().map(|id| id.0),
+                direction,
+                max_txs,
+                filter: Box::new(move |tx: &PoolTransaction| {
+                    filter
+                        .as_ref()
+                        .map_or(true, |filter| filter.matches(tx, &base_asset_id))
+                }),
+            };
+            let txs = futures::stream::once(txpool.pending_transactions(query))
+                .map_err(StorageError::Other)
+                .map_ok(|txs| {
+                    futures::stream::iter(txs).map(|info| {
+                        let id = TransactionId(info.tx().id());
+                        Ok::<_, StorageError>((id, info.into()))
+                    })
+                })
+                .try_flatten();
+
+            Ok(txs)
+        })
+        .await
+    }
+}
+
+#[derive(Default)]
+pub struct TxPoolMutation;
+
+#[Object]
+impl TxPoolMutation {
+    /// Adds the entry to the blacklist of the transaction pool and evicts
+    /// the transactions that use it along with their dependents.
+    /// Returns the ids of the evicted transactions.
+    /// Requires the admin role.
+    #[graphql(complexity = "query_costs().storage_read + child_complexity")]
+    async fn add_to_tx_pool_blacklist(
+        &self,
+        ctx: &Context<'_>,
+        entry: TxPoolBlackListEntryInput,
+    ) -> async_graphql::Result<Vec<TransactionId>> {
+        let entry = BlackListEntry::try_from(entry)?;
+        let blacklist = ctx.data_unchecked::<TxPoolBlackListManager>();
+        let evicted = blacklist.add(entry).await?;
+        Ok(evicted.into_iter().map(TransactionId).collect())
+    }
+
+    /// Removes the entry from the blacklist of the transaction pool.
+    /// Returns `false` if the entry was not blacklisted.
+    /// Requires the admin role.
+    #[graphql(complexity = "query_costs().storage_read")]
+    async fn remove_from_tx_pool_blacklist(
+        &self,
+        ctx: &Context<'_>,
+        entry: TxPoolBlackListEntryInput,
+    ) -> async_graphql::Result<bool> {
+        let entry = BlackListEntry::try_from(entry)?;
+        let blacklist = ctx.data_unchecked::<TxPoolBlackListManager>();
+        Ok(blacklist.remove(entry).await?)
+    }
+}
+
+#[derive(Default)]
+pub struct TxPoolSubscription;
+
+#[Subscription]
+impl TxPoolSubscription {
+    /// Returns a stream of the transactions accepted by the pool.
+    #[graphql(complexity = "query_costs().status_change + child_complexity")]
+    async fn new_pending_transactions<'a>(
+        &self,
+        ctx: &'a Context<'a>,
+    ) -> async_graphql::Result<
+        impl Stream<Item = async_graphql::Result<PendingTransaction>> + 'a,
+    > {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let tx_ids = txpool.new_pending_transactions_subscribe();
+
+        // The transaction can leave the pool before we request it.
+        Ok(tx_ids.filter_map(move |tx_id| async move {
+            txpool
+                .pending_transaction(tx_id)
+                .await
+                .map_err(async_graphql::Error::from)
+                .transpose()
+                .map(|info| info.map(Into::into))
+        }))
+    }
+}
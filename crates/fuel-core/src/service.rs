@@ -30,6 +30,10 @@ use fuel_core_services::{
     TaskNextAction,
 };
 use fuel_core_storage::{
+    iter::{
+        IterDirection,
+        IteratorOverTable,
+    },
     not_found,
     tables::SealedBlockConsensus,
     transactional::{
@@ -48,6 +52,7 @@ use std::{
 pub use config::{
     Config,
     DbType,
+    GasPriceAlgorithm,
     RelayerConsensusConfig,
     VMConfig,
 };
@@ -58,6 +63,9 @@ pub mod config;
 pub mod genesis;
 pub mod metrics;
 mod query;
+pub mod read_replica;
+#[cfg(feature = "rocksdb")]
+pub mod reindex;
 pub mod sub_services;
 pub mod vm_pool;
 
@@ -123,6 +131,16 @@ impl FuelService {
     {
         let config = config.make_config_consistent();
 
+        if config.is_read_replica() {
+            // The read replica can't modify the databases, they are managed by
+            // the primary node.
+            tracing::info!("Initializing the read replica");
+            database.check_version()?;
+            let (services, shared) =
+                sub_services::init_read_replica_sub_services(&config, database)?;
+            return Self::from_sub_services(services, shared)
+        }
+
         // initialize state
         tracing::info!("Initializing database");
         database.check_version()?;
@@ -137,7 +155,13 @@ impl FuelService {
         tracing::info!("Initializing sub services");
         database.sync_aux_db_heights(shutdown_listener)?;
         let (services, shared) = sub_services::init_sub_services(&config, database)?;
+        Self::from_sub_services(services, shared)
+    }
 
+    fn from_sub_services(
+        services: SubServices,
+        shared: SharedState,
+    ) -> anyhow::Result<Self> {
         let sub_services = Arc::new(services);
         let task = Task::new(sub_services.clone(), shared.clone())?;
         let runner = ServiceRunner::new(task);
@@ -179,6 +203,8 @@ impl FuelService {
         combined_database: CombinedDatabase,
         config: Config,
     ) -> anyhow::Result<Self> {
+        #[cfg(feature = "p2p")]
+        genesis::sync_state_from_peers(&config, &combined_database).await?;
         let mut listener = crate::ShutdownListener::spawn();
         let service = Self::new(combined_database, config, &mut listener)?;
         let state = service.start_and_await().await?;
@@ -225,6 +251,9 @@ impl FuelService {
             ConsensusConfig::PoA { .. } => {
                 // We don't support overriding of the heights for PoA version 1.
             }
+            ConsensusConfig::Bft { .. } => {
+                // The set of validators is fixed, there is nothing to override.
+            }
             ConsensusConfig::PoAV2(poa) => {
                 let on_chain_view = combined_database.on_chain().latest_view()?;
 
@@ -294,8 +323,13 @@ impl FuelService {
                 Updating the genesis consensus parameters."
             );
 
-            let genesis_block_height =
-                on_chain_view.genesis_height()?.ok_or(anyhow::anyhow!(
+            // The genesis block is missing if the state was synced from peers,
+            // so the height is taken from the genesis consensus.
+            let genesis_block_height = on_chain_view
+                .iter_all_keys::<SealedBlockConsensus>(Some(IterDirection::Forward))
+                .next()
+                .transpose()?
+                .ok_or(anyhow::anyhow!(
                     "The genesis block height is not found in the database \
                     during overriding the chain config hash."
                 ))?;
@@ -320,6 +354,11 @@ impl FuelService {
     }
 
     async fn prepare_genesis(&self, watcher: &StateWatcher) -> anyhow::Result<()> {
+        if self.shared.config.is_read_replica() {
+            // The genesis is initialized by the primary node.
+            return Ok(())
+        }
+
         // check if chain is initialized
         if let Err(err) = self.shared.database.on_chain().latest_view()?.get_genesis() {
             if err.is_not_found() {
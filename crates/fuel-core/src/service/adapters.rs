@@ -34,7 +34,10 @@ use std::sync::Arc;
 
 use crate::{
     database::{
-        database_description::relayer::Relayer,
+        database_description::{
+            off_chain::OffChain,
+            relayer::Relayer,
+        },
         Database,
     },
     service::{
@@ -85,6 +88,11 @@ impl StaticGasPrice {
     }
 }
 
+/// Serves the ports of the GraphQL API that require the services not running
+/// on the read replica, like the transaction pool or the block production.
+#[derive(Debug, Clone, Default)]
+pub struct ReadReplicaAdapter;
+
 #[derive(Clone)]
 pub struct PoAAdapter {
     shared_state: Option<fuel_core_poa::service::SharedState>,
@@ -101,6 +109,19 @@ impl TxPoolAdapter {
     }
 }
 
+/// Manages the TxPool blacklist and persists its changes in the off-chain database.
+#[derive(Clone)]
+pub struct TxPoolBlackListAdapter {
+    service: TxPoolSharedState,
+    database: Database<OffChain>,
+}
+
+impl TxPoolBlackListAdapter {
+    pub fn new(service: TxPoolSharedState, database: Database<OffChain>) -> Self {
+        Self { service, database }
+    }
+}
+
 pub struct TransactionsSource {
     tx_pool: BorrowedTxPool,
     minimum_gas_price: u64,
@@ -242,7 +263,7 @@ pub struct P2PAdapter {
 }
 
 #[cfg(feature = "p2p")]
-#[derive(Clone)]
+#[derive(Default, Clone)]
 pub struct PeerReportConfig {
     pub successful_block_import: AppScore,
     pub missing_block_headers: AppScore,
@@ -0,0 +1,138 @@
+use crate::service::adapters::{
+    BlockImporterAdapter,
+    BlockProducerAdapter,
+    ExecutorAdapter,
+    P2PAdapter,
+};
+use anyhow::anyhow;
+use fuel_core_bft::ports::{
+    BlockImporter,
+    BlockProducer,
+    BlockValidator,
+    ConsensusNetwork,
+};
+use fuel_core_services::stream::BoxStream;
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::bft::ConsensusMessage,
+        header::BlockHeader,
+        SealedBlock,
+    },
+    fuel_types::BlockHeight,
+    services::p2p::{
+        ConsensusMessageGossipData,
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
+    },
+    tai64::Tai64,
+};
+use std::sync::Arc;
+use tokio_stream::{
+    wrappers::BroadcastStream,
+    StreamExt,
+};
+
+#[async_trait::async_trait]
+impl BlockProducer for BlockProducerAdapter {
+    async fn produce_block(
+        &self,
+        height: BlockHeight,
+        block_time: Tai64,
+    ) -> anyhow::Result<Block> {
+        let result = self
+            .block_producer
+            .produce_and_execute_block_txpool(height, block_time)
+            .await?;
+        Ok(result.into_result().block)
+    }
+}
+
+#[async_trait::async_trait]
+impl BlockValidator for ExecutorAdapter {
+    async fn validate_block(&self, block: &Block) -> anyhow::Result<()> {
+        let executor = self.executor.clone();
+        let block = block.clone();
+        tokio_rayon::spawn_fifo(move || executor.validate(&block))
+            .await
+            .map_err(|err| anyhow!("The proposed block is invalid: {err}"))?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl BlockImporter for BlockImporterAdapter {
+    async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()> {
+        self.block_importer
+            .execute_and_commit(block)
+            .await
+            .map_err(Into::into)
+    }
+
+    fn block_stream(&self) -> BoxStream<BlockHeader> {
+        Box::pin(
+            BroadcastStream::new(self.block_importer.subscribe())
+                .filter_map(|result| result.ok())
+                .map(|result| result.sealed_block.entity.header().clone()),
+        )
+    }
+}
+
+#[cfg(feature = "p2p")]
+impl ConsensusNetwork for P2PAdapter {
+    fn broadcast_consensus_message(
+        &self,
+        message: Arc<ConsensusMessage>,
+    ) -> anyhow::Result<()> {
+        if let Some(service) = &self.service {
+            service.broadcast_consensus_message(message)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn notify_gossip_consensus_message_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        if let Some(service) = &self.service {
+            service.notify_gossip_consensus_message_validity(message_info, validity)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn gossiped_consensus_messages(&self) -> BoxStream<ConsensusMessageGossipData> {
+        if let Some(service) = &self.service {
+            Box::pin(
+                BroadcastStream::new(service.subscribe_consensus_messages())
+                    .filter_map(|result| result.ok()),
+            )
+        } else {
+            Box::pin(tokio_stream::pending())
+        }
+    }
+}
+
+#[cfg(not(feature = "p2p"))]
+impl ConsensusNetwork for P2PAdapter {
+    fn broadcast_consensus_message(
+        &self,
+        _: Arc<ConsensusMessage>,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn notify_gossip_consensus_message_validity(
+        &self,
+        _: GossipsubMessageInfo,
+        _: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn gossiped_consensus_messages(&self) -> BoxStream<ConsensusMessageGossipData> {
+        Box::pin(tokio_stream::pending())
+    }
+}
@@ -55,7 +55,7 @@ impl Debug for Task {
 }
 
 impl SharedState {
-    fn new(database: Database) -> Self {
+    pub(crate) fn new(database: Database) -> Self {
         let genesis_version = 0;
         Self {
             latest_consensus_parameters_version: SharedMutex::new(genesis_version),
@@ -96,6 +96,19 @@ impl SharedState {
         self.cache_consensus_parameters(*version)
     }
 
+    /// Caches the latest consensus parameters from the database. It is used when
+    /// the database is updated without the block importer, e.g. by the read replica.
+    pub(crate) fn refresh_latest_consensus_parameters(&self) -> StorageResult<()> {
+        let latest_consensus_parameters_version = self
+            .database
+            .latest_view()?
+            .latest_consensus_parameters_version()?;
+        self.cache_consensus_parameters(latest_consensus_parameters_version)?;
+        *self.latest_consensus_parameters_version.lock() =
+            latest_consensus_parameters_version;
+        Ok(())
+    }
+
     pub fn latest_consensus_parameters(&self) -> Arc<ConsensusParameters> {
         self.latest_consensus_parameters_with_version().1
     }
@@ -166,15 +179,7 @@ impl RunnableService for Task {
         _: &StateWatcher,
         _: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
-        let latest_consensus_parameters_version = self
-            .shared_state
-            .database
-            .latest_view()?
-            .latest_consensus_parameters_version()?;
-        self.shared_state
-            .cache_consensus_parameters(latest_consensus_parameters_version)?;
-        *self.shared_state.latest_consensus_parameters_version.lock() =
-            latest_consensus_parameters_version;
+        self.shared_state.refresh_latest_consensus_parameters()?;
 
         Ok(self)
     }
@@ -1,7 +1,11 @@
 use crate::fuel_core_graphql_api::ports::GasPriceEstimate as GraphqlGasPriceEstimate;
-use fuel_core_gas_price_service::common::gas_price_algorithm::{
-    GasPriceAlgorithm,
-    SharedGasPriceAlgo,
+use fuel_core_gas_price_service::{
+    common::gas_price_algorithm::{
+        GasPriceAlgorithm,
+        SharedGasPriceAlgo,
+    },
+    v0::uninitialized_task::AlgorithmV0,
+    v1::uninitialized_task::AlgorithmV1,
 };
 
 use fuel_core_producer::block_producer::gas_price::GasPriceProvider as ProducerGasPriceProvider;
@@ -84,3 +88,43 @@ where
         Some(self.algorithm.worst_case_gas_price(height).await)
     }
 }
+
+/// Dispatches the requests to the provider of the gas price algorithm
+/// selected by the node configuration.
+#[derive(Debug, Clone)]
+pub enum VersionedGasPriceProvider {
+    V0(FuelGasPriceProvider<AlgorithmV0>),
+    V1(FuelGasPriceProvider<AlgorithmV1>),
+}
+
+impl VersionedGasPriceProvider {
+    fn next_gas_price(&self) -> u64 {
+        match self {
+            Self::V0(provider) => provider.next_gas_price(),
+            Self::V1(provider) => provider.next_gas_price(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ProducerGasPriceProvider for VersionedGasPriceProvider {
+    async fn next_gas_price(&self) -> anyhow::Result<u64> {
+        Ok(self.next_gas_price())
+    }
+}
+
+impl TxPoolGasPriceProvider for VersionedGasPriceProvider {
+    fn next_gas_price(&self) -> u64 {
+        self.next_gas_price()
+    }
+}
+
+#[async_trait::async_trait]
+impl GraphqlGasPriceEstimate for VersionedGasPriceProvider {
+    async fn worst_case_gas_price(&self, height: BlockHeight) -> Option<u64> {
+        match self {
+            Self::V0(provider) => provider.worst_case_gas_price(height).await,
+            Self::V1(provider) => provider.worst_case_gas_price(height).await,
+        }
+    }
+}
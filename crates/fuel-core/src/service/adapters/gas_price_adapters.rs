@@ -18,6 +18,7 @@ use fuel_core_gas_price_service::{
         GasPriceServiceConfig,
         L2Data,
     },
+    v1::metadata::V1AlgorithmConfig,
 };
 use fuel_core_storage::{
     transactional::HistoricalView,
@@ -37,7 +38,10 @@ use crate::{
         database_description::gas_price::GasPriceDatabase,
         Database,
     },
-    service::Config,
+    service::{
+        config::GasPriceAlgorithm,
+        Config,
+    },
 };
 
 #[cfg(test)]
@@ -64,12 +68,49 @@ impl GasPriceData for Database<GasPriceDatabase> {
 
 impl From<Config> for GasPriceServiceConfig {
     fn from(value: Config) -> Self {
-        GasPriceServiceConfig::new_v0(
-            value.starting_gas_price,
-            value.min_gas_price,
-            value.gas_price_change_percent,
-            value.gas_price_threshold_percent,
-        )
+        match value.gas_price_algorithm {
+            GasPriceAlgorithm::V0 => GasPriceServiceConfig::new_v0(
+                value.starting_gas_price,
+                value.min_gas_price,
+                value.gas_price_change_percent,
+                value.gas_price_threshold_percent,
+            ),
+            GasPriceAlgorithm::V1 => GasPriceServiceConfig::new_v1(value.into()),
+        }
+    }
+}
+
+impl From<Config> for V1AlgorithmConfig {
+    fn from(value: Config) -> Self {
+        // The activity tracking is not configurable yet, so the DA gas price
+        // always stays in the normal mode.
+        let normal_range_size = 100;
+        let capped_range_size = 0;
+        let decrease_range_size = 0;
+        let block_activity_threshold = 0;
+
+        V1AlgorithmConfig {
+            new_exec_gas_price: value.starting_gas_price,
+            min_exec_gas_price: value.min_gas_price,
+            exec_gas_price_change_percent: value
+                .gas_price_change_percent
+                .try_into()
+                .unwrap_or(u16::MAX),
+            l2_block_fullness_threshold_percent: value
+                .gas_price_threshold_percent
+                .try_into()
+                .unwrap_or(u8::MAX),
+            gas_price_factor: value.gas_price_factor,
+            min_da_gas_price: value.min_da_gas_price,
+            max_da_gas_price_change_percent: value.max_da_gas_price_change_percent,
+            da_p_component: value.da_p_component,
+            da_d_component: value.da_d_component,
+            normal_range_size,
+            capped_range_size,
+            decrease_range_size,
+            block_activity_threshold,
+            da_poll_interval: value.da_poll_interval,
+        }
     }
 }
 
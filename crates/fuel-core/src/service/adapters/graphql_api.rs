@@ -2,35 +2,83 @@ use super::{
     BlockImporterAdapter,
     BlockProducerAdapter,
     ConsensusParametersProvider,
+    ReadReplicaAdapter,
     SharedMemoryPool,
     StaticGasPrice,
 };
 use crate::{
-    database::OnChainIterableKeyValueView,
+    combined_database::{
+        checkpoint::CheckpointManifest,
+        CombinedDatabase,
+    },
+    database::{
+        database_description::{
+            gas_price::GasPriceDatabase,
+            off_chain::OffChain,
+        },
+        Database,
+        OnChainIterableKeyValueView,
+    },
     fuel_core_graphql_api::ports::{
         worker,
         worker::BlockAt,
+        BlockImporterPort,
         BlockProducerPort,
+        ConsensusModulePort,
         ConsensusProvider,
+        DatabaseCheckpointPort,
         DatabaseMessageProof,
         GasPriceEstimate,
+        GasPriceHistoryPort,
         P2pPort,
+        TxPoolBlackListPort,
         TxPoolPort,
     },
-    graphql_api::ports::MemoryPool,
+    graphql_api::{
+        ports::MemoryPool,
+        storage::tx_pool_blacklist::TxPoolBlackList,
+    },
     service::{
         adapters::{
             import_result_provider::ImportResultProvider,
             P2PAdapter,
             TxPoolAdapter,
+            TxPoolBlackListAdapter,
         },
         vm_pool::MemoryFromPool,
     },
 };
 use async_trait::async_trait;
-use fuel_core_services::stream::BoxStream;
-use fuel_core_storage::Result as StorageResult;
-use fuel_core_txpool::TxStatusMessage;
+use fuel_core_gas_price_service::common::{
+    fuel_core_storage_adapter::storage::GasPriceHistory,
+    gas_price_history::GasPriceHistoryEntry,
+};
+use fuel_core_services::stream::{
+    BoxStream,
+    IntoBoxStream,
+};
+use fuel_core_storage::{
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IterDirection,
+        IteratorOverTable,
+    },
+    transactional::ReadTransaction,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+};
+use fuel_core_txpool::{
+    config::{
+        BlackList,
+        BlackListEntry,
+    },
+    PendingTxsQuery,
+    TxInfo,
+    TxPoolStats,
+    TxStatusMessage,
+};
 use fuel_core_types::{
     blockchain::header::ConsensusParametersVersion,
     entities::relayer::message::MerkleProof,
@@ -51,7 +99,9 @@ use fuel_core_types::{
 };
 use std::{
     ops::Deref,
+    path::PathBuf,
     sync::Arc,
+    time::Duration,
 };
 
 mod off_chain;
@@ -97,6 +147,75 @@ impl TxPoolPort for TxPoolAdapter {
     ) -> anyhow::Result<BoxStream<TxStatusMessage>> {
         self.service.tx_update_subscribe(id)
     }
+
+    async fn stats(&self) -> anyhow::Result<TxPoolStats> {
+        self.service.stats().await.map_err(|e| anyhow::anyhow!(e))
+    }
+
+    async fn pending_transactions(
+        &self,
+        query: PendingTxsQuery,
+    ) -> anyhow::Result<Vec<TxInfo>> {
+        self.service
+            .pending_txs(query)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    async fn pending_transaction(&self, id: TxId) -> anyhow::Result<Option<TxInfo>> {
+        self.service
+            .find_one(id)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    fn new_pending_transactions_subscribe(&self) -> BoxStream<TxId> {
+        use futures::StreamExt;
+        fuel_core_services::stream::IntoBoxStream::into_boxed(
+            tokio_stream::wrappers::BroadcastStream::new(
+                self.service.new_tx_notification_subscribe(),
+            )
+            .filter_map(|r| futures::future::ready(r.ok())),
+        )
+    }
+}
+
+#[async_trait]
+impl TxPoolBlackListPort for TxPoolBlackListAdapter {
+    async fn blacklist(&self) -> anyhow::Result<BlackList> {
+        self.service
+            .blacklist()
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    async fn add(&self, entry: BlackListEntry) -> anyhow::Result<Vec<TxId>> {
+        let mut transaction = self.database.read_transaction();
+        transaction
+            .storage_as_mut::<TxPoolBlackList>()
+            .insert(&entry, &())?;
+        self.database
+            .commit_changes_without_height(transaction.into_changes())?;
+
+        self.service
+            .add_to_blacklist(entry)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    async fn remove(&self, entry: BlackListEntry) -> anyhow::Result<bool> {
+        let mut transaction = self.database.read_transaction();
+        transaction
+            .storage_as_mut::<TxPoolBlackList>()
+            .remove(&entry)?;
+        self.database
+            .commit_changes_without_height(transaction.into_changes())?;
+
+        self.service
+            .remove_from_blacklist(entry)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
 }
 
 impl DatabaseMessageProof for OnChainIterableKeyValueView {
@@ -181,6 +300,50 @@ impl GasPriceEstimate for StaticGasPrice {
     }
 }
 
+impl GasPriceHistoryPort for Database<GasPriceDatabase> {
+    fn gas_price_history(
+        &self,
+        height: BlockHeight,
+    ) -> BoxedIter<'_, StorageResult<GasPriceHistoryEntry>> {
+        self.iter_all_by_start::<GasPriceHistory>(
+            Some(&height),
+            Some(IterDirection::Forward),
+        )
+        .map(|result| result.map(|(_, entry)| entry))
+        .into_boxed()
+    }
+
+    fn gas_price_history_at(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<GasPriceHistoryEntry>> {
+        let entry = self
+            .storage_as_ref::<GasPriceHistory>()
+            .get(height)?
+            .map(|entry| entry.into_owned());
+        Ok(entry)
+    }
+}
+
+/// The time given to the off-chain and gas price databases to process
+/// the block of the on-chain checkpoint before their checkpoints are created.
+/// The node keeps importing blocks meanwhile.
+const CHECKPOINT_SYNC_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[async_trait]
+impl DatabaseCheckpointPort for CombinedDatabase {
+    async fn create_checkpoint(
+        &self,
+        path: PathBuf,
+    ) -> anyhow::Result<CheckpointManifest> {
+        let database = self.clone();
+        tokio::task::spawn_blocking(move || {
+            database.create_checkpoint(&path, CHECKPOINT_SYNC_TIMEOUT)
+        })
+        .await?
+    }
+}
+
 impl ConsensusProvider for ConsensusParametersProvider {
     fn latest_consensus_params(&self) -> Arc<ConsensusParameters> {
         self.shared_state.latest_consensus_parameters()
@@ -212,6 +375,12 @@ impl GraphQLBlockImporter {
     }
 }
 
+impl BlockImporterPort for BlockImporterAdapter {
+    fn block_events(&self) -> BoxStream<SharedImportResult> {
+        self.events_shared_result()
+    }
+}
+
 impl worker::BlockImporter for GraphQLBlockImporter {
     fn block_events(&self) -> BoxStream<SharedImportResult> {
         self.block_importer_adapter.events_shared_result()
@@ -225,6 +394,121 @@ impl worker::BlockImporter for GraphQLBlockImporter {
     }
 }
 
+fn not_available_on_read_replica() -> anyhow::Error {
+    anyhow::anyhow!("The operation is not available on the read replica")
+}
+
+/// The read replica doesn't run the transaction pool, so it knows only
+/// the transactions included into the blocks.
+#[async_trait]
+impl TxPoolPort for ReadReplicaAdapter {
+    async fn transaction(&self, _: TxId) -> anyhow::Result<Option<Transaction>> {
+        Ok(None)
+    }
+
+    async fn submission_time(&self, _: TxId) -> anyhow::Result<Option<Tai64>> {
+        Ok(None)
+    }
+
+    async fn insert(&self, _: Transaction) -> anyhow::Result<()> {
+        Err(not_available_on_read_replica())
+    }
+
+    fn tx_update_subscribe(&self, _: TxId) -> anyhow::Result<BoxStream<TxStatusMessage>> {
+        // The status from the database is sent before the updates, so the
+        // subscription returns the status of the included transactions.
+        Ok(futures::stream::empty().into_boxed())
+    }
+
+    async fn stats(&self) -> anyhow::Result<TxPoolStats> {
+        Err(not_available_on_read_replica())
+    }
+
+    async fn pending_transactions(
+        &self,
+        _: PendingTxsQuery,
+    ) -> anyhow::Result<Vec<TxInfo>> {
+        Err(not_available_on_read_replica())
+    }
+
+    async fn pending_transaction(&self, _: TxId) -> anyhow::Result<Option<TxInfo>> {
+        Ok(None)
+    }
+
+    fn new_pending_transactions_subscribe(&self) -> BoxStream<TxId> {
+        futures::stream::empty().into_boxed()
+    }
+}
+
+#[async_trait]
+impl TxPoolBlackListPort for ReadReplicaAdapter {
+    async fn blacklist(&self) -> anyhow::Result<BlackList> {
+        Err(not_available_on_read_replica())
+    }
+
+    async fn add(&self, _: BlackListEntry) -> anyhow::Result<Vec<TxId>> {
+        Err(not_available_on_read_replica())
+    }
+
+    async fn remove(&self, _: BlackListEntry) -> anyhow::Result<bool> {
+        Err(not_available_on_read_replica())
+    }
+}
+
+#[async_trait]
+impl BlockProducerPort for ReadReplicaAdapter {
+    async fn dry_run_txs(
+        &self,
+        _: Vec<Transaction>,
+        _: Option<BlockHeight>,
+        _: Option<Tai64>,
+        _: Option<bool>,
+        _: Option<u64>,
+    ) -> anyhow::Result<Vec<TransactionExecutionStatus>> {
+        Err(not_available_on_read_replica())
+    }
+}
+
+#[async_trait]
+impl ConsensusModulePort for ReadReplicaAdapter {
+    async fn manually_produce_blocks(
+        &self,
+        _: Option<Tai64>,
+        _: u32,
+    ) -> anyhow::Result<()> {
+        Err(not_available_on_read_replica())
+    }
+}
+
+impl BlockImporterPort for ReadReplicaAdapter {
+    fn block_events(&self) -> BoxStream<SharedImportResult> {
+        futures::stream::empty().into_boxed()
+    }
+}
+
+#[async_trait]
+impl P2pPort for ReadReplicaAdapter {
+    async fn all_peer_info(&self) -> anyhow::Result<Vec<PeerInfo>> {
+        Ok(vec![])
+    }
+}
+
+#[async_trait]
+impl GasPriceEstimate for ReadReplicaAdapter {
+    async fn worst_case_gas_price(&self, _: BlockHeight) -> Option<u64> {
+        // The estimation requires the state of the gas price algorithm,
+        // which is only maintained by the primary node.
+        None
+    }
+}
+
+#[async_trait]
+impl DatabaseCheckpointPort for ReadReplicaAdapter {
+    async fn create_checkpoint(&self, _: PathBuf) -> anyhow::Result<CheckpointManifest> {
+        Err(not_available_on_read_replica())
+    }
+}
+
 #[async_trait::async_trait]
 impl MemoryPool for SharedMemoryPool {
     type Memory = MemoryFromPool;
@@ -28,6 +28,7 @@ use fuel_core_storage::{
     },
     Error as StorageError,
     Result as StorageResult,
+    SparseMerkleProof,
     StorageAsRef,
 };
 use fuel_core_types::{
@@ -39,6 +40,7 @@ use fuel_core_types::{
     entities::relayer::message::Message,
     fuel_tx::{
         AssetId,
+        Bytes32,
         ContractId,
         Transaction,
         TxId,
@@ -124,6 +126,70 @@ impl DatabaseContracts for OnChainIterableKeyValueView {
             .map(|res| res.map_err(StorageError::from))
             .into_boxed()
     }
+
+    fn contract_storage_slots(
+        &self,
+        contract: ContractId,
+        start_slot: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(Bytes32, Vec<u8>)>> {
+        self.filter_contract_storage_slots(contract, start_slot, Some(direction))
+            .map_ok(|entry| (*entry.key.state_key(), entry.value.0))
+            .map(|res| res.map_err(StorageError::from))
+            .into_boxed()
+    }
+
+    #[cfg(feature = "smt")]
+    fn contract_state_proof(
+        &self,
+        contract: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<SparseMerkleProof> {
+        use fuel_core_storage::{
+            tables::ContractsState,
+            SparseMerkleProofStorage,
+        };
+
+        <Self as SparseMerkleProofStorage<ContractsState>>::proof(
+            self,
+            &(&contract, &key).into(),
+        )
+    }
+
+    #[cfg(not(feature = "smt"))]
+    fn contract_state_proof(
+        &self,
+        _: ContractId,
+        _: Bytes32,
+    ) -> StorageResult<SparseMerkleProof> {
+        Err(anyhow::anyhow!("The node is built without the `smt` feature").into())
+    }
+
+    #[cfg(feature = "smt")]
+    fn contract_balance_proof(
+        &self,
+        contract: ContractId,
+        asset: AssetId,
+    ) -> StorageResult<SparseMerkleProof> {
+        use fuel_core_storage::{
+            tables::ContractsAssets,
+            SparseMerkleProofStorage,
+        };
+
+        <Self as SparseMerkleProofStorage<ContractsAssets>>::proof(
+            self,
+            &(&contract, &asset).into(),
+        )
+    }
+
+    #[cfg(not(feature = "smt"))]
+    fn contract_balance_proof(
+        &self,
+        _: ContractId,
+        _: AssetId,
+    ) -> StorageResult<SparseMerkleProof> {
+        Err(anyhow::anyhow!("The node is built without the `smt` feature").into())
+    }
 }
 
 impl DatabaseChain for OnChainIterableKeyValueView {
@@ -3,13 +3,42 @@ use super::{
     TxPoolAdapter,
 };
 use crate::database::OnChainIterableKeyValueView;
-use fuel_core_p2p::ports::{
-    BlockHeightImporter,
-    P2pDb,
-    TxPool,
+use fuel_core_chain_config::TableEntry;
+use fuel_core_p2p::{
+    ports::{
+        BlockHeightImporter,
+        P2pDb,
+        TxPool,
+    },
+    request_response::messages::StateGroup,
 };
 use fuel_core_services::stream::BoxStream;
-use fuel_core_storage::Result as StorageResult;
+use fuel_core_storage::{
+    iter::{
+        IterDirection,
+        IterableTable,
+        IteratorOverTable,
+    },
+    kv_store::StorageColumn,
+    structured_storage::TableWithBlueprint,
+    tables::{
+        merkle::{
+            FuelBlockMerkleData,
+            FuelBlockMerkleMetadata,
+        },
+        Coins,
+        ContractsAssets,
+        ContractsLatestUtxo,
+        ContractsRawCode,
+        ContractsState,
+        Messages,
+        ProcessedTransactions,
+    },
+    Error as StorageError,
+    Result as StorageResult,
+    StorageAsRef,
+    StorageInspect,
+};
 use fuel_core_types::{
     blockchain::{
         consensus::Genesis,
@@ -17,12 +46,23 @@ use fuel_core_types::{
     },
     fuel_tx::TxId,
     fuel_types::BlockHeight,
+    fuel_vm::BlobData,
     services::p2p::{
         NetworkableTransactionPool,
         Transactions,
     },
 };
-use std::ops::Range;
+use std::{
+    borrow::Borrow,
+    ops::Range,
+};
+
+/// The maximum number of entries in one group of the state served to peers.
+const STATE_GROUP_SIZE: usize = 1000;
+
+/// The maximum size of the encoded entries in one group of the state.
+/// The group contains at least one entry, even if it exceeds the limit.
+const STATE_GROUP_MAX_BYTES: usize = 8 * 1024 * 1024;
 
 impl P2pDb for OnChainIterableKeyValueView {
     fn get_sealed_headers(
@@ -42,6 +82,92 @@ impl P2pDb for OnChainIterableKeyValueView {
     fn get_genesis(&self) -> StorageResult<Genesis> {
         self.get_genesis()
     }
+
+    fn get_state_group(
+        &self,
+        table: &str,
+        after_key: Option<&[u8]>,
+    ) -> StorageResult<Option<StateGroup>> {
+        macro_rules! state_group {
+            ($($table_type: ty),*) => {
+                $(
+                    if table == <$table_type as TableWithBlueprint>::column().name() {
+                        return state_group::<$table_type>(self, after_key).map(Some)
+                    }
+                )*
+            };
+        }
+
+        // The same on-chain tables that are imported from the snapshot during regenesis.
+        state_group!(
+            Coins,
+            Messages,
+            BlobData,
+            ContractsRawCode,
+            ContractsLatestUtxo,
+            ContractsState,
+            ContractsAssets,
+            ProcessedTransactions,
+            FuelBlockMerkleData,
+            FuelBlockMerkleMetadata
+        );
+
+        Ok(None)
+    }
+}
+
+/// Returns the group of entries that follow the `after_key` in the table.
+/// The group is built with one seek, so the cost of the request doesn't depend
+/// on the position of the group inside the table.
+fn state_group<T>(
+    view: &OnChainIterableKeyValueView,
+    after_key: Option<&[u8]>,
+) -> StorageResult<StateGroup>
+where
+    T: TableWithBlueprint,
+    T::OwnedKey: serde::de::DeserializeOwned,
+    TableEntry<T>: serde::Serialize,
+    OnChainIterableKeyValueView:
+        IterableTable<T> + StorageInspect<T, Error = StorageError>,
+{
+    let after_key = after_key
+        .map(|key| {
+            postcard::from_bytes::<T::OwnedKey>(key)
+                .map_err(|e| StorageError::Codec(anyhow::anyhow!(e)))
+        })
+        .transpose()?;
+    if let Some(key) = &after_key {
+        // Peers continue from the last entry of the previous group,
+        // the requests for other keys are rejected.
+        if !view.storage::<T>().contains_key(key.borrow())? {
+            return Err(StorageError::Other(anyhow::anyhow!(
+                "The key of the previous group is not a part of the table"
+            )))
+        }
+    }
+
+    let entries = view
+        .iter_all_by_start::<T>(
+            after_key.as_ref().map(|key| key.borrow()),
+            Some(IterDirection::Forward),
+        )
+        // The iteration starts from the existing `after_key`.
+        .skip(usize::from(after_key.is_some()))
+        .take(STATE_GROUP_SIZE);
+
+    let mut group = StateGroup::new();
+    let mut size = 0usize;
+    for result in entries {
+        let (key, value) = result?;
+        let entry = postcard::to_allocvec(&TableEntry::<T> { key, value })
+            .map_err(|e| StorageError::Codec(anyhow::anyhow!(e)))?;
+        size = size.saturating_add(entry.len());
+        if size > STATE_GROUP_MAX_BYTES && !group.is_empty() {
+            break
+        }
+        group.push(entry);
+    }
+    Ok(group)
 }
 
 impl BlockHeightImporter for BlockImporterAdapter {
@@ -18,17 +18,26 @@ use fuel_core_types::{
         SealedBlockHeader,
     },
     fuel_types::BlockHeight,
-    services::p2p::{
-        peer_reputation::{
-            AppScore,
-            PeerReport,
+    services::{
+        block_importer::Source,
+        p2p::{
+            peer_reputation::{
+                AppScore,
+                PeerReport,
+            },
+            BlockGossipData,
+            GossipsubMessageAcceptance,
+            GossipsubMessageInfo,
+            PeerId,
+            SourcePeer,
+            Transactions,
         },
-        PeerId,
-        SourcePeer,
-        Transactions,
     },
 };
-use std::ops::Range;
+use std::{
+    ops::Range,
+    sync::Arc,
+};
 
 #[async_trait::async_trait]
 impl PeerToPeerPort for P2PAdapter {
@@ -109,6 +118,38 @@ impl PeerToPeerPort for P2PAdapter {
             Err(anyhow::anyhow!("No P2P service available"))
         }
     }
+
+    fn gossiped_block_stream(&self) -> BoxStream<BlockGossipData> {
+        use futures::StreamExt;
+        if let Some(service) = &self.service {
+            fuel_core_services::stream::IntoBoxStream::into_boxed(
+                tokio_stream::wrappers::BroadcastStream::new(service.subscribe_blocks())
+                    .filter_map(|r| futures::future::ready(r.ok())),
+            )
+        } else {
+            fuel_core_services::stream::IntoBoxStream::into_boxed(tokio_stream::pending())
+        }
+    }
+
+    fn notify_gossip_block_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        if let Some(service) = &self.service {
+            service.notify_gossip_block_validity(message_info, validity)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn broadcast_block(&self, block: Arc<SealedBlock>) -> anyhow::Result<()> {
+        if let Some(service) = &self.service {
+            service.broadcast_block(block)
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl P2PAdapter {
@@ -155,6 +196,20 @@ impl BlockImporterPort for BlockImporterAdapter {
                 }),
         )
     }
+
+    fn locally_produced_block_stream(&self) -> BoxStream<Arc<SealedBlock>> {
+        use futures::StreamExt;
+        fuel_core_services::stream::IntoBoxStream::into_boxed(
+            tokio_stream::wrappers::BroadcastStream::new(self.block_importer.subscribe())
+                .filter_map(|r| {
+                    futures::future::ready(r.ok().and_then(|r| {
+                        (r.source == Source::Local)
+                            .then(|| Arc::new(r.sealed_block.clone()))
+                    }))
+                }),
+        )
+    }
+
     async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()> {
         self.execute_and_commit(block).await
     }
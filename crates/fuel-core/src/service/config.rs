@@ -1,4 +1,5 @@
 use std::{
+    num::NonZeroU64,
     path::PathBuf,
     time::Duration,
 };
@@ -61,6 +62,16 @@ pub struct Config {
     pub gas_price_change_percent: u64,
     pub min_gas_price: u64,
     pub gas_price_threshold_percent: u64,
+    pub gas_price_algorithm: GasPriceAlgorithm,
+    // The settings below are used only by the `V1` gas price algorithm.
+    pub gas_price_factor: NonZeroU64,
+    pub min_da_gas_price: u64,
+    pub max_da_gas_price_change_percent: u16,
+    pub da_p_component: i64,
+    pub da_d_component: i64,
+    /// The URL of the block committer API that provides the costs of the DA commitments.
+    pub da_committer_url: Option<String>,
+    pub da_poll_interval: Option<Duration>,
     pub da_compression: DaCompressionConfig,
     pub block_importer: fuel_core_importer::Config,
     #[cfg(feature = "relayer")]
@@ -69,7 +80,13 @@ pub struct Config {
     pub p2p: Option<P2PConfig<NotInitialized>>,
     #[cfg(feature = "p2p")]
     pub sync: fuel_core_sync::Config,
+    /// When set, the empty database is initialized from the state of peers
+    /// instead of the genesis state from the snapshot.
+    #[cfg(feature = "p2p")]
+    pub state_sync: Option<StateSyncConfig>,
     pub consensus_signer: SignMode,
+    /// The timeouts of the BFT consensus steps, used only by the `Bft` consensus.
+    pub bft_timeouts: fuel_core_bft::Timeouts,
     pub name: String,
     pub relayer_consensus_config: fuel_core_consensus_module::RelayerConsensusConfig,
     /// The number of reserved peers to connect to before starting to sync.
@@ -81,6 +98,11 @@ pub struct Config {
 }
 
 impl Config {
+    /// The default maximum percentage change of the DA gas price per block.
+    pub const DEFAULT_MAX_DA_GAS_PRICE_CHANGE_PERCENT: u16 = 10;
+    /// The default proportional gain of the DA gas price controller.
+    pub const DEFAULT_DA_P_COMPONENT: i64 = 4;
+
     #[cfg(feature = "test-helpers")]
     pub fn local_node() -> Self {
         Self::local_node_with_state_config(StateConfig::local_testnet())
@@ -128,11 +150,13 @@ impl Config {
                 crate::state::historical_rocksdb::StateRewindPolicy::RewindFullRange,
             #[cfg(feature = "rocksdb")]
             max_fds: 512,
+            read_replica: None,
         };
         let starting_gas_price = 0;
         let gas_price_change_percent = 0;
         let min_gas_price = 0;
         let gas_price_threshold_percent = 50;
+        let gas_price_factor = NonZeroU64::new(100).expect("100 is not zero");
 
         Self {
             graphql_config: GraphQLConfig {
@@ -148,9 +172,16 @@ impl Config {
                 max_queries_resolver_recursive_depth: 1,
                 max_queries_directives: 10,
                 max_concurrent_queries: 1024,
+                max_subscriptions_per_connection: 32,
                 request_body_bytes_limit: 16 * 1024 * 1024,
                 query_log_threshold_time: Duration::from_secs(2),
                 api_request_timeout: Duration::from_secs(60),
+                admin_api_token: None,
+                api_keys: Default::default(),
+                jwt_secret: None,
+                anonymous_role: crate::graphql_api::Role::Admin,
+                cors_allowed_origins: vec![],
+                admin_addr: None,
                 costs: Default::default(),
             },
             combined_db_config,
@@ -175,6 +206,15 @@ impl Config {
             gas_price_change_percent,
             min_gas_price,
             gas_price_threshold_percent,
+            gas_price_algorithm: GasPriceAlgorithm::V0,
+            gas_price_factor,
+            min_da_gas_price: 0,
+            max_da_gas_price_change_percent:
+                Self::DEFAULT_MAX_DA_GAS_PRICE_CHANGE_PERCENT,
+            da_p_component: Self::DEFAULT_DA_P_COMPONENT,
+            da_d_component: 0,
+            da_committer_url: None,
+            da_poll_interval: None,
             block_importer,
             #[cfg(feature = "relayer")]
             relayer: None,
@@ -182,9 +222,12 @@ impl Config {
             p2p: Some(P2PConfig::<NotInitialized>::default("test_network")),
             #[cfg(feature = "p2p")]
             sync: fuel_core_sync::Config::default(),
+            #[cfg(feature = "p2p")]
+            state_sync: None,
             consensus_signer: SignMode::Key(fuel_core_types::secrecy::Secret::new(
                 fuel_core_chain_config::default_consensus_dev_key().into(),
             )),
+            bft_timeouts: Default::default(),
             name: String::default(),
             relayer_consensus_config: Default::default(),
             min_connected_reserved_peers: 0,
@@ -193,6 +236,12 @@ impl Config {
         }
     }
 
+    /// Returns `true` if the node follows the databases of another node
+    /// and serves only the GraphQL API.
+    pub fn is_read_replica(&self) -> bool {
+        self.combined_db_config.read_replica.is_some()
+    }
+
     // TODO: Rework our configs system to avoid nesting of the same configs.
     pub fn make_config_consistent(mut self) -> Config {
         if !self.debug && !self.utxo_validation {
@@ -233,6 +282,26 @@ pub struct VMConfig {
     pub backtrace: bool,
 }
 
+#[cfg(feature = "p2p")]
+#[derive(Clone, Debug)]
+pub struct StateSyncConfig {
+    /// The number of blocks behind the tip of the best peer at which the state is
+    /// requested. The state must still be available in the history of the peer.
+    pub blocks_behind_tip: u32,
+    /// The time to wait for peers to report their block heights.
+    pub peers_timeout: Duration,
+}
+
+#[cfg(feature = "p2p")]
+impl Default for StateSyncConfig {
+    fn default() -> Self {
+        Self {
+            blocks_behind_tip: 10,
+            peers_timeout: Duration::from_secs(60),
+        }
+    }
+}
+
 #[derive(
     Clone, Debug, Display, Eq, PartialEq, EnumString, EnumVariantNames, ValueEnum,
 )]
@@ -241,3 +310,14 @@ pub enum DbType {
     InMemory,
     RocksDb,
 }
+
+#[derive(
+    Clone, Copy, Debug, Display, Eq, PartialEq, EnumString, EnumVariantNames, ValueEnum,
+)]
+#[strum(serialize_all = "kebab_case")]
+pub enum GasPriceAlgorithm {
+    /// Adjusts the gas price only based on the fullness of L2 blocks.
+    V0,
+    /// Additionally covers the costs of the DA commitments reported by the block committer.
+    V1,
+}
@@ -61,12 +61,16 @@ use fuel_core_types::{
 use itertools::Itertools;
 
 pub use exporter::Exporter;
+#[cfg(feature = "p2p")]
+pub use state_sync::sync_state_from_peers;
 pub use task_manager::NotifyCancel;
 
 mod exporter;
 mod importer;
-mod progress;
-mod task_manager;
+pub(crate) mod progress;
+#[cfg(feature = "p2p")]
+mod state_sync;
+pub(crate) mod task_manager;
 
 /// Performs the importing of the genesis block from the snapshot.
 pub async fn execute_genesis_block(
@@ -0,0 +1,600 @@
+//! # State sync
+//! Initializes the empty database from the state of a peer at a recent height
+//! instead of importing the genesis state and re-executing the whole history.
+//!
+//! The headers of the synced block and the next one are verified against the
+//! consensus rules of the chain config. The synced block commitments only cover
+//! the history of blocks, so the imported block Merkle tree is checked against
+//! the `prev_root` of both headers.
+//!
+//! The other tables, including the contract states and balances, are not
+//! committed by the headers. They are only requested from the reserved nodes,
+//! which are trusted by the operator of the node. The number of entries and
+//! bytes of each table is limited, and the groups of the table must go in the
+//! order of keys, so the peer can't make the node download the table forever.
+
+use super::{
+    execute_genesis_block,
+    importer::SnapshotImporter,
+};
+use crate::{
+    combined_database::{
+        CombinedDatabase,
+        CombinedGenesisDatabase,
+    },
+    database::{
+        database_description::{
+            off_chain::OffChain,
+            on_chain::OnChain,
+        },
+        genesis_progress::GenesisMetadata,
+        Database,
+    },
+    service::{
+        adapters::VerifierAdapter,
+        config::{
+            Config,
+            StateSyncConfig,
+        },
+    },
+};
+use fuel_core_chain_config::{
+    SnapshotReader,
+    TableEntry,
+};
+use fuel_core_p2p::{
+    ports::{
+        BlockHeightImporter,
+        TxPool,
+    },
+    request_response::messages::StateGroupRequest,
+    service::{
+        build_shared_state,
+        new_service,
+        SharedState,
+    },
+    TryPeerId,
+};
+use fuel_core_services::{
+    stream::BoxStream,
+    Service,
+    StateWatcher,
+};
+use fuel_core_storage::{
+    blueprint::BlueprintInspect,
+    codec::{
+        Encode,
+        Encoder,
+    },
+    iter::IteratorOverTable,
+    kv_store::StorageColumn,
+    structured_storage::TableWithBlueprint,
+    tables::{
+        merkle::{
+            FuelBlockMerkleData,
+            FuelBlockMerkleMetadata,
+        },
+        Coins,
+        ConsensusParametersVersions,
+        ContractsAssets,
+        ContractsLatestUtxo,
+        ContractsRawCode,
+        ContractsState,
+        FuelBlocks,
+        Messages,
+        ProcessedTransactions,
+        SealedBlockConsensus,
+        StateTransitionBytecodeVersions,
+        Transactions,
+        UploadedBytecodes,
+    },
+    transactional::{
+        AtomicView,
+        HistoricalView,
+        IntoTransaction,
+        Modifiable,
+        ReadTransaction,
+    },
+    StorageAsMut,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::Consensus,
+        SealedBlock,
+        SealedBlockHeader,
+    },
+    fuel_crypto::Hasher,
+    fuel_tx::{
+        TxId,
+        UniqueIdentifier,
+    },
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+    },
+    fuel_vm::{
+        BlobData,
+        UploadedBytecode,
+    },
+    services::p2p::{
+        NetworkableTransactionPool,
+        PeerId,
+    },
+};
+use itertools::Itertools;
+use std::{
+    borrow::Borrow,
+    collections::{
+        BTreeMap,
+        HashSet,
+    },
+};
+
+/// The delay between the polls of the connected peers.
+const PEERS_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// The maximum number of entries of one table received from the peer.
+const MAX_TABLE_ENTRIES: usize = 100_000_000;
+
+/// The maximum size of the encoded entries of one table received from the peer.
+const MAX_TABLE_BYTES: u64 = 16 * 1024 * 1024 * 1024;
+
+type KeyCodec<T> =
+    <<T as TableWithBlueprint>::Blueprint as BlueprintInspect<T, Database>>::KeyCodec;
+
+/// Initializes the empty `database` from the state of the best connected
+/// reserved node.
+///
+/// Does nothing if the database is already initialized, the state sync or
+/// the P2P is disabled, or the chain of peers is not long enough. In that
+/// case, the node is initialized from the snapshot as usual.
+pub async fn sync_state_from_peers(
+    config: &Config,
+    database: &CombinedDatabase,
+) -> anyhow::Result<()> {
+    let (Some(state_sync), Some(p2p_config)) = (&config.state_sync, &config.p2p) else {
+        return Ok(())
+    };
+    if config.is_read_replica() {
+        return Ok(())
+    }
+    if database.on_chain().latest_height().is_some() {
+        return Ok(())
+    }
+
+    let genesis_progress: Vec<String> = database
+        .on_chain()
+        .iter_all_keys::<GenesisMetadata<OnChain>>(None)
+        .try_collect()?;
+    anyhow::ensure!(
+        genesis_progress.is_empty(),
+        "The database contains a partially imported state, it should be pruned"
+    );
+
+    let trusted_peers: HashSet<PeerId> = p2p_config
+        .reserved_nodes
+        .iter()
+        .filter_map(|address| address.try_to_peer_id())
+        .map(|peer_id| PeerId::from(peer_id.to_bytes()))
+        .collect();
+    anyhow::ensure!(
+        !trusted_peers.is_empty(),
+        "The state sync requires the reserved nodes, the state is only trusted from them"
+    );
+
+    // The local genesis block defines the genesis of the network and
+    // is required to connect to peers.
+    let local_database = CombinedDatabase::in_memory();
+    let result =
+        execute_genesis_block(StateWatcher::default(), config, &local_database).await?;
+    let genesis = result.result().sealed_block.clone();
+    let genesis_height = *genesis.entity.header().height();
+    let chain_config = config.snapshot_reader.chain_config();
+    let chain_id = chain_config.consensus_parameters.chain_id();
+    fuel_core_importer::Importer::new(
+        chain_id,
+        config.block_importer.clone(),
+        local_database.on_chain().clone(),
+        (),
+        (),
+    )
+    .commit_result(result)
+    .await?;
+    let genesis_block = genesis.entity.compress(&chain_id);
+
+    let (shared_state, request_receiver) = build_shared_state(p2p_config.clone());
+    let p2p = new_service(
+        chain_id,
+        genesis_height,
+        p2p_config.clone(),
+        shared_state.clone(),
+        request_receiver,
+        local_database.on_chain().clone(),
+        NoBlocks,
+        NoTxPool,
+    );
+    p2p.start_and_await().await?;
+
+    let sync = StateSync {
+        config,
+        state_sync,
+        database,
+        p2p: &shared_state,
+        trusted_peers,
+        genesis,
+        verifier: VerifierAdapter::new(
+            &genesis_block,
+            chain_config.consensus.clone(),
+            chain_id,
+            local_database.on_chain().clone(),
+        ),
+    };
+    let result = sync.run(p2p.state_watcher()).await;
+
+    p2p.stop_and_await().await?;
+    result
+}
+
+struct StateSync<'a> {
+    config: &'a Config,
+    state_sync: &'a StateSyncConfig,
+    database: &'a CombinedDatabase,
+    p2p: &'a SharedState,
+    /// The reserved nodes from which the state is requested.
+    trusted_peers: HashSet<PeerId>,
+    genesis: SealedBlock,
+    verifier: VerifierAdapter,
+}
+
+impl StateSync<'_> {
+    async fn run(self, watcher: StateWatcher) -> anyhow::Result<()> {
+        let genesis_height = *self.genesis.entity.header().height();
+        let (peer, tip) = self.best_trusted_peer().await?;
+        let Some(height) = tip
+            .checked_sub(self.state_sync.blocks_behind_tip)
+            .filter(|height| *height > *genesis_height)
+        else {
+            tracing::info!(
+                "The chain of peers is too short for the state sync, \
+                initializing from the snapshot"
+            );
+            return Ok(())
+        };
+        let next_height = height.saturating_add(1);
+        tracing::info!("Syncing the state at the height {height} from peers");
+
+        let block = self.fetch_block(&peer, height).await?;
+        tracing::warn!("The state at the height {height} is trusted from {peer}");
+        let next_header = self.fetch_next_header(next_height).await?;
+
+        let mut tables = BTreeMap::new();
+        self.fetch_table::<Coins>(&peer, height, &mut tables)
+            .await?;
+        self.fetch_table::<Messages>(&peer, height, &mut tables)
+            .await?;
+        self.fetch_table::<BlobData>(&peer, height, &mut tables)
+            .await?;
+        self.fetch_table::<ContractsRawCode>(&peer, height, &mut tables)
+            .await?;
+        self.fetch_table::<ContractsLatestUtxo>(&peer, height, &mut tables)
+            .await?;
+        self.fetch_table::<ContractsState>(&peer, height, &mut tables)
+            .await?;
+        self.fetch_table::<ContractsAssets>(&peer, height, &mut tables)
+            .await?;
+        self.fetch_table::<ProcessedTransactions>(&peer, height, &mut tables)
+            .await?;
+        // The block Merkle tree is requested without the synced block,
+        // it is appended during the insertion of the block.
+        let prev_height = height.saturating_sub(1);
+        self.fetch_table::<FuelBlockMerkleData>(&peer, prev_height, &mut tables)
+            .await?;
+        self.fetch_table::<FuelBlockMerkleMetadata>(&peer, prev_height, &mut tables)
+            .await?;
+
+        let on_chain = self
+            .database
+            .on_chain()
+            .clone()
+            .into_genesis()
+            .map_err(|_| anyhow::anyhow!("On chain database is already initialized"))?;
+        let off_chain = self
+            .database
+            .off_chain()
+            .clone()
+            .into_genesis()
+            .map_err(|_| anyhow::anyhow!("Off chain database is already initialized"))?;
+        let chain_config = self.config.snapshot_reader.chain_config().clone();
+        SnapshotImporter::import(
+            CombinedGenesisDatabase {
+                on_chain,
+                off_chain,
+            },
+            block.entity.clone(),
+            SnapshotReader::new_encoded(chain_config, tables, None),
+            watcher,
+        )
+        .await?;
+
+        self.commit(block, next_header)
+    }
+
+    /// Returns the reserved node with the highest block height along with the height.
+    async fn best_trusted_peer(&self) -> anyhow::Result<(PeerId, u32)> {
+        let wait_for_heights = async {
+            loop {
+                let best_peer = self
+                    .p2p
+                    .get_all_peers()
+                    .await?
+                    .into_iter()
+                    .map(|(peer_id, info)| (PeerId::from(peer_id.to_bytes()), info))
+                    .filter(|(peer_id, _)| self.trusted_peers.contains(peer_id))
+                    .filter_map(|(peer_id, info)| {
+                        Some((peer_id, *info.heartbeat_data.block_height?))
+                    })
+                    .max_by_key(|(_, height)| *height);
+                if let Some(best_peer) = best_peer {
+                    return Ok::<_, anyhow::Error>(best_peer)
+                }
+                tokio::time::sleep(PEERS_POLL_INTERVAL).await;
+            }
+        };
+
+        tokio::time::timeout(self.state_sync.peers_timeout, wait_for_heights)
+            .await
+            .map_err(|_| {
+                anyhow::anyhow!(
+                    "No reserved nodes reported their block heights in {:?}",
+                    self.state_sync.peers_timeout
+                )
+            })?
+    }
+
+    /// Fetches the sealed block at the `height`. The header is verified, so it may
+    /// come from any peer, while the transactions are requested from the `peer`.
+    async fn fetch_block(
+        &self,
+        peer: &PeerId,
+        height: u32,
+    ) -> anyhow::Result<SealedBlock> {
+        let header = self.fetch_header(height).await?;
+        let transactions = self
+            .p2p
+            .get_transactions_from_peer(peer.clone(), height..height.saturating_add(1))
+            .await?
+            .and_then(|transactions| transactions.into_iter().next())
+            .ok_or_else(|| {
+                anyhow::anyhow!("The peer didn't return the transactions of {height}")
+            })?;
+        let block =
+            Block::try_from_executed(header.entity, transactions.0).ok_or_else(|| {
+                anyhow::anyhow!("The transactions don't match the header of {height}")
+            })?;
+
+        Ok(SealedBlock {
+            entity: block,
+            consensus: header.consensus,
+        })
+    }
+
+    async fn fetch_next_header(&self, height: u32) -> anyhow::Result<SealedBlockHeader> {
+        self.fetch_header(height).await
+    }
+
+    async fn fetch_header(&self, height: u32) -> anyhow::Result<SealedBlockHeader> {
+        let (_, headers) = self
+            .p2p
+            .get_sealed_block_headers(height..height.saturating_add(1))
+            .await?;
+        let header = headers
+            .and_then(|headers| headers.into_iter().next())
+            .ok_or_else(|| {
+                anyhow::anyhow!("Peers didn't return the header of {height}")
+            })?;
+
+        anyhow::ensure!(
+            **header.entity.height() == height,
+            "The peer returned the header of {} instead of {height}",
+            header.entity.height()
+        );
+        // The genesis consensus is accepted by the verifier for any header.
+        anyhow::ensure!(
+            !matches!(header.consensus, Consensus::Genesis(_))
+                && self.verifier.block_verifier.verify_consensus(&header),
+            "The header of {height} has an invalid consensus seal"
+        );
+
+        Ok(header)
+    }
+
+    async fn fetch_table<T>(
+        &self,
+        peer: &PeerId,
+        height: u32,
+        tables: &mut BTreeMap<String, Vec<Vec<Vec<u8>>>>,
+    ) -> anyhow::Result<()>
+    where
+        T: TableWithBlueprint,
+        T::Blueprint: BlueprintInspect<T, Database>,
+        T::OwnedKey: Borrow<T::Key> + serde::Serialize,
+        TableEntry<T>: serde::de::DeserializeOwned,
+    {
+        let table = T::column().name();
+        let mut groups = vec![];
+        let mut after_key = None;
+        let mut last_encoded_key: Option<Vec<u8>> = None;
+        let mut entries: usize = 0;
+        let mut bytes: u64 = 0;
+        loop {
+            let request = StateGroupRequest {
+                height,
+                table: table.clone(),
+                after_key,
+            };
+            let group = self
+                .p2p
+                .get_state_group_from_peer(peer.clone(), request)
+                .await?
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "The peer doesn't have the state of `{table}` at {height}"
+                    )
+                })?;
+            let Some(last) = group.last() else { break };
+
+            entries = entries.saturating_add(group.len());
+            bytes = group.iter().fold(bytes, |bytes, entry| {
+                bytes.saturating_add(u64::try_from(entry.len()).unwrap_or(u64::MAX))
+            });
+            anyhow::ensure!(
+                entries <= MAX_TABLE_ENTRIES && bytes <= MAX_TABLE_BYTES,
+                "The peer returned more than {MAX_TABLE_ENTRIES} entries or \
+                {MAX_TABLE_BYTES} bytes of `{table}`"
+            );
+
+            // The next group starts after the last received entry. The keys must
+            // increase in the order of the table, otherwise the peer could return
+            // the same groups forever.
+            let entry = postcard::from_bytes::<TableEntry<T>>(last)?;
+            let encoded_key = KeyCodec::<T>::encode(entry.key.borrow())
+                .as_bytes()
+                .into_owned();
+            anyhow::ensure!(
+                last_encoded_key
+                    .as_ref()
+                    .map_or(true, |previous| &encoded_key > previous),
+                "The peer returned the group of `{table}` out of the order of keys"
+            );
+            last_encoded_key = Some(encoded_key);
+            after_key = Some(postcard::to_allocvec(&entry.key)?);
+            groups.push(group);
+        }
+        tracing::info!("Received {} groups of `{table}`", groups.len());
+        tables.insert(table, groups);
+        Ok(())
+    }
+
+    fn commit(
+        &self,
+        block: SealedBlock,
+        next_header: SealedBlockHeader,
+    ) -> anyhow::Result<()> {
+        let chain_config = self.config.snapshot_reader.chain_config();
+        let chain_id = chain_config.consensus_parameters.chain_id();
+        let header = block.entity.header();
+        let height = *header.height();
+        let prev_height = BlockHeight::from(height.saturating_sub(1));
+
+        let view = self.database.on_chain().latest_view()?;
+        let imported_root: Bytes32 =
+            view.storage::<FuelBlocks>().root(&prev_height)?.into();
+        anyhow::ensure!(
+            &imported_root == header.prev_root(),
+            "The received block Merkle tree doesn't match the header of {height}"
+        );
+
+        let mut transaction = view.read_transaction();
+        transaction
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&height, &block.entity.compress(&chain_id))?;
+        let root: Bytes32 = transaction.storage::<FuelBlocks>().root(&height)?.into();
+        anyhow::ensure!(
+            &root == next_header.entity.prev_root(),
+            "The block {height} doesn't match the header of the next block"
+        );
+        transaction
+            .storage_as_mut::<SealedBlockConsensus>()
+            .insert(&height, &block.consensus)?;
+        for tx in block.entity.transactions() {
+            transaction
+                .storage_as_mut::<Transactions>()
+                .insert(&tx.id(&chain_id), tx)?;
+        }
+
+        // The genesis consensus identifies the network, while the genesis block
+        // is not available.
+        transaction
+            .storage_as_mut::<SealedBlockConsensus>()
+            .insert(
+                self.genesis.entity.header().height(),
+                &self.genesis.consensus,
+            )?;
+
+        // The chain config is expected to contain the parameters
+        // of the synced block.
+        transaction
+            .storage_as_mut::<ConsensusParametersVersions>()
+            .insert(
+                &header.consensus_parameters_version,
+                &chain_config.consensus_parameters,
+            )?;
+        let bytecode_root =
+            Hasher::hash(chain_config.state_transition_bytecode.as_slice());
+        transaction
+            .storage_as_mut::<StateTransitionBytecodeVersions>()
+            .insert(&header.state_transition_bytecode_version, &bytecode_root)?;
+        transaction.storage_as_mut::<UploadedBytecodes>().insert(
+            &bytecode_root,
+            &UploadedBytecode::Completed(chain_config.state_transition_bytecode.clone()),
+        )?;
+
+        let on_chain_progress: Vec<String> = view
+            .iter_all_keys::<GenesisMetadata<OnChain>>(None)
+            .try_collect()?;
+        for key in on_chain_progress {
+            transaction
+                .storage_as_mut::<GenesisMetadata<OnChain>>()
+                .remove(&key)?;
+        }
+
+        let mut off_chain = self.database.off_chain().clone().into_transaction();
+        let off_chain_progress: Vec<String> = self
+            .database
+            .off_chain()
+            .iter_all_keys::<GenesisMetadata<OffChain>>(None)
+            .try_collect()?;
+        for key in off_chain_progress {
+            off_chain
+                .storage_as_mut::<GenesisMetadata<OffChain>>()
+                .remove(&key)?;
+        }
+        off_chain.commit()?;
+
+        self.database
+            .on_chain()
+            .clone()
+            .commit_changes(transaction.into_changes())?;
+        tracing::info!("The state at the height {height} is synced from peers");
+
+        Ok(())
+    }
+}
+
+/// The temporary P2P service doesn't import blocks.
+struct NoBlocks;
+
+impl BlockHeightImporter for NoBlocks {
+    fn next_block_height(&self) -> BoxStream<BlockHeight> {
+        Box::pin(fuel_core_services::stream::pending())
+    }
+}
+
+/// The temporary P2P service doesn't share transactions.
+#[derive(Clone)]
+struct NoTxPool;
+
+impl TxPool for NoTxPool {
+    async fn get_tx_ids(&self, _: usize) -> anyhow::Result<Vec<TxId>> {
+        Ok(vec![])
+    }
+
+    async fn get_full_txs(
+        &self,
+        tx_ids: Vec<TxId>,
+    ) -> anyhow::Result<Vec<Option<NetworkableTransactionPool>>> {
+        Ok(tx_ids.iter().map(|_| None).collect())
+    }
+}
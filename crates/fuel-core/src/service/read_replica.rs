@@ -0,0 +1,89 @@
+//! The read replica follows the databases of the primary node running on the
+//! same host and serves only the GraphQL API. The databases are opened as
+//! RocksDB secondary instances and periodically catch up with the primary.
+//! The secondary instances don't support snapshots, so a query running during
+//! the catch-up may observe the state of two different heights.
+
+use crate::{
+    combined_database::CombinedDatabase,
+    service::adapters::consensus_parameters_provider,
+};
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+    TaskNextAction,
+};
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
+
+pub struct Task {
+    database: CombinedDatabase,
+    consensus_parameters: consensus_parameters_provider::SharedState,
+    catch_up_interval: tokio::time::Interval,
+}
+
+impl Task {
+    fn catch_up(&self) -> anyhow::Result<()> {
+        self.database.catch_up_with_primary()?;
+        // The consensus parameters may be upgraded by the new blocks.
+        self.consensus_parameters
+            .refresh_latest_consensus_parameters()?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "ReadReplica";
+    type SharedData = ();
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        self.catch_up()?;
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> TaskNextAction {
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                TaskNextAction::Stop
+            }
+
+            _ = self.catch_up_interval.tick() => {
+                TaskNextAction::always_continue(self.catch_up())
+            }
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    database: CombinedDatabase,
+    consensus_parameters: consensus_parameters_provider::SharedState,
+    catch_up_interval: Duration,
+) -> ServiceRunner<Task> {
+    let mut catch_up_interval = tokio::time::interval(catch_up_interval);
+    catch_up_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    ServiceRunner::new(Task {
+        database,
+        consensus_parameters,
+        catch_up_interval,
+    })
+}
@@ -0,0 +1,943 @@
+//! The reindex rebuilds the off-chain database from the on-chain database.
+//!
+//! The full rebuild replays the blocks through the off-chain worker into the
+//! staging database next to the off-chain database and replaces the off-chain
+//! database with it at the end. The selected indexes are rebuilt in place.
+//!
+//! The progress is stored in the `GenesisMetadata` table of the database being
+//! written, so the interrupted reindex continues from the last processed group.
+
+use crate::{
+    database::{
+        database_description::{
+            off_chain::OffChain,
+            relayer::Relayer,
+            DatabaseDescription,
+            IndexationKind,
+        },
+        genesis_progress::{
+            GenesisMetadata,
+            GenesisProgressInspect,
+            GenesisProgressMutate,
+        },
+        Database,
+    },
+    fuel_core_graphql_api::{
+        ports::worker::{
+            BlockAt,
+            OffChainDatabaseTransaction,
+        },
+        storage::{
+            blocks::FuelBlockIdsToHeights,
+            old::{
+                OldFuelBlocks,
+                OldTransactions,
+            },
+            transactions::{
+                OwnedTransactions,
+                TransactionStatuses,
+            },
+            Column,
+        },
+        worker_service::{
+            self,
+            DaCompressionConfig,
+        },
+    },
+    service::{
+        adapters::{
+            import_result_provider::ImportResultProvider,
+            ExecutorAdapter,
+        },
+        config::{
+            Config,
+            DbType,
+        },
+        genesis::{
+            progress::{
+                MultipleProgressReporter,
+                ProgressReporter,
+            },
+            task_manager::{
+                CancellationToken,
+                TaskManager,
+            },
+            NotifyCancel,
+        },
+    },
+    state::historical_rocksdb::StateRewindPolicy,
+};
+use anyhow::{
+    anyhow,
+    bail,
+    Context,
+};
+use clap::ValueEnum;
+use fuel_core_storage::{
+    iter::{
+        IterDirection,
+        IterableStore,
+        IterableTable,
+        IteratorOverTable,
+    },
+    kv_store::{
+        KeyValueMutate,
+        StorageColumn,
+    },
+    structured_storage::TableWithBlueprint,
+    tables::{
+        Coins,
+        ConsensusParametersVersions,
+        Messages,
+    },
+    transactional::{
+        AtomicView,
+        HistoricalView,
+        StorageTransaction,
+        WriteTransaction,
+    },
+    Error as StorageError,
+    StorageAsMut,
+    StorageAsRef,
+    StorageMutate,
+};
+use fuel_core_types::{
+    blockchain::block::Block,
+    fuel_types::{
+        BlockHeight,
+        ChainId,
+    },
+    services::{
+        executor::Event,
+        txpool::TransactionStatus,
+    },
+};
+use itertools::Itertools;
+use std::{
+    borrow::{
+        Borrow,
+        Cow,
+    },
+    path::PathBuf,
+};
+use strum_macros::{
+    Display,
+    EnumString,
+    EnumVariantNames,
+};
+
+/// The number of entries processed and committed at once.
+const GROUP_SIZE: usize = 1000;
+/// The prefix of the progress keys of the reindex in the `GenesisMetadata` table.
+const PROGRESS_PREFIX: &str = "reindex:";
+/// The key of the on-chain height the progress of the reindex belongs to.
+const HEIGHT_PROGRESS_KEY: &str = "reindex:height";
+/// The directory inside the database path with the staging off-chain database.
+const STAGING_DIRECTORY: &str = "reindex";
+
+/// The indexes of the off-chain database that can be rebuilt in place.
+#[derive(
+    Clone, Copy, Debug, Display, Eq, PartialEq, EnumString, EnumVariantNames, ValueEnum,
+)]
+#[strum(serialize_all = "kebab_case")]
+pub enum OffChainIndex {
+    /// The `CoinBalances` and `MessageBalances` tables.
+    Balances,
+    /// The `OwnedCoins` table.
+    Coins,
+    /// The `OwnedMessageIds` table.
+    Messages,
+    /// The `FuelBlockIdsToHeights` table.
+    Blocks,
+    /// The `OwnedTransactions` table.
+    Transactions,
+    /// The `ContractsInfo` table.
+    Contracts,
+}
+
+impl OffChainIndex {
+    /// Returns the columns of the off-chain database that hold the index.
+    pub fn columns(&self) -> &'static [Column] {
+        match self {
+            OffChainIndex::Balances => &[Column::CoinBalances, Column::MessageBalances],
+            OffChainIndex::Coins => &[Column::OwnedCoins],
+            OffChainIndex::Messages => &[Column::OwnedMessageIds],
+            OffChainIndex::Blocks => &[Column::FuelBlockIdsToHeights],
+            OffChainIndex::Transactions => &[Column::TransactionsByOwnerBlockIdx],
+            OffChainIndex::Contracts => &[Column::ContractsInfo],
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ReindexConfig {
+    pub database_path: PathBuf,
+    pub capacity: usize,
+    /// The state rewind policy of the rebuilt off-chain database.
+    pub state_rewind_policy: StateRewindPolicy,
+    pub max_fds: i32,
+    /// The indexes rebuilt in place. If empty, the whole off-chain database
+    /// is rebuilt by replaying the blocks.
+    pub indexes: Vec<OffChainIndex>,
+    /// The configuration of the executor that re-executes the blocks.
+    pub executor: fuel_core_upgradable_executor::config::Config,
+    pub da_compression: DaCompressionConfig,
+    /// The number of blocks re-executed in parallel during the full rebuild.
+    pub batch_size: usize,
+}
+
+impl ReindexConfig {
+    pub const DEFAULT_BATCH_SIZE: usize = 32;
+
+    /// Creates the configuration of the reindex of the node's database.
+    pub fn from_node_config(
+        config: &Config,
+        indexes: Vec<OffChainIndex>,
+    ) -> anyhow::Result<Self> {
+        let db_config = &config.combined_db_config;
+        if db_config.database_type != DbType::RocksDb {
+            bail!("The reindex is only supported with RocksDB")
+        }
+
+        Ok(Self {
+            database_path: db_config.database_path.clone(),
+            capacity: db_config.max_database_cache_size,
+            state_rewind_policy: db_config.state_rewind_policy,
+            max_fds: db_config.max_fds,
+            indexes,
+            executor: fuel_core_upgradable_executor::config::Config {
+                backtrace: config.vm.backtrace,
+                utxo_validation_default: config.utxo_validation,
+                native_executor_version: config.native_executor_version,
+            },
+            da_compression: config.da_compression.clone(),
+            batch_size: Self::DEFAULT_BATCH_SIZE,
+        })
+    }
+}
+
+/// Rebuilds the off-chain database or the selected indexes of it
+/// from the on-chain database. The node must be stopped.
+pub async fn reindex<W>(config: ReindexConfig, watcher: W) -> anyhow::Result<()>
+where
+    W: NotifyCancel + Clone + Send + Sync + 'static,
+{
+    // Split the fds in equitable manner between the databases
+    let max_fds = match config.max_fds {
+        -1 => -1,
+        max_fds => max_fds.saturating_div(4),
+    };
+    let path = config.database_path.as_path();
+    let on_chain: Database = Database::open_rocksdb(
+        path,
+        config.capacity,
+        StateRewindPolicy::RewindFullRange,
+        max_fds,
+    )?;
+    let Some(latest_height) = HistoricalView::latest_height(&on_chain) else {
+        tracing::warn!("The on-chain database at {path:?} is empty, nothing to reindex");
+        return Ok(())
+    };
+    let (_, consensus_parameters) = on_chain
+        .iter_all::<ConsensusParametersVersions>(Some(IterDirection::Reverse))
+        .next()
+        .transpose()?
+        .ok_or_else(|| anyhow!("The consensus parameters are missing"))?;
+
+    let reindexer = Reindexer {
+        on_chain,
+        latest_height,
+        chain_id: consensus_parameters.chain_id(),
+        watcher,
+        multi_progress_reporter: MultipleProgressReporter::new(tracing::info_span!(
+            "reindex"
+        )),
+    };
+
+    if config.indexes.is_empty() {
+        tracing::info!("Rebuilding the off-chain database up to {latest_height}");
+        reindexer.rebuild_database(&config, max_fds).await
+    } else {
+        tracing::info!(
+            "Rebuilding the {} indexes of the off-chain database",
+            config.indexes.iter().join(", ")
+        );
+        let off_chain = Database::<OffChain>::open_rocksdb(
+            path,
+            config.capacity,
+            config.state_rewind_policy,
+            max_fds,
+        )?;
+        reindexer.rebuild_indexes(off_chain, &config.indexes).await
+    }
+}
+
+struct Reindexer<W> {
+    on_chain: Database,
+    latest_height: BlockHeight,
+    chain_id: ChainId,
+    watcher: W,
+    multi_progress_reporter: MultipleProgressReporter,
+}
+
+impl<W> Reindexer<W>
+where
+    W: NotifyCancel + Clone + Send + Sync + 'static,
+{
+    /// Replays all blocks into the staging database and replaces
+    /// the off-chain database with it.
+    async fn rebuild_database(
+        &self,
+        config: &ReindexConfig,
+        max_fds: i32,
+    ) -> anyhow::Result<()> {
+        let staging_path = config.database_path.join(STAGING_DIRECTORY);
+        let mut staging = Database::<OffChain>::open_rocksdb(
+            &staging_path,
+            config.capacity,
+            config.state_rewind_policy,
+            max_fds,
+        )?;
+        let genesis_height = self
+            .on_chain
+            .latest_view()?
+            .genesis_height()?
+            .ok_or_else(|| anyhow!("The genesis block is missing"))?;
+
+        let off_chain_path = config.database_path.join(OffChain::name());
+        if HistoricalView::latest_height(&staging).is_none() {
+            if off_chain_path.exists() {
+                let source = Database::<OffChain>::open_rocksdb(
+                    &config.database_path,
+                    config.capacity,
+                    config.state_rewind_policy,
+                    max_fds,
+                )?;
+                self.copy_pre_genesis_data(&source, &staging, genesis_height)
+                    .await?;
+            }
+
+            let executor = self.executor(config, max_fds)?;
+            let provider = ImportResultProvider::new(self.on_chain.clone(), executor);
+            let result = provider.result_at_height(BlockAt::Genesis)?;
+            let mut transaction = staging.write_transaction();
+            worker_service::process_block(
+                &result,
+                &mut transaction,
+                &self.chain_id,
+                &config.da_compression,
+                false,
+            )?;
+            transaction.commit()?;
+        }
+
+        self.replay_blocks(config, max_fds, &mut staging).await?;
+
+        // The balances and the owned coins and messages of the genesis state
+        // are not a part of the genesis block, so they are built from the latest state.
+        self.rebuild_indexes(
+            staging,
+            &[
+                OffChainIndex::Balances,
+                OffChainIndex::Coins,
+                OffChainIndex::Messages,
+            ],
+        )
+        .await?;
+
+        if off_chain_path.exists() {
+            std::fs::remove_dir_all(&off_chain_path)?;
+        }
+        std::fs::rename(staging_path.join(OffChain::name()), &off_chain_path)?;
+        std::fs::remove_dir_all(&staging_path)?;
+        tracing::info!("The off-chain database is rebuilt");
+
+        Ok(())
+    }
+
+    fn executor(
+        &self,
+        config: &ReindexConfig,
+        max_fds: i32,
+    ) -> anyhow::Result<ExecutorAdapter> {
+        let relayer = Database::<Relayer>::open_rocksdb(
+            &config.database_path,
+            config.capacity,
+            StateRewindPolicy::NoRewind,
+            max_fds,
+        )?;
+        Ok(ExecutorAdapter::new(
+            self.on_chain.clone(),
+            relayer,
+            config.executor.clone(),
+        ))
+    }
+
+    /// Copies the data of the off-chain database that can't be derived from
+    /// the on-chain database, like the blocks from before the regenesis.
+    async fn copy_pre_genesis_data(
+        &self,
+        source: &Database<OffChain>,
+        staging: &Database<OffChain>,
+        genesis_height: BlockHeight,
+    ) -> anyhow::Result<()> {
+        let mut task_manager = TaskManager::new(self.watcher.clone());
+        for column in [
+            Column::OldFuelBlocks,
+            Column::OldFuelBlockConsensus,
+            Column::OldTransactions,
+            Column::ContractsInfo,
+            Column::SpentMessages,
+            Column::RelayedTransactionStatus,
+            Column::TxPoolBlackList,
+        ] {
+            let source = source.clone();
+            let staging = staging.clone();
+            let reporter = self
+                .multi_progress_reporter
+                .table_reporter(None, column.name());
+            task_manager.spawn_blocking(move |cancel| {
+                let entries =
+                    source.iter_store(column, None, None, IterDirection::Forward);
+                let progress = progress_key("copy", &[column]);
+                run_groups(
+                    staging,
+                    &progress,
+                    &[],
+                    into_groups(entries),
+                    &reporter,
+                    &cancel,
+                    |group, transaction| {
+                        for (key, value) in group {
+                            transaction.put(&key, column, value)?;
+                        }
+                        Ok(())
+                    },
+                )
+            });
+        }
+
+        self.spawn_copy_table::<TransactionStatuses>(
+            &mut task_manager,
+            source,
+            staging,
+            move |_, status| match status {
+                TransactionStatus::Success { block_height, .. }
+                | TransactionStatus::Failed { block_height, .. } => {
+                    *block_height < genesis_height
+                }
+                _ => false,
+            },
+        );
+        self.spawn_copy_table::<FuelBlockIdsToHeights>(
+            &mut task_manager,
+            source,
+            staging,
+            move |_, height| *height < genesis_height,
+        );
+        self.spawn_copy_table::<OwnedTransactions>(
+            &mut task_manager,
+            source,
+            staging,
+            move |key, _| key.block_height < genesis_height,
+        );
+
+        task_manager.wait().await?;
+        Ok(())
+    }
+
+    fn spawn_copy_table<T>(
+        &self,
+        task_manager: &mut TaskManager<()>,
+        source: &Database<OffChain>,
+        staging: &Database<OffChain>,
+        filter: impl Fn(&T::OwnedKey, &T::OwnedValue) -> bool + Send + 'static,
+    ) where
+        T: TableWithBlueprint<Column = Column> + 'static,
+        Database<OffChain>: IterableTable<T>,
+        for<'a> StorageTransaction<&'a mut Database<OffChain>>:
+            StorageMutate<T, Error = StorageError>,
+    {
+        let source = source.clone();
+        let staging = staging.clone();
+        let reporter = self
+            .multi_progress_reporter
+            .table_reporter(None, T::column().name());
+        task_manager.spawn_blocking(move |cancel| {
+            let entries = source.iter_all::<T>(None).filter(|entry| match entry {
+                Ok((key, value)) => filter(key, value),
+                Err(_) => true,
+            });
+            let progress = progress_key("copy", &[T::column()]);
+            run_groups(
+                staging,
+                &progress,
+                &[],
+                into_groups(entries),
+                &reporter,
+                &cancel,
+                |group, transaction| {
+                    for (key, value) in group {
+                        transaction
+                            .storage_as_mut::<T>()
+                            .insert(key.borrow(), value.borrow())?;
+                    }
+                    Ok(())
+                },
+            )
+        });
+    }
+
+    /// Re-executes the blocks after the height of the `staging` database
+    /// in parallel batches and processes the results by the off-chain worker.
+    async fn replay_blocks(
+        &self,
+        config: &ReindexConfig,
+        max_fds: i32,
+        staging: &mut Database<OffChain>,
+    ) -> anyhow::Result<()> {
+        let staging_height = HistoricalView::latest_height(staging)
+            .ok_or_else(|| anyhow!("The staging database is empty"))?;
+        let Some(first_height) = staging_height.succ() else {
+            return Ok(())
+        };
+        if first_height > self.latest_height {
+            return Ok(())
+        }
+
+        // The block is validated against the state at the previous height.
+        self.on_chain.view_at(&staging_height).with_context(|| {
+            format!(
+                "The on-chain database doesn't have the state at the height \
+                {staging_height} required to re-execute the blocks. Keep the whole \
+                state history with `--state-rewind-duration 136y` or rebuild only \
+                the selected indexes."
+            )
+        })?;
+
+        let executor = self.executor(config, max_fds)?;
+        let provider = ImportResultProvider::new(self.on_chain.clone(), executor);
+        let batch_size = u32::try_from(config.batch_size.max(1))
+            .unwrap_or(u32::MAX)
+            .saturating_sub(1);
+        let blocks = self.latest_height.saturating_sub(*first_height);
+        let reporter = self
+            .multi_progress_reporter
+            .table_reporter(Some(usize::try_from(blocks)?.saturating_add(1)), "blocks");
+
+        let mut height = *first_height;
+        loop {
+            if self.watcher.is_cancelled() {
+                bail!("The reindex was cancelled")
+            }
+
+            let last_height = height.saturating_add(batch_size).min(*self.latest_height);
+            let results =
+                futures::future::try_join_all((height..=last_height).map(|height| {
+                    let provider = provider.clone();
+                    tokio_rayon::spawn_fifo(move || {
+                        provider.result_at_height(BlockAt::Specific(height.into()))
+                    })
+                }))
+                .await?;
+
+            for result in results {
+                let mut transaction = staging.write_transaction();
+                worker_service::process_block(
+                    &result,
+                    &mut transaction,
+                    &self.chain_id,
+                    &config.da_compression,
+                    false,
+                )?;
+                transaction.commit()?;
+            }
+            reporter
+                .set_index(usize::try_from(last_height.saturating_sub(*first_height))?);
+
+            match last_height.checked_add(1) {
+                Some(next_height) if last_height < *self.latest_height => {
+                    height = next_height;
+                }
+                _ => break,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Rebuilds the `indexes` of the `off_chain` database in place.
+    async fn rebuild_indexes(
+        &self,
+        mut off_chain: Database<OffChain>,
+        indexes: &[OffChainIndex],
+    ) -> anyhow::Result<()> {
+        let off_chain_height = HistoricalView::latest_height(&off_chain);
+        if off_chain_height != Some(self.latest_height) {
+            bail!(
+                "The off-chain database at the height {off_chain_height:?} is not \
+                synced with the on-chain database at the height {}. Start the node \
+                to sync the databases or rebuild the whole off-chain database.",
+                self.latest_height
+            )
+        }
+        reset_outdated_progress(&mut off_chain, self.latest_height)?;
+
+        let balances = indexes.contains(&OffChainIndex::Balances);
+        if balances {
+            // The balances are not used by the API until they are rebuilt.
+            off_chain.set_indexation_availability(IndexationKind::Balances, false)?;
+        }
+
+        let mut task_manager = TaskManager::new(self.watcher.clone());
+
+        let mut coin_columns = vec![];
+        if indexes.contains(&OffChainIndex::Coins) {
+            coin_columns.push(Column::OwnedCoins);
+        }
+        if balances {
+            coin_columns.push(Column::CoinBalances);
+        }
+        if !coin_columns.is_empty() {
+            let on_chain = self.on_chain.clone();
+            let off_chain = off_chain.clone();
+            let reporter = self.multi_progress_reporter.table_reporter(None, "coins");
+            task_manager.spawn_blocking(move |cancel| {
+                let view = on_chain.latest_view()?;
+                let coins = view
+                    .iter_all::<Coins>(None)
+                    .map_ok(|(utxo_id, coin)| coin.uncompress(utxo_id));
+                let progress = progress_key("coins", &coin_columns);
+                run_groups(
+                    off_chain,
+                    &progress,
+                    &coin_columns,
+                    into_groups(coins),
+                    &reporter,
+                    &cancel,
+                    |group, transaction| {
+                        let events = group
+                            .into_iter()
+                            .map(|coin| Cow::Owned(Event::CoinCreated(coin)));
+                        worker_service::process_executor_events(
+                            events,
+                            transaction,
+                            balances,
+                        )
+                    },
+                )
+            });
+        }
+
+        let mut message_columns = vec![];
+        if indexes.contains(&OffChainIndex::Messages) {
+            message_columns.push(Column::OwnedMessageIds);
+        }
+        if balances {
+            message_columns.push(Column::MessageBalances);
+        }
+        if !message_columns.is_empty() {
+            let on_chain = self.on_chain.clone();
+            let off_chain = off_chain.clone();
+            let reporter = self
+                .multi_progress_reporter
+                .table_reporter(None, "messages");
+            task_manager.spawn_blocking(move |cancel| {
+                let view = on_chain.latest_view()?;
+                let messages = view
+                    .iter_all::<Messages>(None)
+                    .map_ok(|(_, message)| message);
+                let progress = progress_key("messages", &message_columns);
+                run_groups(
+                    off_chain,
+                    &progress,
+                    &message_columns,
+                    into_groups(messages),
+                    &reporter,
+                    &cancel,
+                    |group, transaction| {
+                        let events = group
+                            .into_iter()
+                            .map(|message| Cow::Owned(Event::MessageImported(message)));
+                        worker_service::process_executor_events(
+                            events,
+                            transaction,
+                            balances,
+                        )
+                    },
+                )
+            });
+        }
+
+        // The indexes derived from the blocks are only extended, because they
+        // also contain the entries from the snapshot of the regenesis.
+        let block_indexes = indexes
+            .iter()
+            .copied()
+            .filter(|index| {
+                matches!(
+                    index,
+                    OffChainIndex::Blocks
+                        | OffChainIndex::Transactions
+                        | OffChainIndex::Contracts
+                )
+            })
+            .collect::<Vec<_>>();
+        if !block_indexes.is_empty() {
+            let block_columns = block_indexes
+                .iter()
+                .flat_map(|index| index.columns())
+                .copied()
+                .collect::<Vec<_>>();
+
+            let on_chain = self.on_chain.clone();
+            let off_chain_clone = off_chain.clone();
+            let chain_id = self.chain_id;
+            let latest_height = self.latest_height;
+            let indexes = block_indexes.clone();
+            let progress = progress_key("blocks", &block_columns);
+            let reporter = self.multi_progress_reporter.table_reporter(None, "blocks");
+            task_manager.spawn_blocking(move |cancel| {
+                let view = on_chain.latest_view()?;
+                let genesis_height = view
+                    .genesis_height()?
+                    .ok_or_else(|| anyhow!("The genesis block is missing"))?;
+                let blocks = (*genesis_height..=*latest_height).map(|height| {
+                    view.get_full_block(&height.into())?
+                        .ok_or_else(|| anyhow!("The block at height {height} is missing"))
+                });
+                run_groups(
+                    off_chain_clone,
+                    &progress,
+                    &[],
+                    into_groups(blocks),
+                    &reporter,
+                    &cancel,
+                    |group, transaction| {
+                        for block in group {
+                            index_block(&block, transaction, &indexes, &chain_id)?;
+                        }
+                        Ok(())
+                    },
+                )
+            });
+
+            let off_chain_clone = off_chain.clone();
+            let indexes = block_indexes;
+            let progress = progress_key("old_blocks", &block_columns);
+            let reporter = self
+                .multi_progress_reporter
+                .table_reporter(None, "old blocks");
+            task_manager.spawn_blocking(move |cancel| {
+                let view = off_chain_clone.latest_view()?;
+                let blocks = view.iter_all::<OldFuelBlocks>(None).map(
+                    |entry| -> anyhow::Result<Block> {
+                        let (_, block) = entry?;
+                        let transactions = block
+                            .transactions()
+                            .iter()
+                            .map(|id| {
+                                view.storage::<OldTransactions>()
+                                    .get(id)?
+                                    .map(Cow::into_owned)
+                                    .ok_or_else(|| {
+                                        anyhow!("The old transaction {id} is missing")
+                                    })
+                            })
+                            .try_collect()?;
+                        Ok(block.uncompress(transactions))
+                    },
+                );
+                run_groups(
+                    off_chain_clone.clone(),
+                    &progress,
+                    &[],
+                    into_groups(blocks),
+                    &reporter,
+                    &cancel,
+                    |group, transaction| {
+                        for block in group {
+                            index_block(&block, transaction, &indexes, &chain_id)?;
+                        }
+                        Ok(())
+                    },
+                )
+            });
+        }
+
+        task_manager.wait().await?;
+
+        remove_progress(&mut off_chain)?;
+        if balances {
+            off_chain.set_indexation_availability(IndexationKind::Balances, true)?;
+        }
+        tracing::info!("The indexes of the off-chain database are rebuilt");
+
+        Ok(())
+    }
+}
+
+/// Updates the block-derived `indexes` with the `block`.
+fn index_block<T>(
+    block: &Block,
+    transaction: &mut T,
+    indexes: &[OffChainIndex],
+    chain_id: &ChainId,
+) -> anyhow::Result<()>
+where
+    T: OffChainDatabaseTransaction,
+{
+    if indexes.contains(&OffChainIndex::Blocks) {
+        transaction
+            .storage_as_mut::<FuelBlockIdsToHeights>()
+            .insert(&block.id(), block.header().height())?;
+    }
+    if indexes.contains(&OffChainIndex::Transactions) {
+        worker_service::index_tx_owners_for_block(block, transaction, chain_id)?;
+    }
+    if indexes.contains(&OffChainIndex::Contracts) {
+        worker_service::process_transactions(block.transactions().iter(), transaction)?;
+    }
+    Ok(())
+}
+
+/// Splits the entries into the groups processed within one transaction.
+fn into_groups<T, E>(
+    entries: impl Iterator<Item = Result<T, E>>,
+) -> impl Iterator<Item = anyhow::Result<Vec<T>>>
+where
+    E: Into<anyhow::Error>,
+{
+    let mut entries = entries.fuse();
+    core::iter::from_fn(move || {
+        let group = entries
+            .by_ref()
+            .take(GROUP_SIZE)
+            .collect::<Result<Vec<_>, _>>();
+        match group {
+            Ok(group) if group.is_empty() => None,
+            group => Some(group.map_err(Into::into)),
+        }
+    })
+}
+
+/// Processes the groups that weren't processed by the previous run and
+/// commits each group together with its index as the progress.
+/// The `columns_to_clear` are cleared before the first group.
+fn run_groups<T>(
+    mut off_chain: Database<OffChain>,
+    progress_key: &str,
+    columns_to_clear: &[Column],
+    groups: impl Iterator<Item = anyhow::Result<Vec<T>>>,
+    reporter: &ProgressReporter,
+    cancel_token: &CancellationToken,
+    mut process: impl FnMut(
+        Vec<T>,
+        &mut StorageTransaction<&mut Database<OffChain>>,
+    ) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    let last_group =
+        GenesisProgressInspect::<OffChain>::genesis_progress(&off_chain, progress_key);
+    let first_group = match last_group {
+        Some(last_group) => last_group.saturating_add(1),
+        None => {
+            clear_columns(&mut off_chain, columns_to_clear)?;
+            0
+        }
+    };
+
+    for (index, group) in groups.enumerate().skip(first_group) {
+        if cancel_token.is_cancelled() {
+            bail!("The reindex was cancelled")
+        }
+
+        let mut transaction = off_chain.write_transaction();
+        process(group?, &mut transaction)?;
+        GenesisProgressMutate::<OffChain>::update_genesis_progress(
+            &mut transaction,
+            progress_key,
+            index,
+        )?;
+        let changes = transaction.into_changes();
+        off_chain.commit_changes_without_height(changes)?;
+        reporter.set_index(index);
+    }
+
+    Ok(())
+}
+
+fn clear_columns(
+    off_chain: &mut Database<OffChain>,
+    columns: &[Column],
+) -> anyhow::Result<()> {
+    for column in columns {
+        loop {
+            let keys = off_chain
+                .iter_store_keys(*column, None, None, IterDirection::Forward)
+                .take(GROUP_SIZE)
+                .collect::<Result<Vec<_>, _>>()?;
+            if keys.is_empty() {
+                break
+            }
+
+            let mut transaction = off_chain.write_transaction();
+            for key in keys {
+                transaction.delete(&key, *column)?;
+            }
+            let changes = transaction.into_changes();
+            off_chain.commit_changes_without_height(changes)?;
+        }
+    }
+    Ok(())
+}
+
+fn progress_key(task: &str, columns: &[Column]) -> String {
+    format!(
+        "{PROGRESS_PREFIX}{task}:{}",
+        columns.iter().map(|column| column.name()).join(",")
+    )
+}
+
+/// The progress belongs to the on-chain height of the run that stored it.
+/// The progress of another height is removed, because the latest state differs.
+fn reset_outdated_progress(
+    off_chain: &mut Database<OffChain>,
+    height: BlockHeight,
+) -> anyhow::Result<()> {
+    let height = usize::try_from(*height)?;
+    let progress_height = GenesisProgressInspect::<OffChain>::genesis_progress(
+        off_chain,
+        HEIGHT_PROGRESS_KEY,
+    );
+    if progress_height == Some(height) {
+        return Ok(())
+    }
+
+    remove_progress(off_chain)?;
+    let mut transaction = off_chain.write_transaction();
+    GenesisProgressMutate::<OffChain>::update_genesis_progress(
+        &mut transaction,
+        HEIGHT_PROGRESS_KEY,
+        height,
+    )?;
+    let changes = transaction.into_changes();
+    off_chain.commit_changes_without_height(changes)?;
+    Ok(())
+}
+
+fn remove_progress(off_chain: &mut Database<OffChain>) -> anyhow::Result<()> {
+    let keys = off_chain
+        .iter_all_keys::<GenesisMetadata<OffChain>>(None)
+        .filter_ok(|key| key.starts_with(PROGRESS_PREFIX))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut transaction = off_chain.write_transaction();
+    for key in keys {
+        transaction
+            .storage_as_mut::<GenesisMetadata<OffChain>>()
+            .remove(key.as_str())?;
+    }
+    let changes = transaction.into_changes();
+    off_chain.commit_changes_without_height(changes)?;
+    Ok(())
+}
@@ -13,13 +13,19 @@ use crate::{
     combined_database::CombinedDatabase,
     database::Database,
     fuel_core_graphql_api,
-    fuel_core_graphql_api::Config as GraphQLConfig,
+    fuel_core_graphql_api::{
+        storage::tx_pool_blacklist::TxPoolBlackList,
+        Config as GraphQLConfig,
+    },
     schema::build_schema,
     service::{
         adapters::{
             consensus_module::poa::InDirectoryPredefinedBlocks,
             consensus_parameters_provider,
-            fuel_gas_price_provider::FuelGasPriceProvider,
+            fuel_gas_price_provider::{
+                FuelGasPriceProvider,
+                VersionedGasPriceProvider,
+            },
             graphql_api::GraphQLBlockImporter,
             import_result_provider::ImportResultProvider,
             BlockImporterAdapter,
@@ -28,19 +34,32 @@ use crate::{
             ExecutorAdapter,
             MaybeRelayerAdapter,
             PoAAdapter,
+            ReadReplicaAdapter,
             SharedMemoryPool,
+            StaticGasPrice,
             SystemTime,
             TxPoolAdapter,
+            TxPoolBlackListAdapter,
             VerifierAdapter,
         },
+        config::GasPriceAlgorithm,
+        read_replica,
         Config,
+        ServiceTrait,
         SharedState,
         SubServices,
     },
 };
-use fuel_core_gas_price_service::v0::uninitialized_task::{
-    new_gas_price_service_v0,
-    AlgorithmV0,
+use fuel_core_chain_config::ConsensusConfig;
+use fuel_core_gas_price_service::{
+    v0::uninitialized_task::new_gas_price_service_v0,
+    v1::{
+        da_source_service::block_committer_costs::{
+            BlockCommitterDaBlockCosts,
+            BlockCommitterHttpApi,
+        },
+        uninitialized_task::new_gas_price_service_v1,
+    },
 };
 use fuel_core_poa::{
     signer::SignMode,
@@ -48,6 +67,7 @@ use fuel_core_poa::{
 };
 use fuel_core_storage::{
     self,
+    iter::IteratorOverTable,
     structured_storage::StructuredStorage,
     transactional::AtomicView,
 };
@@ -56,6 +76,12 @@ use fuel_core_types::blockchain::primitives::DaBlockHeight;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
+pub type BftService = fuel_core_bft::Service<
+    BlockProducerAdapter,
+    ExecutorAdapter,
+    BlockImporterAdapter,
+    P2PAdapter,
+>;
 pub type PoAService = fuel_core_poa::Service<
     TxPoolAdapter,
     BlockProducerAdapter,
@@ -71,7 +97,7 @@ pub type BlockProducerService = fuel_core_producer::block_producer::Producer<
     Database,
     TxPoolAdapter,
     ExecutorAdapter,
-    FuelGasPriceProvider<AlgorithmV0>,
+    VersionedGasPriceProvider,
     ConsensusParametersProvider,
 >;
 
@@ -111,6 +137,7 @@ pub fn init_sub_services(
     let verifier = VerifierAdapter::new(
         &genesis_block,
         chain_config.consensus.clone(),
+        chain_id,
         database.on_chain().clone(),
     );
 
@@ -184,21 +211,60 @@ pub fn init_sub_services(
     let block_stream = importer_adapter.events_shared_result();
     let metadata = database.gas_price().clone();
 
-    let gas_price_service_v0 = new_gas_price_service_v0(
-        config.clone().into(),
-        genesis_block_height,
-        settings,
-        block_stream,
-        database.gas_price().clone(),
-        StructuredStorage::new(metadata),
-        database.on_chain().clone(),
-    )?;
+    let (gas_price_service, gas_price_provider): (
+        Box<dyn ServiceTrait + Send + Sync>,
+        _,
+    ) = match config.gas_price_algorithm {
+        GasPriceAlgorithm::V0 => {
+            let service = new_gas_price_service_v0(
+                config.clone().into(),
+                genesis_block_height,
+                settings,
+                block_stream,
+                database.gas_price().clone(),
+                StructuredStorage::new(metadata),
+                database.on_chain().clone(),
+            )?;
+            let provider = VersionedGasPriceProvider::V0(FuelGasPriceProvider::new(
+                service.shared.clone(),
+            ));
+            (Box::new(service), provider)
+        }
+        GasPriceAlgorithm::V1 => {
+            let committer_url = config.da_committer_url.clone().ok_or_else(|| {
+                anyhow::anyhow!(
+                    "The `V1` gas price algorithm requires the block committer URL"
+                )
+            })?;
+            let da_source = BlockCommitterDaBlockCosts::new(
+                BlockCommitterHttpApi::new(committer_url),
+                None,
+            );
+            let service = new_gas_price_service_v1(
+                config.clone().into(),
+                genesis_block_height,
+                settings,
+                block_stream,
+                database.gas_price().clone(),
+                da_source,
+                database.on_chain().clone(),
+            )?;
+            let provider = VersionedGasPriceProvider::V1(FuelGasPriceProvider::new(
+                service.shared.clone(),
+            ));
+            (Box::new(service), provider)
+        }
+    };
+
+    // The entries added to the blacklist at runtime are stored in the off-chain database.
+    let mut txpool_config = config.txpool.clone();
+    for entry in database.off_chain().iter_all_keys::<TxPoolBlackList>(None) {
+        txpool_config.black_list.insert(entry?);
+    }
 
-    let gas_price_provider =
-        FuelGasPriceProvider::new(gas_price_service_v0.shared.clone());
     let txpool = fuel_core_txpool::new_service(
         chain_id,
-        config.txpool.clone(),
+        txpool_config,
         p2p_adapter.clone(),
         importer_adapter.clone(),
         database.on_chain().clone(),
@@ -208,6 +274,8 @@ pub fn init_sub_services(
         executor.clone(),
     );
     let tx_pool_adapter = TxPoolAdapter::new(txpool.shared.clone());
+    let tx_pool_blacklist_adapter =
+        TxPoolBlackListAdapter::new(txpool.shared.clone(), database.off_chain().clone());
 
     #[cfg(feature = "p2p")]
     let mut network = config.p2p.clone().zip(p2p_externals).map(
@@ -245,6 +313,42 @@ pub fn init_sub_services(
         tracing::info!("Enabled manual block production because of `debug` flag");
     }
 
+    // The blocks of the BFT network are produced by the quorum of validators.
+    let bft = match &chain_config.consensus {
+        ConsensusConfig::Bft {
+            validators,
+            threshold,
+        } => {
+            chain_config.consensus.validate()?;
+            production_enabled = false;
+            // The validator signs the votes of every round, so the signing by the KMS
+            // or the remote signer is not supported.
+            let signing_key = match &config.consensus_signer {
+                SignMode::Key(key) => Some(key.clone()),
+                SignMode::Unavailable => None,
+                _ => anyhow::bail!(
+                    "The BFT consensus supports only the signing by the secret key"
+                ),
+            };
+            let bft_config = fuel_core_bft::Config {
+                chain_id,
+                validators: validators.clone(),
+                threshold: *threshold,
+                signing_key,
+                timeouts: config.bft_timeouts,
+            };
+            Some(fuel_core_bft::new_service(
+                &last_block_header,
+                bft_config,
+                producer_adapter.clone(),
+                executor.clone(),
+                importer_adapter.clone(),
+                p2p_adapter.clone(),
+            ))
+        }
+        _ => None,
+    };
+
     let predefined_blocks =
         InDirectoryPredefinedBlocks::new(config.predefined_blocks_path.clone());
     let poa = (production_enabled).then(|| {
@@ -298,6 +402,7 @@ pub fn init_sub_services(
         max_tx: config.txpool.pool_limits.max_txs,
         max_txpool_dependency_chain_length: config.txpool.max_txs_chain_count,
         chain_name,
+        read_only: false,
     };
 
     let graph_ql = fuel_core_graphql_api::api_service::new_service(
@@ -307,10 +412,14 @@ pub fn init_sub_services(
         database.on_chain().clone(),
         database.off_chain().clone(),
         Box::new(tx_pool_adapter),
+        Box::new(tx_pool_blacklist_adapter),
         Box::new(producer_adapter),
         Box::new(poa_adapter.clone()),
+        Box::new(importer_adapter.clone()),
         Box::new(p2p_adapter),
         Box::new(gas_price_provider),
+        Box::new(database.gas_price().clone()),
+        Box::new(database.clone()),
         Box::new(consensus_parameters_provider),
         SharedMemoryPool::new(config.memory_pool_size),
     )?;
@@ -332,7 +441,7 @@ pub fn init_sub_services(
     #[allow(unused_mut)]
     // `FuelService` starts and shutdowns all sub-services in the `services` order
     let mut services: SubServices = vec![
-        Box::new(gas_price_service_v0),
+        gas_price_service,
         Box::new(txpool),
         Box::new(consensus_parameters_provider_service),
     ];
@@ -341,6 +450,10 @@ pub fn init_sub_services(
         services.push(Box::new(poa));
     }
 
+    if let Some(bft) = bft {
+        services.push(Box::new(bft));
+    }
+
     #[cfg(feature = "relayer")]
     if let Some(relayer) = relayer_service {
         services.push(Box::new(relayer));
@@ -359,3 +472,132 @@ pub fn init_sub_services(
 
     Ok((services, shared))
 }
+
+/// Initializes the read replica. It serves the GraphQL API from the databases
+/// following the primary node, without the transaction pool, block production,
+/// synchronization and importer services.
+pub fn init_read_replica_sub_services(
+    config: &Config,
+    database: CombinedDatabase,
+) -> anyhow::Result<(SubServices, SharedState)> {
+    let read_replica = config
+        .combined_db_config
+        .read_replica
+        .clone()
+        .ok_or_else(|| anyhow::anyhow!("The read replica is not configured"))?;
+    let chain_config = config.snapshot_reader.chain_config();
+    let chain_id = chain_config.consensus_parameters.chain_id();
+    let chain_name = chain_config.chain_name.clone();
+    let on_chain_view = database.on_chain().latest_view()?;
+
+    let last_height = on_chain_view.maybe_latest_height()?.ok_or_else(|| {
+        anyhow::anyhow!("The primary node hasn't initialized the genesis yet")
+    })?;
+    let genesis_block = on_chain_view
+        .genesis_block()?
+        .unwrap_or(create_genesis_block(config).compress(&chain_id));
+
+    let executor = ExecutorAdapter::new(
+        database.on_chain().clone(),
+        database.relayer().clone(),
+        fuel_core_upgradable_executor::config::Config {
+            backtrace: config.vm.backtrace,
+            utxo_validation_default: config.utxo_validation,
+            native_executor_version: config.native_executor_version,
+        },
+    );
+    let verifier = VerifierAdapter::new(
+        &genesis_block,
+        chain_config.consensus.clone(),
+        chain_id,
+        database.on_chain().clone(),
+    );
+    // The importer is never used to import blocks, it is a part of the shared state.
+    let importer_adapter = BlockImporterAdapter::new(
+        chain_id,
+        config.block_importer.clone(),
+        database.on_chain().clone(),
+        executor.clone(),
+        verifier,
+    );
+
+    let consensus_parameters_shared_state =
+        consensus_parameters_provider::SharedState::new(database.on_chain().clone());
+    consensus_parameters_shared_state.refresh_latest_consensus_parameters()?;
+    let consensus_parameters_provider =
+        ConsensusParametersProvider::new(consensus_parameters_shared_state.clone());
+
+    #[cfg(feature = "p2p")]
+    let p2p_adapter = P2PAdapter::new(None, Default::default());
+    #[cfg(not(feature = "p2p"))]
+    let p2p_adapter = P2PAdapter::new();
+
+    // The transaction pool is not started, its shared state rejects all requests.
+    let txpool = fuel_core_txpool::new_service(
+        chain_id,
+        config.txpool.clone(),
+        p2p_adapter,
+        importer_adapter.clone(),
+        database.on_chain().clone(),
+        consensus_parameters_provider.clone(),
+        last_height,
+        StaticGasPrice::new(0),
+        executor.clone(),
+    );
+
+    let schema =
+        crate::schema::dap::init(build_schema(), false).data(database.on_chain().clone());
+    let graphql_config = GraphQLConfig {
+        config: config.graphql_config.clone(),
+        utxo_validation: config.utxo_validation,
+        debug: false,
+        vm_backtrace: config.vm.backtrace,
+        max_tx: config.txpool.pool_limits.max_txs,
+        max_txpool_dependency_chain_length: config.txpool.max_txs_chain_count,
+        chain_name,
+        read_only: true,
+    };
+
+    let graph_ql = fuel_core_graphql_api::api_service::new_service(
+        *genesis_block.header().height(),
+        graphql_config,
+        schema,
+        database.on_chain().clone(),
+        database.off_chain().clone(),
+        Box::new(ReadReplicaAdapter),
+        Box::new(ReadReplicaAdapter),
+        Box::new(ReadReplicaAdapter),
+        Box::new(ReadReplicaAdapter),
+        Box::new(ReadReplicaAdapter),
+        Box::new(ReadReplicaAdapter),
+        Box::new(ReadReplicaAdapter),
+        Box::new(database.gas_price().clone()),
+        Box::new(ReadReplicaAdapter),
+        Box::new(consensus_parameters_provider),
+        SharedMemoryPool::new(config.memory_pool_size),
+    )?;
+
+    let read_replica_service = read_replica::new_service(
+        database.clone(),
+        consensus_parameters_shared_state,
+        read_replica.catch_up_interval,
+    );
+
+    let shared = SharedState {
+        poa_adapter: PoAAdapter::new(None),
+        txpool_shared_state: txpool.shared.clone(),
+        #[cfg(feature = "p2p")]
+        network: None,
+        #[cfg(feature = "relayer")]
+        relayer: None,
+        graph_ql: graph_ql.shared.clone(),
+        database,
+        block_importer: importer_adapter,
+        executor,
+        config: config.clone(),
+    };
+
+    let services: SubServices = vec![Box::new(read_replica_service), Box::new(graph_ql)];
+
+    Ok((services, shared))
+}
@@ -15,7 +15,10 @@ use fuel_core_storage::{
     transactional::Changes,
     Result as StorageResult,
 };
-use std::fmt::Debug;
+use std::{
+    fmt::Debug,
+    path::Path,
+};
 
 pub mod data_source;
 pub mod generic_database;
@@ -61,11 +64,22 @@ pub trait TransactableStorage<Height>: IterableStore + Debug + Send + Sync {
     fn view_at_height(
         &self,
         height: &Height,
-    ) -> StorageResult<KeyValueView<Self::Column>>;
+    ) -> StorageResult<IterableKeyValueView<Self::Column>>;
 
     fn latest_view(&self) -> StorageResult<IterableKeyValueView<Self::Column>>;
 
     fn rollback_block_to(&self, height: &Height) -> StorageResult<()>;
+
+    /// Applies the changes made by the primary instance of the storage.
+    /// Only the secondary instances follow the primary, it is a no-op for others.
+    fn catch_up_with_primary(&self) -> StorageResult<()> {
+        Ok(())
+    }
+
+    /// Creates the checkpoint of the storage in the `path`.
+    fn create_checkpoint(&self, _path: &Path) -> StorageResult<()> {
+        Err(anyhow::anyhow!("The storage doesn't support checkpoints").into())
+    }
 }
 
 // It is used only to allow conversion of the `StorageTransaction` into the `DataSource`.
@@ -79,7 +93,10 @@ where
         unimplemented!()
     }
 
-    fn view_at_height(&self, _: &Height) -> StorageResult<KeyValueView<Self::Column>> {
+    fn view_at_height(
+        &self,
+        _: &Height,
+    ) -> StorageResult<IterableKeyValueView<Self::Column>> {
         unimplemented!()
     }
 
@@ -17,6 +17,8 @@ use fuel_core_storage::{
     MerkleRootStorage,
     PredicateStorageRequirements,
     Result as StorageResult,
+    SparseMerkleProof,
+    SparseMerkleProofStorage,
     StorageAsRef,
     StorageInspect,
     StorageRead,
@@ -98,6 +100,16 @@ where
     }
 }
 
+impl<M, Storage> SparseMerkleProofStorage<M> for GenericDatabase<Storage>
+where
+    M: Mappable,
+    StructuredStorage<Storage>: SparseMerkleProofStorage<M, Error = StorageError>,
+{
+    fn proof(&self, key: &M::Key) -> StorageResult<SparseMerkleProof> {
+        <_ as SparseMerkleProofStorage<M>>::proof(&self.storage, key)
+    }
+}
+
 impl<Storage> KeyValueInspect for GenericDatabase<Storage>
 where
     Storage: KeyValueInspect,
@@ -17,11 +17,12 @@ use crate::{
             view_at_height::ViewAtHeight,
         },
         iterable_key_value_view::IterableKeyValueViewWrapper,
-        key_value_view::KeyValueViewWrapper,
-        rocks_db::RocksDb,
+        rocks_db::{
+            ColumnStatistics,
+            RocksDb,
+        },
         ColumnType,
         IterableKeyValueView,
-        KeyValueView,
         TransactableStorage,
     },
 };
@@ -118,6 +119,67 @@ where
         })
     }
 
+    /// Opens the secondary instance following the database at the `path`.
+    /// The secondary instance keeps its own files at the `secondary_path`.
+    pub fn open_secondary<PrimaryPath, SecondaryPath>(
+        path: PrimaryPath,
+        secondary_path: SecondaryPath,
+        capacity: Option<usize>,
+        max_fds: i32,
+    ) -> DatabaseResult<Self>
+    where
+        PrimaryPath: AsRef<Path>,
+        SecondaryPath: AsRef<Path>,
+    {
+        let columns = enum_iterator::all::<Column<Description>>().collect::<Vec<_>>();
+        let db = RocksDb::<Historical<Description>>::open_secondary(
+            path,
+            secondary_path,
+            columns,
+            capacity,
+            max_fds,
+        )?;
+        // The secondary instance never writes, so the history is not tracked.
+        Ok(Self {
+            state_rewind_policy: StateRewindPolicy::NoRewind,
+            db,
+        })
+    }
+
+    /// Opens the database at the `path` in the read-only mode.
+    /// The database can be opened while the node uses it.
+    pub fn open_read_only<P: AsRef<Path>>(
+        path: P,
+        capacity: Option<usize>,
+        max_fds: i32,
+    ) -> DatabaseResult<Self> {
+        let columns = enum_iterator::all::<Column<Description>>().collect::<Vec<_>>();
+        let db = RocksDb::<Historical<Description>>::open_read_only(
+            path, columns, capacity, false, max_fds,
+        )?;
+        Ok(Self {
+            state_rewind_policy: StateRewindPolicy::NoRewind,
+            db,
+        })
+    }
+
+    /// Returns the statistics of the `column`, see [`RocksDb::column_statistics`].
+    pub fn column_statistics(
+        &self,
+        column: Column<Description>,
+        count_keys: bool,
+    ) -> StorageResult<ColumnStatistics> {
+        self.db.column_statistics(column, count_keys)
+    }
+
+    /// Returns the oldest and the latest heights of the modifications history.
+    /// The database can be rolled back to any height in this range.
+    pub fn modifications_history_range(&self) -> StorageResult<Option<(u64, u64)>> {
+        let oldest_height = self.oldest_changes_height()?;
+        let latest_height = self.latest_changes_height()?;
+        Ok(oldest_height.zip(latest_height))
+    }
+
     fn reverse_history_changes(&self, changes: &Changes) -> StorageResult<Changes> {
         let mut reverse_changes = Changes::default();
 
@@ -309,6 +371,25 @@ where
         Ok(oldest_height)
     }
 
+    fn latest_changes_height(&self) -> StorageResult<Option<u64>> {
+        let modifications_history_migration_in_progress = self.is_migration_in_progress();
+
+        let (v2_latest_height, v1_latest_height) = self.multiversion_changes_heights(
+            IterDirection::Reverse,
+            modifications_history_migration_in_progress,
+        );
+
+        let v2_latest_height = v2_latest_height.transpose()?;
+        let v1_latest_height = v1_latest_height.transpose()?;
+
+        let latest_height = match (v1_latest_height, v2_latest_height) {
+            (None, v2) => v2,
+            (v1, None) => v1,
+            (Some(v1), Some(v2)) => Some(v1.max(v2)),
+        };
+        Ok(latest_height)
+    }
+
     #[cfg(test)]
     fn rollback_last_block(&self) -> StorageResult<u64> {
         let modifications_history_migration_in_progress = self.is_migration_in_progress();
@@ -593,9 +674,11 @@ where
     fn view_at_height(
         &self,
         height: &Description::Height,
-    ) -> StorageResult<KeyValueView<ColumnType<Description>>> {
+    ) -> StorageResult<IterableKeyValueView<ColumnType<Description>>> {
         let view = self.create_view_at(height)?;
-        Ok(KeyValueView::from_storage(KeyValueViewWrapper::new(view)))
+        Ok(IterableKeyValueView::from_storage(
+            IterableKeyValueViewWrapper::new(view),
+        ))
     }
 
     fn latest_view(
@@ -610,6 +693,16 @@ where
     fn rollback_block_to(&self, height: &Description::Height) -> StorageResult<()> {
         self.rollback_block_to(height.as_u64())
     }
+
+    fn catch_up_with_primary(&self) -> StorageResult<()> {
+        self.db.try_catch_up_with_primary()?;
+        Ok(())
+    }
+
+    fn create_checkpoint(&self, path: &Path) -> StorageResult<()> {
+        self.db.create_checkpoint(path)?;
+        Ok(())
+    }
 }
 
 pub fn height_key(key: &[u8], height: &u64) -> Vec<u8> {
@@ -10,13 +10,22 @@ use crate::{
             height_key,
         },
         rocks_db::{
+            next_prefix,
             KeyAndValue,
             RocksDb,
         },
     },
 };
 use fuel_core_storage::{
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IterDirection,
+        IterableStore,
+    },
     kv_store::{
+        KVItem,
+        KeyItem,
         KeyValueInspect,
         Value,
         WriteOperation,
@@ -27,6 +36,13 @@ use rocksdb::{
     IteratorMode,
     ReadOptions,
 };
+use std::{
+    cmp::Ordering,
+    iter::Peekable,
+};
+
+/// The size of the height suffix of the keys in the historical duplicate columns.
+const HEIGHT_SUFFIX_SIZE: usize = core::mem::size_of::<u64>();
 
 pub struct ViewAtHeight<Description> {
     height: u64,
@@ -76,7 +92,7 @@ where
                 return match value {
                     WriteOperation::Insert(value) => Ok(Some(value)),
                     WriteOperation::Remove => Ok(None),
-                }
+                };
             }
         }
 
@@ -84,6 +100,235 @@ where
     }
 }
 
+impl<Description> ViewAtHeight<Description>
+where
+    Description: DatabaseDescription,
+{
+    /// Returns an iterator over the reverse modifications of the `column`
+    /// that are required to restore the state at the height of the view.
+    /// The iterator yields at most one modification per key, in the order of keys.
+    fn historical_modifications(
+        &self,
+        column: Description::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(Vec<u8>, WriteOperation)>> {
+        if let (Some(prefix), Some(start)) = (prefix, start) {
+            if !start.starts_with(prefix) {
+                return core::iter::empty().into_boxed()
+            }
+        }
+
+        // Keys in the historical duplicate column are `key ++ height`, so all modifications
+        // of the same key are grouped together and sorted by the height in ascending order.
+        let seek_key = match direction {
+            IterDirection::Forward => start.or(prefix).map(<[u8]>::to_vec),
+            IterDirection::Reverse => match (prefix, start) {
+                (_, Some(start)) => Some(height_key(start, &u64::MAX)),
+                (Some(prefix), None) => next_prefix(prefix.to_vec()),
+                (None, None) => None,
+            },
+        };
+        let iter_mode = match (&seek_key, direction) {
+            (Some(key), IterDirection::Forward) => {
+                IteratorMode::From(key.as_slice(), rocksdb::Direction::Forward)
+            }
+            (Some(key), IterDirection::Reverse) => {
+                IteratorMode::From(key.as_slice(), rocksdb::Direction::Reverse)
+            }
+            (None, IterDirection::Forward) => IteratorMode::Start,
+            (None, IterDirection::Reverse) => IteratorMode::End,
+        };
+
+        let mut options = self.read_db.read_options();
+        // The prefix extractor of the historical duplicate column includes the height,
+        // so we need to ignore it to iterate over several keys.
+        options.set_total_order_seek(true);
+
+        let prefix = prefix.map(<[u8]>::to_vec);
+        let height = self.height;
+        let modifications = self
+            .read_db
+            .iterator::<KeyAndValue>(
+                Column::HistoricalDuplicateColumn(column),
+                options,
+                iter_mode,
+            )
+            .take_while(move |item| match (item, &prefix) {
+                (Ok((key, _)), Some(prefix)) => key.starts_with(prefix),
+                _ => true,
+            })
+            .filter_map(move |item| {
+                let (height_key, value) = match item {
+                    Ok(item) => item,
+                    Err(err) => return Some(Err(err)),
+                };
+                let key_len = height_key.len().checked_sub(HEIGHT_SUFFIX_SIZE)?;
+                let (key, modification_height) = height_key.split_at(key_len);
+                let modification_height = u64::from_be_bytes(
+                    modification_height
+                        .try_into()
+                        .expect("The slice has the size of the `u64`; qed"),
+                );
+
+                // Modifications below the height of the view were already applied.
+                if modification_height < height {
+                    return None
+                }
+
+                Some(
+                    deserialize::<WriteOperation>(&value)
+                        .map(|operation| (key.to_vec(), modification_height, operation)),
+                )
+            });
+
+        NearestModifications {
+            inner: modifications.peekable(),
+        }
+        .into_boxed()
+    }
+}
+
+/// Selects the nearest to the view height modification for each key.
+struct NearestModifications<I>
+where
+    I: Iterator<Item = StorageResult<(Vec<u8>, u64, WriteOperation)>>,
+{
+    inner: Peekable<I>,
+}
+
+impl<I> Iterator for NearestModifications<I>
+where
+    I: Iterator<Item = StorageResult<(Vec<u8>, u64, WriteOperation)>>,
+{
+    type Item = StorageResult<(Vec<u8>, WriteOperation)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, mut height, mut operation) = match self.inner.next()? {
+            Ok(item) => item,
+            Err(err) => return Some(Err(err)),
+        };
+
+        while let Some(Ok((next_key, next_height, _))) = self.inner.peek() {
+            if next_key != &key {
+                break
+            }
+
+            if *next_height < height {
+                height = *next_height;
+                let (_, _, next_operation) = self
+                    .inner
+                    .next()
+                    .expect("The item was peeked above; qed")
+                    .expect("The item is `Ok`; qed");
+                operation = next_operation;
+            } else {
+                self.inner.next();
+            }
+        }
+
+        Some(Ok((key, operation)))
+    }
+}
+
+/// Merges the latest state of the column with the historical modifications,
+/// producing the state of the column at the height of the view.
+struct MergedIterator<'a> {
+    latest: Peekable<BoxedIter<'a, KVItem>>,
+    modifications: Peekable<BoxedIter<'a, StorageResult<(Vec<u8>, WriteOperation)>>>,
+    direction: IterDirection,
+}
+
+impl<'a> Iterator for MergedIterator<'a> {
+    type Item = KVItem;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let ordering = match (self.latest.peek(), self.modifications.peek()) {
+                (None, None) => return None,
+                (Some(Err(_)), _) | (Some(Ok(_)), None) => return self.latest.next(),
+                (_, Some(Err(_))) => {
+                    let Some(Err(err)) = self.modifications.next() else {
+                        unreachable!("The error was peeked above; qed")
+                    };
+                    return Some(Err(err))
+                }
+                (None, Some(Ok(_))) => Ordering::Greater,
+                (Some(Ok((latest_key, _))), Some(Ok((modified_key, _)))) => {
+                    let ordering = latest_key.cmp(modified_key);
+                    match self.direction {
+                        IterDirection::Forward => ordering,
+                        IterDirection::Reverse => ordering.reverse(),
+                    }
+                }
+            };
+
+            match ordering {
+                Ordering::Less => return self.latest.next(),
+                Ordering::Equal => {
+                    // The historical modification overrides the latest value.
+                    self.latest.next();
+                }
+                Ordering::Greater => {}
+            }
+
+            let (key, operation) = self
+                .modifications
+                .next()
+                .expect("The item was peeked above; qed")
+                .expect("The item is `Ok`; qed");
+
+            match operation {
+                WriteOperation::Insert(value) => return Some(Ok((key, value))),
+                // The key didn't exist at the height of the view.
+                WriteOperation::Remove => continue,
+            }
+        }
+    }
+}
+
+impl<Description> IterableStore for ViewAtHeight<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn iter_store(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        let latest = self.read_db.iter_store(
+            Column::OriginalColumn(column),
+            prefix,
+            start,
+            direction,
+        );
+        let modifications =
+            self.historical_modifications(column, prefix, start, direction);
+
+        MergedIterator {
+            latest: latest.peekable(),
+            modifications: modifications.peekable(),
+            direction,
+        }
+        .into_boxed()
+    }
+
+    fn iter_store_keys(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KeyItem> {
+        self.iter_store(column, prefix, start, direction)
+            .map(|item| item.map(|(key, _)| key))
+            .into_boxed()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -98,6 +343,7 @@ mod tests {
         },
     };
     use fuel_core_storage::{
+        iter::IteratorOverTable,
         tables::ContractsAssets,
         transactional::{
             IntoTransaction,
@@ -267,4 +513,132 @@ mod tests {
         assert_eq!(balance_at_height_two, 321);
         assert_eq!(balance_at_height_three, 321);
     }
+
+    fn asset_key(contract: u8, asset: u8) -> ContractsAssetKey {
+        ContractsAssetKey::new(&[contract; 32].into(), &[asset; 32].into())
+    }
+
+    fn balances_at(
+        historical_rocks_db: &HistoricalRocksDB<OnChain>,
+        height: u32,
+        prefix: Option<u8>,
+        direction: IterDirection,
+    ) -> Vec<(ContractsAssetKey, u64)> {
+        let view = historical_rocks_db.view_at_height(&height.into()).unwrap();
+        let prefix = prefix.map(|contract| [contract; 32]);
+        view.iter_all_filtered::<ContractsAssets, _>(prefix, None, Some(direction))
+            .map(|result| result.unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn historical_rocksdb_view_at_each_height_iterates_over_historical_state() {
+        // Given
+        let rocks_db = RocksDb::<Historical<OnChain>>::default_open_temp(None).unwrap();
+        let historical_rocks_db =
+            HistoricalRocksDB::new(rocks_db, StateRewindPolicy::RewindFullRange).unwrap();
+
+        // At height 1 insert balances for two assets of two contracts.
+        let mut transaction = historical_rocks_db.read_transaction();
+        for (contract, asset, amount) in [(1, 1, 11), (1, 2, 12), (2, 1, 21), (2, 2, 22)]
+        {
+            transaction
+                .storage_as_mut::<ContractsAssets>()
+                .insert(&asset_key(contract, asset), &amount)
+                .unwrap();
+        }
+        historical_rocks_db
+            .commit_changes(Some(1u32.into()), transaction.into_changes())
+            .unwrap();
+
+        // At height 2 update one balance, remove another one and add a new one.
+        let mut transaction = historical_rocks_db.read_transaction();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&asset_key(1, 1), &111)
+            .unwrap();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .remove(&asset_key(1, 2))
+            .unwrap();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&asset_key(1, 3), &13)
+            .unwrap();
+        historical_rocks_db
+            .commit_changes(Some(2u32.into()), transaction.into_changes())
+            .unwrap();
+
+        // At height 3 remove the balance of the first contract.
+        let mut transaction = historical_rocks_db.read_transaction();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .remove(&asset_key(1, 1))
+            .unwrap();
+        historical_rocks_db
+            .commit_changes(Some(3u32.into()), transaction.into_changes())
+            .unwrap();
+
+        // When
+        let forward_at_zero =
+            balances_at(&historical_rocks_db, 0, None, IterDirection::Forward);
+        let forward_at_one =
+            balances_at(&historical_rocks_db, 1, None, IterDirection::Forward);
+        let forward_at_two =
+            balances_at(&historical_rocks_db, 2, None, IterDirection::Forward);
+        let forward_at_three =
+            balances_at(&historical_rocks_db, 3, None, IterDirection::Forward);
+        let reverse_at_one =
+            balances_at(&historical_rocks_db, 1, None, IterDirection::Reverse);
+        let prefix_at_two =
+            balances_at(&historical_rocks_db, 2, Some(1), IterDirection::Forward);
+        let reverse_prefix_at_two =
+            balances_at(&historical_rocks_db, 2, Some(1), IterDirection::Reverse);
+
+        // Then
+        assert_eq!(forward_at_zero, vec![]);
+        assert_eq!(
+            forward_at_one,
+            vec![
+                (asset_key(1, 1), 11),
+                (asset_key(1, 2), 12),
+                (asset_key(2, 1), 21),
+                (asset_key(2, 2), 22),
+            ]
+        );
+        assert_eq!(
+            forward_at_two,
+            vec![
+                (asset_key(1, 1), 111),
+                (asset_key(1, 3), 13),
+                (asset_key(2, 1), 21),
+                (asset_key(2, 2), 22),
+            ]
+        );
+        assert_eq!(
+            forward_at_three,
+            vec![
+                (asset_key(1, 3), 13),
+                (asset_key(2, 1), 21),
+                (asset_key(2, 2), 22),
+            ]
+        );
+        assert_eq!(
+            reverse_at_one,
+            vec![
+                (asset_key(2, 2), 22),
+                (asset_key(2, 1), 21),
+                (asset_key(1, 2), 12),
+                (asset_key(1, 1), 11),
+            ]
+        );
+        assert_eq!(
+            prefix_at_two,
+            vec![(asset_key(1, 1), 111), (asset_key(1, 3), 13)]
+        );
+        assert_eq!(
+            reverse_prefix_at_two,
+            vec![(asset_key(1, 3), 13), (asset_key(1, 1), 111)]
+        );
+    }
 }
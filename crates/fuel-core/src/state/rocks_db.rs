@@ -95,12 +95,27 @@ impl Drop for DropResources {
     }
 }
 
+/// The statistics of the column reported by RocksDB.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ColumnStatistics {
+    /// The number of keys estimated by RocksDB.
+    pub estimated_keys: u64,
+    /// The exact number of keys, if it was counted.
+    pub keys: Option<u64>,
+    /// The total size of the SST files of the column in bytes.
+    pub sst_files_size: u64,
+}
+
 pub struct RocksDb<Description> {
     read_options: ReadOptions,
     db: Arc<DB>,
     create_family: Arc<Mutex<BTreeMap<String, Options>>>,
     snapshot: Option<rocksdb::SnapshotWithThreadMode<'static, DB>>,
     metrics: Arc<DatabaseMetrics>,
+    /// The secondary instance follows the primary and doesn't support snapshots.
+    secondary: bool,
+    /// The read-only instance can't create the missing column families.
+    read_only: bool,
     // used for RAII
     _drop: Arc<DropResources>,
     _marker: core::marker::PhantomData<Description>,
@@ -184,7 +199,7 @@ where
         error_if_log_file_exist: bool,
         max_fds: i32,
     ) -> DatabaseResult<Self> {
-        Self::open_with(
+        let mut db = Self::open_with(
             |options, primary_path, cfs| {
                 DB::open_cf_descriptors_read_only(
                     options,
@@ -197,7 +212,9 @@ where
             columns,
             capacity,
             max_fds,
-        )
+        )?;
+        db.read_only = true;
+        Ok(db)
     }
 
     pub fn open_secondary<PrimaryPath, SecondaryPath>(
@@ -211,12 +228,14 @@ where
         PrimaryPath: AsRef<Path>,
         SecondaryPath: AsRef<Path>,
     {
-        Self::open_with(
+        // Each database requires its own directory for the secondary instance.
+        let secondary_path = secondary_path.as_ref().join(Description::name());
+        let mut db = Self::open_with(
             |options, primary_path, cfs| {
                 DB::open_cf_descriptors_as_secondary(
                     options,
                     primary_path,
-                    secondary_path.as_ref().to_path_buf(),
+                    secondary_path.clone(),
                     cfs,
                 )
             },
@@ -224,7 +243,69 @@ where
             columns,
             capacity,
             max_fds,
-        )
+        )?;
+        db.secondary = true;
+        Ok(db)
+    }
+
+    /// Applies the changes made by the primary instance since the last catch-up.
+    /// It is a no-op for the primary and read-only instances.
+    pub fn try_catch_up_with_primary(&self) -> DatabaseResult<()> {
+        if !self.secondary {
+            return Ok(())
+        }
+        self.db
+            .try_catch_up_with_primary()
+            .map_err(|e| DatabaseError::Other(e.into()))
+    }
+
+    /// Creates the checkpoint of the database in the `path`. The checkpoint
+    /// hard-links the SST files if the `path` is on the same filesystem.
+    /// The database is stored in the subdirectory, the same as for [`Self::open`].
+    pub fn create_checkpoint(&self, path: &Path) -> DatabaseResult<()> {
+        if self.secondary {
+            return Err(DatabaseError::Other(anyhow::anyhow!(
+                "The secondary instance doesn't support checkpoints"
+            )))
+        }
+        let checkpoint = rocksdb::checkpoint::Checkpoint::new(&self.db)
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        checkpoint
+            .create_checkpoint(path.join(Description::name()))
+            .map_err(|e| DatabaseError::Other(e.into()))
+    }
+
+    /// Returns the statistics of the `column`. The exact number of keys
+    /// requires iteration over the whole column, so it is counted only on demand.
+    pub fn column_statistics(
+        &self,
+        column: Description::Column,
+        count_keys: bool,
+    ) -> StorageResult<ColumnStatistics> {
+        let family = self.cf(column);
+        let int_property = |name| {
+            self.db
+                .property_int_value_cf(&family, name)
+                .map(Option::unwrap_or_default)
+                .map_err(|e| DatabaseError::Other(e.into()))
+        };
+        let estimated_keys = int_property(rocksdb::properties::ESTIMATE_NUM_KEYS)?;
+        let sst_files_size = int_property(rocksdb::properties::TOTAL_SST_FILES_SIZE)?;
+
+        let keys = if count_keys {
+            let keys = self
+                .iter_store_keys(column, None, None, IterDirection::Forward)
+                .try_fold(0u64, |keys, key| key.map(|_| keys.saturating_add(1)))?;
+            Some(keys)
+        } else {
+            None
+        };
+
+        Ok(ColumnStatistics {
+            estimated_keys,
+            keys,
+            sst_files_size,
+        })
     }
 
     pub fn open_with<F, P>(
@@ -356,6 +437,8 @@ where
             snapshot: None,
             db,
             metrics,
+            secondary: false,
+            read_only: false,
             create_family,
             _drop: Default::default(),
             _marker: Default::default(),
@@ -375,7 +458,7 @@ where
         opts
     }
 
-    fn read_options(&self) -> ReadOptions {
+    pub(crate) fn read_options(&self) -> ReadOptions {
         Self::generate_read_options(&self.snapshot)
     }
 
@@ -390,6 +473,28 @@ where
         let create_family = self.create_family.clone();
         let metrics = self.metrics.clone();
         let _drop = self._drop.clone();
+        let secondary = self.secondary;
+        let read_only = self.read_only;
+
+        // The secondary instance doesn't support iteration over snapshots,
+        // so its views read the latest state applied by the catch-up.
+        // The views are not isolated from the catch-up: the reads after it see
+        // the new state, while the earlier reads of the same view saw the old one.
+        // The catch-up isn't blocked by the views, so a long-living view can't
+        // stop the replica from following the primary.
+        if secondary {
+            return RocksDb {
+                read_options: Self::generate_read_options(&None),
+                snapshot: None,
+                db,
+                create_family,
+                metrics,
+                secondary,
+                read_only,
+                _drop,
+                _marker: Default::default(),
+            }
+        }
 
         // Safety: We are transmuting the snapshot to 'static lifetime, but it's safe
         // because we are not going to use it after the RocksDb is dropped.
@@ -409,6 +514,8 @@ where
             db,
             create_family,
             metrics,
+            secondary,
+            read_only,
             _drop,
             _marker: Default::default(),
         }
@@ -422,6 +529,13 @@ where
         let family = self.db.cf_handle(&Self::col_name(column));
 
         match family {
+            // The secondary and read-only instances can't create the column family
+            // missing in the database. The default column family is always empty,
+            // so it is used instead.
+            None if self.secondary || self.read_only => self
+                .db
+                .cf_handle(rocksdb::DEFAULT_COLUMN_FAMILY_NAME)
+                .expect("The default column family is always opened"),
             None => {
                 let mut lock = self
                     .create_family
@@ -849,7 +963,7 @@ where
 }
 
 /// The `None` means overflow, so there is not following prefix.
-fn next_prefix(mut prefix: Vec<u8>) -> Option<Vec<u8>> {
+pub(crate) fn next_prefix(mut prefix: Vec<u8>) -> Option<Vec<u8>> {
     for byte in prefix.iter_mut().rev() {
         if let Some(new_byte) = byte.checked_add(1) {
             *byte = new_byte;
@@ -1153,6 +1267,61 @@ mod tests {
         assert_eq!(Ok(()), result);
     }
 
+    #[test]
+    fn secondary_db_sees_primary_changes_after_catch_up() {
+        let (mut primary_db, tmp_dir) = create_db();
+        let secondary_temp = TempDir::new().unwrap();
+        let columns = enum_iterator::all::<<OnChain as DatabaseDescription>::Column>()
+            .collect::<Vec<_>>();
+        let secondary_db = RocksDb::<OnChain>::open_secondary(
+            tmp_dir.path(),
+            secondary_temp.path(),
+            columns,
+            None,
+            512,
+        )
+        .unwrap();
+        let key = vec![0xA, 0xB, 0xC];
+        let value = Value::from([1, 2, 3]);
+
+        // Given
+        primary_db
+            .put(&key, Column::Metadata, value.clone())
+            .unwrap();
+        assert_eq!(secondary_db.get(&key, Column::Metadata).unwrap(), None);
+
+        // When
+        secondary_db.try_catch_up_with_primary().unwrap();
+
+        // Then
+        let view = secondary_db.create_snapshot();
+        assert_eq!(view.get(&key, Column::Metadata).unwrap(), Some(value));
+    }
+
+    #[test]
+    fn checkpoint_is_not_affected_by_later_changes() {
+        let (mut db, _tmp) = create_db();
+        let checkpoint_temp = TempDir::new().unwrap();
+        let key = vec![0xA, 0xB, 0xC];
+        let value = Value::from([1, 2, 3]);
+
+        // Given
+        db.put(&key, Column::Metadata, value.clone()).unwrap();
+
+        // When
+        db.create_checkpoint(checkpoint_temp.path()).unwrap();
+        db.delete(&key, Column::Metadata).unwrap();
+
+        // Then
+        let checkpoint_db =
+            RocksDb::<OnChain>::default_open(checkpoint_temp.path(), None, 512).unwrap();
+        assert_eq!(
+            checkpoint_db.get(&key, Column::Metadata).unwrap(),
+            Some(value)
+        );
+        assert_eq!(db.get(&key, Column::Metadata).unwrap(), None);
+    }
+
     #[test]
     fn snapshot_allows_get_entry_after_it_was_removed() {
         let (mut db, _tmp) = create_db();
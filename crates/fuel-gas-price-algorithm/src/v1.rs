@@ -623,11 +623,11 @@ impl AlgorithmUpdaterV1 {
             .saturating_add(projection_portion);
     }
 
-    fn descaled_exec_price(&self) -> u64 {
+    pub fn descaled_exec_price(&self) -> u64 {
         self.new_scaled_exec_price.div(self.gas_price_factor)
     }
 
-    fn descaled_da_price(&self) -> u64 {
+    pub fn descaled_da_price(&self) -> u64 {
         self.new_scaled_da_gas_price.div(self.gas_price_factor)
     }
 
@@ -0,0 +1,126 @@
+//! The encrypted keystore files in the Ethereum keystore (V3) format.
+//! The secret key is encrypted with AES-128-CTR by the key derived from
+//! the password with scrypt. Files derived with PBKDF2 are also readable.
+
+use anyhow::{
+    anyhow,
+    Context,
+};
+use fuel_core_types::fuel_crypto::{
+    rand::{
+        prelude::StdRng,
+        SeedableRng,
+    },
+    SecretKey,
+};
+use std::{
+    ops::Deref,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+/// Encrypts the `secret` with the `password` and writes it into the `dir` under
+/// the `name`, or under a random UUID if the name is not specified.
+/// Returns the path to the file.
+pub fn encrypt_secret(
+    dir: &Path,
+    name: Option<&str>,
+    secret: &SecretKey,
+    password: &str,
+) -> anyhow::Result<PathBuf> {
+    std::fs::create_dir_all(dir)?;
+    let mut rng = StdRng::from_entropy();
+    // The keystore returns the UUID of the key, which is the file name only
+    // if the name is not specified.
+    let id = eth_keystore::encrypt_key(dir, &mut rng, secret.deref(), password, name)
+        .with_context(|| {
+        format!("failed to write the keystore into {}", dir.display())
+    })?;
+    Ok(dir.join(name.unwrap_or(&id)))
+}
+
+/// Decrypts the secret key from the keystore file at the `path`.
+pub fn decrypt_secret(path: &Path, password: &str) -> anyhow::Result<SecretKey> {
+    let bytes = eth_keystore::decrypt_key(path, password)
+        .with_context(|| format!("failed to decrypt the keystore {}", path.display()))?;
+    SecretKey::try_from(bytes.as_slice()).map_err(|_| anyhow!("invalid secret key"))
+}
+
+/// Re-encrypts the keystore file at the `path` with the `new_password`.
+/// The file is replaced atomically, so the key isn't lost if the process is interrupted.
+pub fn change_password(
+    path: &Path,
+    password: &str,
+    new_password: &str,
+) -> anyhow::Result<()> {
+    let secret = decrypt_secret(path, password)?;
+    let dir = path.parent().unwrap_or(Path::new(""));
+    let name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| anyhow!("invalid keystore path {}", path.display()))?;
+    let tmp = encrypt_secret(dir, Some(&format!("{name}.tmp")), &secret, new_password)?;
+    std::fs::rename(tmp, path)?;
+    Ok(())
+}
+
+/// Reads the password from the file, ignoring the trailing line break.
+pub fn read_password_file(path: &Path) -> anyhow::Result<String> {
+    let password = std::fs::read_to_string(path).with_context(|| {
+        format!("failed to read the password file {}", path.display())
+    })?;
+    Ok(password.trim_end_matches(['\r', '\n']).to_string())
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decrypt_secret__returns_encrypted_secret() {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        let secret = SecretKey::random(&mut StdRng::seed_from_u64(1));
+        let path = encrypt_secret(dir.path(), Some("key"), &secret, "password").unwrap();
+
+        // When
+        let decrypted = decrypt_secret(&path, "password").unwrap();
+
+        // Then
+        assert_eq!(path, dir.path().join("key"));
+        assert_eq!(decrypted, secret);
+    }
+
+    #[test]
+    fn decrypt_secret__fails_with_wrong_password() {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        let secret = SecretKey::random(&mut StdRng::seed_from_u64(2));
+        let path = encrypt_secret(dir.path(), None, &secret, "password").unwrap();
+
+        // When
+        let result = decrypt_secret(&path, "wrong");
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn change_password__replaces_password_of_keystore() {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        let secret = SecretKey::random(&mut StdRng::seed_from_u64(3));
+        let path = encrypt_secret(dir.path(), Some("key"), &secret, "old").unwrap();
+
+        // When
+        change_password(&path, "old", "new").unwrap();
+
+        // Then
+        assert!(decrypt_secret(&path, "old").is_err());
+        assert_eq!(decrypt_secret(&path, "new").unwrap(), secret);
+        assert!(!dir.path().join("key.tmp").exists());
+    }
+}
@@ -21,9 +21,15 @@ use libp2p_identity::{
 use serde::Serialize;
 use std::{
     ops::Deref,
+    path::{
+        Path,
+        PathBuf,
+    },
     str::FromStr,
 };
 
+pub mod keystore;
+
 #[derive(Clone, Copy, Debug, Default, Serialize, ValueEnum)]
 #[serde(rename_all = "kebab-case")]
 pub enum KeyType {
@@ -68,6 +74,13 @@ pub struct NewKeyResponse {
     typ: KeyType,
 }
 
+#[derive(Clone, Debug, Serialize)]
+pub struct KeystoreResponse {
+    keystore: PathBuf,
+    #[serde(flatten)]
+    key: ParseSecretResponse,
+}
+
 fn serialize_option_to_string<S, T>(
     opt: &Option<T>,
     serializer: S,
@@ -115,13 +128,79 @@ pub fn new_key(key_type: KeyType) -> anyhow::Result<NewKeyResponse> {
     })
 }
 
+/// Parses the secret key in hex format.
+pub fn parse_secret_key(secret: &str) -> anyhow::Result<SecretKey> {
+    SecretKey::from_str(secret).map_err(|_| anyhow::anyhow!("invalid secret key"))
+}
+
 pub fn parse_secret(
     key_type: KeyType,
     secret: &str,
 ) -> anyhow::Result<ParseSecretResponse> {
-    let secret =
-        SecretKey::from_str(secret).map_err(|_| anyhow::anyhow!("invalid secret key"))?;
-    Ok(match key_type {
+    let secret = parse_secret_key(secret)?;
+    Ok(describe_secret(key_type, &secret))
+}
+
+/// Generates a new key and stores it in the `dir` encrypted with the `password`.
+pub fn new_keystore(
+    key_type: KeyType,
+    dir: &Path,
+    name: Option<&str>,
+    password: &str,
+) -> anyhow::Result<KeystoreResponse> {
+    let mut rng = StdRng::from_entropy();
+    let secret = SecretKey::random(&mut rng);
+    store_secret(key_type, &secret, dir, name, password)
+}
+
+/// Stores the existing `secret` in the `dir` encrypted with the `password`.
+pub fn import_secret(
+    key_type: KeyType,
+    secret: &str,
+    dir: &Path,
+    name: Option<&str>,
+    password: &str,
+) -> anyhow::Result<KeystoreResponse> {
+    let secret = parse_secret_key(secret)?;
+    store_secret(key_type, &secret, dir, name, password)
+}
+
+/// Decrypts the secret from the keystore file at the `path`.
+pub fn export_secret(
+    key_type: KeyType,
+    path: &Path,
+    password: &str,
+) -> anyhow::Result<NewKeyResponse> {
+    let secret = keystore::decrypt_secret(path, password)?;
+    let ParseSecretResponse {
+        address,
+        peer_id,
+        typ,
+    } = describe_secret(key_type, &secret);
+    Ok(NewKeyResponse {
+        secret,
+        address,
+        peer_id,
+        typ,
+    })
+}
+
+fn store_secret(
+    key_type: KeyType,
+    secret: &SecretKey,
+    dir: &Path,
+    name: Option<&str>,
+    password: &str,
+) -> anyhow::Result<KeystoreResponse> {
+    let keystore = keystore::encrypt_secret(dir, name, secret, password)?;
+    Ok(KeystoreResponse {
+        keystore,
+        key: describe_secret(key_type, secret),
+    })
+}
+
+fn describe_secret(key_type: KeyType, secret: &SecretKey) -> ParseSecretResponse {
+    match key_type {
         KeyType::BlockProduction => {
             let address = Input::owner(&secret.public_key());
             ParseSecretResponse {
@@ -143,5 +222,5 @@ pub fn parse_secret(
                 typ: key_type,
             }
         }
-    })
+    }
 }
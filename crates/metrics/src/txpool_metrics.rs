@@ -6,6 +6,7 @@ use crate::{
     global_registry,
 };
 use prometheus_client::metrics::{
+    counter::Counter,
     gauge::Gauge,
     histogram::Histogram,
 };
@@ -26,6 +27,16 @@ pub struct TxPoolMetrics {
     pub transaction_insertion_time_in_thread_pool_microseconds: Histogram,
     /// How long it took for the selection algorithm to select transactions
     pub select_transactions_time_microseconds: Histogram,
+    /// Number of transactions rejected because their owner hit the limit of transactions
+    pub owner_tx_count_limit_rejections: Counter,
+    /// Number of transactions rejected because their owner hit the limit of gas
+    pub owner_gas_limit_rejections: Counter,
+    /// Number of gossiped transactions rejected because their peer hit the limit of pending transactions
+    pub peer_tx_count_limit_rejections: Counter,
+    /// Number of entries in the blacklist
+    pub blacklist_entries: Gauge,
+    /// Number of transactions evicted because their inputs were blacklisted
+    pub blacklist_evicted_transactions: Counter,
 }
 
 impl Default for TxPoolMetrics {
@@ -42,6 +53,13 @@ impl Default for TxPoolMetrics {
         let number_of_transactions_pending_verification = Gauge::default();
         let number_of_executable_transactions = Gauge::default();
 
+        let owner_tx_count_limit_rejections = Counter::default();
+        let owner_gas_limit_rejections = Counter::default();
+        let peer_tx_count_limit_rejections = Counter::default();
+
+        let blacklist_entries = Gauge::default();
+        let blacklist_evicted_transactions = Counter::default();
+
         let metrics = TxPoolMetrics {
             tx_size,
             number_of_transactions,
@@ -50,6 +68,11 @@ impl Default for TxPoolMetrics {
             transaction_time_in_txpool_secs,
             transaction_insertion_time_in_thread_pool_microseconds,
             select_transactions_time_microseconds,
+            owner_tx_count_limit_rejections,
+            owner_gas_limit_rejections,
+            peer_tx_count_limit_rejections,
+            blacklist_entries,
+            blacklist_evicted_transactions,
         };
 
         let mut registry = global_registry().registry.lock();
@@ -97,6 +120,36 @@ impl Default for TxPoolMetrics {
                 .clone(),
         );
 
+        registry.register(
+            "txpool_owner_tx_count_limit_rejections",
+            "The number of transactions rejected because their owner hit the limit of transactions in the txpool",
+            metrics.owner_tx_count_limit_rejections.clone(),
+        );
+
+        registry.register(
+            "txpool_owner_gas_limit_rejections",
+            "The number of transactions rejected because their owner hit the limit of gas in the txpool",
+            metrics.owner_gas_limit_rejections.clone(),
+        );
+
+        registry.register(
+            "txpool_peer_tx_count_limit_rejections",
+            "The number of gossiped transactions rejected because their peer hit the limit of transactions pending verification",
+            metrics.peer_tx_count_limit_rejections.clone(),
+        );
+
+        registry.register(
+            "txpool_blacklist_entries",
+            "The number of entries in the txpool blacklist",
+            metrics.blacklist_entries.clone(),
+        );
+
+        registry.register(
+            "txpool_blacklist_evicted_transactions",
+            "The number of transactions evicted from the txpool because their inputs were blacklisted",
+            metrics.blacklist_evicted_transactions.clone(),
+        );
+
         metrics
     }
 }
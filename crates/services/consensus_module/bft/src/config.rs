@@ -0,0 +1,65 @@
+use crate::state_machine::Step;
+use fuel_core_types::{
+    blockchain::primitives::SecretKeyWrapper,
+    fuel_types::{
+        Address,
+        ChainId,
+    },
+    secrecy::Secret,
+};
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// The id of the chain. It is signed with the proposals and votes.
+    pub chain_id: ChainId,
+    /// Addresses of the validators participating in the consensus.
+    pub validators: Vec<Address>,
+    /// The number of validators' votes required to reach the quorum.
+    pub threshold: u32,
+    /// The signing key of the validator. The node without the key
+    /// only follows the consensus and doesn't vote.
+    pub signing_key: Option<Secret<SecretKeyWrapper>>,
+    pub timeouts: Timeouts,
+}
+
+/// Timeouts of the consensus steps. Each next round of the same height
+/// waits `delta` longer than the previous one to give validators more time
+/// to agree after the network delays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeouts {
+    /// How long to wait for the proposal before prevoting for nil.
+    pub propose: Duration,
+    /// How long to wait for the prevotes quorum before precommitting for nil.
+    pub prevote: Duration,
+    /// How long to wait for the precommits quorum before starting the next round.
+    pub precommit: Duration,
+    /// The increase of the timeouts with each next round.
+    pub delta: Duration,
+    /// How long to wait after the block commit before starting the next height.
+    pub commit: Duration,
+}
+
+impl Default for Timeouts {
+    fn default() -> Self {
+        Self {
+            propose: Duration::from_secs(3),
+            prevote: Duration::from_secs(1),
+            precommit: Duration::from_secs(1),
+            delta: Duration::from_millis(500),
+            commit: Duration::from_secs(1),
+        }
+    }
+}
+
+impl Timeouts {
+    /// Returns the timeout of the `step` in the `round`.
+    pub fn of(&self, step: Step, round: u32) -> Duration {
+        let base = match step {
+            Step::Propose => self.propose,
+            Step::Prevote => self.prevote,
+            Step::Precommit => self.precommit,
+        };
+        base.saturating_add(self.delta.saturating_mul(round))
+    }
+}
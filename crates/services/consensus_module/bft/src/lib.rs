@@ -2,3 +2,21 @@
 #![deny(clippy::cast_possible_truncation)]
 #![deny(unused_crate_dependencies)]
 #![deny(warnings)]
+
+#[cfg(test)]
+mod service_test;
+
+pub mod config;
+pub mod ports;
+pub mod service;
+pub mod state_machine;
+pub mod verifier;
+
+pub use config::{
+    Config,
+    Timeouts,
+};
+pub use service::{
+    new_service,
+    Service,
+};
@@ -0,0 +1,62 @@
+use fuel_core_services::stream::BoxStream;
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::bft::ConsensusMessage,
+        header::BlockHeader,
+        SealedBlock,
+    },
+    fuel_types::BlockHeight,
+    services::p2p::{
+        ConsensusMessageGossipData,
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
+    },
+    tai64::Tai64,
+};
+use std::sync::Arc;
+
+#[async_trait::async_trait]
+pub trait BlockProducer: Send + Sync {
+    /// Produces the block at the `height` with transactions from the `TxPool`.
+    async fn produce_block(
+        &self,
+        height: BlockHeight,
+        block_time: Tai64,
+    ) -> anyhow::Result<Block>;
+}
+
+#[async_trait::async_trait]
+pub trait BlockValidator: Send + Sync {
+    /// Executes the block proposed by another validator without committing it.
+    /// Returns an error if the block is invalid.
+    async fn validate_block(&self, block: &Block) -> anyhow::Result<()>;
+}
+
+#[async_trait::async_trait]
+pub trait BlockImporter: Send + Sync {
+    /// Executes and commits the block sealed by the quorum of validators.
+    async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()>;
+
+    /// The stream of the headers of imported blocks, including blocks
+    /// received from the network during synchronization.
+    fn block_stream(&self) -> BoxStream<BlockHeader>;
+}
+
+pub trait ConsensusNetwork: Send + Sync {
+    /// Gossip broadcast a consensus message of the validator.
+    fn broadcast_consensus_message(
+        &self,
+        message: Arc<ConsensusMessage>,
+    ) -> anyhow::Result<()>;
+
+    /// Report the validity of a consensus message received from the network.
+    fn notify_gossip_consensus_message_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()>;
+
+    /// The stream of consensus messages gossiped by other validators.
+    fn gossiped_consensus_messages(&self) -> BoxStream<ConsensusMessageGossipData>;
+}
@@ -0,0 +1,493 @@
+use crate::{
+    ports::{
+        BlockImporter,
+        BlockProducer,
+        BlockValidator,
+        ConsensusNetwork,
+    },
+    state_machine::{
+        Action,
+        StateMachine,
+        Step,
+        Timeout,
+        ROUND_WINDOW,
+    },
+    Config,
+    Timeouts,
+};
+use anyhow::anyhow;
+use fuel_core_services::{
+    stream::{
+        BoxFuture,
+        BoxStream,
+    },
+    EmptyShared,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+    TaskNextAction,
+};
+use fuel_core_types::{
+    blockchain::{
+        consensus::bft::{
+            ConsensusMessage,
+            VoteType,
+        },
+        header::BlockHeader,
+        primitives::SecretKeyWrapper,
+    },
+    fuel_crypto::SecretKey,
+    fuel_tx::Input,
+    fuel_types::{
+        Address,
+        BlockHeight,
+        ChainId,
+    },
+    secrecy::{
+        ExposeSecret,
+        Secret,
+    },
+    services::p2p::{
+        ConsensusMessageGossipData,
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
+    },
+    tai64::Tai64,
+};
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    ops::Deref,
+    sync::Arc,
+};
+use tokio::time::{
+    sleep_until,
+    Instant,
+};
+use tokio_stream::StreamExt;
+
+/// The maximum number of messages of one validator for the next height kept until
+/// the validator catches up with it. The honest validator sends at most one
+/// message per step of each round in the `ROUND_WINDOW`.
+const MAX_FUTURE_MESSAGES_PER_VALIDATOR: usize = 64;
+
+/// The key of the message for the next height. Only the first message of the signer
+/// for the step of the round is kept.
+type FutureMessageKey = (BlockHeight, u32, Step);
+
+pub type Service<P, V, I, N> = ServiceRunner<MainTask<P, V, I, N>>;
+
+pub struct MainTask<P, V, I, N> {
+    state_machine: StateMachine,
+    chain_id: ChainId,
+    validators: Vec<Address>,
+    signing_key: Option<Secret<SecretKeyWrapper>>,
+    timeouts: Timeouts,
+    block_producer: P,
+    block_validator: V,
+    block_importer: I,
+    network: N,
+    gossiped_messages: BoxStream<ConsensusMessageGossipData>,
+    imported_blocks: BoxStream<BlockHeader>,
+    scheduled_timeouts: Vec<(Instant, Timeout)>,
+    /// The height to start once the commit timeout of the previous block expires.
+    next_height: Option<(Instant, BlockHeight)>,
+    /// Messages for the next height received before the validator reached it,
+    /// grouped by the signer.
+    future_messages: HashMap<Address, HashMap<FutureMessageKey, ConsensusMessage>>,
+    last_timestamp: Tai64,
+}
+
+impl<P, V, I, N> MainTask<P, V, I, N>
+where
+    I: BlockImporter,
+    N: ConsensusNetwork,
+{
+    pub fn new(
+        last_block: &BlockHeader,
+        config: Config,
+        block_producer: P,
+        block_validator: V,
+        block_importer: I,
+        network: N,
+    ) -> Self {
+        let Config {
+            chain_id,
+            validators,
+            threshold,
+            signing_key,
+            timeouts,
+        } = config;
+        let address = signing_key
+            .as_ref()
+            .map(|key| Input::owner(&key.expose_secret().public_key()));
+        let state_machine =
+            StateMachine::new(chain_id, validators.clone(), threshold, address);
+        let gossiped_messages = network.gossiped_consensus_messages();
+        let imported_blocks = block_importer.block_stream();
+        let next_height = last_block
+            .height()
+            .succ()
+            .map(|height| (Instant::now(), height));
+
+        Self {
+            state_machine,
+            chain_id,
+            validators,
+            signing_key,
+            timeouts,
+            block_producer,
+            block_validator,
+            block_importer,
+            network,
+            gossiped_messages,
+            imported_blocks,
+            scheduled_timeouts: vec![],
+            next_height,
+            future_messages: HashMap::new(),
+            last_timestamp: last_block.time(),
+        }
+    }
+}
+
+impl<P, V, I, N> MainTask<P, V, I, N>
+where
+    P: BlockProducer,
+    V: BlockValidator,
+    I: BlockImporter,
+    N: ConsensusNetwork,
+{
+    fn next_deadline(&self) -> Option<Instant> {
+        self.scheduled_timeouts
+            .iter()
+            .map(|(deadline, _)| *deadline)
+            .chain(self.next_height.map(|(deadline, _)| deadline))
+            .min()
+    }
+
+    async fn on_deadline(&mut self) -> anyhow::Result<()> {
+        let now = Instant::now();
+        if let Some((deadline, height)) = self.next_height {
+            if deadline <= now {
+                self.next_height = None;
+                self.start_height(height).await?;
+            }
+        }
+
+        let (expired, pending) = core::mem::take(&mut self.scheduled_timeouts)
+            .into_iter()
+            .partition::<Vec<_>, _>(|(deadline, _)| *deadline <= now);
+        self.scheduled_timeouts = pending;
+        for (_, timeout) in expired {
+            let actions = self.state_machine.on_timeout(timeout);
+            self.execute(actions).await?;
+        }
+        Ok(())
+    }
+
+    async fn start_height(&mut self, height: BlockHeight) -> anyhow::Result<()> {
+        tracing::debug!("Starting the consensus for the block {height}");
+        self.scheduled_timeouts.clear();
+        let actions = self.state_machine.start_height(height);
+        self.execute(actions).await?;
+
+        let messages = core::mem::take(&mut self.future_messages);
+        for message in messages.into_values().flat_map(HashMap::into_values) {
+            if message.height() == height {
+                self.on_message(message).await?;
+            }
+        }
+        Ok(())
+    }
+
+    fn on_block_imported(&mut self, header: &BlockHeader) {
+        self.last_timestamp = self.last_timestamp.max(header.time());
+
+        let Some(next_height) = header.height().succ() else {
+            return
+        };
+        let already_scheduled = self
+            .next_height
+            .is_some_and(|(_, height)| height >= next_height);
+        if *header.height() < self.state_machine.height() || already_scheduled {
+            return
+        }
+
+        // The block at the current height is committed, either by this validator or
+        // by the quorum without it. The next height starts after the commit timeout
+        // to give the proposer time to collect transactions.
+        self.scheduled_timeouts.clear();
+        self.prune_future_messages(next_height);
+        let deadline = Instant::now()
+            .checked_add(self.timeouts.commit)
+            .unwrap_or_else(Instant::now);
+        self.next_height = Some((deadline, next_height));
+    }
+
+    async fn on_gossiped_message(
+        &mut self,
+        mut message: ConsensusMessageGossipData,
+    ) -> anyhow::Result<()> {
+        let info = GossipsubMessageInfo {
+            message_id: message.message_id.clone(),
+            peer_id: message.peer_id.clone(),
+        };
+        let Some(data) = message.data.take() else {
+            return Ok(())
+        };
+
+        let is_from_validator = data
+            .signer(&self.chain_id)
+            .is_some_and(|signer| self.validators.contains(&signer));
+        let validity = if is_from_validator {
+            GossipsubMessageAcceptance::Accept
+        } else {
+            GossipsubMessageAcceptance::Reject
+        };
+        self.network
+            .notify_gossip_consensus_message_validity(info, validity)?;
+
+        if is_from_validator {
+            self.on_message(data).await?;
+        }
+        Ok(())
+    }
+
+    async fn on_message(&mut self, message: ConsensusMessage) -> anyhow::Result<()> {
+        let current_height = match self.next_height {
+            // The validator waits for the next height, all messages for it are future.
+            Some((_, height)) => height.pred().unwrap_or_default(),
+            None => self.state_machine.height(),
+        };
+        if message.height() > current_height {
+            if current_height.succ() == Some(message.height()) {
+                self.buffer_future_message(message);
+            }
+            return Ok(())
+        }
+        if message.height() < current_height || self.next_height.is_some() {
+            return Ok(())
+        }
+
+        let actions = match message {
+            ConsensusMessage::Proposal(proposal) => {
+                let round = proposal.proposal.round;
+                if proposal.signer(&self.chain_id) != self.state_machine.proposer(round) {
+                    return Ok(())
+                }
+                let is_valid = match self
+                    .block_validator
+                    .validate_block(&proposal.proposal.block)
+                    .await
+                {
+                    Ok(()) => true,
+                    Err(err) => {
+                        tracing::warn!(
+                            "The proposal of the round {round} is invalid: {err}"
+                        );
+                        false
+                    }
+                };
+                self.state_machine.on_proposal(proposal, is_valid)
+            }
+            ConsensusMessage::Vote(vote) => self.state_machine.on_vote(vote),
+        };
+        self.execute(actions).await
+    }
+
+    /// Keeps the message for the next height until the validator reaches it.
+    fn buffer_future_message(&mut self, message: ConsensusMessage) {
+        let Some(signer) = message.signer(&self.chain_id) else {
+            return
+        };
+        if message.round() > ROUND_WINDOW {
+            return
+        }
+        let step = match &message {
+            ConsensusMessage::Proposal(_) => Step::Propose,
+            ConsensusMessage::Vote(vote) => match vote.vote.vote_type {
+                VoteType::Prevote => Step::Prevote,
+                VoteType::Precommit => Step::Precommit,
+            },
+        };
+        let messages = self.future_messages.entry(signer).or_default();
+        if messages.len() >= MAX_FUTURE_MESSAGES_PER_VALIDATOR {
+            return
+        }
+        messages
+            .entry((message.height(), message.round(), step))
+            .or_insert(message);
+    }
+
+    /// Drops the messages for the heights before the `height`.
+    fn prune_future_messages(&mut self, height: BlockHeight) {
+        self.future_messages.retain(|_, messages| {
+            messages.retain(|(message_height, _, _), _| *message_height >= height);
+            !messages.is_empty()
+        });
+    }
+
+    async fn execute(&mut self, actions: Vec<Action>) -> anyhow::Result<()> {
+        let mut actions = VecDeque::from(actions);
+        while let Some(action) = actions.pop_front() {
+            let next_actions = match action {
+                Action::ProduceBlock { height, round } => {
+                    let block_time = self.last_timestamp.max(Tai64::now());
+                    match self.block_producer.produce_block(height, block_time).await {
+                        Ok(block) => self.state_machine.on_block_produced(round, block),
+                        Err(err) => {
+                            // The timeout of the proposal moves validators to the next round.
+                            tracing::error!(
+                                "Failed to produce the block {height}: {err}"
+                            );
+                            vec![]
+                        }
+                    }
+                }
+                Action::Propose(proposal) => {
+                    let proposal = proposal.sign(&self.chain_id, self.signing_key()?);
+                    self.broadcast(ConsensusMessage::Proposal(proposal.clone()));
+                    self.state_machine.on_proposal(proposal, true)
+                }
+                Action::Vote(vote) => {
+                    let vote = vote.sign(&self.chain_id, self.signing_key()?);
+                    self.broadcast(ConsensusMessage::Vote(vote.clone()));
+                    self.state_machine.on_vote(vote)
+                }
+                Action::ScheduleTimeout(timeout) => {
+                    let duration = self.timeouts.of(timeout.step, timeout.round);
+                    let deadline = Instant::now()
+                        .checked_add(duration)
+                        .unwrap_or_else(Instant::now);
+                    self.scheduled_timeouts.push((deadline, timeout));
+                    vec![]
+                }
+                Action::Commit(block) => {
+                    let height = *block.entity.header().height();
+                    tracing::info!("The block {height} is committed by the validators");
+                    if let Err(err) = self.block_importer.execute_and_commit(block).await
+                    {
+                        // The block may be already imported from the network.
+                        tracing::warn!("Failed to import the block {height}: {err}");
+                    }
+                    vec![]
+                }
+            };
+            actions.extend(next_actions);
+        }
+        Ok(())
+    }
+
+    fn broadcast(&self, message: ConsensusMessage) {
+        if let Err(err) = self.network.broadcast_consensus_message(Arc::new(message)) {
+            tracing::warn!("Failed to broadcast the consensus message: {err}");
+        }
+    }
+
+    fn signing_key(&self) -> anyhow::Result<&SecretKey> {
+        self.signing_key
+            .as_ref()
+            .map(|key| key.expose_secret().deref())
+            .ok_or_else(|| anyhow!("The BFT signing key is not configured"))
+    }
+}
+
+#[async_trait::async_trait]
+impl<P, V, I, N> RunnableService for MainTask<P, V, I, N>
+where
+    Self: RunnableTask,
+{
+    const NAME: &'static str = "BFT";
+
+    type SharedData = EmptyShared;
+    type Task = MainTask<P, V, I, N>;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        EmptyShared
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl<P, V, I, N> RunnableTask for MainTask<P, V, I, N>
+where
+    P: BlockProducer,
+    V: BlockValidator,
+    I: BlockImporter,
+    N: ConsensusNetwork,
+{
+    async fn run(&mut self, watcher: &mut StateWatcher) -> TaskNextAction {
+        let next_deadline: BoxFuture<()> = match self.next_deadline() {
+            Some(deadline) => Box::pin(sleep_until(deadline)),
+            None => Box::pin(core::future::pending()),
+        };
+
+        tokio::select! {
+            biased;
+            _ = watcher.while_started() => {
+                TaskNextAction::Stop
+            }
+            header = self.imported_blocks.next() => {
+                if let Some(header) = header {
+                    self.on_block_imported(&header);
+                    TaskNextAction::Continue
+                } else {
+                    tracing::error!("The block importer stream is closed");
+                    TaskNextAction::Stop
+                }
+            }
+            message = self.gossiped_messages.next() => {
+                if let Some(message) = message {
+                    let res = self.on_gossiped_message(message).await;
+                    TaskNextAction::always_continue(res)
+                } else {
+                    TaskNextAction::Stop
+                }
+            }
+            _ = next_deadline => {
+                let res = self.on_deadline().await;
+                TaskNextAction::always_continue(res)
+            }
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        tracing::info!("BFT MainTask shutting down");
+        Ok(())
+    }
+}
+
+pub fn new_service<P, V, I, N>(
+    last_block: &BlockHeader,
+    config: Config,
+    block_producer: P,
+    block_validator: V,
+    block_importer: I,
+    network: N,
+) -> Service<P, V, I, N>
+where
+    P: BlockProducer + 'static,
+    V: BlockValidator + 'static,
+    I: BlockImporter + 'static,
+    N: ConsensusNetwork + 'static,
+{
+    Service::new(MainTask::new(
+        last_block,
+        config,
+        block_producer,
+        block_validator,
+        block_importer,
+        network,
+    ))
+}
@@ -0,0 +1,326 @@
+#![allow(clippy::arithmetic_side_effects)]
+#![allow(non_snake_case)]
+
+use crate::{
+    new_service,
+    ports::{
+        BlockImporter,
+        BlockProducer,
+        BlockValidator,
+        ConsensusNetwork,
+    },
+    verifier::verify_consensus,
+    Config,
+    Service,
+    Timeouts,
+};
+use fuel_core_chain_config::ConsensusConfig;
+use fuel_core_services::{
+    stream::BoxStream,
+    Service as ServiceTrait,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::{
+            bft::{
+                ConsensusMessage,
+                Vote,
+            },
+            Consensus,
+        },
+        header::BlockHeader,
+        SealedBlock,
+    },
+    fuel_crypto::SecretKey,
+    fuel_tx::Input,
+    fuel_types::{
+        BlockHeight,
+        ChainId,
+    },
+    secrecy::Secret,
+    services::p2p::{
+        ConsensusMessageGossipData,
+        GossipData,
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
+    },
+    tai64::Tai64,
+};
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+use std::{
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Duration,
+};
+use tokio::sync::broadcast;
+use tokio_stream::{
+    wrappers::BroadcastStream,
+    StreamExt,
+};
+
+struct FakeBlockProducer;
+
+#[async_trait::async_trait]
+impl BlockProducer for FakeBlockProducer {
+    async fn produce_block(
+        &self,
+        height: BlockHeight,
+        block_time: Tai64,
+    ) -> anyhow::Result<Block> {
+        let mut block = Block::default();
+        block.header_mut().set_block_height(height);
+        block.header_mut().set_time(block_time);
+        Ok(block)
+    }
+}
+
+struct FakeBlockValidator;
+
+#[async_trait::async_trait]
+impl BlockValidator for FakeBlockValidator {
+    async fn validate_block(&self, _: &Block) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+struct FakeBlockImporter {
+    blocks: Arc<Mutex<Vec<SealedBlock>>>,
+    headers: broadcast::Sender<BlockHeader>,
+}
+
+impl FakeBlockImporter {
+    fn new() -> Self {
+        let (headers, _) = broadcast::channel(16);
+        Self {
+            blocks: Default::default(),
+            headers,
+        }
+    }
+
+    fn blocks(&self) -> Vec<SealedBlock> {
+        self.blocks.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl BlockImporter for FakeBlockImporter {
+    async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()> {
+        let header = block.entity.header().clone();
+        self.blocks.lock().unwrap().push(block);
+        let _ = self.headers.send(header);
+        Ok(())
+    }
+
+    fn block_stream(&self) -> BoxStream<BlockHeader> {
+        Box::pin(
+            BroadcastStream::new(self.headers.subscribe())
+                .filter_map(|result| result.ok()),
+        )
+    }
+}
+
+/// Gossips messages between validators of the same process.
+#[derive(Clone)]
+struct FakeNetwork {
+    index: usize,
+    messages: broadcast::Sender<(usize, ConsensusMessage)>,
+}
+
+impl ConsensusNetwork for FakeNetwork {
+    fn broadcast_consensus_message(
+        &self,
+        message: Arc<ConsensusMessage>,
+    ) -> anyhow::Result<()> {
+        let _ = self.messages.send((self.index, message.as_ref().clone()));
+        Ok(())
+    }
+
+    fn notify_gossip_consensus_message_validity(
+        &self,
+        _: GossipsubMessageInfo,
+        _: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn gossiped_consensus_messages(&self) -> BoxStream<ConsensusMessageGossipData> {
+        let index = self.index;
+        Box::pin(BroadcastStream::new(self.messages.subscribe()).filter_map(
+            move |result| match result {
+                Ok((sender, message)) if sender != index => {
+                    Some(GossipData::new(message, Vec::<u8>::new(), Vec::<u8>::new()))
+                }
+                _ => None,
+            },
+        ))
+    }
+}
+
+type TestService =
+    Service<FakeBlockProducer, FakeBlockValidator, FakeBlockImporter, FakeNetwork>;
+
+struct Validators {
+    consensus_config: ConsensusConfig,
+    keys: Vec<SecretKey>,
+    messages: broadcast::Sender<(usize, ConsensusMessage)>,
+    importers: Vec<FakeBlockImporter>,
+    services: Vec<TestService>,
+}
+
+fn validators(count: usize) -> Validators {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let keys: Vec<_> = (0..count).map(|_| SecretKey::random(&mut rng)).collect();
+    let addresses = keys
+        .iter()
+        .map(|key| Input::owner(&key.public_key()))
+        .collect();
+    let consensus_config = ConsensusConfig::bft(addresses);
+    let ConsensusConfig::Bft {
+        validators,
+        threshold,
+    } = consensus_config.clone()
+    else {
+        unreachable!("The BFT config is created above");
+    };
+
+    let (messages, _) = broadcast::channel(1024);
+    let mut importers = vec![];
+    let mut services = vec![];
+    for (index, key) in keys.iter().copied().enumerate() {
+        let config = Config {
+            chain_id: ChainId::default(),
+            validators: validators.clone(),
+            threshold,
+            signing_key: Some(Secret::new(key.into())),
+            timeouts: Timeouts {
+                propose: Duration::from_millis(500),
+                prevote: Duration::from_millis(200),
+                precommit: Duration::from_millis(200),
+                delta: Duration::from_millis(100),
+                commit: Duration::from_millis(10),
+            },
+        };
+        let importer = FakeBlockImporter::new();
+        let network = FakeNetwork {
+            index,
+            messages: messages.clone(),
+        };
+        services.push(new_service(
+            &BlockHeader::default(),
+            config,
+            FakeBlockProducer,
+            FakeBlockValidator,
+            importer.clone(),
+            network,
+        ));
+        importers.push(importer);
+    }
+
+    Validators {
+        consensus_config,
+        keys,
+        messages,
+        importers,
+        services,
+    }
+}
+
+async fn wait_for_blocks(importer: &FakeBlockImporter, count: usize) {
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while importer.blocks().len() < count {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("Validators should commit blocks in time");
+}
+
+#[tokio::test]
+async fn run__validators_commit_the_same_blocks() {
+    // Given
+    let validators = validators(4);
+
+    // When
+    for service in &validators.services {
+        service.start_and_await().await.unwrap();
+    }
+    for importer in &validators.importers {
+        wait_for_blocks(importer, 3).await;
+    }
+
+    // Then
+    let expected: Vec<_> = validators.importers[0].blocks()[..3]
+        .iter()
+        .map(|block| block.entity.id())
+        .collect();
+    for importer in &validators.importers {
+        let blocks = importer.blocks();
+        for (index, sealed) in blocks[..3].iter().enumerate() {
+            let height = u32::try_from(index + 1).unwrap();
+            assert_eq!(*sealed.entity.header().height(), height.into());
+            assert_eq!(sealed.entity.id(), expected[index]);
+            let Consensus::Bft(seal) = &sealed.consensus else {
+                panic!("Expected BFT seal");
+            };
+            assert!(verify_consensus(
+                &validators.consensus_config,
+                &ChainId::default(),
+                sealed.entity.header(),
+                seal
+            ));
+        }
+    }
+    for service in validators.services {
+        service.stop_and_await().await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn run__quorum_commits_blocks_when_one_validator_is_down() {
+    // Given
+    let validators = validators(4);
+
+    // When
+    for service in &validators.services[..3] {
+        service.start_and_await().await.unwrap();
+    }
+
+    // Then
+    for importer in &validators.importers[..3] {
+        wait_for_blocks(importer, 3).await;
+    }
+    assert!(validators.importers[3].blocks().is_empty());
+}
+
+#[tokio::test]
+async fn run__quorum_commits_blocks_when_faulty_validator_floods_future_heights() {
+    // Given
+    let validators = validators(4);
+    let faulty = 3;
+    for height in 2..=3u32 {
+        for round in 0..300 {
+            let vote = Vote::prevote(height.into(), round, None)
+                .sign(&ChainId::default(), &validators.keys[faulty]);
+            let _ = validators
+                .messages
+                .send((faulty, ConsensusMessage::Vote(vote)));
+        }
+    }
+
+    // When
+    for service in &validators.services[..faulty] {
+        service.start_and_await().await.unwrap();
+    }
+
+    // Then
+    for importer in &validators.importers[..faulty] {
+        wait_for_blocks(importer, 3).await;
+    }
+}
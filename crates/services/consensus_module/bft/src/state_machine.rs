@@ -0,0 +1,528 @@
+//! The Tendermint consensus algorithm for a single height, described in
+//! "The latest gossip on BFT consensus" by Buchman, Kwon and Milosevic.
+//!
+//! The state machine doesn't perform any I/O. The service feeds it with the
+//! proposals, votes and expired timeouts, and executes the returned actions.
+//! The proposals and votes created by the validator itself are also fed back
+//! after signing, so they are counted in the same way as the votes of others.
+
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::{
+            bft::{
+                BftConsensus,
+                Proposal,
+                SignedProposal,
+                SignedVote,
+                Vote,
+                VoteType,
+            },
+            Consensus,
+            Sealed,
+        },
+        primitives::BlockId,
+        SealedBlock,
+    },
+    fuel_crypto::Signature,
+    fuel_types::{
+        Address,
+        BlockHeight,
+        ChainId,
+    },
+};
+use std::collections::{
+    BTreeMap,
+    HashMap,
+    HashSet,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The number of rounds after the current one for which the messages are kept.
+/// The messages for the rounds outside of the window are dropped, so the faulty
+/// validator can't fill the memory with votes for arbitrary rounds.
+pub const ROUND_WINDOW: u32 = 16;
+
+/// The step of the round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Step {
+    Propose,
+    Prevote,
+    Precommit,
+}
+
+/// The timeout of the step in the round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeout {
+    pub height: BlockHeight,
+    pub round: u32,
+    pub step: Step,
+}
+
+/// The action that the service should perform.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// The validator is the proposer of the round and should produce a new block.
+    /// The block is passed back with `StateMachine::on_block_produced`.
+    ProduceBlock { height: BlockHeight, round: u32 },
+    /// Sign and broadcast the proposal.
+    Propose(Proposal),
+    /// Sign and broadcast the vote.
+    Vote(Vote),
+    /// Call `StateMachine::on_timeout` when the timeout expires.
+    ScheduleTimeout(Timeout),
+    /// The quorum of validators committed the block.
+    Commit(SealedBlock),
+}
+
+struct RoundProposal {
+    block: Block,
+    block_id: BlockId,
+    valid_round: Option<u32>,
+    is_valid: bool,
+}
+
+pub struct StateMachine {
+    chain_id: ChainId,
+    validators: Vec<Address>,
+    threshold: usize,
+    /// The address of the validator. `None` if the node doesn't vote.
+    address: Option<Address>,
+    height: BlockHeight,
+    round: u32,
+    step: Step,
+    locked: Option<(u32, Block)>,
+    valid: Option<(u32, Block)>,
+    proposals: BTreeMap<u32, RoundProposal>,
+    votes: HashMap<(u32, VoteType), BTreeMap<Address, SignedVote>>,
+    prevote_timeout_scheduled: bool,
+    precommit_timeout_scheduled: bool,
+    prevote_quorum_seen: bool,
+    committed: bool,
+}
+
+impl StateMachine {
+    pub fn new(
+        chain_id: ChainId,
+        validators: Vec<Address>,
+        threshold: u32,
+        address: Option<Address>,
+    ) -> Self {
+        let address = address.filter(|address| validators.contains(address));
+        Self {
+            chain_id,
+            validators,
+            threshold: usize::try_from(threshold).unwrap_or(usize::MAX),
+            address,
+            height: BlockHeight::default(),
+            round: 0,
+            step: Step::Propose,
+            locked: None,
+            valid: None,
+            proposals: BTreeMap::new(),
+            votes: HashMap::new(),
+            prevote_timeout_scheduled: false,
+            precommit_timeout_scheduled: false,
+            prevote_quorum_seen: false,
+            committed: false,
+        }
+    }
+
+    pub fn height(&self) -> BlockHeight {
+        self.height
+    }
+
+    pub fn round(&self) -> u32 {
+        self.round
+    }
+
+    pub fn step(&self) -> Step {
+        self.step
+    }
+
+    /// Returns `true` if the block at the current height is committed.
+    pub fn is_committed(&self) -> bool {
+        self.committed
+    }
+
+    /// Returns the proposer of the `round` at the current height.
+    /// Validators propose blocks in the round-robin order.
+    pub fn proposer(&self, round: u32) -> Option<Address> {
+        let count = u64::try_from(self.validators.len()).ok()?;
+        let index = u64::from(*self.height)
+            .checked_add(u64::from(round))?
+            .checked_rem(count)?;
+        self.validators.get(usize::try_from(index).ok()?).copied()
+    }
+
+    /// Starts the consensus for the new `height` from the first round.
+    pub fn start_height(&mut self, height: BlockHeight) -> Vec<Action> {
+        self.height = height;
+        self.locked = None;
+        self.valid = None;
+        self.proposals.clear();
+        self.votes.clear();
+        self.committed = false;
+        let mut actions = self.start_round(0);
+        self.process(&mut actions);
+        actions
+    }
+
+    /// Handles the block produced after the `Action::ProduceBlock`.
+    pub fn on_block_produced(&mut self, round: u32, block: Block) -> Vec<Action> {
+        if self.committed
+            || round != self.round
+            || self.step != Step::Propose
+            || *block.header().height() != self.height
+            || !self.is_own_turn(round)
+        {
+            return vec![]
+        }
+
+        vec![Action::Propose(Proposal {
+            height: self.height,
+            round,
+            valid_round: None,
+            block,
+        })]
+    }
+
+    /// Handles the proposal for the current height. The `is_valid` is the result
+    /// of the validation of the proposed block by the executor.
+    pub fn on_proposal(
+        &mut self,
+        proposal: SignedProposal,
+        is_valid: bool,
+    ) -> Vec<Action> {
+        let signer = proposal.signer(&self.chain_id);
+        let SignedProposal { proposal, .. } = proposal;
+        if self.committed
+            || proposal.height != self.height
+            || proposal.round > self.round.saturating_add(ROUND_WINDOW)
+            || *proposal.block.header().height() != self.height
+            || signer.is_none()
+            || signer != self.proposer(proposal.round)
+            || proposal
+                .valid_round
+                .is_some_and(|valid_round| valid_round >= proposal.round)
+            || self.proposals.contains_key(&proposal.round)
+        {
+            return vec![]
+        }
+
+        let block_id = proposal.block.id();
+        self.proposals.insert(
+            proposal.round,
+            RoundProposal {
+                block: proposal.block,
+                block_id,
+                valid_round: proposal.valid_round,
+                is_valid,
+            },
+        );
+
+        let mut actions = vec![];
+        self.process(&mut actions);
+        actions
+    }
+
+    /// Handles the vote for the current height. Votes for the past rounds are
+    /// counted, because the quorum of precommits may be collected for the block
+    /// of any round. Votes for the rounds after `round + ROUND_WINDOW` are dropped.
+    pub fn on_vote(&mut self, vote: SignedVote) -> Vec<Action> {
+        if self.committed
+            || vote.vote.height != self.height
+            || vote.vote.round > self.round.saturating_add(ROUND_WINDOW)
+        {
+            return vec![]
+        }
+        let Some(signer) = vote.signer(&self.chain_id) else {
+            return vec![]
+        };
+        if !self.validators.contains(&signer) {
+            return vec![]
+        }
+
+        let votes = self
+            .votes
+            .entry((vote.vote.round, vote.vote.vote_type))
+            .or_default();
+        if votes.contains_key(&signer) {
+            // Only the first vote of the validator in the round is counted.
+            return vec![]
+        }
+        votes.insert(signer, vote);
+
+        let mut actions = vec![];
+        self.process(&mut actions);
+        actions
+    }
+
+    /// Handles the expired timeout.
+    pub fn on_timeout(&mut self, timeout: Timeout) -> Vec<Action> {
+        if self.committed || timeout.height != self.height || timeout.round != self.round
+        {
+            return vec![]
+        }
+
+        let mut actions = vec![];
+        match timeout.step {
+            Step::Propose if self.step == Step::Propose => {
+                self.vote(VoteType::Prevote, None, &mut actions);
+                self.step = Step::Prevote;
+            }
+            Step::Prevote if self.step == Step::Prevote => {
+                self.vote(VoteType::Precommit, None, &mut actions);
+                self.step = Step::Precommit;
+            }
+            Step::Precommit => {
+                let next_round = self.round.saturating_add(1);
+                actions.extend(self.start_round(next_round));
+            }
+            _ => return vec![],
+        }
+        self.process(&mut actions);
+        actions
+    }
+
+    fn start_round(&mut self, round: u32) -> Vec<Action> {
+        self.round = round;
+        self.step = Step::Propose;
+        self.prevote_timeout_scheduled = false;
+        self.precommit_timeout_scheduled = false;
+        self.prevote_quorum_seen = false;
+
+        let mut actions = vec![];
+        if self.is_own_turn(round) {
+            match &self.valid {
+                Some((valid_round, block)) => actions.push(Action::Propose(Proposal {
+                    height: self.height,
+                    round,
+                    valid_round: Some(*valid_round),
+                    block: block.clone(),
+                })),
+                None => actions.push(Action::ProduceBlock {
+                    height: self.height,
+                    round,
+                }),
+            }
+        }
+        actions.push(self.timeout(Step::Propose));
+        actions
+    }
+
+    /// Applies the rules of the algorithm until none of them can be applied.
+    fn process(&mut self, actions: &mut Vec<Action>) {
+        while !self.committed && self.apply_rule(actions) {}
+    }
+
+    /// Applies the first rule whose conditions are met.
+    /// Returns `false` if no rule can be applied.
+    fn apply_rule(&mut self, actions: &mut Vec<Action>) -> bool {
+        if let Some(block) = self.committed_block() {
+            actions.push(Action::Commit(block));
+            self.committed = true;
+            return true
+        }
+
+        let round = self.round;
+        if self.step == Step::Propose {
+            if let Some(proposal) = self.proposals.get(&round) {
+                let block_id = proposal.block_id;
+                let accept = match proposal.valid_round {
+                    None => Some(
+                        proposal.is_valid
+                            && self
+                                .locked
+                                .as_ref()
+                                .map_or(true, |(_, locked)| locked.id() == block_id),
+                    ),
+                    Some(valid_round)
+                        if self.count(valid_round, VoteType::Prevote, Some(block_id))
+                            >= self.threshold =>
+                    {
+                        Some(
+                            proposal.is_valid
+                                && self.locked.as_ref().map_or(
+                                    true,
+                                    |(locked_round, locked)| {
+                                        *locked_round <= valid_round
+                                            || locked.id() == block_id
+                                    },
+                                ),
+                        )
+                    }
+                    Some(_) => None,
+                };
+                if let Some(accept) = accept {
+                    let block_id = accept.then_some(block_id);
+                    self.vote(VoteType::Prevote, block_id, actions);
+                    self.step = Step::Prevote;
+                    return true
+                }
+            }
+        }
+
+        if self.step == Step::Prevote
+            && !self.prevote_timeout_scheduled
+            && self.total(round, VoteType::Prevote) >= self.threshold
+        {
+            self.prevote_timeout_scheduled = true;
+            actions.push(self.timeout(Step::Prevote));
+            return true
+        }
+
+        if self.step != Step::Propose && !self.prevote_quorum_seen {
+            let quorum_block = self.proposals.get(&round).filter(|proposal| {
+                proposal.is_valid
+                    && self.count(round, VoteType::Prevote, Some(proposal.block_id))
+                        >= self.threshold
+            });
+            if let Some(proposal) = quorum_block {
+                let block_id = proposal.block_id;
+                let block = proposal.block.clone();
+                self.prevote_quorum_seen = true;
+                if self.step == Step::Prevote {
+                    self.locked = Some((round, block.clone()));
+                    self.vote(VoteType::Precommit, Some(block_id), actions);
+                    self.step = Step::Precommit;
+                }
+                self.valid = Some((round, block));
+                return true
+            }
+        }
+
+        if self.step == Step::Prevote
+            && self.count(round, VoteType::Prevote, None) >= self.threshold
+        {
+            self.vote(VoteType::Precommit, None, actions);
+            self.step = Step::Precommit;
+            return true
+        }
+
+        if !self.precommit_timeout_scheduled
+            && self.total(round, VoteType::Precommit) >= self.threshold
+        {
+            self.precommit_timeout_scheduled = true;
+            actions.push(self.timeout(Step::Precommit));
+            return true
+        }
+
+        if let Some(next_round) = self.round_to_skip_to() {
+            actions.extend(self.start_round(next_round));
+            return true
+        }
+
+        false
+    }
+
+    /// Returns the sealed block if the quorum of validators precommitted
+    /// for the valid proposal in any round.
+    fn committed_block(&self) -> Option<SealedBlock> {
+        self.proposals.iter().find_map(|(round, proposal)| {
+            if !proposal.is_valid {
+                return None
+            }
+            let signatures =
+                self.signatures(*round, VoteType::Precommit, Some(proposal.block_id));
+            (signatures.len() >= self.threshold).then(|| Sealed {
+                entity: proposal.block.clone(),
+                consensus: Consensus::Bft(BftConsensus::new(*round, signatures)),
+            })
+        })
+    }
+
+    /// Returns the highest future round in which at least `f + 1` validators
+    /// participate. At least one of them is honest, so the validator is behind.
+    fn round_to_skip_to(&self) -> Option<u32> {
+        let faulty_quorum = self
+            .validators
+            .len()
+            .saturating_sub(self.threshold)
+            .saturating_add(1);
+        let mut senders: BTreeMap<u32, HashSet<Address>> = BTreeMap::new();
+        for ((round, _), votes) in self.votes.iter() {
+            if *round > self.round {
+                senders
+                    .entry(*round)
+                    .or_default()
+                    .extend(votes.keys().copied());
+            }
+        }
+        for round in self.proposals.keys() {
+            if *round > self.round {
+                if let Some(proposer) = self.proposer(*round) {
+                    senders.entry(*round).or_default().insert(proposer);
+                }
+            }
+        }
+        senders
+            .into_iter()
+            .rev()
+            .find(|(_, senders)| senders.len() >= faulty_quorum)
+            .map(|(round, _)| round)
+    }
+
+    fn is_own_turn(&self, round: u32) -> bool {
+        self.address.is_some() && self.proposer(round) == self.address
+    }
+
+    fn vote(
+        &self,
+        vote_type: VoteType,
+        block_id: Option<BlockId>,
+        actions: &mut Vec<Action>,
+    ) {
+        if self.address.is_some() {
+            actions.push(Action::Vote(Vote {
+                vote_type,
+                height: self.height,
+                round: self.round,
+                block_id,
+            }));
+        }
+    }
+
+    fn timeout(&self, step: Step) -> Action {
+        Action::ScheduleTimeout(Timeout {
+            height: self.height,
+            round: self.round,
+            step,
+        })
+    }
+
+    fn votes(
+        &self,
+        round: u32,
+        vote_type: VoteType,
+    ) -> impl Iterator<Item = &SignedVote> {
+        self.votes
+            .get(&(round, vote_type))
+            .into_iter()
+            .flat_map(|votes| votes.values())
+    }
+
+    fn total(&self, round: u32, vote_type: VoteType) -> usize {
+        self.votes(round, vote_type).count()
+    }
+
+    fn count(&self, round: u32, vote_type: VoteType, block_id: Option<BlockId>) -> usize {
+        self.votes(round, vote_type)
+            .filter(|vote| vote.vote.block_id == block_id)
+            .count()
+    }
+
+    fn signatures(
+        &self,
+        round: u32,
+        vote_type: VoteType,
+        block_id: Option<BlockId>,
+    ) -> Vec<Signature> {
+        self.votes(round, vote_type)
+            .filter(|vote| vote.vote.block_id == block_id)
+            .map(|vote| vote.signature)
+            .collect()
+    }
+}
@@ -0,0 +1,462 @@
+#![allow(non_snake_case)]
+
+use super::*;
+use fuel_core_chain_config::ConsensusConfig;
+use fuel_core_types::{
+    blockchain::consensus::bft::ConsensusMessage,
+    fuel_crypto::SecretKey,
+    fuel_tx::Input,
+    tai64::Tai64,
+};
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+use std::collections::VecDeque;
+
+const HEIGHT: u32 = 1;
+
+fn chain_id() -> ChainId {
+    ChainId::new(9)
+}
+
+struct Node {
+    key: SecretKey,
+    machine: StateMachine,
+    committed: Option<SealedBlock>,
+}
+
+/// Delivers messages between the state machines of validators in memory.
+struct Network {
+    nodes: Vec<Node>,
+    messages: VecDeque<ConsensusMessage>,
+    timeouts: Vec<(usize, Timeout)>,
+    offline: HashSet<usize>,
+    /// The rounds in which the proposed blocks are invalid.
+    invalid_rounds: HashSet<u32>,
+    /// The rounds in which proposers don't gossip the proposals.
+    silent_rounds: HashSet<u32>,
+}
+
+impl Network {
+    fn new(count: usize) -> Self {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let keys: Vec<_> = (0..count).map(|_| SecretKey::random(&mut rng)).collect();
+        let validators: Vec<_> = keys
+            .iter()
+            .map(|key| Input::owner(&key.public_key()))
+            .collect();
+        let ConsensusConfig::Bft { threshold, .. } =
+            ConsensusConfig::bft(validators.clone())
+        else {
+            unreachable!("The BFT config is created above");
+        };
+        let nodes = keys
+            .into_iter()
+            .map(|key| Node {
+                machine: StateMachine::new(
+                    chain_id(),
+                    validators.clone(),
+                    threshold,
+                    Some(Input::owner(&key.public_key())),
+                ),
+                key,
+                committed: None,
+            })
+            .collect();
+
+        Self {
+            nodes,
+            messages: VecDeque::new(),
+            timeouts: vec![],
+            offline: HashSet::new(),
+            invalid_rounds: HashSet::new(),
+            silent_rounds: HashSet::new(),
+        }
+    }
+
+    fn online(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.nodes.len()).filter(|index| !self.offline.contains(index))
+    }
+
+    fn start(&mut self) {
+        for index in self.online().collect::<Vec<_>>() {
+            let actions = self.nodes[index].machine.start_height(HEIGHT.into());
+            self.apply(index, actions);
+        }
+    }
+
+    fn apply(&mut self, index: usize, actions: Vec<Action>) {
+        for action in actions {
+            let node = &mut self.nodes[index];
+            match action {
+                Action::ProduceBlock { height, round } => {
+                    let block = block(height, round);
+                    let actions = node.machine.on_block_produced(round, block);
+                    self.apply(index, actions);
+                }
+                Action::Propose(proposal) => {
+                    if !self.silent_rounds.contains(&proposal.round) {
+                        let proposal = proposal.sign(&chain_id(), &node.key);
+                        self.messages
+                            .push_back(ConsensusMessage::Proposal(proposal));
+                    }
+                }
+                Action::Vote(vote) => {
+                    let vote = vote.sign(&chain_id(), &node.key);
+                    self.messages.push_back(ConsensusMessage::Vote(vote));
+                }
+                Action::ScheduleTimeout(timeout) => {
+                    self.timeouts.push((index, timeout));
+                }
+                Action::Commit(block) => {
+                    assert!(node.committed.is_none(), "The block is committed twice");
+                    node.committed = Some(block);
+                }
+            }
+        }
+    }
+
+    /// Delivers all messages to all online validators until nobody sends new messages.
+    fn deliver(&mut self) {
+        while let Some(message) = self.messages.pop_front() {
+            for index in self.online().collect::<Vec<_>>() {
+                let machine = &mut self.nodes[index].machine;
+                let actions = match message.clone() {
+                    ConsensusMessage::Proposal(proposal) => {
+                        let is_valid =
+                            !self.invalid_rounds.contains(&proposal.proposal.round);
+                        machine.on_proposal(proposal, is_valid)
+                    }
+                    ConsensusMessage::Vote(vote) => machine.on_vote(vote),
+                };
+                self.apply(index, actions);
+            }
+        }
+    }
+
+    /// Expires all scheduled timeouts of the `step`.
+    fn expire(&mut self, step: Step) {
+        let (expired, pending) = core::mem::take(&mut self.timeouts)
+            .into_iter()
+            .partition::<Vec<_>, _>(|(_, timeout)| timeout.step == step);
+        self.timeouts = pending;
+        for (index, timeout) in expired {
+            let actions = self.nodes[index].machine.on_timeout(timeout);
+            self.apply(index, actions);
+        }
+    }
+
+    fn committed(&self) -> Vec<&SealedBlock> {
+        self.online()
+            .filter_map(|index| self.nodes[index].committed.as_ref())
+            .collect()
+    }
+}
+
+fn block(height: BlockHeight, round: u32) -> Block {
+    let mut block = Block::default();
+    block.header_mut().set_block_height(height);
+    block.header_mut().set_time(Tai64(u64::from(round)));
+    block
+}
+
+#[test]
+fn start_height__proposer_of_first_round_produces_block() {
+    // Given
+    let mut network = Network::new(4);
+
+    // When
+    let actions: Vec<_> = network
+        .nodes
+        .iter_mut()
+        .map(|node| node.machine.start_height(HEIGHT.into()))
+        .collect();
+
+    // Then
+    // The proposer depends on the height, so it is known after the start.
+    let proposer = network.nodes[0].machine.proposer(0).unwrap();
+    for (node, actions) in network.nodes.iter().zip(actions) {
+        let is_proposer = Input::owner(&node.key.public_key()) == proposer;
+        let produces_block = actions
+            .iter()
+            .any(|action| matches!(action, Action::ProduceBlock { round: 0, .. }));
+        assert_eq!(produces_block, is_proposer);
+    }
+}
+
+#[test]
+fn deliver__all_validators_commit_block_of_first_round() {
+    // Given
+    let mut network = Network::new(4);
+    network.start();
+
+    // When
+    network.deliver();
+
+    // Then
+    let committed = network.committed();
+    assert_eq!(committed.len(), 4);
+    let expected_block = block(HEIGHT.into(), 0);
+    for sealed in committed {
+        assert_eq!(sealed.entity.id(), expected_block.id());
+        let Consensus::Bft(seal) = &sealed.consensus else {
+            panic!("Expected BFT seal");
+        };
+        assert_eq!(seal.round, 0);
+        assert!(seal.signatures.len() >= 3);
+    }
+}
+
+#[test]
+fn deliver__quorum_commits_block_without_one_faulty_validator() {
+    // Given
+    let mut network = Network::new(4);
+    // The proposer of the first round is online.
+    network.offline.insert(3);
+    network.start();
+
+    // When
+    network.deliver();
+
+    // Then
+    assert_eq!(network.committed().len(), 3);
+}
+
+#[test]
+fn deliver__nothing_is_committed_without_quorum() {
+    // Given
+    let mut network = Network::new(4);
+    network.offline.extend([2, 3]);
+    network.start();
+
+    // When
+    network.deliver();
+
+    // Then
+    assert!(network.committed().is_empty());
+}
+
+#[test]
+fn expire__missing_proposal_moves_validators_to_next_round() {
+    // Given
+    let mut network = Network::new(4);
+    network.silent_rounds.insert(0);
+    network.start();
+    network.deliver();
+
+    // When
+    network.expire(Step::Propose);
+    network.deliver();
+    network.expire(Step::Precommit);
+    network.deliver();
+
+    // Then
+    let committed = network.committed();
+    assert_eq!(committed.len(), 4);
+    for sealed in committed {
+        assert_eq!(sealed.entity.id(), block(HEIGHT.into(), 1).id());
+        let Consensus::Bft(seal) = &sealed.consensus else {
+            panic!("Expected BFT seal");
+        };
+        assert_eq!(seal.round, 1);
+    }
+}
+
+#[test]
+fn deliver__invalid_proposal_is_prevoted_nil() {
+    // Given
+    let mut network = Network::new(4);
+    network.invalid_rounds.insert(0);
+    network.start();
+
+    // When
+    network.deliver();
+
+    // Then
+    assert!(network.committed().is_empty());
+    for node in &network.nodes {
+        assert_eq!(node.machine.step(), Step::Precommit);
+        assert!(node.machine.locked.is_none());
+    }
+}
+
+#[test]
+fn on_vote__vote_of_unknown_validator_is_ignored() {
+    // Given
+    let mut network = Network::new(4);
+    network.start();
+    let unknown = SecretKey::random(&mut StdRng::seed_from_u64(1));
+    let vote = Vote::prevote(HEIGHT.into(), 5, None).sign(&chain_id(), &unknown);
+
+    // When
+    let actions = network.nodes[0].machine.on_vote(vote);
+
+    // Then
+    assert!(actions.is_empty());
+    assert!(network.nodes[0].machine.votes.is_empty());
+}
+
+#[test]
+fn on_proposal__proposal_of_not_proposer_is_ignored() {
+    // Given
+    let mut network = Network::new(4);
+    network.start();
+    let proposer = network.nodes[0].machine.proposer(0).unwrap();
+    let not_proposer = network
+        .nodes
+        .iter()
+        .find(|node| Input::owner(&node.key.public_key()) != proposer)
+        .unwrap()
+        .key;
+    let proposal = Proposal {
+        height: HEIGHT.into(),
+        round: 0,
+        valid_round: None,
+        block: block(HEIGHT.into(), 0),
+    }
+    .sign(&chain_id(), &not_proposer);
+
+    // When
+    let actions = network.nodes[0].machine.on_proposal(proposal, true);
+
+    // Then
+    assert!(actions.is_empty());
+    assert!(network.nodes[0].machine.proposals.is_empty());
+}
+
+#[test]
+fn on_vote__votes_of_faulty_quorum_from_higher_round_move_validator_to_that_round() {
+    // Given
+    let mut network = Network::new(4);
+    network.start();
+    let height = BlockHeight::from(HEIGHT);
+    let votes: Vec<_> = network.nodes[1..3]
+        .iter()
+        .map(|node| Vote::prevote(height, 3, None).sign(&chain_id(), &node.key))
+        .collect();
+
+    // When
+    let machine = &mut network.nodes[0].machine;
+    for vote in votes {
+        machine.on_vote(vote);
+    }
+
+    // Then
+    assert_eq!(machine.round(), 3);
+    assert_eq!(machine.step(), Step::Propose);
+}
+
+#[test]
+fn on_vote__vote_signed_for_other_chain_is_ignored() {
+    // Given
+    let mut network = Network::new(4);
+    network.start();
+    let other_chain_id = ChainId::new(10);
+    let vote = Vote::prevote(HEIGHT.into(), 0, None)
+        .sign(&other_chain_id, &network.nodes[1].key);
+
+    // When
+    let actions = network.nodes[0].machine.on_vote(vote);
+
+    // Then
+    assert!(actions.is_empty());
+    assert!(network.nodes[0].machine.votes.is_empty());
+}
+
+#[test]
+fn on_vote__vote_for_round_beyond_window_is_ignored() {
+    // Given
+    let mut network = Network::new(4);
+    network.start();
+    let height = BlockHeight::from(HEIGHT);
+    let round = ROUND_WINDOW.saturating_add(1);
+    let votes: Vec<_> = network.nodes[1..3]
+        .iter()
+        .map(|node| Vote::prevote(height, round, None).sign(&chain_id(), &node.key))
+        .collect();
+
+    // When
+    let machine = &mut network.nodes[0].machine;
+    for vote in votes {
+        machine.on_vote(vote);
+    }
+
+    // Then
+    assert_eq!(machine.round(), 0);
+    assert!(machine.votes.is_empty());
+}
+
+#[test]
+fn on_vote__precommits_for_past_round_commit_its_block() {
+    // Given
+    let mut network = Network::new(4);
+    network.start();
+    let height = BlockHeight::from(HEIGHT);
+    let votes: Vec<_> = network.nodes[1..3]
+        .iter()
+        .map(|node| Vote::prevote(height, 3, None).sign(&chain_id(), &node.key))
+        .collect();
+    let machine = &mut network.nodes[0].machine;
+    for vote in votes {
+        machine.on_vote(vote);
+    }
+    assert_eq!(machine.round(), 3);
+    let proposer = machine.proposer(2).unwrap();
+    let proposer_key = network
+        .nodes
+        .iter()
+        .find(|node| Input::owner(&node.key.public_key()) == proposer)
+        .unwrap()
+        .key;
+    let past_block = block(height, 2);
+    let block_id = past_block.id();
+    let proposal = Proposal {
+        height,
+        round: 2,
+        valid_round: None,
+        block: past_block,
+    }
+    .sign(&chain_id(), &proposer_key);
+    let precommits: Vec<_> = network.nodes[1..]
+        .iter()
+        .map(|node| {
+            Vote::precommit(height, 2, Some(block_id)).sign(&chain_id(), &node.key)
+        })
+        .collect();
+
+    // When
+    let machine = &mut network.nodes[0].machine;
+    let mut actions = machine.on_proposal(proposal, true);
+    for vote in precommits {
+        actions.extend(machine.on_vote(vote));
+    }
+
+    // Then
+    let committed = actions.into_iter().find_map(|action| match action {
+        Action::Commit(block) => Some(block),
+        _ => None,
+    });
+    let committed = committed.expect("The block of the past round is committed");
+    assert_eq!(committed.entity.id(), block_id);
+    assert!(machine.is_committed());
+}
+
+#[test]
+fn on_timeout__outdated_timeout_is_ignored() {
+    // Given
+    let mut network = Network::new(4);
+    network.start();
+    let machine = &mut network.nodes[0].machine;
+
+    // When
+    let actions = machine.on_timeout(Timeout {
+        height: HEIGHT.into(),
+        round: 1,
+        step: Step::Propose,
+    });
+
+    // Then
+    assert!(actions.is_empty());
+    assert_eq!(machine.step(), Step::Propose);
+}
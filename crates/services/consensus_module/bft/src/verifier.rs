@@ -0,0 +1,44 @@
+use fuel_core_chain_config::ConsensusConfig;
+use fuel_core_types::{
+    blockchain::{
+        consensus::bft::BftConsensus,
+        header::BlockHeader,
+    },
+    fuel_types::ChainId,
+};
+use std::collections::HashSet;
+
+#[cfg(test)]
+mod tests;
+
+/// Verifies that the quorum of validators precommitted for the block on the chain.
+/// Signatures of unknown validators and duplicates are not counted.
+/// The block is rejected if the threshold of the config is invalid.
+pub fn verify_consensus(
+    consensus_config: &ConsensusConfig,
+    chain_id: &ChainId,
+    header: &BlockHeader,
+    consensus: &BftConsensus,
+) -> bool {
+    if consensus_config.validate().is_err() {
+        return false
+    }
+
+    match consensus_config {
+        ConsensusConfig::Bft {
+            validators,
+            threshold,
+        } => {
+            let id = header.id();
+            let signers: HashSet<_> = consensus
+                .signers(chain_id, *header.height(), &id)
+                .into_iter()
+                .filter(|signer| validators.contains(signer))
+                .collect();
+            let quorum = usize::try_from(*threshold).unwrap_or(usize::MAX);
+            signers.len() >= quorum
+        }
+        // The BFT seal is not valid for the network with PoA consensus.
+        ConsensusConfig::PoA { .. } | ConsensusConfig::PoAV2(_) => false,
+    }
+}
@@ -0,0 +1,181 @@
+#![allow(non_snake_case)]
+
+use super::*;
+use fuel_core_types::{
+    blockchain::consensus::bft::Vote,
+    fuel_crypto::{
+        SecretKey,
+        Signature,
+    },
+    fuel_tx::Input,
+};
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+
+fn chain_id() -> ChainId {
+    ChainId::new(9)
+}
+
+fn keys(count: usize) -> Vec<SecretKey> {
+    let mut rng = StdRng::seed_from_u64(2322);
+    (0..count).map(|_| SecretKey::random(&mut rng)).collect()
+}
+
+fn config(keys: &[SecretKey], threshold: u32) -> ConsensusConfig {
+    ConsensusConfig::Bft {
+        validators: keys
+            .iter()
+            .map(|key| Input::owner(&key.public_key()))
+            .collect(),
+        threshold,
+    }
+}
+
+fn header() -> BlockHeader {
+    let mut header = BlockHeader::default();
+    header.set_block_height(10u32.into());
+    header
+}
+
+fn precommits(header: &BlockHeader, round: u32, keys: &[SecretKey]) -> Vec<Signature> {
+    precommits_of_chain(&chain_id(), header, round, keys)
+}
+
+fn precommits_of_chain(
+    chain_id: &ChainId,
+    header: &BlockHeader,
+    round: u32,
+    keys: &[SecretKey],
+) -> Vec<Signature> {
+    keys.iter()
+        .map(|key| {
+            Vote::precommit(*header.height(), round, Some(header.id()))
+                .sign(chain_id, key)
+                .signature
+        })
+        .collect()
+}
+
+#[test]
+fn verify_consensus__accepts_quorum_of_precommits() {
+    // Given
+    let keys = keys(4);
+    let header = header();
+    let consensus = BftConsensus::new(1, precommits(&header, 1, &keys[..3]));
+
+    // When
+    let valid = verify_consensus(&config(&keys, 3), &chain_id(), &header, &consensus);
+
+    // Then
+    assert!(valid);
+}
+
+#[test]
+fn verify_consensus__rejects_less_than_quorum() {
+    // Given
+    let keys = keys(4);
+    let header = header();
+    let consensus = BftConsensus::new(0, precommits(&header, 0, &keys[..2]));
+
+    // When
+    let valid = verify_consensus(&config(&keys, 3), &chain_id(), &header, &consensus);
+
+    // Then
+    assert!(!valid);
+}
+
+#[test]
+fn verify_consensus__does_not_count_duplicated_signatures() {
+    // Given
+    let keys = keys(4);
+    let header = header();
+    let signatures = precommits(&header, 0, &keys[..2]);
+    let duplicated = [signatures.clone(), signatures].concat();
+    let consensus = BftConsensus::new(0, duplicated);
+
+    // When
+    let valid = verify_consensus(&config(&keys, 3), &chain_id(), &header, &consensus);
+
+    // Then
+    assert!(!valid);
+}
+
+#[test]
+fn verify_consensus__does_not_count_unknown_validators() {
+    // Given
+    let keys = keys(5);
+    let header = header();
+    let consensus = BftConsensus::new(0, precommits(&header, 0, &keys[2..]));
+
+    // When
+    let valid =
+        verify_consensus(&config(&keys[..4], 3), &chain_id(), &header, &consensus);
+
+    // Then
+    assert!(!valid);
+}
+
+#[test]
+fn verify_consensus__rejects_signatures_of_another_round() {
+    // Given
+    let keys = keys(4);
+    let header = header();
+    let consensus = BftConsensus::new(1, precommits(&header, 0, &keys));
+
+    // When
+    let valid = verify_consensus(&config(&keys, 3), &chain_id(), &header, &consensus);
+
+    // Then
+    assert!(!valid);
+}
+
+#[test]
+fn verify_consensus__rejects_bft_seal_for_poa_network() {
+    // Given
+    let keys = keys(1);
+    let header = header();
+    let consensus = BftConsensus::new(0, precommits(&header, 0, &keys));
+
+    // When
+    let valid = verify_consensus(
+        &ConsensusConfig::default_poa(),
+        &chain_id(),
+        &header,
+        &consensus,
+    );
+
+    // Then
+    assert!(!valid);
+}
+
+#[test]
+fn verify_consensus__rejects_signatures_of_another_chain() {
+    // Given
+    let keys = keys(4);
+    let header = header();
+    let other_chain_id = ChainId::new(10);
+    let signatures = precommits_of_chain(&other_chain_id, &header, 0, &keys);
+    let consensus = BftConsensus::new(0, signatures);
+
+    // When
+    let valid = verify_consensus(&config(&keys, 3), &chain_id(), &header, &consensus);
+
+    // Then
+    assert!(!valid);
+}
+
+#[test]
+fn verify_consensus__rejects_config_with_invalid_threshold() {
+    // Given
+    let keys = keys(4);
+    let header = header();
+    let consensus = BftConsensus::new(0, precommits(&header, 0, &keys));
+
+    // When
+    let valid = verify_consensus(&config(&keys, 2), &chain_id(), &header, &consensus);
+
+    // Then
+    assert!(!valid);
+}
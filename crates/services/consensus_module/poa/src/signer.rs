@@ -31,6 +31,13 @@ use fuel_core_types::{
 };
 use std::ops::Deref;
 
+pub mod remote;
+
+pub use remote::{
+    RemoteSigner,
+    RemoteSignerConfig,
+};
+
 /// How the block is signed
 #[derive(Clone, Debug)]
 pub enum SignMode {
@@ -45,12 +52,18 @@ pub enum SignMode {
         client: aws_sdk_kms::Client,
         cached_public_key_bytes: Vec<u8>,
     },
+    /// Sign using the remote signer
+    Remote(RemoteSigner),
 }
 
 impl SignMode {
     /// Is block signing (production) available
     pub fn is_available(&self) -> bool {
-        !matches!(self, SignMode::Unavailable)
+        match self {
+            SignMode::Unavailable => false,
+            SignMode::Remote(signer) => signer.is_healthy(),
+            _ => true,
+        }
     }
 
     /// Sign a block
@@ -70,6 +83,7 @@ impl SignMode {
                 client,
                 cached_public_key_bytes,
             } => sign_with_kms(client, key_id, cached_public_key_bytes, message).await?,
+            SignMode::Remote(signer) => signer.sign(message).await?,
         };
         Ok(Consensus::PoA(PoAConsensus::new(poa_signature)))
     }
@@ -93,6 +107,7 @@ impl SignMode {
                     k256::PublicKey::from_public_key_der(cached_public_key_bytes)?;
                 Ok(Some(PublicKey::from(k256_public_key)))
             }
+            SignMode::Remote(signer) => Ok(Some(signer.public_key())),
         }
     }
 
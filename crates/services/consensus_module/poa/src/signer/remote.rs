@@ -0,0 +1,278 @@
+//! The client of the remote signer.
+//!
+//! The remote signer holds the block production key and exposes a small
+//! HTTP/JSON API, so the key can stay behind the operator's own infrastructure:
+//!
+//! - `GET /v1/public-key` returns `{"public_key": "<hex>"}` with the 64-byte
+//!   uncompressed secp256k1 public key of the signer.
+//! - `GET /v1/health` responds with a successful status while the signer is
+//!   able to sign.
+//! - `POST /v1/sign` accepts `{"digest": "<hex>"}` with the 32-byte block id and
+//!   returns `{"signature": "<hex>"}` with the 64-byte compact signature, the
+//!   recovery id is encoded into the highest bit of `s`.
+
+use anyhow::{
+    anyhow,
+    Context,
+};
+use fuel_core_types::{
+    fuel_crypto::{
+        Message,
+        PublicKey,
+    },
+    fuel_types::{
+        Bytes32,
+        Bytes64,
+    },
+    fuel_vm::Signature,
+};
+use rand::Rng;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::{
+    str::FromStr,
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Arc,
+        Weak,
+    },
+    time::Duration,
+};
+
+/// The delay before the first retry of the failed request to the remote signer.
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(100);
+/// The upper bound of the delay between the retries.
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(2);
+
+/// The settings of the connection to the remote signer.
+#[derive(Clone, Debug)]
+pub struct RemoteSignerConfig {
+    /// The base URL of the remote signer API.
+    pub url: String,
+    /// The timeout of a single request to the remote signer.
+    pub timeout: Duration,
+    /// The number of retries of the failed request.
+    pub retries: usize,
+    /// The interval between the health checks of the remote signer.
+    pub health_check_interval: Duration,
+}
+
+#[derive(Deserialize)]
+struct PublicKeyResponse {
+    public_key: String,
+}
+
+#[derive(Serialize)]
+struct SignRequest {
+    digest: String,
+}
+
+#[derive(Deserialize)]
+struct SignResponse {
+    signature: String,
+}
+
+/// Signs the blocks with the key held by the remote signer.
+#[derive(Clone, Debug)]
+pub struct RemoteSigner {
+    client: reqwest::Client,
+    url: reqwest::Url,
+    retries: usize,
+    public_key: PublicKey,
+    healthy: Arc<AtomicBool>,
+}
+
+impl RemoteSigner {
+    /// Fetches the public key of the remote signer and starts the background
+    /// health checks. The health checks stop when the last clone of the signer
+    /// is dropped.
+    pub async fn connect(config: RemoteSignerConfig) -> anyhow::Result<Self> {
+        let mut url = reqwest::Url::parse(&config.url)
+            .with_context(|| format!("invalid remote signer URL {}", config.url))?;
+        // The API paths are resolved relative to the base URL.
+        if !url.path().ends_with('/') {
+            url.set_path(&format!("{}/", url.path()));
+        }
+        let client = reqwest::Client::builder().timeout(config.timeout).build()?;
+
+        let public_key =
+            retry(config.retries, || fetch_public_key(&client, &url)).await?;
+        let healthy = Arc::new(AtomicBool::new(check_health(&client, &url).await));
+
+        tokio::spawn(health_checks(
+            client.clone(),
+            url.clone(),
+            Arc::downgrade(&healthy),
+            config.health_check_interval,
+        ));
+        Ok(Self {
+            client,
+            url,
+            retries: config.retries,
+            public_key,
+            healthy,
+        })
+    }
+
+    /// Returns the result of the latest health check.
+    pub fn is_healthy(&self) -> bool {
+        self.healthy.load(Ordering::Relaxed)
+    }
+
+    /// Returns the public key of the remote signer.
+    pub fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    /// Signs the `message` and verifies that the signature belongs to the remote signer.
+    pub async fn sign(&self, message: Message) -> anyhow::Result<Signature> {
+        let signature = retry(self.retries, || {
+            request_signature(&self.client, &self.url, &message)
+        })
+        .await?;
+        signature
+            .verify(&self.public_key, &message)
+            .map_err(|_| anyhow!("the remote signer returned an invalid signature"))?;
+        Ok(signature)
+    }
+}
+
+async fn fetch_public_key(
+    client: &reqwest::Client,
+    url: &reqwest::Url,
+) -> anyhow::Result<PublicKey> {
+    let response: PublicKeyResponse = client
+        .get(url.join("v1/public-key")?)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    PublicKey::from_str(&response.public_key)
+        .map_err(|_| anyhow!("the remote signer returned an invalid public key"))
+}
+
+async fn request_signature(
+    client: &reqwest::Client,
+    url: &reqwest::Url,
+    message: &Message,
+) -> anyhow::Result<Signature> {
+    let request = SignRequest {
+        digest: format!("{:x}", Bytes32::from(**message)),
+    };
+    let response: SignResponse = client
+        .post(url.join("v1/sign")?)
+        .json(&request)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    let bytes = Bytes64::from_str(&response.signature)
+        .map_err(|_| anyhow!("the remote signer returned a malformed signature"))?;
+    Ok(Signature::from_bytes(*bytes))
+}
+
+/// Calls `f` until it succeeds, at most `retries + 1` times.
+async fn retry<T, F, Fut>(retries: usize, f: F) -> anyhow::Result<T>
+where
+    F: Fn() -> Fut,
+    Fut: core::future::Future<Output = anyhow::Result<T>>,
+{
+    let mut attempt = 0;
+    loop {
+        match f().await {
+            Ok(value) => return Ok(value),
+            Err(err) if attempt < retries => {
+                tracing::warn!("Request to the remote signer failed, retrying: {err:?}");
+                tokio::time::sleep(retry_delay(attempt)).await;
+                attempt = attempt.saturating_add(1);
+            }
+            Err(err) => return Err(err.context("the remote signer is unreachable")),
+        }
+    }
+}
+
+/// Returns the delay before the retry after `attempt` failed retries. The delay
+/// grows exponentially up to `RETRY_MAX_DELAY` and is randomized between its half
+/// and the full value, so the producers sharing the signer don't retry in lockstep.
+fn retry_delay(attempt: usize) -> Duration {
+    let factor = u32::try_from(attempt)
+        .ok()
+        .and_then(|exponent| 2u32.checked_pow(exponent))
+        .unwrap_or(u32::MAX);
+    let delay = RETRY_BASE_DELAY.saturating_mul(factor).min(RETRY_MAX_DELAY);
+    let millis = u64::try_from(delay.as_millis()).unwrap_or(u64::MAX);
+    let jittered = rand::thread_rng().gen_range(millis.saturating_div(2)..=millis);
+    Duration::from_millis(jittered)
+}
+
+async fn check_health(client: &reqwest::Client, url: &reqwest::Url) -> bool {
+    let Ok(url) = url.join("v1/health") else {
+        return false
+    };
+    match client.get(url).send().await {
+        Ok(response) => response.status().is_success(),
+        Err(err) => {
+            tracing::debug!("Health check of the remote signer failed: {err:?}");
+            false
+        }
+    }
+}
+
+async fn health_checks(
+    client: reqwest::Client,
+    url: reqwest::Url,
+    healthy: Weak<AtomicBool>,
+    interval: Duration,
+) {
+    let mut interval = tokio::time::interval(interval);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    // The first tick completes immediately, and the signer is checked on connection.
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        let is_healthy = check_health(&client, &url).await;
+        let Some(healthy) = healthy.upgrade() else {
+            break
+        };
+        if healthy.swap(is_healthy, Ordering::Relaxed) != is_healthy {
+            if is_healthy {
+                tracing::info!("The remote signer is available");
+            } else {
+                tracing::warn!("The remote signer is unavailable");
+            }
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_delay__grows_exponentially_up_to_max_delay() {
+        for attempt in [0, 1, 2, 5, 64, usize::MAX] {
+            // Given
+            let factor = u32::try_from(attempt)
+                .ok()
+                .and_then(|exponent| 2u32.checked_pow(exponent))
+                .unwrap_or(u32::MAX);
+            let expected = RETRY_BASE_DELAY.saturating_mul(factor).min(RETRY_MAX_DELAY);
+
+            // When
+            let delay = retry_delay(attempt);
+
+            // Then
+            assert!(delay <= expected, "attempt {attempt}: {delay:?}");
+            let half = expected.checked_div(2).unwrap();
+            assert!(delay >= half, "attempt {attempt}: {delay:?}");
+        }
+    }
+}
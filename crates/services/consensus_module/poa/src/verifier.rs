@@ -37,6 +37,8 @@ pub fn verify_consensus(
                 .recover(m)
                 .map_or(false, |k| Input::owner(&k) == signing_key)
         }
+        // The PoA seal is not valid for the network with BFT consensus.
+        ConsensusConfig::Bft { .. } => false,
     }
 }
 
@@ -64,7 +64,8 @@ where
                     block.header(),
                 )
             }
-            Consensus::PoA(_) => {
+            // The rules for the fields of the next block are the same for PoA and BFT.
+            Consensus::PoA(_) | Consensus::Bft(_) => {
                 let view = self.view_provider.latest_view()?;
                 fuel_core_poa::verifier::verify_block_fields(&view, block)
             }
@@ -85,6 +86,12 @@ where
                 header,
                 consensus,
             ),
+            Consensus::Bft(consensus) => fuel_core_bft::verifier::verify_consensus(
+                &self.config.consensus,
+                &self.config.chain_id,
+                header,
+                consensus,
+            ),
             _ => false,
         }
     }
@@ -3,13 +3,18 @@
 use fuel_core_chain_config::ConsensusConfig;
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
-    fuel_types::BlockHeight,
+    fuel_types::{
+        BlockHeight,
+        ChainId,
+    },
 };
 
 /// The config of the block verifier.
 pub struct Config {
     /// The consensus config.
     pub consensus: ConsensusConfig,
+    /// The id of the chain, signed by the BFT validators.
+    pub chain_id: ChainId,
     /// The block height of the genesis block.
     pub block_height: BlockHeight,
     /// The DA block height at genesis block.
@@ -20,11 +25,13 @@ impl Config {
     /// Creates the verifier config for all possible consensuses.
     pub fn new(
         consensus: ConsensusConfig,
+        chain_id: ChainId,
         block_height: BlockHeight,
         da_block_height: DaBlockHeight,
     ) -> Self {
         Self {
             consensus,
+            chain_id,
             block_height,
             da_block_height,
         }
@@ -1,5 +1,6 @@
 pub mod fuel_core_storage_adapter;
 pub mod gas_price_algorithm;
+pub mod gas_price_history;
 pub mod l2_block_source;
 pub mod updater_metadata;
 pub mod utils;
@@ -3,8 +3,10 @@ use crate::{
         fuel_core_storage_adapter::storage::{
             BundleIdTable,
             GasPriceColumn,
+            GasPriceHistory,
             GasPriceMetadata,
         },
+        gas_price_history::GasPriceHistoryEntry,
         updater_metadata::UpdaterMetadata,
         utils::{
             BlockInfo,
@@ -15,9 +17,12 @@ use crate::{
     ports::{
         GasPriceServiceAtomicStorage,
         GetDaBundleId,
+        GetGasPriceHistory,
         GetMetadataStorage,
         SetDaBundleId,
+        SetGasPriceHistory,
         SetMetadataStorage,
+        SetMetadataWithGasPriceHistory,
     },
 };
 use anyhow::anyhow;
@@ -99,6 +104,82 @@ where
     }
 }
 
+impl<Storage> SetGasPriceHistory for Storage
+where
+    Storage: Send + Sync,
+    Storage: Modifiable,
+    for<'a> StorageTransaction<&'a mut Storage>:
+        StorageMutate<GasPriceHistory, Error = StorageError>,
+{
+    fn set_gas_price_history(
+        &mut self,
+        entry: &GasPriceHistoryEntry,
+    ) -> GasPriceResult<()> {
+        let block_height = entry.l2_block_height();
+        let mut tx = self.write_transaction();
+        tx.storage_as_mut::<GasPriceHistory>()
+            .insert(&block_height, entry)
+            .and_then(|_| tx.commit())
+            .map_err(|err| GasPriceError::CouldNotSetGasPriceHistory {
+                block_height,
+                source_error: err.into(),
+            })?;
+        Ok(())
+    }
+}
+
+impl<Storage> SetMetadataWithGasPriceHistory for Storage
+where
+    Storage: Send + Sync,
+    Storage: Modifiable,
+    for<'a> StorageTransaction<&'a mut Storage>:
+        StorageMutate<GasPriceMetadata, Error = StorageError>
+            + StorageMutate<GasPriceHistory, Error = StorageError>,
+{
+    fn set_metadata_with_gas_price_history(
+        &mut self,
+        metadata: &UpdaterMetadata,
+        entry: &GasPriceHistoryEntry,
+    ) -> GasPriceResult<()> {
+        let mut tx = self.write_transaction();
+        let block_height = metadata.l2_block_height();
+        tx.storage_as_mut::<GasPriceMetadata>()
+            .insert(&block_height, metadata)
+            .map_err(|err| GasPriceError::CouldNotSetMetadata {
+                block_height,
+                source_error: err.into(),
+            })?;
+        let block_height = entry.l2_block_height();
+        tx.storage_as_mut::<GasPriceHistory>()
+            .insert(&block_height, entry)
+            .map_err(|err| GasPriceError::CouldNotSetGasPriceHistory {
+                block_height,
+                source_error: err.into(),
+            })?;
+        tx.commit().map_err(|err| GasPriceError::CouldNotCommit(err.into()))?;
+        Ok(())
+    }
+}
+
+impl<Storage> GetGasPriceHistory for Storage
+where
+    Storage: Send + Sync,
+    Storage: StorageInspect<GasPriceHistory, Error = StorageError>,
+{
+    fn get_gas_price_history(
+        &self,
+        block_height: &BlockHeight,
+    ) -> GasPriceResult<Option<GasPriceHistoryEntry>> {
+        let entry = self
+            .storage::<GasPriceHistory>()
+            .get(block_height)
+            .map_err(|err| GasPriceError::CouldNotFetchGasPriceHistory {
+                source_error: err.into(),
+            })?;
+        Ok(entry.map(|inner| inner.into_owned()))
+    }
+}
+
 impl<Storage> GetDaBundleId for Storage
 where
     Storage: Send + Sync,
@@ -80,3 +80,27 @@ async fn set_metadata__can_set_metadata() {
     let expected = Some(metadata);
     assert_eq!(expected, actual);
 }
+
+#[tokio::test]
+async fn set_metadata_with_gas_price_history__sets_both_for_the_block() {
+    // given
+    let mut database = database();
+    let block_height: BlockHeight = 1u32.into();
+    let metadata = arb_metadata_with_l2_height(block_height);
+    let entry = GasPriceHistoryEntry {
+        l2_block_height: block_height.into(),
+        exec_gas_price: 100,
+        ..Default::default()
+    };
+
+    // when
+    database
+        .set_metadata_with_gas_price_history(&metadata, &entry)
+        .unwrap();
+
+    // then
+    let actual_metadata = database.get_metadata(&block_height).unwrap();
+    assert_eq!(Some(metadata), actual_metadata);
+    let actual_entry = database.get_gas_price_history(&block_height).unwrap();
+    assert_eq!(Some(entry), actual_entry);
+}
@@ -1,4 +1,7 @@
-use crate::common::updater_metadata::UpdaterMetadata;
+use crate::common::{
+    gas_price_history::GasPriceHistoryEntry,
+    updater_metadata::UpdaterMetadata,
+};
 use fuel_core_storage::{
     blueprint::plain::Plain,
     codec::{
@@ -29,6 +32,7 @@ pub enum GasPriceColumn {
     State = 1,
     UnrecordedBlocks = 2,
     BundleId = 3,
+    History = 4,
 }
 
 impl GasPriceColumn {
@@ -113,3 +117,23 @@ impl TableWithBlueprint for BundleIdTable {
         GasPriceColumn::BundleId
     }
 }
+
+/// The storage table for the per-height history of the gas prices calculated by the
+/// gas price algorithm updater
+pub struct GasPriceHistory;
+
+impl Mappable for GasPriceHistory {
+    type Key = Self::OwnedKey;
+    type OwnedKey = BlockHeight;
+    type Value = Self::OwnedValue;
+    type OwnedValue = GasPriceHistoryEntry;
+}
+
+impl TableWithBlueprint for GasPriceHistory {
+    type Blueprint = Plain<Primitive<4>, Postcard>;
+    type Column = GasPriceColumn;
+
+    fn column() -> Self::Column {
+        GasPriceColumn::History
+    }
+}
@@ -0,0 +1,27 @@
+use fuel_core_types::fuel_types::BlockHeight;
+
+/// The gas prices calculated by the gas price algorithm updater after processing
+/// an L2 block, together with the data of the block that was used to calculate them.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct GasPriceHistoryEntry {
+    /// The height of the processed L2 block
+    pub l2_block_height: u32,
+    /// The execution gas price calculated for the next block
+    pub exec_gas_price: u64,
+    /// The DA gas price calculated for the next block
+    pub da_gas_price: u64,
+    /// The gas used by the processed L2 block
+    pub block_gas_used: u64,
+    /// The gas capacity of the processed L2 block
+    pub block_gas_capacity: u64,
+    /// The size of the processed L2 block in bytes
+    pub block_bytes: u64,
+    /// The total cost of the DA records applied while processing the block
+    pub recorded_da_cost: u128,
+}
+
+impl GasPriceHistoryEntry {
+    pub fn l2_block_height(&self) -> BlockHeight {
+        self.l2_block_height.into()
+    }
+}
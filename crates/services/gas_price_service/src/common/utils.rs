@@ -15,6 +15,15 @@ pub enum Error {
         block_height: BlockHeight,
         source_error: anyhow::Error,
     },
+    #[error(
+        "Failed to set gas price history at height {block_height:?}: {source_error:?}"
+    )]
+    CouldNotSetGasPriceHistory {
+        block_height: BlockHeight,
+        source_error: anyhow::Error,
+    },
+    #[error("Failed to retrieve gas price history: {source_error:?}")]
+    CouldNotFetchGasPriceHistory { source_error: anyhow::Error },
     #[error("Failed to initialize updater: {0:?}")]
     CouldNotInitUpdater(anyhow::Error),
     #[error("Failed to convert metadata to concrete type. THere is no migration path for this metadata version")]
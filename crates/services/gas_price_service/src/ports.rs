@@ -7,6 +7,7 @@ use fuel_core_types::{
 
 use crate::{
     common::{
+        gas_price_history::GasPriceHistoryEntry,
         updater_metadata::UpdaterMetadata,
         utils::Result,
     },
@@ -34,6 +35,27 @@ pub trait GetMetadataStorage: Send + Sync {
         -> Result<Option<UpdaterMetadata>>;
 }
 
+pub trait SetGasPriceHistory: Send + Sync {
+    fn set_gas_price_history(&mut self, entry: &GasPriceHistoryEntry) -> Result<()>;
+}
+
+/// Writes the metadata and the gas price history entry of the same block in one
+/// transaction, so the history doesn't diverge from the metadata after a crash.
+pub trait SetMetadataWithGasPriceHistory: Send + Sync {
+    fn set_metadata_with_gas_price_history(
+        &mut self,
+        metadata: &UpdaterMetadata,
+        entry: &GasPriceHistoryEntry,
+    ) -> Result<()>;
+}
+
+pub trait GetGasPriceHistory: Send + Sync {
+    fn get_gas_price_history(
+        &self,
+        block_height: &BlockHeight,
+    ) -> Result<Option<GasPriceHistoryEntry>>;
+}
+
 pub trait SetDaBundleId: Send + Sync {
     fn set_bundle_id(&mut self, block_height: &BlockHeight, bundle_id: u32)
         -> Result<()>;
@@ -52,6 +74,7 @@ where
     type Transaction<'a>: AsUnrecordedBlocks
         + SetMetadataStorage
         + GetMetadataStorage
+        + SetGasPriceHistory
         + SetDaBundleId
         + GetDaBundleId
     where
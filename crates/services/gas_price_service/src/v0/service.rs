@@ -1,5 +1,6 @@
 use crate::{
     common::{
+        gas_price_history::GasPriceHistoryEntry,
         l2_block_source::L2BlockSource,
         updater_metadata::UpdaterMetadata,
         utils::BlockInfo,
@@ -7,6 +8,7 @@ use crate::{
     ports::{
         GetMetadataStorage,
         SetMetadataStorage,
+        SetMetadataWithGasPriceHistory,
     },
     v0::algorithm::SharedV0Algorithm,
 };
@@ -38,7 +40,7 @@ pub struct GasPriceServiceV0<L2, Metadata> {
 
 impl<L2, Metadata> GasPriceServiceV0<L2, Metadata>
 where
-    Metadata: GetMetadataStorage + SetMetadataStorage,
+    Metadata: GetMetadataStorage + SetMetadataStorage + SetMetadataWithGasPriceHistory,
 {
     pub fn new(
         l2_block_source: L2,
@@ -86,13 +88,26 @@ where
         height: u32,
         gas_used: u64,
         block_gas_capacity: u64,
+        block_bytes: u64,
     ) -> anyhow::Result<()> {
         let capacity = self.validate_block_gas_capacity(block_gas_capacity)?;
 
         self.algorithm_updater
             .update_l2_block_data(height, gas_used, capacity)?;
 
-        self.set_metadata().await?;
+        let metadata: UpdaterMetadata = self.algorithm_updater.clone().into();
+        let history = GasPriceHistoryEntry {
+            l2_block_height: height,
+            exec_gas_price: self.algorithm_updater.new_exec_price,
+            da_gas_price: 0,
+            block_gas_used: gas_used,
+            block_gas_capacity,
+            block_bytes,
+            recorded_da_cost: 0,
+        };
+        self.metadata_storage
+            .set_metadata_with_gas_price_history(&metadata, &history)
+            .map_err(|err| anyhow!(err))?;
         Ok(())
     }
 
@@ -108,10 +123,16 @@ where
                 height,
                 gas_used,
                 block_gas_capacity,
+                block_bytes,
                 ..
             } => {
-                self.handle_normal_block(height, gas_used, block_gas_capacity)
-                    .await?;
+                self.handle_normal_block(
+                    height,
+                    gas_used,
+                    block_gas_capacity,
+                    block_bytes,
+                )
+                .await?;
             }
         }
 
@@ -123,7 +144,7 @@ where
 impl<L2, Metadata> GasPriceServiceV0<L2, Metadata>
 where
     L2: L2BlockSource,
-    Metadata: GetMetadataStorage + SetMetadataStorage,
+    Metadata: GetMetadataStorage + SetMetadataStorage + SetMetadataWithGasPriceHistory,
 {
     async fn process_l2_block_res(
         &mut self,
@@ -141,7 +162,7 @@ where
 impl<L2, Metadata> RunnableTask for GasPriceServiceV0<L2, Metadata>
 where
     L2: L2BlockSource,
-    Metadata: GetMetadataStorage + SetMetadataStorage,
+    Metadata: GetMetadataStorage + SetMetadataStorage + SetMetadataWithGasPriceHistory,
 {
     async fn run(&mut self, watcher: &mut StateWatcher) -> TaskNextAction {
         tracing::trace!("Call of `run` function of the gas price service v0");
@@ -174,6 +195,7 @@ where
 mod tests {
     use crate::{
         common::{
+            gas_price_history::GasPriceHistoryEntry,
             l2_block_source::L2BlockSource,
             updater_metadata::UpdaterMetadata,
             utils::{
@@ -184,6 +206,7 @@ mod tests {
         ports::{
             GetMetadataStorage,
             SetMetadataStorage,
+            SetMetadataWithGasPriceHistory,
         },
         v0::{
             metadata::V0AlgorithmConfig,
@@ -240,6 +263,16 @@ mod tests {
         }
     }
 
+    impl SetMetadataWithGasPriceHistory for FakeMetadata {
+        fn set_metadata_with_gas_price_history(
+            &mut self,
+            metadata: &UpdaterMetadata,
+            _: &GasPriceHistoryEntry,
+        ) -> GasPriceResult<()> {
+            self.set_metadata(metadata)
+        }
+    }
+
     #[tokio::test]
     async fn run__updates_gas_price() {
         // given
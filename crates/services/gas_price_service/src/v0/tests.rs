@@ -6,6 +6,7 @@ use crate::{
             GasPriceSettings,
             GasPriceSettingsProvider,
         },
+        gas_price_history::GasPriceHistoryEntry,
         l2_block_source::L2BlockSource,
         updater_metadata::UpdaterMetadata,
         utils::{
@@ -19,6 +20,7 @@ use crate::{
         GetMetadataStorage,
         L2Data,
         SetMetadataStorage,
+        SetMetadataWithGasPriceHistory,
     },
     v0::{
         metadata::{
@@ -101,6 +103,16 @@ impl GetMetadataStorage for FakeMetadata {
     }
 }
 
+impl SetMetadataWithGasPriceHistory for FakeMetadata {
+    fn set_metadata_with_gas_price_history(
+        &mut self,
+        metadata: &UpdaterMetadata,
+        _: &GasPriceHistoryEntry,
+    ) -> GasPriceResult<()> {
+        self.set_metadata(metadata)
+    }
+}
+
 struct ErroringMetadata;
 
 impl SetMetadataStorage for ErroringMetadata {
@@ -120,6 +132,19 @@ impl GetMetadataStorage for ErroringMetadata {
     }
 }
 
+impl SetMetadataWithGasPriceHistory for ErroringMetadata {
+    fn set_metadata_with_gas_price_history(
+        &mut self,
+        _: &UpdaterMetadata,
+        _: &GasPriceHistoryEntry,
+    ) -> GasPriceResult<()> {
+        Err(GasPriceError::CouldNotSetGasPriceHistory {
+            block_height: Default::default(),
+            source_error: anyhow!("boo!"),
+        })
+    }
+}
+
 fn arbitrary_config() -> V0AlgorithmConfig {
     V0AlgorithmConfig {
         starting_gas_price: 100,
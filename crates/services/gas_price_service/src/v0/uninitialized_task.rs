@@ -6,6 +6,7 @@ use crate::{
             GasPriceSettingsProvider,
         },
         gas_price_algorithm::SharedGasPriceAlgo,
+        gas_price_history::GasPriceHistoryEntry,
         l2_block_source::FuelL2BlockSource,
         updater_metadata::UpdaterMetadata,
         utils::{
@@ -19,6 +20,7 @@ use crate::{
         GasPriceServiceConfig,
         L2Data,
         SetMetadataStorage,
+        SetMetadataWithGasPriceHistory,
     },
     v0::{
         algorithm::SharedV0Algorithm,
@@ -66,7 +68,7 @@ where
     L2DataStore: L2Data,
     L2DataStoreView: AtomicView<LatestView = L2DataStore>,
     GasPriceStore: GasPriceData,
-    Metadata: GetMetadataStorage + SetMetadataStorage,
+    Metadata: GetMetadataStorage + SetMetadataStorage + SetMetadataWithGasPriceHistory,
     SettingsProvider: GasPriceSettingsProvider,
 {
     pub fn new(
@@ -167,7 +169,7 @@ where
     L2DataStore: L2Data,
     L2DataStoreView: AtomicView<LatestView = L2DataStore>,
     GasPriceStore: GasPriceData,
-    Metadata: GetMetadataStorage + SetMetadataStorage,
+    Metadata: GetMetadataStorage + SetMetadataStorage + SetMetadataWithGasPriceHistory,
     SettingsProvider: GasPriceSettingsProvider,
 {
     const NAME: &'static str = "GasPriceServiceV0";
@@ -194,7 +196,7 @@ pub fn initialize_algorithm<Metadata>(
     metadata_storage: &Metadata,
 ) -> GasPriceResult<(AlgorithmUpdaterV0, SharedV0Algorithm)>
 where
-    Metadata: GetMetadataStorage + SetMetadataStorage,
+    Metadata: GetMetadataStorage,
 {
     let min_exec_gas_price = config.min_gas_price;
     let exec_gas_price_change_percent = config.gas_price_change_percent;
@@ -244,7 +246,7 @@ fn sync_gas_price_db_with_on_chain_storage<
 where
     L2DataStore: L2Data,
     L2DataStoreView: AtomicView<LatestView = L2DataStore>,
-    Metadata: GetMetadataStorage + SetMetadataStorage,
+    Metadata: GetMetadataStorage + SetMetadataWithGasPriceHistory,
     SettingsProvider: GasPriceSettingsProvider,
 {
     let metadata = metadata_storage
@@ -288,7 +290,7 @@ fn sync_v0_metadata<L2DataStore, L2DataStoreView, Metadata, SettingsProvider>(
 where
     L2DataStore: L2Data,
     L2DataStoreView: AtomicView<LatestView = L2DataStore>,
-    Metadata: SetMetadataStorage,
+    Metadata: SetMetadataWithGasPriceHistory,
     SettingsProvider: GasPriceSettingsProvider,
 {
     let first = metadata_height.saturating_add(1);
@@ -305,17 +307,30 @@ where
         } = settings.settings(&param_version)?;
         let block_gas_capacity = block_gas_limit.try_into()?;
 
-        let block_gas_used =
+        let (block_gas_used, block_bytes) =
             match get_block_info(&block, gas_price_factor, block_gas_limit)? {
                 BlockInfo::GenesisBlock => {
                     Err(anyhow::anyhow!("should not be genesis block"))?
                 }
-                BlockInfo::Block { gas_used, .. } => gas_used,
+                BlockInfo::Block {
+                    gas_used,
+                    block_bytes,
+                    ..
+                } => (gas_used, block_bytes),
             };
 
         updater.update_l2_block_data(height, block_gas_used, block_gas_capacity)?;
         let metadata: UpdaterMetadata = updater.clone().into();
-        metadata_storage.set_metadata(&metadata)?;
+        let history = GasPriceHistoryEntry {
+            l2_block_height: height,
+            exec_gas_price: updater.new_exec_price,
+            da_gas_price: 0,
+            block_gas_used,
+            block_gas_capacity: block_gas_limit,
+            block_bytes,
+            recorded_da_cost: 0,
+        };
+        metadata_storage.set_metadata_with_gas_price_history(&metadata, &history)?;
     }
 
     Ok(())
@@ -345,7 +360,7 @@ where
     L2DataStoreView: AtomicView<LatestView = L2DataStore>,
     GasPriceStore: GasPriceData,
     SettingsProvider: GasPriceSettingsProvider,
-    Metadata: GetMetadataStorage + SetMetadataStorage,
+    Metadata: GetMetadataStorage + SetMetadataStorage + SetMetadataWithGasPriceHistory,
 {
     let v0_config = config.v0().ok_or(anyhow::anyhow!("Expected V0 config"))?;
     let gas_price_init = UninitializedTask::new(
@@ -3,7 +3,10 @@ use fuel_gas_price_algorithm::v1::{
     AlgorithmUpdaterV1,
     L2ActivityTracker,
 };
-use std::num::NonZeroU64;
+use std::{
+    num::NonZeroU64,
+    time::Duration,
+};
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq)]
 pub struct V1Metadata {
@@ -74,6 +77,9 @@ pub struct V1AlgorithmConfig {
     pub capped_range_size: u16,
     pub decrease_range_size: u16,
     pub block_activity_threshold: u8,
+    /// The interval at which the DA block costs are requested from the source.
+    /// Uses the default interval of the DA source service if not set.
+    pub da_poll_interval: Option<Duration>,
 }
 
 pub fn updater_from_config(value: &V1AlgorithmConfig) -> AlgorithmUpdaterV1 {
@@ -3,6 +3,7 @@ use std::num::NonZeroU64;
 use crate::{
     common::{
         gas_price_algorithm::SharedGasPriceAlgo,
+        gas_price_history::GasPriceHistoryEntry,
         l2_block_source::L2BlockSource,
         updater_metadata::UpdaterMetadata,
         utils::{
@@ -15,6 +16,7 @@ use crate::{
         GetDaBundleId,
         GetMetadataStorage,
         SetDaBundleId,
+        SetGasPriceHistory,
         SetMetadataStorage,
     },
     v0::metadata::V0Metadata,
@@ -43,8 +45,9 @@ use crate::{
 use anyhow::anyhow;
 use async_trait::async_trait;
 use fuel_core_services::{
-    RunnableService,
     RunnableTask,
+    Service,
+    ServiceRunner,
     StateWatcher,
     TaskNextAction,
 };
@@ -61,7 +64,10 @@ use futures::FutureExt;
 use tokio::sync::broadcast::Receiver;
 
 /// The service that updates the gas price algorithm.
-pub struct GasPriceServiceV1<L2, DA, StorageTxProvider> {
+pub struct GasPriceServiceV1<L2, DA, StorageTxProvider>
+where
+    DA: DaBlockCostsSource + 'static,
+{
     /// The algorithm that can be used in the next block
     shared_algo: SharedV1Algorithm,
     /// The L2 block source
@@ -69,7 +75,7 @@ pub struct GasPriceServiceV1<L2, DA, StorageTxProvider> {
     /// The algorithm updater
     algorithm_updater: AlgorithmUpdaterV1,
     /// the da source adapter handle
-    da_source_adapter_handle: DaSourceService<DA>,
+    da_source_adapter_handle: ServiceRunner<DaSourceService<DA>>,
     /// The da source channel
     da_source_channel: Receiver<DaBlockCosts>,
     /// Buffer of block costs from the DA chain
@@ -81,7 +87,7 @@ pub struct GasPriceServiceV1<L2, DA, StorageTxProvider> {
 impl<L2, DA, AtomicStorage> GasPriceServiceV1<L2, DA, AtomicStorage>
 where
     L2: L2BlockSource,
-    DA: DaBlockCostsSource,
+    DA: DaBlockCostsSource + 'static,
     AtomicStorage: GasPriceServiceAtomicStorage,
 {
     async fn commit_block_data_to_algorithm(
@@ -99,18 +105,17 @@ where
 
 impl<L2, DA, AtomicStorage> GasPriceServiceV1<L2, DA, AtomicStorage>
 where
-    DA: DaBlockCostsSource,
+    DA: DaBlockCostsSource + 'static,
     AtomicStorage: GasPriceServiceAtomicStorage,
 {
     pub fn new(
         l2_block_source: L2,
         shared_algo: SharedV1Algorithm,
         algorithm_updater: AlgorithmUpdaterV1,
-        da_source_adapter_handle: DaSourceService<DA>,
+        da_source_adapter_handle: ServiceRunner<DaSourceService<DA>>,
         storage_tx_provider: AtomicStorage,
     ) -> Self {
-        let da_source_channel =
-            da_source_adapter_handle.shared_data().clone().subscribe();
+        let da_source_channel = da_source_adapter_handle.shared.clone().subscribe();
         Self {
             shared_algo,
             l2_block_source,
@@ -160,6 +165,7 @@ where
         let mut bundle_id = storage_tx
             .get_bundle_id(&BlockHeight::from(prev_height))
             .map_err(|err| anyhow!(err))?;
+        let mut recorded_da_cost = 0u128;
 
         for da_block_costs in &self.da_block_costs_buffer {
             tracing::debug!("Updating DA block costs: {:?}", da_block_costs);
@@ -170,6 +176,8 @@ where
                 &mut storage_tx.as_unrecorded_blocks(),
             )?;
             bundle_id = Some(da_block_costs.bundle_id);
+            recorded_da_cost =
+                recorded_da_cost.saturating_add(da_block_costs.blob_cost_wei);
         }
 
         if let Some(bundle_id) = bundle_id {
@@ -191,6 +199,18 @@ where
         storage_tx
             .set_metadata(&metadata)
             .map_err(|err| anyhow!(err))?;
+        let history = GasPriceHistoryEntry {
+            l2_block_height: height,
+            exec_gas_price: self.algorithm_updater.descaled_exec_price(),
+            da_gas_price: self.algorithm_updater.descaled_da_price(),
+            block_gas_used: gas_used,
+            block_gas_capacity,
+            block_bytes,
+            recorded_da_cost,
+        };
+        storage_tx
+            .set_gas_price_history(&history)
+            .map_err(|err| anyhow!(err))?;
         AtomicStorage::commit_transaction(storage_tx)?;
         let new_algo = self.algorithm_updater.algorithm();
         self.shared_algo.update(new_algo).await;
@@ -238,7 +258,7 @@ where
 impl<L2, DA, AtomicStorage> RunnableTask for GasPriceServiceV1<L2, DA, AtomicStorage>
 where
     L2: L2BlockSource,
-    DA: DaBlockCostsSource,
+    DA: DaBlockCostsSource + 'static,
     AtomicStorage: GasPriceServiceAtomicStorage,
 {
     async fn run(&mut self, watcher: &mut StateWatcher) -> TaskNextAction {
@@ -276,8 +296,8 @@ where
             self.apply_block_info_to_gas_algorithm(block).await?;
         }
 
-        // run shutdown hooks for internal services
-        self.da_source_adapter_handle.shutdown().await?;
+        // stop internal services
+        self.da_source_adapter_handle.stop_and_await().await?;
 
         Ok(())
     }
@@ -340,6 +360,8 @@ mod tests {
 
     use fuel_core_services::{
         RunnableTask,
+        Service,
+        ServiceRunner,
         StateWatcher,
     };
     use fuel_core_storage::{
@@ -359,6 +381,7 @@ mod tests {
                 BundleIdTable,
                 GasPriceColumn,
                 GasPriceColumn::UnrecordedBlocks,
+                GasPriceHistory,
                 UnrecordedBlocksTable,
             },
             gas_price_algorithm::SharedGasPriceAlgo,
@@ -468,19 +491,20 @@ mod tests {
             capped_range_size: 100,
             decrease_range_size: 4,
             block_activity_threshold: 20,
+            da_poll_interval: None,
         };
         let inner = database();
         let (algo_updater, shared_algo) =
             initialize_algorithm(&config, l2_block_height, &metadata_storage).unwrap();
 
         let notifier = Arc::new(tokio::sync::Notify::new());
-        let dummy_da_source = DaSourceService::new(
+        let dummy_da_source = ServiceRunner::new(DaSourceService::new(
             DummyDaBlockCosts::new(
                 Err(anyhow::anyhow!("unused at the moment")),
                 notifier.clone(),
             ),
             None,
-        );
+        ));
 
         let mut service = GasPriceServiceV1::new(
             l2_block_source,
@@ -536,6 +560,7 @@ mod tests {
             capped_range_size: 100,
             decrease_range_size: 4,
             block_activity_threshold: 20,
+            da_poll_interval: None,
         };
         let mut inner = database();
         let mut tx = inner.write_transaction();
@@ -551,7 +576,7 @@ mod tests {
         algo_updater.new_scaled_da_gas_price = 10_000_000;
 
         let notifier = Arc::new(tokio::sync::Notify::new());
-        let da_source = DaSourceService::new(
+        let da_source = ServiceRunner::new(DaSourceService::new(
             DummyDaBlockCosts::new(
                 Ok(DaBlockCosts {
                     bundle_id: 1,
@@ -562,7 +587,11 @@ mod tests {
                 notifier.clone(),
             ),
             Some(Duration::from_millis(1)),
-        );
+        ));
+        // the RunnableTask depends on the handle passed to it for the da block cost source to already be running,
+        // which is the responsibility of the UninitializedTask in the `into_task` method of the RunnableService
+        // here we mimic that behaviour by starting the da block cost service.
+        da_source.start_and_await().await.unwrap();
         let mut watcher = StateWatcher::started();
 
         let mut service = GasPriceServiceV1::new(
@@ -575,15 +604,6 @@ mod tests {
         let read_algo = service.next_block_algorithm();
         let initial_price = read_algo.next_gas_price();
 
-        // the RunnableTask depends on the handle passed to it for the da block cost source to already be running,
-        // which is the responsibility of the UninitializedTask in the `into_task` method of the RunnableService
-        // here we mimic that behaviour by running the da block cost service.
-        let mut da_source_watcher = StateWatcher::started();
-        service
-            .da_source_adapter_handle
-            .run(&mut da_source_watcher)
-            .await;
-
         service.run(&mut watcher).await;
         tokio::time::sleep(Duration::from_millis(100)).await;
         l2_block_sender.send(l2_block_2).await.unwrap();
@@ -613,6 +633,7 @@ mod tests {
             capped_range_size: 100,
             decrease_range_size: 4,
             block_activity_threshold: 20,
+            da_poll_interval: None,
         }
     }
 
@@ -651,7 +672,7 @@ mod tests {
         algo_updater.new_scaled_da_gas_price = 10_000_000;
 
         let notifier = Arc::new(tokio::sync::Notify::new());
-        let da_source = DaSourceService::new(
+        let da_source = ServiceRunner::new(DaSourceService::new(
             DummyDaBlockCosts::new(
                 Ok(DaBlockCosts {
                     bundle_id,
@@ -662,7 +683,11 @@ mod tests {
                 notifier.clone(),
             ),
             Some(Duration::from_millis(1)),
-        );
+        ));
+        // the RunnableTask depends on the handle passed to it for the da block cost source to already be running,
+        // which is the responsibility of the UninitializedTask in the `into_task` method of the RunnableService
+        // here we mimic that behaviour by starting the da block cost service.
+        da_source.start_and_await().await.unwrap();
         let mut watcher = StateWatcher::started();
 
         let mut service = GasPriceServiceV1::new(
@@ -675,15 +700,6 @@ mod tests {
         let read_algo = service.next_block_algorithm();
         let initial_price = read_algo.next_gas_price();
 
-        // the RunnableTask depends on the handle passed to it for the da block cost source to already be running,
-        // which is the responsibility of the UninitializedTask in the `into_task` method of the RunnableService
-        // here we mimic that behaviour by running the da block cost service.
-        let mut da_source_watcher = StateWatcher::started();
-        service
-            .da_source_adapter_handle
-            .run(&mut da_source_watcher)
-            .await;
-
         service.run(&mut watcher).await;
         tokio::time::sleep(Duration::from_millis(100)).await;
         l2_block_sender.send(l2_block_2).await.unwrap();
@@ -703,4 +719,87 @@ mod tests {
 
         service.shutdown().await.unwrap();
     }
+
+    #[tokio::test]
+    async fn run__persists_gas_price_history_with_recorded_da_costs() {
+        // given
+        let block_height = 2;
+        let l2_block_2 = BlockInfo::Block {
+            height: block_height,
+            gas_used: 60,
+            block_gas_capacity: 100,
+            block_bytes: 100,
+            block_fees: 100,
+        };
+
+        let (l2_block_sender, l2_block_receiver) = mpsc::channel(1);
+        let l2_block_source = FakeL2BlockSource {
+            l2_block: l2_block_receiver,
+        };
+
+        let config = arbitrary_v1_algorithm_config();
+        let mut inner = database();
+        let mut tx = inner.write_transaction();
+        tx.storage_as_mut::<UnrecordedBlocksTable>()
+            .insert(&BlockHeight::from(1), &100)
+            .unwrap();
+        tx.commit().unwrap();
+        let mut algo_updater = updater_from_config(&config);
+        let shared_algo =
+            SharedGasPriceAlgo::new_with_algorithm(algo_updater.algorithm());
+        algo_updater.l2_block_height = block_height - 1;
+
+        let notifier = Arc::new(tokio::sync::Notify::new());
+        let da_source = ServiceRunner::new(DaSourceService::new(
+            DummyDaBlockCosts::new(
+                Ok(DaBlockCosts {
+                    bundle_id: 1,
+                    l2_blocks: (1..2).collect(),
+                    blob_cost_wei: 9000,
+                    bundle_size_bytes: 3000,
+                }),
+                notifier.clone(),
+            ),
+            Some(Duration::from_millis(1)),
+        ));
+        da_source.start_and_await().await.unwrap();
+        let mut watcher = StateWatcher::started();
+
+        let mut service = GasPriceServiceV1::new(
+            l2_block_source,
+            shared_algo,
+            algo_updater,
+            da_source,
+            inner,
+        );
+        let read_algo = service.next_block_algorithm();
+
+        service.run(&mut watcher).await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        l2_block_sender.send(l2_block_2).await.unwrap();
+
+        // when
+        service.run(&mut watcher).await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        // then
+        let history = service
+            .storage_tx_provider
+            .storage::<GasPriceHistory>()
+            .get(&BlockHeight::from(block_height))
+            .unwrap()
+            .unwrap()
+            .into_owned();
+        assert_eq!(history.l2_block_height, block_height);
+        assert_eq!(history.block_gas_used, 60);
+        assert_eq!(history.block_gas_capacity, 100);
+        assert_eq!(history.block_bytes, 100);
+        assert_eq!(history.recorded_da_cost, 9000);
+        assert_eq!(
+            history.exec_gas_price + history.da_gas_price,
+            read_algo.next_gas_price()
+        );
+
+        service.shutdown().await.unwrap();
+    }
 }
@@ -9,6 +9,7 @@ use crate::{
             GasPriceSettings,
             GasPriceSettingsProvider,
         },
+        gas_price_history::GasPriceHistoryEntry,
         l2_block_source::L2BlockSource,
         updater_metadata::UpdaterMetadata,
         utils::{
@@ -24,8 +25,10 @@ use crate::{
         GetMetadataStorage,
         L2Data,
         SetDaBundleId,
+        SetGasPriceHistory,
         SetMetadataStorage,
     },
+    v0::metadata::V0Metadata,
     v1::{
         algorithm::SharedV1Algorithm,
         da_source_service::{
@@ -60,6 +63,7 @@ use fuel_core_services::{
         IntoBoxStream,
     },
     RunnableTask,
+    ServiceRunner,
     StateWatcher,
 };
 use fuel_core_storage::{
@@ -196,6 +200,15 @@ impl GetMetadataStorage for UnimplementedStorageTx {
     }
 }
 
+impl SetGasPriceHistory for UnimplementedStorageTx {
+    fn set_gas_price_history(
+        &mut self,
+        _entry: &GasPriceHistoryEntry,
+    ) -> GasPriceResult<()> {
+        unimplemented!()
+    }
+}
+
 impl UnrecordedBlocks for UnimplementedStorageTx {
     fn insert(&mut self, _height: Height, _bytes: Bytes) -> Result<(), String> {
         unimplemented!()
@@ -292,6 +305,7 @@ fn zero_threshold_arbitrary_config() -> V1AlgorithmConfig {
         capped_range_size: 0,
         decrease_range_size: 0,
         block_activity_threshold: 0,
+        da_poll_interval: None,
     }
 }
 
@@ -325,6 +339,7 @@ fn different_arb_config() -> V1AlgorithmConfig {
         capped_range_size: 0,
         decrease_range_size: 0,
         block_activity_threshold: 0,
+        da_poll_interval: None,
     }
 }
 
@@ -368,7 +383,7 @@ async fn next_gas_price__affected_by_new_l2_block() {
     let (algo_updater, shared_algo) =
         initialize_algorithm(&config, height, &metadata_storage).unwrap();
     let da_source = FakeDABlockCost::never_returns();
-    let da_source_service = DaSourceService::new(da_source, None);
+    let da_source_service = ServiceRunner::new(DaSourceService::new(da_source, None));
     let mut service = GasPriceServiceV1::new(
         l2_block_source,
         shared_algo,
@@ -412,7 +427,7 @@ async fn run__new_l2_block_saves_old_metadata() {
     let algo_updater = updater_from_config(&config);
     let shared_algo = SharedV1Algorithm::new_with_algorithm(algo_updater.algorithm());
     let da_source = FakeDABlockCost::never_returns();
-    let da_source_service = DaSourceService::new(da_source, None);
+    let da_source_service = ServiceRunner::new(DaSourceService::new(da_source, None));
     let mut service = GasPriceServiceV1::new(
         l2_block_source,
         shared_algo,
@@ -705,3 +720,41 @@ async fn uninitialized_task__init__starts_da_service_with_bundle_id_in_storage()
     let expected = Some(bundle_id);
     assert_eq!(*actual, expected);
 }
+
+#[tokio::test]
+async fn uninitialized_task__init__migrates_v0_metadata_to_v1() {
+    // given
+    let block_height = 1;
+    let v0_metadata = V0Metadata {
+        new_exec_price: 123,
+        l2_block_height: block_height,
+    };
+    let config = zero_threshold_arbitrary_config();
+    let mut inner = database();
+    let mut tx = inner.begin_transaction().unwrap();
+    tx.set_metadata(&UpdaterMetadata::V0(v0_metadata.clone()))
+        .unwrap();
+    StorageTransaction::commit_transaction(tx).unwrap();
+    let service = UninitializedTask::new(
+        config.clone(),
+        Some(block_height.into()),
+        0.into(),
+        FakeSettings,
+        empty_block_stream(),
+        inner,
+        FakeDABlockCost::never_returns(),
+        FakeOnChainDb::new(block_height),
+    )
+    .unwrap();
+
+    // when
+    let service = service.init().await.unwrap();
+
+    // then
+    let actual = service
+        .storage_tx_provider()
+        .get_metadata(&block_height.into())
+        .unwrap();
+    let expected = V1Metadata::construct_from_v0_metadata(v0_metadata, &config).unwrap();
+    assert_eq!(actual, Some(UpdaterMetadata::V1(expected)));
+}
@@ -8,6 +8,7 @@ use crate::{
             GasPriceSettingsProvider,
         },
         gas_price_algorithm::SharedGasPriceAlgo,
+        gas_price_history::GasPriceHistoryEntry,
         l2_block_source::FuelL2BlockSource,
         updater_metadata::UpdaterMetadata,
         utils::{
@@ -24,6 +25,7 @@ use crate::{
         GetMetadataStorage,
         L2Data,
         SetDaBundleId,
+        SetGasPriceHistory,
         SetMetadataStorage,
     },
     v1::{
@@ -54,6 +56,7 @@ use anyhow::Error;
 use fuel_core_services::{
     stream::BoxStream,
     RunnableService,
+    Service,
     ServiceRunner,
     StateWatcher,
 };
@@ -81,6 +84,8 @@ use fuel_gas_price_algorithm::v1::{
 
 pub mod fuel_storage_unrecorded_blocks;
 
+pub use fuel_gas_price_algorithm::v1::AlgorithmV1;
+
 pub struct UninitializedTask<L2DataStoreView, GasPriceStore, DA, SettingsProvider> {
     pub config: V1AlgorithmConfig,
     pub gas_metadata_height: Option<BlockHeight>,
@@ -100,7 +105,7 @@ where
     L2DataStore: L2Data,
     L2DataStoreView: AtomicView<LatestView = L2DataStore>,
     AtomicStorage: GasPriceServiceAtomicStorage,
-    DA: DaBlockCostsSource,
+    DA: DaBlockCostsSource + 'static,
     SettingsProvider: GasPriceSettingsProvider,
 {
     #[allow(clippy::too_many_arguments)]
@@ -165,15 +170,20 @@ where
             self.block_stream,
         );
 
-        // TODO: Add to config
-        // https://github.com/FuelLabs/fuel-core/issues/2140
-        let poll_interval = None;
+        if !first_run {
+            migrate_v0_metadata(&self.config, metadata_height, &mut self.gas_price_db)?;
+        }
+
         if let Some(bundle_id) =
             self.gas_price_db.get_bundle_id(&metadata_height.into())?
         {
             self.da_source.set_last_value(bundle_id).await?;
         }
-        let da_service = DaSourceService::new(self.da_source, poll_interval);
+        let da_service = ServiceRunner::new(DaSourceService::new(
+            self.da_source,
+            self.config.da_poll_interval,
+        ));
+        da_service.start_and_await().await?;
 
         if BlockHeight::from(latest_block_height) == self.genesis_block_height
             || first_run
@@ -238,6 +248,32 @@ where
     }
 }
 
+/// Replaces the metadata of the `V0` algorithm at the `metadata_height` with
+/// its `V1` equivalent. It allows switching the node from the `V0` algorithm
+/// to the `V1` without losing the gas price history.
+fn migrate_v0_metadata<AtomicStorage>(
+    config: &V1AlgorithmConfig,
+    metadata_height: u32,
+    persisted_data: &mut AtomicStorage,
+) -> anyhow::Result<()>
+where
+    AtomicStorage: GasPriceServiceAtomicStorage,
+{
+    let Some(UpdaterMetadata::V0(metadata)) =
+        persisted_data.get_metadata(&metadata_height.into())?
+    else {
+        return Ok(())
+    };
+
+    tracing::info!("Migrating the gas price metadata at height {metadata_height} to V1");
+    let metadata = V1Metadata::construct_from_v0_metadata(metadata, config)?;
+    let mut tx = persisted_data.begin_transaction()?;
+    tx.set_metadata(&UpdaterMetadata::V1(metadata))?;
+    AtomicStorage::commit_transaction(tx)?;
+
+    Ok(())
+}
+
 fn sync_gas_price_db_with_on_chain_storage<
     L2DataStore,
     L2DataStoreView,
@@ -332,6 +368,15 @@ where
         )?;
         let metadata: UpdaterMetadata = updater.clone().into();
         tx.set_metadata(&metadata)?;
+        tx.set_gas_price_history(&GasPriceHistoryEntry {
+            l2_block_height: height,
+            exec_gas_price: updater.descaled_exec_price(),
+            da_gas_price: updater.descaled_da_price(),
+            block_gas_used,
+            block_gas_capacity: block_gas_limit,
+            block_bytes,
+            recorded_da_cost: 0,
+        })?;
     }
     AtomicStorage::commit_transaction(tx)?;
 
@@ -355,7 +400,7 @@ where
     L2DataStore::LatestView: L2Data,
     AtomicStorage: GasPriceServiceAtomicStorage + GasPriceData,
     SettingsProvider: GasPriceSettingsProvider,
-    DA: DaBlockCostsSource,
+    DA: DaBlockCostsSource + 'static,
 {
     let metadata_height = gas_price_db.latest_height();
     let gas_price_init = UninitializedTask::new(
@@ -118,6 +118,7 @@ impl CachedView {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::request_response::messages::StateGroup;
     use fuel_core_types::blockchain::consensus::Genesis;
     use std::sync::Arc;
     use tokio::sync::Notify;
@@ -166,6 +167,14 @@ mod tests {
             self.sender.notify_waiters();
             Ok(Genesis::default())
         }
+
+        fn get_state_group(
+            &self,
+            _table: &str,
+            _after_key: Option<&[u8]>,
+        ) -> StorageResult<Option<StateGroup>> {
+            unimplemented!()
+        }
     }
 
     #[tokio::test]
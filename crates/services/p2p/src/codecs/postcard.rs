@@ -158,6 +158,10 @@ impl GossipsubCodec for PostcardCodec {
     fn encode(&self, data: Self::RequestMessage) -> Result<Vec<u8>, io::Error> {
         let encoded_data = match data {
             GossipsubBroadcastRequest::NewTx(tx) => postcard::to_stdvec(&*tx),
+            GossipsubBroadcastRequest::NewBlock(block) => postcard::to_stdvec(&*block),
+            GossipsubBroadcastRequest::ConsensusMessage(message) => {
+                postcard::to_stdvec(&*message)
+            }
         };
 
         encoded_data.map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))
@@ -170,6 +174,12 @@ impl GossipsubCodec for PostcardCodec {
     ) -> Result<Self::ResponseMessage, io::Error> {
         let decoded_response = match gossipsub_tag {
             GossipTopicTag::NewTx => GossipsubMessage::NewTx(deserialize(encoded_data)?),
+            GossipTopicTag::NewBlock => {
+                GossipsubMessage::NewBlock(deserialize(encoded_data)?)
+            }
+            GossipTopicTag::ConsensusMessage => {
+                GossipsubMessage::ConsensusMessage(deserialize(encoded_data)?)
+            }
         };
 
         Ok(decoded_response)
@@ -10,6 +10,8 @@ use super::messages::{
 };
 
 pub const NEW_TX_GOSSIP_TOPIC: &str = "new_tx";
+pub const NEW_BLOCK_GOSSIP_TOPIC: &str = "new_block";
+pub const CONSENSUS_GOSSIP_TOPIC: &str = "consensus";
 
 /// Holds used Gossipsub Topics
 /// Each field contains TopicHash of existing topics
@@ -17,15 +19,23 @@ pub const NEW_TX_GOSSIP_TOPIC: &str = "new_tx";
 #[derive(Debug)]
 pub struct GossipsubTopics {
     new_tx_topic: TopicHash,
+    new_block_topic: TopicHash,
+    consensus_topic: TopicHash,
 }
 
 impl GossipsubTopics {
     pub fn new(network_name: &str) -> Self {
         let new_tx_topic: Sha256Topic =
             Topic::new(format!("{NEW_TX_GOSSIP_TOPIC}/{network_name}"));
+        let new_block_topic: Sha256Topic =
+            Topic::new(format!("{NEW_BLOCK_GOSSIP_TOPIC}/{network_name}"));
+        let consensus_topic: Sha256Topic =
+            Topic::new(format!("{CONSENSUS_GOSSIP_TOPIC}/{network_name}"));
 
         Self {
             new_tx_topic: new_tx_topic.hash(),
+            new_block_topic: new_block_topic.hash(),
+            consensus_topic: consensus_topic.hash(),
         }
     }
 
@@ -36,6 +46,10 @@ impl GossipsubTopics {
     ) -> Option<GossipTopicTag> {
         match incoming_topic {
             hash if hash == &self.new_tx_topic => Some(GossipTopicTag::NewTx),
+            hash if hash == &self.new_block_topic => Some(GossipTopicTag::NewBlock),
+            hash if hash == &self.consensus_topic => {
+                Some(GossipTopicTag::ConsensusMessage)
+            }
             _ => None,
         }
     }
@@ -48,6 +62,10 @@ impl GossipsubTopics {
     ) -> TopicHash {
         match outgoing_request {
             GossipsubBroadcastRequest::NewTx(_) => self.new_tx_topic.clone(),
+            GossipsubBroadcastRequest::NewBlock(_) => self.new_block_topic.clone(),
+            GossipsubBroadcastRequest::ConsensusMessage(_) => {
+                self.consensus_topic.clone()
+            }
         }
     }
 }
@@ -55,7 +73,18 @@ impl GossipsubTopics {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use fuel_core_types::fuel_tx::Transaction;
+    use fuel_core_types::{
+        blockchain::{
+            consensus::bft::{
+                ConsensusMessage,
+                Vote,
+            },
+            SealedBlock,
+        },
+        fuel_crypto::SecretKey,
+        fuel_tx::Transaction,
+        fuel_types::ChainId,
+    };
     use libp2p::gossipsub::Topic;
     use std::sync::Arc;
 
@@ -84,4 +113,51 @@ mod tests {
             new_tx_topic.hash()
         );
     }
+
+    #[test]
+    fn test_new_block_gossipsub_topic() {
+        let network_name = "fuel_test_network";
+        let new_block_topic: Sha256Topic =
+            Topic::new(format!("{NEW_BLOCK_GOSSIP_TOPIC}/{network_name}"));
+
+        let gossipsub_topics = GossipsubTopics::new(network_name);
+
+        assert_eq!(gossipsub_topics.new_block_topic, new_block_topic.hash());
+        assert_eq!(
+            gossipsub_topics.get_gossipsub_tag(&new_block_topic.hash()),
+            Some(GossipTopicTag::NewBlock)
+        );
+        let broadcast_req =
+            GossipsubBroadcastRequest::NewBlock(Arc::new(SealedBlock::default()));
+        assert_eq!(
+            gossipsub_topics.get_gossipsub_topic_hash(&broadcast_req),
+            new_block_topic.hash()
+        );
+    }
+
+    #[test]
+    fn test_consensus_gossipsub_topic() {
+        let network_name = "fuel_test_network";
+        let consensus_topic: Sha256Topic =
+            Topic::new(format!("{CONSENSUS_GOSSIP_TOPIC}/{network_name}"));
+
+        let gossipsub_topics = GossipsubTopics::new(network_name);
+
+        assert_eq!(gossipsub_topics.consensus_topic, consensus_topic.hash());
+        assert_eq!(
+            gossipsub_topics.get_gossipsub_tag(&consensus_topic.hash()),
+            Some(GossipTopicTag::ConsensusMessage)
+        );
+        let vote = Vote::prevote(1u32.into(), 0, None).sign(
+            &ChainId::default(),
+            &SecretKey::random(&mut rand::thread_rng()),
+        );
+        let broadcast_req = GossipsubBroadcastRequest::ConsensusMessage(Arc::new(
+            ConsensusMessage::Vote(vote),
+        ));
+        assert_eq!(
+            gossipsub_topics.get_gossipsub_topic_hash(&broadcast_req),
+            consensus_topic.hash()
+        );
+    }
 }
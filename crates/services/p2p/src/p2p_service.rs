@@ -735,6 +735,18 @@ impl FuelP2PService {
                                 c.send((peer, Err(ResponseError::TypeMismatch))).is_ok()
                             }
                         },
+                        ResponseSender::StateGroup(c) => match response {
+                            V2ResponseMessage::StateGroup(v) => {
+                                c.send((peer, Ok(v))).is_ok()
+                            }
+                            _ => {
+                                warn!(
+                                    "Invalid response type received for request {:?}",
+                                    request_id
+                                );
+                                c.send((peer, Err(ResponseError::TypeMismatch))).is_ok()
+                            }
+                        },
                     };
 
                     if !send_ok {
@@ -776,6 +788,9 @@ impl FuelP2PService {
                         ResponseSender::TxPoolFullTransactions(c) => {
                             let _ = c.send((peer, Err(ResponseError::P2P(error))));
                         }
+                        ResponseSender::StateGroup(c) => {
+                            let _ = c.send((peer, Err(ResponseError::P2P(error))));
+                        }
                     };
                 }
             }
@@ -851,10 +866,15 @@ mod tests {
         config::Config,
         gossipsub::{
             messages::{
+                GossipTopicTag,
                 GossipsubBroadcastRequest,
                 GossipsubMessage,
             },
-            topics::NEW_TX_GOSSIP_TOPIC,
+            topics::{
+                CONSENSUS_GOSSIP_TOPIC,
+                NEW_BLOCK_GOSSIP_TOPIC,
+                NEW_TX_GOSSIP_TOPIC,
+            },
         },
         p2p_service::FuelP2PEvent,
         peer_manager::PeerInfo,
@@ -862,6 +882,8 @@ mod tests {
             RequestMessage,
             ResponseError,
             ResponseSender,
+            StateGroup,
+            StateGroupRequest,
             V2ResponseMessage,
         },
         service::to_message_acceptance,
@@ -869,12 +891,18 @@ mod tests {
     use fuel_core_types::{
         blockchain::{
             consensus::{
+                bft::{
+                    ConsensusMessage,
+                    Vote,
+                },
                 poa::PoAConsensus,
                 Consensus,
             },
             header::BlockHeader,
+            SealedBlock,
             SealedBlockHeader,
         },
+        fuel_crypto::SecretKey,
         fuel_tx::{
             Transaction,
             TransactionBuilder,
@@ -1439,6 +1467,54 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    #[instrument]
+    async fn gossipsub_broadcast_block_with_accept() {
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            gossipsub_broadcast(
+                GossipsubBroadcastRequest::NewBlock(Arc::new(SealedBlock::default())),
+                GossipsubMessageAcceptance::Accept,
+            ),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn gossipsub_broadcast_block_with_reject() {
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            gossipsub_broadcast(
+                GossipsubBroadcastRequest::NewBlock(Arc::new(SealedBlock::default())),
+                GossipsubMessageAcceptance::Reject,
+            ),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn gossipsub_broadcast_consensus_message_with_accept() {
+        let vote = Vote::prevote(1u32.into(), 0, None).sign(
+            &ChainId::default(),
+            &SecretKey::random(&mut rand::thread_rng()),
+        );
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            gossipsub_broadcast(
+                GossipsubBroadcastRequest::ConsensusMessage(Arc::new(
+                    ConsensusMessage::Vote(vote),
+                )),
+                GossipsubMessageAcceptance::Accept,
+            ),
+        )
+        .await
+        .unwrap();
+    }
+
     #[tokio::test]
     #[instrument]
     async fn gossipsub_broadcast_tx_with_reject() {
@@ -1584,12 +1660,20 @@ mod tests {
     ) {
         let mut p2p_config = Config::default_initialized("gossipsub_exchanges_messages");
 
-        let selected_topic: Sha256Topic = {
-            let topic = match broadcast_request {
-                GossipsubBroadcastRequest::NewTx(_) => NEW_TX_GOSSIP_TOPIC,
+        let (selected_topic, selected_tag): (Sha256Topic, _) = {
+            let (topic, tag) = match broadcast_request {
+                GossipsubBroadcastRequest::NewTx(_) => {
+                    (NEW_TX_GOSSIP_TOPIC, GossipTopicTag::NewTx)
+                }
+                GossipsubBroadcastRequest::NewBlock(_) => {
+                    (NEW_BLOCK_GOSSIP_TOPIC, GossipTopicTag::NewBlock)
+                }
+                GossipsubBroadcastRequest::ConsensusMessage(_) => {
+                    (CONSENSUS_GOSSIP_TOPIC, GossipTopicTag::ConsensusMessage)
+                }
             };
 
-            Topic::new(format!("{}/{}", topic, p2p_config.network_name))
+            (Topic::new(format!("{}/{}", topic, p2p_config.network_name)), tag)
         };
 
         let mut message_sent = false;
@@ -1624,16 +1708,17 @@ mod tests {
 
             tokio::select! {
                 node_a_event = node_a.next_event() => {
-                    if let Some(FuelP2PEvent::NewSubscription { peer_id, .. }) = &node_a_event {
-                        if peer_id == &node_b.local_peer_id {
+                    // The peer must be subscribed to the topic of the message
+                    if let Some(FuelP2PEvent::NewSubscription { peer_id, tag }) = &node_a_event {
+                        if peer_id == &node_b.local_peer_id && tag == &selected_tag {
                             a_connected_to_b = true;
                         }
                     }
                     tracing::info!("Node A Event: {:?}", node_a_event);
                 },
                 node_b_event = node_b.next_event() => {
-                    if let Some(FuelP2PEvent::NewSubscription { peer_id, .. }) = &node_b_event {
-                        if peer_id == &node_c.local_peer_id {
+                    if let Some(FuelP2PEvent::NewSubscription { peer_id, tag }) = &node_b_event {
+                        if peer_id == &node_c.local_peer_id && tag == &selected_tag {
                             b_connected_to_c = true;
                         }
                     }
@@ -1657,6 +1742,24 @@ mod tests {
                                     panic!("Wrong GossipsubMessage")
                                 }
                             }
+                            GossipsubMessage::NewBlock(received) => {
+                                let GossipsubBroadcastRequest::NewBlock(sent) = &broadcast_request else {
+                                    panic!("Wrong GossipsubMessage")
+                                };
+                                if sent.as_ref() != received {
+                                    tracing::error!("Wrong p2p message {:?}", message);
+                                    panic!("Wrong GossipsubMessage")
+                                }
+                            }
+                            GossipsubMessage::ConsensusMessage(received) => {
+                                let GossipsubBroadcastRequest::ConsensusMessage(sent) = &broadcast_request else {
+                                    panic!("Wrong GossipsubMessage")
+                                };
+                                if sent.as_ref() != received {
+                                    tracing::error!("Wrong p2p message {:?}", message);
+                                    panic!("Wrong GossipsubMessage")
+                                }
+                            }
                         }
 
                         // Node B received the correct message
@@ -1717,6 +1820,14 @@ mod tests {
             && a.entity.consensus() == b.entity.consensus()
     }
 
+    fn arbitrary_state_group(request: &StateGroupRequest) -> StateGroup {
+        (0..5)
+            .map(|i| {
+                format!("{}:{:?}:{i}", request.table, request.after_key).into_bytes()
+            })
+            .collect()
+    }
+
     async fn request_response_works_with(request_msg: RequestMessage) {
         let mut p2p_config = Config::default_initialized("request_response_works_with");
 
@@ -1854,6 +1965,22 @@ mod tests {
                                             }
                                         });
                                     }
+                                    RequestMessage::StateGroup(request) => {
+                                        let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
+                                        assert!(node_a.send_request_msg(None, request_msg.clone(), ResponseSender::StateGroup(tx_orchestrator)).is_ok());
+                                        let tx_test_end = tx_test_end.clone();
+                                        tokio::spawn(async move {
+                                            let response_message = rx_orchestrator.await;
+
+                                            if let Ok((_, Ok(Ok(group)))) = response_message {
+                                                let check = group == arbitrary_state_group(&request);
+                                                let _ = tx_test_end.send(check).await;
+                                            } else {
+                                                tracing::error!("Orchestrator failed to receive a message: {:?}", response_message);
+                                                let _ = tx_test_end.send(false).await;
+                                            }
+                                        });
+                                    }
                                 }
                             }
                         }
@@ -1889,6 +2016,10 @@ mod tests {
                                 }).collect();
                                 let _ = node_b.send_response_msg(*request_id, V2ResponseMessage::TxPoolFullTransactions(Ok(txs)));
                             }
+                            RequestMessage::StateGroup(request) => {
+                                let group = arbitrary_state_group(request);
+                                let _ = node_b.send_response_msg(*request_id, V2ResponseMessage::StateGroup(Ok(group)));
+                            }
                         }
                     }
 
@@ -1927,6 +2058,17 @@ mod tests {
         request_response_works_with(RequestMessage::TxPoolFullTransactions(tx_ids)).await
     }
 
+    #[tokio::test]
+    #[instrument]
+    async fn request_response_works_with_state_group() {
+        let request = StateGroupRequest {
+            height: 10,
+            table: "Coins".to_string(),
+            after_key: Some(vec![1, 2, 3]),
+        };
+        request_response_works_with(RequestMessage::StateGroup(request)).await
+    }
+
     /// We send a request for transactions, but it's responded by only headers
     #[tokio::test]
     #[instrument]
@@ -1,3 +1,4 @@
+use crate::request_response::messages::StateGroup;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_types::{
@@ -26,6 +27,15 @@ pub trait P2pDb: Send + Sync {
     ) -> StorageResult<Option<Vec<Transactions>>>;
 
     fn get_genesis(&self) -> StorageResult<Genesis>;
+
+    /// Returns the postcard-encoded entries of the `table` that follow
+    /// the postcard-encoded `after_key`, or the first entries if it is `None`.
+    /// Returns `None` if the table is not a part of the state snapshot.
+    fn get_state_group(
+        &self,
+        table: &str,
+        after_key: Option<&[u8]>,
+    ) -> StorageResult<Option<StateGroup>>;
 }
 
 pub trait BlockHeightImporter: Send + Sync {
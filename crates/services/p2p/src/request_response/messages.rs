@@ -33,8 +33,26 @@ pub enum RequestMessage {
     Transactions(Range<u32>),
     TxPoolAllTransactionsIds,
     TxPoolFullTransactions(Vec<TxId>),
+    StateGroup(StateGroupRequest),
 }
 
+/// Requests one group of entries of the on-chain table at the height.
+/// The groups are the same `TableEntry` groups that are written into the snapshot.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone)]
+pub struct StateGroupRequest {
+    /// The height of the block at which the state is requested.
+    pub height: u32,
+    /// The name of the table's column.
+    pub table: String,
+    /// The postcard-encoded key of the last entry of the previous group.
+    /// The first group of the table is requested with `None`.
+    pub after_key: Option<Vec<u8>>,
+}
+
+/// The postcard-encoded `TableEntry`s of the group.
+/// The group is empty if there are no entries after the requested key.
+pub type StateGroup = Vec<Vec<u8>>;
+
 #[derive(Error, Debug, Clone, Serialize, Deserialize)]
 pub enum ResponseMessageErrorCode {
     /// The peer sent an empty response using protocol `/fuel/req_res/0.0.1`
@@ -46,6 +64,8 @@ pub enum ResponseMessageErrorCode {
     Timeout = 2,
     #[error("Sync processor is out of capacity")]
     SyncProcessorOutOfCapacity = 3,
+    #[error("The requested state is not available")]
+    StateNotAvailable = 4,
     #[error("The peer sent an unknown error code")]
     #[serde(skip_serializing, other)]
     Unknown,
@@ -57,6 +77,7 @@ pub enum V1ResponseMessage {
     Transactions(Option<Vec<Transactions>>),
     TxPoolAllTransactionsIds(Option<Vec<TxId>>),
     TxPoolFullTransactions(Option<Vec<Option<NetworkableTransactionPool>>>),
+    StateGroup(Option<StateGroup>),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -67,6 +88,7 @@ pub enum V2ResponseMessage {
     TxPoolFullTransactions(
         Result<Vec<Option<NetworkableTransactionPool>>, ResponseMessageErrorCode>,
     ),
+    StateGroup(Result<StateGroup, ResponseMessageErrorCode>),
 }
 
 impl From<V1ResponseMessage> for V2ResponseMessage {
@@ -91,6 +113,9 @@ impl From<V1ResponseMessage> for V2ResponseMessage {
                     vec.ok_or(ResponseMessageErrorCode::ProtocolV1EmptyResponse),
                 )
             }
+            V1ResponseMessage::StateGroup(group) => V2ResponseMessage::StateGroup(
+                group.ok_or(ResponseMessageErrorCode::ProtocolV1EmptyResponse),
+            ),
         }
     }
 }
@@ -110,6 +135,9 @@ impl From<V2ResponseMessage> for V1ResponseMessage {
             V2ResponseMessage::TxPoolFullTransactions(tx_pool) => {
                 V1ResponseMessage::TxPoolFullTransactions(tx_pool.ok())
             }
+            V2ResponseMessage::StateGroup(group) => {
+                V1ResponseMessage::StateGroup(group.ok())
+            }
         }
     }
 }
@@ -138,6 +166,7 @@ pub enum ResponseSender {
             Result<Vec<Option<NetworkableTransactionPool>>, ResponseMessageErrorCode>,
         >,
     ),
+    StateGroup(OnResponse<Result<StateGroup, ResponseMessageErrorCode>>),
 }
 
 #[derive(Debug, Error)]
@@ -170,7 +199,11 @@ pub enum ResponseSendError {
 #[cfg(test)]
 #[allow(non_snake_case)]
 mod tests {
-    use super::ResponseMessageErrorCode;
+    use super::{
+        ResponseMessageErrorCode,
+        V1ResponseMessage,
+        V2ResponseMessage,
+    };
 
     #[test]
     fn response_message_error_code__unknown_error_cannot_be_serialized() {
@@ -204,4 +237,18 @@ mod tests {
             ResponseMessageErrorCode::Unknown
         ));
     }
+
+    #[test]
+    fn v2_response_message__state_group_error_is_converted_to_empty_v1_response() {
+        // Given
+        let response = V2ResponseMessage::StateGroup(Err(
+            ResponseMessageErrorCode::StateNotAvailable,
+        ));
+
+        // When
+        let v1_response = V1ResponseMessage::from(response);
+
+        // Then
+        assert!(matches!(v1_response, V1ResponseMessage::StateGroup(None)));
+    }
 }
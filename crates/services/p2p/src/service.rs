@@ -26,6 +26,8 @@ use crate::{
         RequestMessage,
         ResponseMessageErrorCode,
         ResponseSender,
+        StateGroup,
+        StateGroupRequest,
         V2ResponseMessage,
     },
 };
@@ -42,9 +44,16 @@ use fuel_core_services::{
     TaskNextAction,
     TraceErr,
 };
-use fuel_core_storage::transactional::AtomicView;
+use fuel_core_storage::transactional::{
+    AtomicView,
+    HistoricalView,
+};
 use fuel_core_types::{
-    blockchain::SealedBlockHeader,
+    blockchain::{
+        consensus::bft::ConsensusMessage,
+        SealedBlock,
+        SealedBlockHeader,
+    },
     fuel_tx::{
         Transaction,
         TxId,
@@ -59,7 +68,9 @@ use fuel_core_types::{
             AppScore,
             PeerReport,
         },
+        BlockGossipData,
         BlockHeightHeartbeatData,
+        ConsensusMessageGossipData,
         GossipData,
         GossipsubMessageAcceptance,
         GossipsubMessageInfo,
@@ -117,6 +128,8 @@ pub enum TaskError {
 pub enum TaskRequest {
     // Broadcast requests to p2p network
     BroadcastTransaction(Arc<Transaction>),
+    BroadcastBlock(Arc<SealedBlock>),
+    BroadcastConsensusMessage(Arc<ConsensusMessage>),
     // Request to get information about all connected peers
     GetAllPeerInfo {
         channel: oneshot::Sender<Vec<(PeerId, PeerInfo)>>,
@@ -149,6 +162,11 @@ pub enum TaskRequest {
             Result<Vec<Option<NetworkableTransactionPool>>, ResponseMessageErrorCode>,
         >,
     },
+    GetStateGroup {
+        request: StateGroupRequest,
+        from_peer: PeerId,
+        channel: OnResponse<Result<StateGroup, ResponseMessageErrorCode>>,
+    },
     // Responds back to the p2p network
     RespondWithGossipsubMessageReport((GossipsubMessageInfo, GossipsubMessageAcceptance)),
     RespondWithPeerReport {
@@ -173,6 +191,10 @@ pub enum TaskRequest {
             Result<Vec<Option<NetworkableTransactionPool>>, ResponseMessageErrorCode>,
         request_id: InboundRequestId,
     },
+    DatabaseStateGroupLookUp {
+        response: Result<StateGroup, ResponseMessageErrorCode>,
+        request_id: InboundRequestId,
+    },
 }
 
 impl Debug for TaskRequest {
@@ -181,6 +203,12 @@ impl Debug for TaskRequest {
             TaskRequest::BroadcastTransaction(_) => {
                 write!(f, "TaskRequest::BroadcastTransaction")
             }
+            TaskRequest::BroadcastBlock(_) => {
+                write!(f, "TaskRequest::BroadcastBlock")
+            }
+            TaskRequest::BroadcastConsensusMessage(_) => {
+                write!(f, "TaskRequest::BroadcastConsensusMessage")
+            }
             TaskRequest::GetSealedHeaders { .. } => {
                 write!(f, "TaskRequest::GetSealedHeaders")
             }
@@ -196,6 +224,9 @@ impl Debug for TaskRequest {
             TaskRequest::TxPoolGetFullTransactions { .. } => {
                 write!(f, "TaskRequest::TxPoolGetFullTransactions")
             }
+            TaskRequest::GetStateGroup { .. } => {
+                write!(f, "TaskRequest::GetStateGroup")
+            }
             TaskRequest::RespondWithGossipsubMessageReport(_) => {
                 write!(f, "TaskRequest::RespondWithGossipsubMessageReport")
             }
@@ -217,6 +248,9 @@ impl Debug for TaskRequest {
             TaskRequest::TxPoolFullTransactions { .. } => {
                 write!(f, "TaskRequest::TxPoolFullTransactions")
             }
+            TaskRequest::DatabaseStateGroupLookUp { .. } => {
+                write!(f, "TaskRequest::DatabaseStateGroupLookUp")
+            }
         }
     }
 }
@@ -369,6 +403,13 @@ pub trait Broadcast: Send {
     fn tx_broadcast(&self, transaction: TransactionGossipData) -> anyhow::Result<()>;
 
     fn new_tx_subscription_broadcast(&self, peer_id: FuelPeerId) -> anyhow::Result<()>;
+
+    fn block_broadcast(&self, block: BlockGossipData) -> anyhow::Result<()>;
+
+    fn consensus_message_broadcast(
+        &self,
+        message: ConsensusMessageGossipData,
+    ) -> anyhow::Result<()>;
 }
 
 impl Broadcast for SharedState {
@@ -398,6 +439,19 @@ impl Broadcast for SharedState {
         self.new_tx_subscription_broadcast.send(peer_id)?;
         Ok(())
     }
+
+    fn block_broadcast(&self, block: BlockGossipData) -> anyhow::Result<()> {
+        self.block_broadcast.send(block)?;
+        Ok(())
+    }
+
+    fn consensus_message_broadcast(
+        &self,
+        message: ConsensusMessageGossipData,
+    ) -> anyhow::Result<()> {
+        self.consensus_message_broadcast.send(message)?;
+        Ok(())
+    }
 }
 
 /// Uninitialized task for the p2p that can be upgraded later into [`Task`].
@@ -524,8 +578,9 @@ where
 impl<P, V, B, T> Task<P, V, B, T>
 where
     P: TaskP2PService + 'static,
-    V: AtomicView + 'static,
+    V: HistoricalView<Height = BlockHeight> + 'static,
     V::LatestView: P2pDb,
+    V::ViewAtHeight: P2pDb,
     T: TxPool + 'static,
     B: Send,
 {
@@ -554,6 +609,9 @@ where
             RequestMessage::TxPoolFullTransactions(tx_ids) => {
                 self.handle_full_transactions_request(tx_ids, request_id)
             }
+            RequestMessage::StateGroup(request) => {
+                self.handle_state_group_request(request, request_id)
+            }
         }
     }
 
@@ -671,6 +729,57 @@ where
         )
     }
 
+    fn handle_state_group_request(
+        &mut self,
+        request: StateGroupRequest,
+        request_id: InboundRequestId,
+    ) -> anyhow::Result<()> {
+        let instant = Instant::now();
+        let timeout = self.response_timeout;
+        let response_channel = self.request_sender.clone();
+
+        // Only nodes that keep the history of the state can serve it at old heights.
+        let height = BlockHeight::from(request.height);
+        let Ok(view) = self.view_provider.view_at(&height) else {
+            tracing::debug!("The state at height {height} is not available");
+            let err = Err(ResponseMessageErrorCode::StateNotAvailable);
+            let _ = self
+                .p2p_service
+                .send_response_msg(request_id, V2ResponseMessage::StateGroup(err));
+            return Ok(());
+        };
+
+        let result = self.db_heavy_task_processor.try_spawn(move || {
+            if instant.elapsed() > timeout {
+                tracing::warn!("Request timed out");
+                return;
+            }
+
+            let response = view
+                .get_state_group(&request.table, request.after_key.as_deref())
+                .trace_err("Failed to read the state group")
+                .ok()
+                .flatten()
+                .ok_or(ResponseMessageErrorCode::StateNotAvailable);
+
+            let _ = response_channel
+                .try_send(TaskRequest::DatabaseStateGroupLookUp {
+                    response,
+                    request_id,
+                })
+                .trace_err("Failed to send response to the request channel");
+        });
+
+        if result.is_err() {
+            let err = Err(ResponseMessageErrorCode::SyncProcessorOutOfCapacity);
+            let _ = self
+                .p2p_service
+                .send_response_msg(request_id, V2ResponseMessage::StateGroup(err));
+        }
+
+        Ok(())
+    }
+
     fn handle_txpool_request<F, ResponseSenderFn, TaskRequestFn, R>(
         &mut self,
         request_id: InboundRequestId,
@@ -767,8 +876,9 @@ fn convert_peer_id(peer_id: &PeerId) -> anyhow::Result<FuelPeerId> {
 #[async_trait::async_trait]
 impl<V, T> RunnableService for UninitializedTask<V, SharedState, T>
 where
-    V: AtomicView + 'static,
+    V: HistoricalView<Height = BlockHeight> + 'static,
     V::LatestView: P2pDb,
+    V::ViewAtHeight: P2pDb,
     T: TxPool + 'static,
 {
     const NAME: &'static str = "P2P";
@@ -873,8 +983,9 @@ where
 impl<P, V, B, T> RunnableTask for Task<P, V, B, T>
 where
     P: TaskP2PService + 'static,
-    V: AtomicView + 'static,
+    V: HistoricalView<Height = BlockHeight> + 'static,
     V::LatestView: P2pDb,
+    V::ViewAtHeight: P2pDb,
     B: Broadcast + 'static,
     T: TxPool + 'static,
 {
@@ -903,6 +1014,21 @@ where
                             tracing::error!("Got an error during transaction {} broadcasting {}", tx_id, e);
                         }
                     }
+                    Some(TaskRequest::BroadcastBlock(block)) => {
+                        let height = *block.entity.header().height();
+                        let broadcast = GossipsubBroadcastRequest::NewBlock(block);
+                        let result = self.p2p_service.publish_message(broadcast);
+                        if let Err(e) = result {
+                            tracing::error!("Got an error during block {} broadcasting {}", height, e);
+                        }
+                    }
+                    Some(TaskRequest::BroadcastConsensusMessage(message)) => {
+                        let broadcast = GossipsubBroadcastRequest::ConsensusMessage(message);
+                        let result = self.p2p_service.publish_message(broadcast);
+                        if let Err(e) = result {
+                            tracing::error!("Got an error during consensus message broadcasting {}", e);
+                        }
+                    }
                     Some(TaskRequest::GetSealedHeaders { block_height_range, channel}) => {
                         // Note: this range has already been checked for
                         // validity in `SharedState::get_sealed_block_headers`.
@@ -940,6 +1066,11 @@ where
                         let request_msg = RequestMessage::TxPoolFullTransactions(tx_ids);
                         self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel).expect("We always have a peer here, so send has a target");
                     }
+                    Some(TaskRequest::GetStateGroup { request, from_peer, channel }) => {
+                        let channel = ResponseSender::StateGroup(channel);
+                        let request_msg = RequestMessage::StateGroup(request);
+                        self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel).expect("We always have a peer here, so send has a target");
+                    }
                     Some(TaskRequest::RespondWithGossipsubMessageReport((message, acceptance))) => {
                         let res = self.p2p_service.report_message(message, acceptance);
                         if let Err(err) = res {
@@ -968,6 +1099,9 @@ where
                     Some(TaskRequest::TxPoolFullTransactions { response, request_id }) => {
                         let _ = self.p2p_service.send_response_msg(request_id, V2ResponseMessage::TxPoolFullTransactions(response));
                     }
+                    Some(TaskRequest::DatabaseStateGroupLookUp { response, request_id }) => {
+                        let _ = self.p2p_service.send_response_msg(request_id, V2ResponseMessage::StateGroup(response));
+                    }
                     None => {
                         tracing::error!("The P2P `Task` should be holder of the `Sender`");
                         return TaskNextAction::Stop
@@ -994,6 +1128,14 @@ where
                                 let next_transaction = GossipData::new(transaction, peer_id, message_id);
                                 let _ = self.broadcast.tx_broadcast(next_transaction);
                             },
+                            GossipsubMessage::NewBlock(block) => {
+                                let next_block = GossipData::new(block, peer_id, message_id);
+                                let _ = self.broadcast.block_broadcast(next_block);
+                            },
+                            GossipsubMessage::ConsensusMessage(consensus_message) => {
+                                let next_message = GossipData::new(consensus_message, peer_id, message_id);
+                                let _ = self.broadcast.consensus_message_broadcast(next_message);
+                            },
                         }
                     },
                     Some(FuelP2PEvent::InboundRequestMessage { request_message, request_id }) => {
@@ -1043,6 +1185,10 @@ pub struct SharedState {
     new_tx_subscription_broadcast: broadcast::Sender<FuelPeerId>,
     /// Sender of p2p transaction used for subscribing.
     tx_broadcast: broadcast::Sender<TransactionGossipData>,
+    /// Sender of p2p blocks used for subscribing.
+    block_broadcast: broadcast::Sender<BlockGossipData>,
+    /// Sender of p2p consensus messages used for subscribing.
+    consensus_message_broadcast: broadcast::Sender<ConsensusMessageGossipData>,
     /// Sender of reserved peers connection updates.
     reserved_peers_broadcast: broadcast::Sender<usize>,
     /// Used for communicating with the `Task`.
@@ -1245,6 +1391,42 @@ impl SharedState {
             .collect()
     }
 
+    pub async fn get_state_group_from_peer(
+        &self,
+        peer_id: FuelPeerId,
+        request: StateGroupRequest,
+    ) -> anyhow::Result<Option<StateGroup>> {
+        let (sender, receiver) = oneshot::channel();
+        let from_peer = PeerId::from_bytes(peer_id.as_ref()).expect("Valid PeerId");
+        let request = TaskRequest::GetStateGroup {
+            request,
+            from_peer,
+            channel: sender,
+        };
+        self.request_sender.send(request).await?;
+
+        let (response_from_peer, response) =
+            receiver.await.map_err(|e| anyhow!("{e}"))?;
+        debug_assert_eq!(
+            peer_id.as_ref(),
+            response_from_peer.to_bytes(),
+            "Bug: response from non-requested peer"
+        );
+
+        match response {
+            Err(request_response_protocol_error) => Err(anyhow!(
+                "Invalid response from peer {request_response_protocol_error:?}"
+            )),
+            Ok(Err(response_error_code)) => {
+                warn!(
+                    "Peer {peer_id:?} failed to respond with the state group: {response_error_code:?}"
+                );
+                Ok(None)
+            }
+            Ok(Ok(group)) => Ok(Some(group)),
+        }
+    }
+
     pub fn broadcast_transaction(
         &self,
         transaction: Arc<Transaction>,
@@ -1254,6 +1436,47 @@ impl SharedState {
         Ok(())
     }
 
+    pub fn broadcast_block(&self, block: Arc<SealedBlock>) -> anyhow::Result<()> {
+        self.request_sender
+            .try_send(TaskRequest::BroadcastBlock(block))?;
+        Ok(())
+    }
+
+    pub fn notify_gossip_block_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        acceptance: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        self.request_sender
+            .try_send(TaskRequest::RespondWithGossipsubMessageReport((
+                message_info,
+                acceptance,
+            )))?;
+        Ok(())
+    }
+
+    pub fn broadcast_consensus_message(
+        &self,
+        message: Arc<ConsensusMessage>,
+    ) -> anyhow::Result<()> {
+        self.request_sender
+            .try_send(TaskRequest::BroadcastConsensusMessage(message))?;
+        Ok(())
+    }
+
+    pub fn notify_gossip_consensus_message_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        acceptance: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        self.request_sender
+            .try_send(TaskRequest::RespondWithGossipsubMessageReport((
+                message_info,
+                acceptance,
+            )))?;
+        Ok(())
+    }
+
     pub async fn get_all_peers(&self) -> anyhow::Result<Vec<(PeerId, PeerInfo)>> {
         let (sender, receiver) = oneshot::channel();
 
@@ -1272,6 +1495,16 @@ impl SharedState {
         self.tx_broadcast.subscribe()
     }
 
+    pub fn subscribe_blocks(&self) -> broadcast::Receiver<BlockGossipData> {
+        self.block_broadcast.subscribe()
+    }
+
+    pub fn subscribe_consensus_messages(
+        &self,
+    ) -> broadcast::Receiver<ConsensusMessageGossipData> {
+        self.consensus_message_broadcast.subscribe()
+    }
+
     pub fn subscribe_block_height(
         &self,
     ) -> broadcast::Receiver<BlockHeightHeartbeatData> {
@@ -1314,6 +1547,8 @@ pub fn build_shared_state(
 ) -> (SharedState, Receiver<TaskRequest>) {
     let (request_sender, request_receiver) = mpsc::channel(CHANNEL_SIZE);
     let (tx_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
+    let (block_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
+    let (consensus_message_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
     let (new_tx_subscription_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
     let (block_height_broadcast, _) = broadcast::channel(CHANNEL_SIZE);
 
@@ -1330,6 +1565,8 @@ pub fn build_shared_state(
             request_sender,
             new_tx_subscription_broadcast,
             tx_broadcast,
+            block_broadcast,
+            consensus_message_broadcast,
             reserved_peers_broadcast,
             block_height_broadcast,
             max_txs_per_request: config.max_txs_per_request,
@@ -1350,8 +1587,9 @@ pub fn new_service<V, B, T>(
     tx_pool: T,
 ) -> Service<V, T>
 where
-    V: AtomicView + 'static,
+    V: HistoricalView<Height = BlockHeight> + 'static,
     V::LatestView: P2pDb,
+    V::ViewAtHeight: P2pDb,
     B: BlockHeightImporter,
     T: TxPool,
 {
@@ -1433,6 +1671,19 @@ pub mod tests {
         }
     }
 
+    impl HistoricalView for FakeDb {
+        type Height = BlockHeight;
+        type ViewAtHeight = Self;
+
+        fn latest_height(&self) -> Option<Self::Height> {
+            None
+        }
+
+        fn view_at(&self, _: &Self::Height) -> StorageResult<Self::ViewAtHeight> {
+            Ok(self.clone())
+        }
+    }
+
     impl P2pDb for FakeDb {
         fn get_sealed_headers(
             &self,
@@ -1451,6 +1702,14 @@ pub mod tests {
         fn get_genesis(&self) -> StorageResult<Genesis> {
             Ok(Default::default())
         }
+
+        fn get_state_group(
+            &self,
+            _table: &str,
+            _after_key: Option<&[u8]>,
+        ) -> StorageResult<Option<StateGroup>> {
+            unimplemented!()
+        }
     }
 
     #[derive(Clone, Debug)]
@@ -1584,6 +1843,19 @@ pub mod tests {
         }
     }
 
+    impl HistoricalView for FakeDB {
+        type Height = BlockHeight;
+        type ViewAtHeight = Self;
+
+        fn latest_height(&self) -> Option<Self::Height> {
+            None
+        }
+
+        fn view_at(&self, _: &Self::Height) -> StorageResult<Self::ViewAtHeight> {
+            Ok(self.clone())
+        }
+    }
+
     impl P2pDb for FakeDB {
         fn get_sealed_headers(
             &self,
@@ -1602,6 +1874,14 @@ pub mod tests {
         fn get_genesis(&self) -> StorageResult<Genesis> {
             todo!()
         }
+
+        fn get_state_group(
+            &self,
+            _table: &str,
+            _after_key: Option<&[u8]>,
+        ) -> StorageResult<Option<StateGroup>> {
+            todo!()
+        }
     }
 
     struct FakeBroadcast {
@@ -1643,6 +1923,17 @@ pub mod tests {
         ) -> anyhow::Result<()> {
             todo!()
         }
+
+        fn block_broadcast(&self, _block: BlockGossipData) -> anyhow::Result<()> {
+            todo!()
+        }
+
+        fn consensus_message_broadcast(
+            &self,
+            _message: ConsensusMessageGossipData,
+        ) -> anyhow::Result<()> {
+            todo!()
+        }
     }
 
     #[tokio::test]
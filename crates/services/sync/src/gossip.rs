@@ -0,0 +1,205 @@
+//! # Block gossip
+//! Imports blocks gossiped by peers right after their production and
+//! gossips blocks produced by this node. The request/response synchronization
+//! is used only when the gossiped block doesn't follow the committed one.
+
+use std::sync::Arc;
+
+use fuel_core_services::{
+    stream::{
+        BoxStream,
+        IntoBoxStream,
+    },
+    SharedMutex,
+    TraceErr,
+};
+use fuel_core_types::{
+    blockchain::{
+        SealedBlock,
+        SealedBlockHeader,
+    },
+    services::p2p::{
+        BlockGossipData,
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
+        PeerId,
+    },
+};
+use futures::stream::StreamExt;
+use tokio::sync::Notify;
+
+use crate::{
+    ports::{
+        BlockImporterPort,
+        ConsensusPort,
+        PeerReportReason,
+        PeerToPeerPort,
+    },
+    state::State,
+};
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) enum GossipEvent {
+    /// The block gossiped by a peer.
+    Gossiped(BlockGossipData),
+    /// The block produced by this node.
+    Produced(Arc<SealedBlock>),
+}
+
+pub(crate) struct BlockGossip<P, E, C> {
+    events: BoxStream<GossipEvent>,
+    state: SharedMutex<State>,
+    notify: Arc<Notify>,
+    p2p: Arc<P>,
+    executor: Arc<E>,
+    consensus: Arc<C>,
+}
+
+impl<P, E, C> BlockGossip<P, E, C>
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    E: BlockImporterPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    pub(crate) fn new(
+        state: SharedMutex<State>,
+        notify: Arc<Notify>,
+        p2p: Arc<P>,
+        executor: Arc<E>,
+        consensus: Arc<C>,
+    ) -> Self {
+        let events = futures::stream::select(
+            p2p.gossiped_block_stream().map(GossipEvent::Gossiped),
+            executor
+                .locally_produced_block_stream()
+                .map(GossipEvent::Produced),
+        )
+        // The gossip is optional, the end of the streams shouldn't stop the sync.
+        .chain(futures::stream::pending())
+        .into_boxed();
+        Self {
+            events,
+            state,
+            notify,
+            p2p,
+            executor,
+            consensus,
+        }
+    }
+
+    /// Waits for the next gossip event.
+    /// It is cancel safe and can be used in the `select!`.
+    pub(crate) async fn next_event(&mut self) -> Option<GossipEvent> {
+        self.events.next().await
+    }
+
+    pub(crate) async fn handle(&self, event: GossipEvent) {
+        match event {
+            GossipEvent::Gossiped(block) => self.on_gossiped_block(block).await,
+            GossipEvent::Produced(block) => {
+                let _ = self
+                    .p2p
+                    .broadcast_block(block)
+                    .trace_err("Failed to gossip the produced block");
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn on_gossiped_block(&self, mut gossip: BlockGossipData) {
+        let message_info = GossipsubMessageInfo {
+            message_id: gossip.message_id.clone(),
+            peer_id: gossip.peer_id.clone(),
+        };
+        let peer_id = gossip.peer_id.clone();
+        let Some(block) = gossip.data.take() else {
+            return
+        };
+
+        let height = **block.entity.header().height();
+        let synced_height = self.state.apply(|s| s.synced_height());
+        // The blocks at or below the synced height are not propagated further,
+        // so the stale blocks are not amplified across the network.
+        let is_stale = synced_height.map_or(false, |synced| height <= synced);
+        let validity = if is_stale {
+            GossipsubMessageAcceptance::Ignore
+        } else {
+            self.check_block(&block, &peer_id)
+        };
+        // Only valid blocks are propagated further to other peers.
+        let _ = self
+            .p2p
+            .notify_gossip_block_validity(message_info, validity)
+            .trace_err("Failed to notify the validity of the gossiped block");
+        if validity != GossipsubMessageAcceptance::Accept {
+            return
+        }
+
+        let is_next = synced_height.and_then(|h| h.checked_add(1)) == Some(height);
+        if is_next {
+            tracing::debug!("Importing the gossiped block {height}");
+            let result = self
+                .executor
+                .execute_and_commit(block)
+                .await
+                .trace_err("Failed to import the gossiped block");
+            if result.is_ok() {
+                report_peer(&self.p2p, peer_id, PeerReportReason::SuccessfulBlockImport);
+                return
+            }
+            // The regular synchronization retries to import the block below.
+        }
+
+        // There is a gap between the committed and the gossiped block,
+        // it is filled by requesting headers and transactions from peers.
+        if self.state.apply(|s| s.observe(height)) {
+            self.notify.notify_one();
+        }
+    }
+
+    fn check_block(
+        &self,
+        block: &SealedBlock,
+        peer_id: &PeerId,
+    ) -> GossipsubMessageAcceptance {
+        let header = SealedBlockHeader {
+            entity: block.entity.header().clone(),
+            consensus: block.consensus.clone(),
+        };
+        let is_sealed = self
+            .consensus
+            .check_sealed_header(&header)
+            .trace_err("Failed to check consensus on the gossiped block")
+            .unwrap_or(false);
+        if !is_sealed {
+            report_peer(&self.p2p, peer_id.clone(), PeerReportReason::BadBlockHeader);
+            return GossipsubMessageAcceptance::Reject
+        }
+
+        if !header
+            .entity
+            .validate_transactions(block.entity.transactions())
+        {
+            report_peer(
+                &self.p2p,
+                peer_id.clone(),
+                PeerReportReason::InvalidTransactions,
+            );
+            return GossipsubMessageAcceptance::Reject
+        }
+
+        GossipsubMessageAcceptance::Accept
+    }
+}
+
+fn report_peer<P>(p2p: &Arc<P>, peer_id: PeerId, reason: PeerReportReason)
+where
+    P: PeerToPeerPort + Send + Sync + 'static,
+{
+    // Failure to report a peer is a non-fatal error; ignore the error
+    let _ = p2p
+        .report_peer(peer_id.clone(), reason)
+        .trace_err(&format!("Failed to report peer {:?}", peer_id));
+}
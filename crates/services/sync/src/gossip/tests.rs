@@ -0,0 +1,207 @@
+#![allow(non_snake_case)]
+
+use super::*;
+use crate::{
+    import::test_helpers::{
+        empty_header,
+        random_peer,
+    },
+    ports::{
+        MockBlockImporterPort,
+        MockConsensusPort,
+        MockPeerToPeerPort,
+    },
+};
+use fuel_core_types::{
+    blockchain::block::Block,
+    fuel_tx::Transaction,
+    services::p2p::GossipData,
+};
+
+fn gossiped_block(height: u32) -> BlockGossipData {
+    let header = empty_header(height);
+    let block = SealedBlock {
+        entity: Block::try_from_executed(header.entity, vec![]).unwrap(),
+        consensus: header.consensus,
+    };
+    GossipData::new(block, random_peer(), vec![1, 2, 3])
+}
+
+struct Mocks {
+    p2p: MockPeerToPeerPort,
+    executor: MockBlockImporterPort,
+    consensus: MockConsensusPort,
+}
+
+impl Mocks {
+    fn new(is_sealed: bool) -> Self {
+        let mut p2p = MockPeerToPeerPort::default();
+        p2p.expect_gossiped_block_stream()
+            .returning(|| futures::stream::pending().into_boxed());
+        p2p.expect_report_peer().returning(|_, _| Ok(()));
+        let mut executor = MockBlockImporterPort::default();
+        executor
+            .expect_locally_produced_block_stream()
+            .returning(|| futures::stream::pending().into_boxed());
+        let mut consensus = MockConsensusPort::default();
+        consensus
+            .expect_check_sealed_header()
+            .returning(move |_| Ok(is_sealed));
+        Self {
+            p2p,
+            executor,
+            consensus,
+        }
+    }
+
+    fn into_gossip(
+        self,
+        state: State,
+    ) -> (
+        BlockGossip<MockPeerToPeerPort, MockBlockImporterPort, MockConsensusPort>,
+        SharedMutex<State>,
+    ) {
+        let state = SharedMutex::new(state);
+        let gossip = BlockGossip::new(
+            state.clone(),
+            Arc::new(Notify::new()),
+            Arc::new(self.p2p),
+            Arc::new(self.executor),
+            Arc::new(self.consensus),
+        );
+        (gossip, state)
+    }
+}
+
+#[tokio::test]
+async fn handle__next_block_is_accepted_and_imported() {
+    // Given
+    let mut mocks = Mocks::new(true);
+    mocks
+        .p2p
+        .expect_notify_gossip_block_validity()
+        .withf(|_, validity| *validity == GossipsubMessageAcceptance::Accept)
+        .times(1)
+        .returning(|_, _| Ok(()));
+    mocks
+        .executor
+        .expect_execute_and_commit()
+        .withf(|block| **block.entity.header().height() == 5)
+        .times(1)
+        .returning(|_| Ok(()));
+    let (gossip, state) = mocks.into_gossip(State::new(4, None));
+
+    // When
+    gossip
+        .handle(GossipEvent::Gossiped(gossiped_block(5)))
+        .await;
+
+    // Then
+    assert_eq!(state.apply(|s| s.synced_height()), Some(4));
+}
+
+#[tokio::test]
+async fn handle__block_at_synced_height_is_ignored() {
+    // Given
+    let mut mocks = Mocks::new(true);
+    mocks
+        .p2p
+        .expect_notify_gossip_block_validity()
+        .withf(|_, validity| *validity == GossipsubMessageAcceptance::Ignore)
+        .times(1)
+        .returning(|_, _| Ok(()));
+    mocks.executor.expect_execute_and_commit().never();
+    let (gossip, state) = mocks.into_gossip(State::new(4, None));
+
+    // When
+    gossip
+        .handle(GossipEvent::Gossiped(gossiped_block(4)))
+        .await;
+
+    // Then
+    assert_eq!(state.apply(|s| s.synced_height()), Some(4));
+}
+
+#[tokio::test]
+async fn handle__block_with_invalid_seal_is_rejected() {
+    // Given
+    let mut mocks = Mocks::new(false);
+    mocks
+        .p2p
+        .expect_notify_gossip_block_validity()
+        .withf(|_, validity| *validity == GossipsubMessageAcceptance::Reject)
+        .times(1)
+        .returning(|_, _| Ok(()));
+    mocks.executor.expect_execute_and_commit().never();
+    let (gossip, state) = mocks.into_gossip(State::new(4, None));
+
+    // When
+    gossip
+        .handle(GossipEvent::Gossiped(gossiped_block(5)))
+        .await;
+
+    // Then
+    assert_eq!(state.apply(|s| s.synced_height()), Some(4));
+}
+
+#[tokio::test]
+async fn handle__block_with_not_matching_transactions_is_rejected() {
+    // Given
+    let mut mocks = Mocks::new(true);
+    mocks
+        .p2p
+        .expect_notify_gossip_block_validity()
+        .withf(|_, validity| *validity == GossipsubMessageAcceptance::Reject)
+        .times(1)
+        .returning(|_, _| Ok(()));
+    mocks.executor.expect_execute_and_commit().never();
+    let (gossip, _) = mocks.into_gossip(State::new(4, None));
+    let mut block = gossiped_block(5);
+    if let Some(block) = block.data.as_mut() {
+        block
+            .entity
+            .transactions_mut()
+            .push(Transaction::default_test_tx());
+    }
+
+    // When
+    gossip.handle(GossipEvent::Gossiped(block)).await;
+}
+
+#[tokio::test]
+async fn handle__block_after_gap_is_synced_with_requests() {
+    // Given
+    let mut mocks = Mocks::new(true);
+    mocks
+        .p2p
+        .expect_notify_gossip_block_validity()
+        .withf(|_, validity| *validity == GossipsubMessageAcceptance::Accept)
+        .times(1)
+        .returning(|_, _| Ok(()));
+    mocks.executor.expect_execute_and_commit().never();
+    let (gossip, state) = mocks.into_gossip(State::new(4, None));
+
+    // When
+    gossip
+        .handle(GossipEvent::Gossiped(gossiped_block(8)))
+        .await;
+
+    // Then
+    assert_eq!(state.apply(|s| s.process_range()), Some(5..=8));
+}
+
+#[tokio::test]
+async fn handle__produced_block_is_gossiped() {
+    // Given
+    let mut mocks = Mocks::new(true);
+    mocks
+        .p2p
+        .expect_broadcast_block()
+        .times(1)
+        .returning(|_| Ok(()));
+    let (gossip, _) = mocks.into_gossip(State::new(4, None));
+    let block = gossiped_block(5).data.unwrap();
+
+    // When
+    gossip.handle(GossipEvent::Produced(Arc::new(block))).await;
+}
@@ -10,7 +10,10 @@ use fuel_core_types::{
     blockchain::SealedBlock,
     fuel_types::BlockHeight,
 };
-use std::time::Duration;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
 
 pub struct PressureBlockImporter(MockBlockImporterPort, Duration, SharedCounts);
 
@@ -20,6 +23,10 @@ impl BlockImporterPort for PressureBlockImporter {
         self.0.committed_height_stream()
     }
 
+    fn locally_produced_block_stream(&self) -> BoxStream<Arc<SealedBlock>> {
+        self.0.locally_produced_block_stream()
+    }
+
     async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()> {
         self.2.apply(|c| c.inc_executes());
         tokio::time::sleep(self.1).await;
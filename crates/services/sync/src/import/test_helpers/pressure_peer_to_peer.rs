@@ -12,9 +12,15 @@ use crate::{
 };
 use fuel_core_services::stream::BoxStream;
 use fuel_core_types::{
-    blockchain::SealedBlockHeader,
+    blockchain::{
+        SealedBlock,
+        SealedBlockHeader,
+    },
     fuel_types::BlockHeight,
     services::p2p::{
+        BlockGossipData,
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
         PeerId,
         SourcePeer,
         Transactions,
@@ -22,6 +28,7 @@ use fuel_core_types::{
 };
 use std::{
     ops::Range,
+    sync::Arc,
     time::Duration,
 };
 
@@ -80,6 +87,22 @@ impl PeerToPeerPort for PressurePeerToPeer {
     ) -> anyhow::Result<()> {
         Ok(())
     }
+
+    fn gossiped_block_stream(&self) -> BoxStream<BlockGossipData> {
+        self.p2p.gossiped_block_stream()
+    }
+
+    fn notify_gossip_block_validity(
+        &self,
+        _message_info: GossipsubMessageInfo,
+        _validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn broadcast_block(&self, _block: Arc<SealedBlock>) -> anyhow::Result<()> {
+        Ok(())
+    }
 }
 
 impl PressurePeerToPeer {
@@ -9,12 +9,18 @@ use fuel_core_types::{
     },
     fuel_types::BlockHeight,
     services::p2p::{
+        BlockGossipData,
+        GossipsubMessageAcceptance,
+        GossipsubMessageInfo,
         PeerId,
         SourcePeer,
         Transactions,
     },
 };
-use std::ops::Range;
+use std::{
+    ops::Range,
+    sync::Arc,
+};
 
 /// Possible reasons to report a peer
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -62,6 +68,20 @@ pub trait PeerToPeerPort {
 
     /// Report a peer for some reason to modify their reputation.
     fn report_peer(&self, peer: PeerId, report: PeerReportReason) -> anyhow::Result<()>;
+
+    /// Stream of sealed blocks gossiped by peers.
+    fn gossiped_block_stream(&self) -> BoxStream<BlockGossipData>;
+
+    /// Report the validity of the gossiped block.
+    /// Only accepted blocks are propagated further to other peers.
+    fn notify_gossip_block_validity(
+        &self,
+        message_info: GossipsubMessageInfo,
+        validity: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()>;
+
+    /// Gossip the sealed block to the network.
+    fn broadcast_block(&self, block: Arc<SealedBlock>) -> anyhow::Result<()>;
 }
 
 #[cfg_attr(any(test, feature = "benchmarking"), mockall::automock)]
@@ -81,6 +101,9 @@ pub trait BlockImporterPort {
     /// Stream of newly committed block heights.
     fn committed_height_stream(&self) -> BoxStream<BlockHeight>;
 
+    /// Stream of blocks produced and committed by this node.
+    fn locally_produced_block_stream(&self) -> BoxStream<Arc<SealedBlock>>;
+
     /// Execute the given sealed block
     /// and commit it to the database.
     async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()>;
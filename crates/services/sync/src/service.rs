@@ -2,6 +2,7 @@
 use std::sync::Arc;
 
 use crate::{
+    gossip::BlockGossip,
     import::{
         Config,
         Import,
@@ -72,6 +73,7 @@ where
     C: ConsensusPort + Send + Sync + 'static,
 {
     sync_heights: SyncHeights,
+    block_gossip: BlockGossip<P, E, C>,
     import_task_handle: ServiceRunner<ImportTask<P, E, C>>,
 }
 
@@ -103,10 +105,18 @@ where
             state.clone(),
             notify.clone(),
         );
+        let block_gossip = BlockGossip::new(
+            state.clone(),
+            notify.clone(),
+            p2p.clone(),
+            executor.clone(),
+            consensus.clone(),
+        );
         let import = Import::new(state, notify, params, p2p, executor, consensus);
         let import_task_handle = ServiceRunner::new(ImportTask(import));
         Ok(Self {
             sync_heights,
+            block_gossip,
             import_task_handle,
         })
     }
@@ -120,9 +130,18 @@ where
     C: ConsensusPort + Send + Sync + 'static,
 {
     async fn run(&mut self, _: &mut StateWatcher) -> TaskNextAction {
-        match self.sync_heights.sync().await {
-            None => TaskNextAction::Stop,
-            Some(_) => TaskNextAction::Continue,
+        tokio::select! {
+            biased;
+            result = self.sync_heights.sync() => match result {
+                None => TaskNextAction::Stop,
+                Some(_) => TaskNextAction::Continue,
+            },
+            event = self.block_gossip.next_event() => {
+                if let Some(event) = event {
+                    self.block_gossip.handle(event).await;
+                }
+                TaskNextAction::Continue
+            }
         }
     }
 
@@ -38,6 +38,8 @@ async fn test_new_service() {
         })
         .into_boxed()
     });
+    p2p.expect_gossiped_block_stream()
+        .returning(|| futures::stream::pending().into_boxed());
     p2p.expect_get_sealed_block_headers().returning(|range| {
         Box::pin(async move {
             let peer = random_peer();
@@ -58,6 +60,9 @@ async fn test_new_service() {
     importer
         .expect_committed_height_stream()
         .returning(|| futures::stream::pending::<BlockHeight>().into_boxed());
+    importer
+        .expect_locally_produced_block_stream()
+        .returning(|| futures::stream::pending().into_boxed());
     let (tx, mut rx) = tokio::sync::mpsc::channel(100);
     importer.expect_execute_and_commit().returning(move |h| {
         tx.try_send(**h.entity.header().height()).unwrap();
@@ -117,6 +117,14 @@ impl State {
         self.apply_status(new_status);
     }
 
+    /// Get the committed height if there is nothing to process.
+    pub fn synced_height(&self) -> Option<u32> {
+        match &self.status {
+            Status::Committed(height) => Some(*height),
+            _ => None,
+        }
+    }
+
     #[tracing::instrument]
     /// Record that a block has been observed.
     pub fn observe(&mut self, height: u32) -> bool {
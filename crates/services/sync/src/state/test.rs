@@ -113,3 +113,12 @@ fn test_failed(mut state: State, range: RangeInclusive<u32>) -> Status {
     state.failed_to_process(range);
     state.status
 }
+
+#[test_case(State::new(None, None) => None)]
+#[test_case(State::new(10, None) => Some(10))]
+#[test_case(State::new(None, 10) => None)]
+#[test_case(State::new(10, 10) => Some(10))]
+#[test_case(State::new(10, 11) => None)]
+fn test_synced_height(state: State) -> Option<u32> {
+    state.synced_height()
+}
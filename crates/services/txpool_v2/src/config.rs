@@ -1,5 +1,6 @@
 use std::{
     collections::HashSet,
+    path::PathBuf,
     time::Duration,
 };
 
@@ -40,6 +41,21 @@ pub struct BlackList {
     pub contracts: HashSet<ContractId>,
 }
 
+/// The single entry of the [`BlackList`].
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub enum BlackListEntry {
+    /// Blacklisted address.
+    Owner(Address),
+    /// Blacklisted UTXO id.
+    Coin(UtxoId),
+    /// Blacklisted message by `Nonce`.
+    Message(Nonce),
+    /// Blacklisted contract.
+    Contract(ContractId),
+}
+
 impl BlackList {
     /// Create a new blacklist.
     pub fn new(
@@ -56,6 +72,42 @@ impl BlackList {
         }
     }
 
+    /// Adds the entry to the blacklist.
+    /// Returns `false` if the entry was already blacklisted.
+    pub fn insert(&mut self, entry: BlackListEntry) -> bool {
+        match entry {
+            BlackListEntry::Owner(owner) => self.owners.insert(owner),
+            BlackListEntry::Coin(utxo_id) => self.coins.insert(utxo_id),
+            BlackListEntry::Message(nonce) => self.messages.insert(nonce),
+            BlackListEntry::Contract(contract_id) => self.contracts.insert(contract_id),
+        }
+    }
+
+    /// Removes the entry from the blacklist.
+    /// Returns `false` if the entry was not blacklisted.
+    pub fn remove(&mut self, entry: &BlackListEntry) -> bool {
+        match entry {
+            BlackListEntry::Owner(owner) => self.owners.remove(owner),
+            BlackListEntry::Coin(utxo_id) => self.coins.remove(utxo_id),
+            BlackListEntry::Message(nonce) => self.messages.remove(nonce),
+            BlackListEntry::Contract(contract_id) => self.contracts.remove(contract_id),
+        }
+    }
+
+    /// Returns the number of entries in the blacklist.
+    pub fn len(&self) -> usize {
+        self.owners
+            .len()
+            .saturating_add(self.coins.len())
+            .saturating_add(self.messages.len())
+            .saturating_add(self.contracts.len())
+    }
+
+    /// Returns `true` if nothing is blacklisted.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Check if the transaction has blacklisted inputs.
     pub fn check_blacklisting(
         &self,
@@ -130,6 +182,8 @@ pub struct Config {
     pub max_txs_chain_count: usize,
     /// Pool limits
     pub pool_limits: PoolLimits,
+    /// Limits per the owner of the transaction inputs and per the peer gossiping transactions.
+    pub sender_limits: SenderLimits,
     /// Service channel limits
     pub service_channel_limits: ServiceChannelLimits,
     /// Interval for checking the time to live of transactions.
@@ -142,6 +196,26 @@ pub struct Config {
     pub black_list: BlackList,
     /// Enable metrics when set to true
     pub metrics: bool,
+    /// The file journaling the accepted transactions.
+    /// When set, the pool is restored from it on start-up.
+    pub journal_path: Option<PathBuf>,
+    /// The policy used to select transactions for the block.
+    pub selection_policy: SelectionPolicy,
+}
+
+/// The policy used to select transactions from the pool for the block.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionPolicy {
+    /// Selects transactions with the highest tip/gas ratio first.
+    #[default]
+    RatioTipGas,
+    /// Selects transactions in the order of their submission.
+    Fifo,
+    /// Selects transactions with the highest tip/byte ratio first.
+    TipPerByte,
+    /// Selects transactions with the highest tip/gas ratio first,
+    /// but includes at most `max_txs_per_owner` transactions of the same owner in the block.
+    Fairness { max_txs_per_owner: usize },
 }
 
 #[derive(Clone, Debug)]
@@ -154,6 +228,16 @@ pub struct PoolLimits {
     pub max_bytes_size: usize,
 }
 
+#[derive(Clone, Debug)]
+pub struct SenderLimits {
+    /// Maximum number of transactions of one owner in the pool.
+    pub max_txs_per_owner: usize,
+    /// Maximum number of gas of one owner in the pool.
+    pub max_gas_per_owner: u64,
+    /// Maximum number of gossiped transactions of one peer pending verification.
+    pub max_pending_txs_per_peer: usize,
+}
+
 #[derive(Clone, Debug)]
 pub struct ServiceChannelLimits {
     /// Maximum number of pending requests waiting in the write pool channel.
@@ -174,6 +258,16 @@ pub struct HeavyWorkConfig {
     pub size_of_p2p_sync_queue: usize,
 }
 
+impl Config {
+    /// The default maximum number of transactions of one owner in the pool.
+    pub const DEFAULT_MAX_TXS_PER_OWNER: usize = 1024;
+    /// The default maximum number of gas of one owner in the pool.
+    pub const DEFAULT_MAX_GAS_PER_OWNER: u64 = 30_000_000_000;
+    /// The default maximum number of gossiped transactions of one peer pending
+    /// verification.
+    pub const DEFAULT_MAX_PENDING_TXS_PER_PEER: usize = 128;
+}
+
 #[cfg(feature = "test-helpers")]
 impl Default for Config {
     fn default() -> Self {
@@ -189,6 +283,11 @@ impl Default for Config {
                 max_gas: 100_000_000_000,
                 max_bytes_size: 1_000_000_000,
             },
+            sender_limits: SenderLimits {
+                max_txs_per_owner: Self::DEFAULT_MAX_TXS_PER_OWNER,
+                max_gas_per_owner: Self::DEFAULT_MAX_GAS_PER_OWNER,
+                max_pending_txs_per_peer: Self::DEFAULT_MAX_PENDING_TXS_PER_PEER,
+            },
             heavy_work: HeavyWorkConfig {
                 // It is important for tests to have only one thread for verification
                 // because some of them rely on the ordering of insertion.
@@ -202,6 +301,8 @@ impl Default for Config {
                 max_pending_read_pool_requests: 1000,
             },
             metrics: false,
+            journal_path: None,
+            selection_policy: SelectionPolicy::default(),
         }
     }
 }
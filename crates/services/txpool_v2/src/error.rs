@@ -9,6 +9,7 @@ use fuel_core_types::{
     },
     fuel_types::Nonce,
     fuel_vm::checked_transaction::CheckError,
+    services::p2p::PeerId,
 };
 
 use crate::ports::WasmValidityError;
@@ -37,6 +38,31 @@ pub enum Error {
     MintIsDisallowed,
     #[display(fmt = "Pool limit is hit, try to increase gas_price")]
     NotInsertedLimitHit,
+    #[display(fmt = "The owner `{owner}` already has {limit} transactions in the pool")]
+    NotInsertedOwnerTxCountLimitHit {
+        /// The owner of the transaction inputs.
+        owner: Address,
+        /// The maximum number of transactions of the owner in the pool.
+        limit: usize,
+    },
+    #[display(
+        fmt = "The transactions of the owner `{owner}` would exceed the limit of {limit} gas in the pool"
+    )]
+    NotInsertedOwnerGasLimitHit {
+        /// The owner of the transaction inputs.
+        owner: Address,
+        /// The maximum gas of the transactions of the owner in the pool.
+        limit: u64,
+    },
+    #[display(
+        fmt = "The peer `{peer_id}` already has {limit} transactions pending verification"
+    )]
+    NotInsertedPeerTxCountLimitHit {
+        /// The peer that gossiped the transaction.
+        peer_id: PeerId,
+        /// The maximum number of transactions of the peer pending verification.
+        limit: usize,
+    },
     #[display(fmt = "Transaction is removed: {_0}")]
     Removed(RemovedReason),
     #[display(fmt = "Transaction has been skipped during block insertion: {_0}")]
@@ -68,6 +94,8 @@ pub enum RemovedReason {
         fmt = "Transaction expired because it exceeded the configured time to live `tx-pool-ttl`."
     )]
     Ttl,
+    #[display(fmt = "Transaction was removed because its inputs were blacklisted")]
+    Blacklisted,
 }
 
 #[derive(Clone, Debug, derive_more::Display)]
@@ -61,10 +61,13 @@ mod tests;
 fuel_core_trace::enable_tracing!();
 
 use fuel_core_types::fuel_asm::Word;
+pub use pool::TxPoolStats;
 pub use selection_algorithms::Constraints;
 pub use service::{
     new_service,
+    PendingTxsQuery,
     Service,
+    TxInfo,
 };
 pub use shared_state::{
     BorrowedTxPool,
@@ -14,6 +14,7 @@ use fuel_core_metrics::txpool_metrics::txpool_metrics;
 use fuel_core_types::{
     fuel_tx::{
         field::BlobId,
+        Address,
         TxId,
     },
     services::txpool::{
@@ -28,7 +29,11 @@ use crate::{
         CollisionManager,
         Collisions,
     },
-    config::Config,
+    config::{
+        BlackList,
+        BlackListEntry,
+        Config,
+    },
     error::{
         DependencyError,
         Error,
@@ -49,6 +54,44 @@ use crate::{
 #[cfg(test)]
 use std::collections::HashSet;
 
+/// The current usage of the pool along with its limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TxPoolStats {
+    /// Number of transactions in the pool.
+    pub tx_count: usize,
+    /// Total gas of the transactions in the pool.
+    pub total_gas: u64,
+    /// Total size of the transactions in the pool in bytes.
+    pub total_bytes_size: usize,
+    /// Maximum number of transactions in the pool.
+    pub max_txs: usize,
+    /// Maximum number of gas in the pool.
+    pub max_gas: u64,
+    /// Maximum number of bytes in the pool.
+    pub max_bytes_size: usize,
+}
+
+/// The transactions of one owner in the pool.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct OwnerUsage {
+    /// Number of transactions of the owner.
+    pub txs: usize,
+    /// Total gas of the transactions of the owner.
+    pub gas: u64,
+}
+
+/// Returns the unique owners of the inputs of the transaction.
+pub(crate) fn input_owners(transaction: &PoolTransaction) -> Vec<Address> {
+    let mut owners: Vec<Address> = transaction
+        .inputs()
+        .iter()
+        .filter_map(|input| input.input_owner().copied())
+        .collect();
+    owners.sort();
+    owners.dedup();
+    owners
+}
+
 /// The pool is the main component of the txpool service. It is responsible for storing transactions
 /// and allowing the selection of transactions for inclusion in a block.
 pub struct Pool<S, SI, CM, SA> {
@@ -66,6 +109,8 @@ pub struct Pool<S, SI, CM, SA> {
     pub(crate) current_gas: u64,
     /// Current pool size in bytes.
     pub(crate) current_bytes_size: usize,
+    /// The transactions in the pool per owner of their inputs.
+    pub(crate) owners_usage: HashMap<Address, OwnerUsage>,
 }
 
 impl<S, SI, CM, SA> Pool<S, SI, CM, SA> {
@@ -84,6 +129,7 @@ impl<S, SI, CM, SA> Pool<S, SI, CM, SA> {
             tx_id_to_storage_id: HashMap::new(),
             current_gas: 0,
             current_bytes_size: 0,
+            owners_usage: HashMap::new(),
         }
     }
 
@@ -98,6 +144,29 @@ impl<S, SI, CM, SA> Pool<S, SI, CM, SA> {
     pub fn tx_count(&self) -> usize {
         self.tx_id_to_storage_id.len()
     }
+
+    /// Returns the blacklist used by the pool.
+    pub fn blacklist(&self) -> &BlackList {
+        &self.config.black_list
+    }
+
+    /// Removes the entry from the blacklist.
+    /// Returns `false` if the entry was not blacklisted.
+    pub fn remove_from_blacklist(&mut self, entry: &BlackListEntry) -> bool {
+        self.config.black_list.remove(entry)
+    }
+
+    /// Returns the current usage of the pool along with its limits.
+    pub fn stats(&self) -> TxPoolStats {
+        TxPoolStats {
+            tx_count: self.tx_count(),
+            total_gas: self.current_gas,
+            total_bytes_size: self.current_bytes_size,
+            max_txs: self.config.pool_limits.max_txs,
+            max_gas: self.config.pool_limits.max_gas,
+            max_bytes_size: self.config.pool_limits.max_bytes_size,
+        }
+    }
 }
 
 impl<S: Storage, CM, SA> Pool<S, S::StorageIndex, CM, SA>
@@ -155,6 +224,7 @@ where
         let gas = tx.max_gas();
         let creation_instant = SystemTime::now();
         let bytes_size = tx.metered_bytes_size();
+        let owners = input_owners(tx);
 
         let storage_id = self
             .storage
@@ -162,6 +232,11 @@ where
 
         self.current_gas = self.current_gas.saturating_add(gas);
         self.current_bytes_size = self.current_bytes_size.saturating_add(bytes_size);
+        for owner in owners {
+            let usage = self.owners_usage.entry(owner).or_default();
+            usage.txs = usage.txs.saturating_add(1);
+            usage.gas = usage.gas.saturating_add(gas);
+        }
         debug_assert!(!self.tx_id_to_storage_id.contains_key(&tx_id));
         self.tx_id_to_storage_id.insert(tx_id, storage_id);
 
@@ -237,6 +312,8 @@ where
             )
             .map_err(Error::Collided)?;
 
+        self.check_owner_limits(checked_transaction.tx(), &collisions)?;
+
         let can_fit_into_pool = self.can_fit_into_pool(&checked_transaction)?;
 
         let mut transactions_to_remove = vec![];
@@ -254,6 +331,53 @@ where
         Ok(can_store_transaction)
     }
 
+    /// Check that the owners of the transaction inputs don't exceed their limits.
+    /// The transactions replaced by the new one are not counted.
+    fn check_owner_limits(
+        &self,
+        tx: &PoolTransaction,
+        collisions: &Collisions<S::StorageIndex>,
+    ) -> Result<(), Error> {
+        let limits = &self.config.sender_limits;
+        let collided_txs = collisions
+            .keys()
+            .filter_map(|storage_id| Storage::get(&self.storage, storage_id))
+            .map(|storage_data| &storage_data.transaction)
+            .collect::<Vec<_>>();
+
+        for owner in input_owners(tx) {
+            let mut usage = self.owners_usage.get(&owner).copied().unwrap_or_default();
+            for collided_tx in &collided_txs {
+                if input_owners(collided_tx).contains(&owner) {
+                    usage.txs = usage.txs.saturating_sub(1);
+                    usage.gas = usage.gas.saturating_sub(collided_tx.max_gas());
+                }
+            }
+
+            if usage.txs >= limits.max_txs_per_owner {
+                if self.config.metrics {
+                    txpool_metrics().owner_tx_count_limit_rejections.inc();
+                }
+                return Err(Error::NotInsertedOwnerTxCountLimitHit {
+                    owner,
+                    limit: limits.max_txs_per_owner,
+                })
+            }
+
+            if usage.gas.saturating_add(tx.max_gas()) > limits.max_gas_per_owner {
+                if self.config.metrics {
+                    txpool_metrics().owner_gas_limit_rejections.inc();
+                }
+                return Err(Error::NotInsertedOwnerGasLimitHit {
+                    owner,
+                    limit: limits.max_gas_per_owner,
+                })
+            }
+        }
+
+        Ok(())
+    }
+
     fn record_transaction_time_in_txpool(tx: &StorageData) {
         if let Ok(elapsed) = tx.creation_instant.elapsed() {
             txpool_metrics()
@@ -325,6 +449,12 @@ where
         self.tx_id_to_storage_id.contains_key(tx_id)
     }
 
+    /// Returns the length of the longest chain of the transaction's dependencies in the pool.
+    pub fn dependencies_depth(&self, tx_id: &TxId) -> Option<usize> {
+        let storage_id = self.tx_id_to_storage_id.get(tx_id)?;
+        Some(self.storage.dependencies_depth(storage_id))
+    }
+
     pub fn iter_tx_ids(&self) -> impl Iterator<Item = &TxId> {
         self.tx_id_to_storage_id.keys()
     }
@@ -497,6 +627,29 @@ where
         Ok(transactions_to_remove)
     }
 
+    /// Adds the entry to the blacklist and removes the transactions
+    /// with the blacklisted inputs along with their dependents.
+    pub fn add_to_blacklist(&mut self, entry: BlackListEntry) -> Vec<ArcPoolTx> {
+        if !self.config.black_list.insert(entry) {
+            return vec![]
+        }
+
+        let blacklisted_txs = self
+            .tx_id_to_storage_id
+            .values()
+            .filter_map(|storage_id| Storage::get(&self.storage, storage_id))
+            .filter(|storage_data| {
+                self.config
+                    .black_list
+                    .check_blacklisting(&storage_data.transaction)
+                    .is_err()
+            })
+            .map(|storage_data| storage_data.transaction.id())
+            .collect();
+
+        self.remove_transaction_and_dependents(blacklisted_txs)
+    }
+
     /// Remove transaction and its dependents.
     pub fn remove_transaction_and_dependents(
         &mut self,
@@ -558,6 +711,15 @@ where
                 .current_bytes_size
                 .saturating_sub(tx.metered_bytes_size());
             self.tx_id_to_storage_id.remove(&tx.id());
+            for owner in input_owners(tx) {
+                if let Some(usage) = self.owners_usage.get_mut(&owner) {
+                    usage.txs = usage.txs.saturating_sub(1);
+                    usage.gas = usage.gas.saturating_sub(tx.max_gas());
+                    if usage.txs == 0 {
+                        self.owners_usage.remove(&owner);
+                    }
+                }
+            }
             self.collision_manager.on_removed_transaction(tx);
             self.selection_algorithm
                 .on_removed_transaction(storage_entry);
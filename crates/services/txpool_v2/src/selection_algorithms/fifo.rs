@@ -0,0 +1,52 @@
+use std::{
+    cmp::Ordering,
+    time::SystemTime,
+};
+
+use fuel_core_types::fuel_tx::TxId;
+
+use crate::storage::StorageData;
+
+use super::ordered::{
+    OrderedSelection,
+    SelectionKey,
+};
+
+/// Key used to sort transactions by the time of their submission.
+/// The older transaction is greater, ties are broken by the transaction id.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub struct Key {
+    creation_instant: SystemTime,
+    tx_id: TxId,
+}
+
+impl SelectionKey for Key {
+    fn new(store_entry: &StorageData) -> Self {
+        Self {
+            creation_instant: store_entry.creation_instant,
+            tx_id: store_entry.transaction.id(),
+        }
+    }
+
+    fn tx_id(&self) -> TxId {
+        self.tx_id
+    }
+}
+
+impl Ord for Key {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .creation_instant
+            .cmp(&self.creation_instant)
+            .then_with(|| self.tx_id.cmp(&other.tx_id))
+    }
+}
+
+impl PartialOrd for Key {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The selection algorithm that selects transactions in the order of their submission.
+pub type FifoSelection<S> = OrderedSelection<S, Key>;
@@ -1,9 +1,20 @@
-use crate::storage::{
-    RemovedTransactions,
-    StorageData,
+use std::fmt::Debug;
+
+use crate::{
+    config::SelectionPolicy,
+    storage::{
+        RemovedTransactions,
+        StorageData,
+    },
 };
 
+#[cfg(test)]
+use fuel_core_types::services::txpool::ArcPoolTx;
+
+pub mod fifo;
+pub mod ordered;
 pub mod ratio_tip_gas;
+pub mod tip_per_byte;
 
 /// Constraints that the selection algorithm has to respect.
 pub struct Constraints {
@@ -46,3 +57,139 @@ pub trait SelectionAlgorithm {
     /// Inform the selection algorithm that a transaction was removed from the pool.
     fn on_removed_transaction(&mut self, storage_entry: &StorageData);
 }
+
+/// The storage used by the selection algorithms to access transactions and their dependents.
+pub trait SelectionAlgorithmStorage {
+    type StorageIndex: Debug;
+
+    fn get(&self, index: &Self::StorageIndex) -> Option<&StorageData>;
+
+    fn get_dependents(
+        &self,
+        index: &Self::StorageIndex,
+    ) -> impl Iterator<Item = Self::StorageIndex>;
+
+    fn has_dependencies(&self, index: &Self::StorageIndex) -> bool;
+
+    fn remove(&mut self, index: &Self::StorageIndex) -> Option<StorageData>;
+}
+
+/// The selection algorithm chosen by the [`SelectionPolicy`].
+pub enum ConfiguredSelection<S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    RatioTipGas(ratio_tip_gas::RatioTipGasSelection<S>),
+    Fifo(fifo::FifoSelection<S>),
+    TipPerByte(tip_per_byte::TipPerByteSelection<S>),
+}
+
+impl<S> ConfiguredSelection<S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    pub fn new(policy: &SelectionPolicy) -> Self {
+        match policy {
+            SelectionPolicy::RatioTipGas => {
+                Self::RatioTipGas(ratio_tip_gas::RatioTipGasSelection::new())
+            }
+            SelectionPolicy::Fifo => Self::Fifo(fifo::FifoSelection::new()),
+            SelectionPolicy::TipPerByte => {
+                Self::TipPerByte(tip_per_byte::TipPerByteSelection::new())
+            }
+            SelectionPolicy::Fairness { max_txs_per_owner } => Self::RatioTipGas(
+                ratio_tip_gas::RatioTipGasSelection::new()
+                    .with_max_txs_per_owner(*max_txs_per_owner),
+            ),
+        }
+    }
+
+    #[cfg(test)]
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::RatioTipGas(selection) => selection.is_empty(),
+            Self::Fifo(selection) => selection.is_empty(),
+            Self::TipPerByte(selection) => selection.is_empty(),
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn assert_integrity(&self, expected_txs: &[ArcPoolTx]) {
+        match self {
+            Self::RatioTipGas(selection) => selection.assert_integrity(expected_txs),
+            Self::Fifo(selection) => selection.assert_integrity(expected_txs),
+            Self::TipPerByte(selection) => selection.assert_integrity(expected_txs),
+        }
+    }
+}
+
+impl<S> SelectionAlgorithm for ConfiguredSelection<S>
+where
+    S: SelectionAlgorithmStorage,
+{
+    type Storage = S;
+    type StorageIndex = S::StorageIndex;
+
+    fn gather_best_txs(
+        &mut self,
+        constraints: Constraints,
+        storage: &mut S,
+    ) -> RemovedTransactions {
+        match self {
+            Self::RatioTipGas(selection) => {
+                selection.gather_best_txs(constraints, storage)
+            }
+            Self::Fifo(selection) => selection.gather_best_txs(constraints, storage),
+            Self::TipPerByte(selection) => {
+                selection.gather_best_txs(constraints, storage)
+            }
+        }
+    }
+
+    fn new_executable_transaction(
+        &mut self,
+        storage_id: Self::StorageIndex,
+        store_entry: &StorageData,
+    ) {
+        match self {
+            Self::RatioTipGas(selection) => {
+                selection.new_executable_transaction(storage_id, store_entry)
+            }
+            Self::Fifo(selection) => {
+                selection.new_executable_transaction(storage_id, store_entry)
+            }
+            Self::TipPerByte(selection) => {
+                selection.new_executable_transaction(storage_id, store_entry)
+            }
+        }
+    }
+
+    fn number_of_executable_transactions(&self) -> usize {
+        match self {
+            Self::RatioTipGas(selection) => selection.number_of_executable_transactions(),
+            Self::Fifo(selection) => selection.number_of_executable_transactions(),
+            Self::TipPerByte(selection) => selection.number_of_executable_transactions(),
+        }
+    }
+
+    fn get_less_worth_txs(&self) -> impl Iterator<Item = &Self::StorageIndex> {
+        match self {
+            Self::RatioTipGas(selection) => Box::new(selection.get_less_worth_txs())
+                as Box<dyn Iterator<Item = &Self::StorageIndex> + '_>,
+            Self::Fifo(selection) => Box::new(selection.get_less_worth_txs()),
+            Self::TipPerByte(selection) => Box::new(selection.get_less_worth_txs()),
+        }
+    }
+
+    fn on_removed_transaction(&mut self, storage_entry: &StorageData) {
+        match self {
+            Self::RatioTipGas(selection) => {
+                selection.on_removed_transaction(storage_entry)
+            }
+            Self::Fifo(selection) => selection.on_removed_transaction(storage_entry),
+            Self::TipPerByte(selection) => {
+                selection.on_removed_transaction(storage_entry)
+            }
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use std::{
+    cmp::Reverse,
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
+    fmt::Debug,
+};
+
+use fuel_core_types::fuel_tx::{
+    Address,
+    TxId,
+};
+
+use crate::{
+    pool::input_owners,
+    storage::{
+        RemovedTransactions,
+        StorageData,
+    },
+};
+
+use super::{
+    Constraints,
+    SelectionAlgorithm,
+    SelectionAlgorithmStorage,
+};
+
+#[cfg(test)]
+use fuel_core_types::services::txpool::ArcPoolTx;
+
+/// Key used to sort executable transactions.
+/// Transactions with the greatest key are selected first.
+pub trait SelectionKey: Ord + Copy + Debug {
+    /// Creates the key for the stored transaction.
+    fn new(store_entry: &StorageData) -> Self;
+
+    /// Returns the id of the transaction behind the key.
+    fn tx_id(&self) -> TxId;
+}
+
+/// The selection algorithm that selects executable transactions in the order of their keys.
+/// When the transaction is selected, its dependents become executable and take part in the selection.
+pub struct OrderedSelection<S, K>
+where
+    S: SelectionAlgorithmStorage,
+{
+    executable_transactions_sorted: BTreeMap<Reverse<K>, S::StorageIndex>,
+    /// The maximum number of transactions of the same owner selected for one block.
+    max_txs_per_owner: Option<usize>,
+}
+
+impl<S, K> Default for OrderedSelection<S, K>
+where
+    S: SelectionAlgorithmStorage,
+    K: SelectionKey,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, K> OrderedSelection<S, K>
+where
+    S: SelectionAlgorithmStorage,
+    K: SelectionKey,
+{
+    pub fn new() -> Self {
+        Self {
+            executable_transactions_sorted: BTreeMap::new(),
+            max_txs_per_owner: None,
+        }
+    }
+
+    /// Limits the number of transactions of the same owner selected for one block.
+    pub fn with_max_txs_per_owner(self, max_txs_per_owner: usize) -> Self {
+        Self {
+            max_txs_per_owner: Some(max_txs_per_owner),
+            ..self
+        }
+    }
+
+    #[cfg(test)]
+    pub fn is_empty(&self) -> bool {
+        self.executable_transactions_sorted.is_empty()
+    }
+
+    fn on_removed_transaction_inner(&mut self, key: K) {
+        self.executable_transactions_sorted.remove(&Reverse(key));
+    }
+
+    #[cfg(test)]
+    pub(crate) fn assert_integrity(&self, expected_txs: &[ArcPoolTx]) {
+        let mut expected_txs: HashMap<TxId, ArcPoolTx> = expected_txs
+            .iter()
+            .map(|tx| (tx.id(), tx.clone()))
+            .collect();
+        for key in self.executable_transactions_sorted.keys() {
+            let tx_id = key.0.tx_id();
+            expected_txs.remove(&tx_id).unwrap_or_else(|| {
+                panic!(
+                    "Transaction with id {:?} is not in the expected transactions.",
+                    tx_id
+                )
+            });
+        }
+        assert!(
+            expected_txs.is_empty(),
+            "Some transactions are missing from the selection algorithm: {:?}",
+            expected_txs.keys().collect::<Vec<_>>()
+        );
+    }
+}
+
+impl<S, K> SelectionAlgorithm for OrderedSelection<S, K>
+where
+    S: SelectionAlgorithmStorage,
+    K: SelectionKey,
+{
+    type Storage = S;
+    type StorageIndex = S::StorageIndex;
+
+    fn gather_best_txs(
+        &mut self,
+        constraints: Constraints,
+        storage: &mut S,
+    ) -> RemovedTransactions {
+        let mut gas_left = constraints.max_gas;
+        let mut space_left = constraints.maximum_block_size as usize;
+        let mut nb_left = constraints.maximum_txs;
+        let mut selected_per_owner: HashMap<Address, usize> = HashMap::new();
+        let mut result = Vec::new();
+
+        // Take iterate over all transactions with the highest key. If transaction
+        // fits in the gas limit select it and mark all its dependents to be promoted.
+        // Do that until end of the list or gas limit is reached. If gas limit is not
+        // reached, but we have promoted transactions we can start again from the beginning.
+        // Otherwise, we can break the loop.
+        // It is done in this way to minimize number of iteration of the list of executable
+        // transactions.
+        while gas_left > 0
+            && nb_left > 0
+            && space_left > 0
+            && !self.executable_transactions_sorted.is_empty()
+        {
+            let mut clean_up_list = Vec::new();
+            let mut transactions_to_remove = Vec::new();
+            let mut transactions_to_promote = Vec::new();
+
+            for (key, storage_id) in &self.executable_transactions_sorted {
+                if nb_left == 0 || gas_left == 0 || space_left == 0 {
+                    break
+                }
+
+                let Some(stored_transaction) = storage.get(storage_id) else {
+                    debug_assert!(
+                        false,
+                        "Transaction not found in the storage during `gather_best_txs`."
+                    );
+                    tracing::warn!(
+                        "Transaction {} not found in the storage during `gather_best_txs`.",
+                        key.0.tx_id()
+                    );
+                    transactions_to_remove.push(*key);
+                    continue
+                };
+
+                let less_price = stored_transaction.transaction.max_gas_price()
+                    < constraints.minimal_gas_price;
+
+                if less_price {
+                    continue
+                }
+
+                let not_enough_gas = stored_transaction.transaction.max_gas() > gas_left;
+                let too_big_tx =
+                    stored_transaction.transaction.metered_bytes_size() > space_left;
+
+                if not_enough_gas || too_big_tx {
+                    continue
+                }
+
+                let owners = match self.max_txs_per_owner {
+                    Some(max_txs_per_owner) => {
+                        let owners = input_owners(&stored_transaction.transaction);
+                        let owner_limit_hit = owners.iter().any(|owner| {
+                            selected_per_owner.get(owner).copied().unwrap_or_default()
+                                >= max_txs_per_owner
+                        });
+                        if owner_limit_hit {
+                            continue
+                        }
+                        owners
+                    }
+                    None => vec![],
+                };
+
+                gas_left =
+                    gas_left.saturating_sub(stored_transaction.transaction.max_gas());
+                space_left = space_left
+                    .saturating_sub(stored_transaction.transaction.metered_bytes_size());
+                nb_left = nb_left.saturating_sub(1);
+                for owner in owners {
+                    let selected = selected_per_owner.entry(owner).or_default();
+                    *selected = selected.saturating_add(1);
+                }
+
+                let dependents = storage.get_dependents(storage_id).collect::<Vec<_>>();
+                debug_assert!(!storage.has_dependencies(storage_id));
+                let removed = storage.remove(storage_id).expect(
+                    "We just get the transaction from the storage above, it should exist.",
+                );
+                clean_up_list.push(*key);
+                result.push(removed);
+
+                for dependent in dependents {
+                    if !storage.has_dependencies(&dependent) {
+                        transactions_to_promote.push(dependent);
+                    }
+                }
+            }
+
+            for remove in transactions_to_remove {
+                let key = remove.0;
+                self.on_removed_transaction_inner(key);
+            }
+
+            // If no transaction fits in the gas limit and no one to promote, we can break the loop
+            if clean_up_list.is_empty() && transactions_to_promote.is_empty() {
+                break
+            }
+
+            for key in clean_up_list {
+                let key = key.0;
+                // Remove selected transactions from the sorted list
+                self.on_removed_transaction_inner(key);
+            }
+
+            for promote in transactions_to_promote {
+                let storage = storage.get(&promote).expect(
+                    "We just get the dependent from the storage, it should exist.",
+                );
+
+                self.new_executable_transaction(promote, storage);
+            }
+        }
+
+        result
+    }
+
+    fn new_executable_transaction(
+        &mut self,
+        storage_id: Self::StorageIndex,
+        store_entry: &StorageData,
+    ) {
+        let key = K::new(store_entry);
+        self.executable_transactions_sorted
+            .insert(Reverse(key), storage_id);
+    }
+
+    fn get_less_worth_txs(&self) -> impl Iterator<Item = &Self::StorageIndex> {
+        self.executable_transactions_sorted.values().rev()
+    }
+
+    fn on_removed_transaction(&mut self, storage_entry: &StorageData) {
+        let key = K::new(storage_entry);
+        self.on_removed_transaction_inner(key)
+    }
+
+    fn number_of_executable_transactions(&self) -> usize {
+        self.executable_transactions_sorted.len()
+    }
+}
@@ -0,0 +1,34 @@
+use fuel_core_types::fuel_tx::TxId;
+
+use crate::storage::StorageData;
+
+use super::{
+    ordered::{
+        OrderedSelection,
+        SelectionKey,
+    },
+    ratio_tip_gas,
+    ratio_tip_gas::RatioTipGas,
+};
+
+/// Key used to sort transactions by tip/byte ratio.
+/// It first compares the tip/byte ratio, then the creation instant and finally the transaction id.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Debug)]
+pub struct Key(ratio_tip_gas::Key);
+
+impl SelectionKey for Key {
+    fn new(store_entry: &StorageData) -> Self {
+        let transaction = &store_entry.transaction;
+        let size = transaction.metered_bytes_size() as u64;
+        let tip_byte_ratio = RatioTipGas::new(transaction.tip(), size.max(1));
+
+        Self(ratio_tip_gas::Key::from_ratio(tip_byte_ratio, store_entry))
+    }
+
+    fn tx_id(&self) -> TxId {
+        self.0.tx_id()
+    }
+}
+
+/// The selection algorithm that selects transactions based on the tip/byte ratio.
+pub type TipPerByteSelection<S> = OrderedSelection<S, Key>;
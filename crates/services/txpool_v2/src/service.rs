@@ -12,14 +12,22 @@ use fuel_core_services::{
     StateWatcher,
     SyncProcessor,
 };
+use fuel_core_storage::iter::IterDirection;
 use fuel_core_txpool::{
     collision_manager::basic::BasicCollisionManager,
-    config::Config,
+    config::{
+        BlackList,
+        BlackListEntry,
+        Config,
+    },
     error::{
         Error,
         RemovedReason,
     },
-    pool::Pool,
+    pool::{
+        Pool,
+        TxPoolStats,
+    },
     ports::{
         AtomicView,
         BlockImporter as BlockImporterTrait,
@@ -30,8 +38,13 @@ use fuel_core_txpool::{
         TxPoolPersistentStorage,
         WasmChecker as WasmCheckerTrait,
     },
-    selection_algorithms::ratio_tip_gas::RatioTipGasSelection,
+    selection_algorithms::ConfiguredSelection,
     service::{
+        journal::{
+            Journal,
+            JournalWriter,
+            JournaledTx,
+        },
         memory::MemoryPool,
         p2p::P2PExt,
         pruner::TransactionPruner,
@@ -71,6 +84,7 @@ use fuel_core_types::{
         },
         txpool::{
             ArcPoolTx,
+            PoolTransaction,
             TransactionStatus,
         },
     },
@@ -80,9 +94,12 @@ use futures::StreamExt;
 use parking_lot::RwLock;
 use std::{
     collections::{
+        BTreeSet,
+        HashMap,
         HashSet,
         VecDeque,
     },
+    path::PathBuf,
     sync::Arc,
     time::{
         SystemTime,
@@ -98,6 +115,7 @@ use tokio::{
     time::MissedTickBehavior,
 };
 
+mod journal;
 pub(crate) mod memory;
 mod p2p;
 mod pruner;
@@ -108,7 +126,7 @@ pub type TxPool = Pool<
     GraphStorage,
     <GraphStorage as Storage>::StorageIndex,
     BasicCollisionManager<<GraphStorage as Storage>::StorageIndex>,
-    RatioTipGasSelection<GraphStorage>,
+    ConfiguredSelection<GraphStorage>,
 >;
 
 pub(crate) type Shared<T> = Arc<RwLock<T>>;
@@ -123,6 +141,8 @@ pub struct TxInfo {
     tx: ArcPoolTx,
     /// The creation instant of the transaction
     creation_instant: SystemTime,
+    /// The length of the longest chain of the transaction's dependencies in the pool
+    dependencies_depth: usize,
 }
 
 impl TxInfo {
@@ -133,6 +153,22 @@ impl TxInfo {
     pub fn creation_instant(&self) -> &SystemTime {
         &self.creation_instant
     }
+
+    pub fn dependencies_depth(&self) -> usize {
+        self.dependencies_depth
+    }
+}
+
+/// Selects the page of the transactions from the pool ordered by their ids.
+pub struct PendingTxsQuery {
+    /// The id of the first transaction of the page, if it is still in the pool.
+    pub start: Option<TxId>,
+    /// The order of the ids.
+    pub direction: IterDirection,
+    /// The maximum number of the transactions in the page.
+    pub max_txs: usize,
+    /// Only the transactions matching the filter are returned.
+    pub filter: Box<dyn Fn(&PoolTransaction) -> bool + Send>,
 }
 
 impl TryFrom<TxInfo> for TransactionStatus {
@@ -165,8 +201,17 @@ pub enum WritePoolRequest {
     RemoveCoinDependents {
         transactions: Vec<(TxId, String)>,
     },
+    AddToBlackList {
+        entry: BlackListEntry,
+        response_channel: oneshot::Sender<Vec<TxId>>,
+    },
+    RemoveFromBlackList {
+        entry: BlackListEntry,
+        response_channel: oneshot::Sender<bool>,
+    },
 }
 
+#[allow(clippy::enum_variant_names)]
 pub enum ReadPoolRequest {
     GetTxIds {
         max_txs: usize,
@@ -176,6 +221,16 @@ pub enum ReadPoolRequest {
         tx_ids: Vec<TxId>,
         response_channel: oneshot::Sender<Vec<Option<TxInfo>>>,
     },
+    GetPendingTxs {
+        query: PendingTxsQuery,
+        response_channel: oneshot::Sender<Vec<TxInfo>>,
+    },
+    GetStats {
+        response_channel: oneshot::Sender<TxPoolStats>,
+    },
+    GetBlackList {
+        response_channel: oneshot::Sender<BlackList>,
+    },
 }
 
 pub struct Task<View> {
@@ -190,8 +245,13 @@ pub struct Task<View> {
     pool: Shared<TxPool>,
     current_height: Shared<BlockHeight>,
     tx_sync_history: Shared<HashSet<PeerId>>,
+    pending_txs_per_peer: Shared<HashMap<PeerId, usize>>,
+    max_pending_txs_per_peer: usize,
     shared_state: SharedState,
     metrics: bool,
+    journal_path: Option<PathBuf>,
+    journal: Option<JournalWriter>,
+    journal_stopped: Option<oneshot::Receiver<()>>,
 }
 
 #[async_trait::async_trait]
@@ -216,6 +276,7 @@ where
         _: &StateWatcher,
         _: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
+        self.restore_from_journal()?;
         Ok(self)
     }
 }
@@ -296,7 +357,13 @@ where
         }
     }
 
-    async fn shutdown(self) -> anyhow::Result<()> {
+    async fn shutdown(mut self) -> anyhow::Result<()> {
+        // The journal is stopped after the verifications in progress
+        // drop their writers.
+        drop(self.journal.take());
+        if let Some(stopped) = self.journal_stopped.take() {
+            let _ = stopped.await;
+        }
         Ok(())
     }
 }
@@ -307,12 +374,16 @@ where
 {
     fn import_block(&mut self, result: SharedImportResult) {
         let new_height = *result.sealed_block.entity.header().height();
-        let executed_transaction = result.tx_status.iter().map(|s| s.id).collect();
+        let executed_transaction: Vec<TxId> =
+            result.tx_status.iter().map(|s| s.id).collect();
         // We don't want block importer way for us to process the result.
         drop(result);
 
         {
             let mut tx_pool = self.pool.write();
+            // The transactions extracted for the block by this node are
+            // already removed from the pool, but are still in the journal.
+            record_removal(&self.journal, executed_transaction.iter().copied());
             tx_pool.remove_transaction(executed_transaction);
             if !tx_pool.is_empty() {
                 self.shared_state.new_txs_notifier.send_replace(());
@@ -357,7 +428,83 @@ where
             WritePoolRequest::RemoveCoinDependents { transactions } => {
                 self.manage_remove_coin_dependents(transactions);
             }
+            WritePoolRequest::AddToBlackList {
+                entry,
+                response_channel,
+            } => {
+                let removed = self.add_to_blacklist(entry);
+                if response_channel.send(removed).is_err() {
+                    tracing::error!(
+                        "Failed to send the result back for `AddToBlackList` request"
+                    );
+                }
+            }
+            WritePoolRequest::RemoveFromBlackList {
+                entry,
+                response_channel,
+            } => {
+                let removed = self.remove_from_blacklist(entry);
+                if response_channel.send(removed).is_err() {
+                    tracing::error!(
+                        "Failed to send the result back for `RemoveFromBlackList` request"
+                    );
+                }
+            }
+        }
+    }
+
+    fn add_to_blacklist(&self, entry: BlackListEntry) -> Vec<TxId> {
+        let (removed, entries) = {
+            let mut pool = self.pool.write();
+            let removed = pool.add_to_blacklist(entry);
+            record_removal(&self.journal, removed.iter().map(|tx| tx.id()));
+            (removed, pool.blacklist().len())
+        };
+
+        let removed: Vec<_> = removed.iter().map(|tx| tx.id()).collect();
+        tracing::info!(
+            target: "audit",
+            ?entry,
+            evicted = removed.len(),
+            "Added the entry to the txpool blacklist"
+        );
+        if self.metrics {
+            let metrics = txpool_metrics();
+            metrics
+                .blacklist_entries
+                .set(i64::try_from(entries).unwrap_or(i64::MAX));
+            metrics
+                .blacklist_evicted_transactions
+                .inc_by(removed.len() as u64);
+        }
+
+        for tx_id in &removed {
+            self.shared_state
+                .tx_status_sender
+                .send_squeezed_out(*tx_id, Error::Removed(RemovedReason::Blacklisted));
+        }
+        removed
+    }
+
+    fn remove_from_blacklist(&self, entry: BlackListEntry) -> bool {
+        let (removed, entries) = {
+            let mut pool = self.pool.write();
+            let removed = pool.remove_from_blacklist(&entry);
+            (removed, pool.blacklist().len())
+        };
+
+        tracing::info!(
+            target: "audit",
+            ?entry,
+            removed,
+            "Removed the entry from the txpool blacklist"
+        );
+        if self.metrics {
+            txpool_metrics()
+                .blacklist_entries
+                .set(i64::try_from(entries).unwrap_or(i64::MAX));
         }
+        removed
     }
 
     fn insert_transactions(&self, transactions: Vec<Arc<Transaction>>) {
@@ -392,6 +539,7 @@ where
         let shared_state = self.shared_state.clone();
         let current_height = self.current_height.clone();
         let time_txs_submitted = self.pruner.time_txs_submitted.clone();
+        let journal = self.journal.clone();
         let tx_id = transaction.id(&self.chain_id);
         let utxo_validation = self.utxo_validation;
 
@@ -432,19 +580,33 @@ where
 
             let tx = Arc::new(checked_tx);
 
+            let submitted_time = SystemTime::now();
             let result = {
                 let mut pool = pool.write();
                 let result = verification.persistent_storage_provider.latest_view();
 
-                match result {
-                    Ok(view) => pool.insert(tx, &view),
+                let result = match result {
+                    Ok(view) => pool.insert(tx.clone(), &view),
                     Err(err) => Err(Error::Database(format!("{:?}", err))),
+                };
+
+                // Journal while holding the lock to keep the order of records
+                // the same as the order of changes in the pool. The records
+                // are written into the file in the background.
+                if let (Some(journal), Ok(removed_txs)) = (&journal, &result) {
+                    journal.record_insert(JournaledTx {
+                        tx_id,
+                        tx: tx.as_ref().into(),
+                        submitted_at: submitted_time,
+                    });
+                    journal.record_remove(removed_txs.iter().map(|tx| tx.id()));
                 }
+
+                result
             };
 
             let removed_txs = match result {
                 Ok(removed_txs) => {
-                    let submitted_time = SystemTime::now();
                     time_txs_submitted
                         .write()
                         .push_front((submitted_time, tx_id));
@@ -498,7 +660,12 @@ where
 
     fn manage_remove_coin_dependents(&self, transactions: Vec<(TxId, String)>) {
         for (tx_id, reason) in transactions {
-            let dependents = self.pool.write().remove_coin_dependents(tx_id);
+            let dependents = {
+                let mut pool = self.pool.write();
+                let dependents = pool.remove_coin_dependents(tx_id);
+                record_removal(&self.journal, dependents.iter().map(|tx| tx.id()));
+                dependents
+            };
 
             for removed_tx in dependents {
                 self.shared_state.tx_status_sender.send_squeezed_out(
@@ -522,11 +689,41 @@ where
             return;
         };
 
-        let info = Some(GossipsubMessageInfo {
+        let info = GossipsubMessageInfo {
             message_id,
-            peer_id,
-        });
-        let op = self.insert_transaction(Arc::new(tx), info, None);
+            peer_id: peer_id.clone(),
+        };
+
+        {
+            let mut pending_txs_per_peer = self.pending_txs_per_peer.write();
+            let pending = pending_txs_per_peer.entry(peer_id.clone()).or_default();
+            if *pending >= self.max_pending_txs_per_peer {
+                if self.metrics {
+                    txpool_metrics().peer_tx_count_limit_rejections.inc();
+                }
+                let err = Error::NotInsertedPeerTxCountLimitHit {
+                    peer_id,
+                    limit: self.max_pending_txs_per_peer,
+                };
+                tracing::debug!("Ignoring the gossiped transaction: {err}");
+                self.p2p.process_insertion_result(Some(info), &Err(err));
+                return
+            }
+            *pending = pending.saturating_add(1);
+        }
+
+        let op = self.insert_transaction(Arc::new(tx), Some(info), None);
+        let pending_txs_per_peer = self.pending_txs_per_peer.clone();
+        let op = move || {
+            op();
+            let mut pending_txs_per_peer = pending_txs_per_peer.write();
+            if let Some(pending) = pending_txs_per_peer.get_mut(&peer_id) {
+                *pending = pending.saturating_sub(1);
+                if *pending == 0 {
+                    pending_txs_per_peer.remove(&peer_id);
+                }
+            }
+        };
         self.transaction_verifier_process
             .spawn_reserved(reservation, op);
     }
@@ -623,6 +820,7 @@ where
         {
             let mut pool = self.pool.write();
             removed = pool.remove_transaction_and_dependents(txs_to_remove);
+            record_removal(&self.journal, removed.iter().map(|tx| tx.id()));
         }
 
         for tx in removed {
@@ -639,6 +837,78 @@ where
         }
     }
 
+    /// Re-verifies the transactions from the journal and inserts them into the pool.
+    /// Transactions that are expired or don't pass verifications anymore are dropped.
+    fn restore_from_journal(&mut self) -> anyhow::Result<()> {
+        let Some(path) = &self.journal_path else {
+            return Ok(())
+        };
+        let (mut journal, journaled_txs) = Journal::open(path)?;
+
+        let current_height = *self.current_height.read();
+        let now = SystemTime::now();
+        let mut restored = vec![];
+        for journaled in journaled_txs {
+            let tx_id = journaled.tx_id;
+            let expired = now
+                .duration_since(journaled.submitted_at)
+                .is_ok_and(|duration| duration >= self.pruner.txs_ttl);
+            if expired {
+                tracing::info!(
+                    "Dropping the journaled transaction {tx_id}: {}",
+                    Error::Removed(RemovedReason::Ttl)
+                );
+                continue
+            }
+
+            let result = self
+                .verification
+                .perform_all_verifications(
+                    journaled.tx.clone(),
+                    &self.pool,
+                    current_height,
+                    self.utxo_validation,
+                )
+                .and_then(|checked_tx| {
+                    let view = self
+                        .verification
+                        .persistent_storage_provider
+                        .latest_view()
+                        .map_err(|err| Error::Database(format!("{:?}", err)))?;
+                    self.pool.write().insert(Arc::new(checked_tx), &view)
+                });
+
+            match result {
+                Ok(_) => {
+                    self.pruner
+                        .time_txs_submitted
+                        .write()
+                        .push_front((journaled.submitted_at, tx_id));
+                    restored.push(journaled);
+                }
+                Err(err) => {
+                    tracing::info!("Dropping the journaled transaction {tx_id}: {err}");
+                }
+            }
+        }
+
+        // Restored transactions can be squeezed out by the ones restored after them.
+        {
+            let pool = self.pool.read();
+            restored.retain(|journaled| pool.contains(&journaled.tx_id));
+        }
+        tracing::info!("Restored {} transactions from the journal", restored.len());
+        journal.compact(&restored)?;
+
+        if !restored.is_empty() {
+            self.shared_state.new_txs_notifier.send_replace(());
+        }
+        let (journal, stopped) = JournalWriter::spawn(journal)?;
+        self.journal = Some(journal);
+        self.journal_stopped = Some(stopped);
+        Ok(())
+    }
+
     fn process_read(&self, request: ReadPoolRequest) {
         match request {
             ReadPoolRequest::GetTxIds {
@@ -663,12 +933,7 @@ where
                     let pool = self.pool.read();
                     tx_ids
                         .into_iter()
-                        .map(|tx_id| {
-                            pool.find_one(&tx_id).map(|stored_data| TxInfo {
-                                tx: stored_data.transaction.clone(),
-                                creation_instant: stored_data.creation_instant,
-                            })
-                        })
+                        .map(|tx_id| tx_info(&pool, &tx_id))
                         .collect()
                 };
                 if response_channel.send(txs).is_err() {
@@ -677,8 +942,81 @@ where
                     );
                 }
             }
+            ReadPoolRequest::GetPendingTxs {
+                query,
+                response_channel,
+            } => {
+                let txs = pending_txs(&self.pool.read(), &query);
+                if response_channel.send(txs).is_err() {
+                    tracing::error!(
+                        "Failed to send the result back for `GetPendingTxs` request"
+                    );
+                }
+            }
+            ReadPoolRequest::GetStats { response_channel } => {
+                let stats = self.pool.read().stats();
+                if response_channel.send(stats).is_err() {
+                    tracing::error!(
+                        "Failed to send the result back for `GetStats` request"
+                    );
+                }
+            }
+            ReadPoolRequest::GetBlackList { response_channel } => {
+                let blacklist = self.pool.read().blacklist().clone();
+                if response_channel.send(blacklist).is_err() {
+                    tracing::error!(
+                        "Failed to send the result back for `GetBlackList` request"
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn tx_info(pool: &TxPool, tx_id: &TxId) -> Option<TxInfo> {
+    pool.find_one(tx_id).map(|stored_data| TxInfo {
+        tx: stored_data.transaction.clone(),
+        creation_instant: stored_data.creation_instant,
+        dependencies_depth: pool.dependencies_depth(tx_id).unwrap_or_default(),
+    })
+}
+
+/// Scans the pool once and keeps only the ids of the page, so the transactions
+/// outside of the page are not copied.
+fn pending_txs(pool: &TxPool, query: &PendingTxsQuery) -> Vec<TxInfo> {
+    let mut page = BTreeSet::new();
+    for tx_id in pool.iter_tx_ids() {
+        let in_range = match (query.start, query.direction) {
+            (Some(start), IterDirection::Forward) => *tx_id >= start,
+            (Some(start), IterDirection::Reverse) => *tx_id <= start,
+            (None, _) => true,
+        };
+        if !in_range {
+            continue
+        }
+        let Some(stored_data) = pool.find_one(tx_id) else {
+            continue
+        };
+        if !(query.filter)(&stored_data.transaction) {
+            continue
+        }
+        page.insert(*tx_id);
+        if page.len() > query.max_txs {
+            match query.direction {
+                IterDirection::Forward => page.pop_last(),
+                IterDirection::Reverse => page.pop_first(),
+            };
         }
     }
+
+    let mut tx_ids: Vec<_> = page.into_iter().collect();
+    if query.direction == IterDirection::Reverse {
+        tx_ids.reverse();
+    }
+    tx_ids
+        .iter()
+        .filter_map(|tx_id| tx_info(pool, tx_id))
+        .collect()
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -782,14 +1120,21 @@ where
     .unwrap();
 
     let metrics = config.metrics;
+    let journal_path = config.journal_path.clone();
+    let max_pending_txs_per_peer = config.sender_limits.max_pending_txs_per_peer;
 
     let utxo_validation = config.utxo_validation;
+    if metrics {
+        txpool_metrics()
+            .blacklist_entries
+            .set(i64::try_from(config.black_list.len()).unwrap_or(i64::MAX));
+    }
     let txpool = Pool::new(
         GraphStorage::new(GraphConfig {
             max_txs_chain_count: config.max_txs_chain_count,
         }),
         BasicCollisionManager::new(),
-        RatioTipGasSelection::new(),
+        ConfiguredSelection::new(&config.selection_policy),
         config,
     );
 
@@ -807,5 +1152,19 @@ where
         shared_state,
         metrics,
         tx_sync_history: Default::default(),
+        pending_txs_per_peer: Default::default(),
+        max_pending_txs_per_peer,
+        journal_path,
+        journal: None,
+        journal_stopped: None,
     })
 }
+
+fn record_removal(
+    journal: &Option<JournalWriter>,
+    tx_ids: impl IntoIterator<Item = TxId>,
+) {
+    if let Some(journal) = journal {
+        journal.record_remove(tx_ids);
+    }
+}
@@ -0,0 +1,417 @@
+//! The append-only file with the transactions accepted by the pool.
+//! Each record is either an insertion of the transaction or a removal of
+//! the transaction by its id. On start-up, the journal is replayed and
+//! compacted to contain only the transactions restored into the pool.
+//! While the pool is running, the records are written by the background
+//! thread, and the journal is compacted once the records of the removed
+//! transactions exceed the threshold.
+
+use fuel_core_types::{
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
+    fuel_types::canonical::{
+        Deserialize,
+        Serialize,
+    },
+};
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
+    fs::{
+        File,
+        OpenOptions,
+    },
+    io::{
+        Read,
+        Write,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::mpsc,
+    time::{
+        Duration,
+        SystemTime,
+    },
+};
+use tokio::sync::oneshot;
+
+const INSERT_RECORD: u8 = 0;
+const REMOVE_RECORD: u8 = 1;
+
+/// The size of the records of the removed transactions
+/// after which the journal is compacted.
+const COMPACTION_THRESHOLD: u64 = 64 * 1024 * 1024;
+
+/// The transaction from the journal with the time of its submission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct JournaledTx {
+    pub tx_id: TxId,
+    pub tx: Transaction,
+    pub submitted_at: SystemTime,
+}
+
+pub(crate) struct Journal {
+    path: PathBuf,
+    file: File,
+    /// The transactions that are not removed, used to compact the journal.
+    txs: JournaledTxs,
+    /// The size of the journal file.
+    len: u64,
+    compaction_threshold: u64,
+}
+
+impl Journal {
+    /// Opens the journal at the `path`, creating it if it doesn't exist,
+    /// and returns the transactions that were not removed, in the order of insertion.
+    pub fn open(path: &Path) -> anyhow::Result<(Self, Vec<JournaledTx>)> {
+        let mut bytes = vec![];
+        match File::open(path) {
+            Ok(mut file) => {
+                file.read_to_end(&mut bytes)?;
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+        let txs = replay(&bytes);
+
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let journal = Self {
+            path: path.to_path_buf(),
+            file,
+            txs: JournaledTxs::default(),
+            len: 0,
+            compaction_threshold: COMPACTION_THRESHOLD,
+        };
+        Ok((journal, txs))
+    }
+
+    pub fn record_insert(&mut self, journaled: JournaledTx) -> anyhow::Result<()> {
+        let record = encode_insert(&journaled)?;
+        self.file.write_all(&record)?;
+        self.len = self.len.saturating_add(record.len() as u64);
+        self.txs.insert(journaled, record.len() as u64);
+        self.compact_if_needed()
+    }
+
+    pub fn record_remove(
+        &mut self,
+        tx_ids: impl IntoIterator<Item = TxId>,
+    ) -> anyhow::Result<()> {
+        let mut records = vec![];
+        // Only the journaled transactions are recorded, the other ids
+        // may belong to the transactions included by the other nodes.
+        for tx_id in tx_ids {
+            if self.txs.remove(&tx_id) {
+                records.push(REMOVE_RECORD);
+                records.extend_from_slice(tx_id.as_ref());
+            }
+        }
+        if !records.is_empty() {
+            self.file.write_all(&records)?;
+            self.len = self.len.saturating_add(records.len() as u64);
+        }
+        self.compact_if_needed()
+    }
+
+    /// Replaces the content of the journal with insertions of the `txs`.
+    pub fn compact(&mut self, txs: &[JournaledTx]) -> anyhow::Result<()> {
+        let mut records = vec![];
+        let mut compacted = JournaledTxs::default();
+        for journaled in txs {
+            let record = encode_insert(journaled)?;
+            records.extend_from_slice(&record);
+            compacted.insert(journaled.clone(), record.len() as u64);
+        }
+
+        let tmp_path = self.path.with_extension("tmp");
+        let mut tmp = File::create(&tmp_path)?;
+        tmp.write_all(&records)?;
+        tmp.sync_all()?;
+        std::fs::rename(&tmp_path, &self.path)?;
+
+        self.file = OpenOptions::new().append(true).open(&self.path)?;
+        self.txs = compacted;
+        self.len = records.len() as u64;
+        Ok(())
+    }
+
+    fn compact_if_needed(&mut self) -> anyhow::Result<()> {
+        let removed_len = self.len.saturating_sub(self.txs.len);
+        if removed_len < self.compaction_threshold {
+            return Ok(())
+        }
+
+        let txs: Vec<_> = self.txs.txs.values().map(|(tx, _)| tx.clone()).collect();
+        self.compact(&txs)?;
+        tracing::debug!("Compacted the journal to {} transactions", txs.len());
+        Ok(())
+    }
+}
+
+/// The record of the change in the pool.
+enum Record {
+    Insert(JournaledTx),
+    Remove(Vec<TxId>),
+}
+
+/// Sends the records to the background thread that writes them into the journal,
+/// so the file system doesn't block the pool.
+#[derive(Clone)]
+pub(crate) struct JournalWriter {
+    sender: mpsc::Sender<Record>,
+}
+
+impl JournalWriter {
+    /// Spawns the thread writing into the `journal`. The returned receiver
+    /// is notified when all senders are dropped and the records are written.
+    pub fn spawn(mut journal: Journal) -> anyhow::Result<(Self, oneshot::Receiver<()>)> {
+        let (sender, receiver) = mpsc::channel();
+        let (stopped_sender, stopped) = oneshot::channel();
+        std::thread::Builder::new()
+            .name("TxPool_Journal".to_string())
+            .spawn(move || {
+                while let Ok(record) = receiver.recv() {
+                    match record {
+                        Record::Insert(journaled) => {
+                            let tx_id = journaled.tx_id;
+                            if let Err(err) = journal.record_insert(journaled) {
+                                tracing::error!(
+                                    "Failed to journal the transaction {tx_id}: {err}"
+                                );
+                            }
+                        }
+                        Record::Remove(tx_ids) => {
+                            if let Err(err) = journal.record_remove(tx_ids) {
+                                tracing::error!(
+                                    "Failed to journal removed transactions: {err}"
+                                );
+                            }
+                        }
+                    }
+                }
+                let _ = stopped_sender.send(());
+            })?;
+
+        Ok((Self { sender }, stopped))
+    }
+
+    pub fn record_insert(&self, journaled: JournaledTx) {
+        let _ = self.sender.send(Record::Insert(journaled));
+    }
+
+    pub fn record_remove(&self, tx_ids: impl IntoIterator<Item = TxId>) {
+        let tx_ids: Vec<_> = tx_ids.into_iter().collect();
+        if !tx_ids.is_empty() {
+            let _ = self.sender.send(Record::Remove(tx_ids));
+        }
+    }
+}
+
+/// The transactions of the journal in the order of insertion
+/// along with the sizes of their records.
+#[derive(Default)]
+struct JournaledTxs {
+    sequence: u64,
+    txs: BTreeMap<u64, (JournaledTx, u64)>,
+    sequences: HashMap<TxId, u64>,
+    /// The size of the records of the transactions.
+    len: u64,
+}
+
+impl JournaledTxs {
+    fn insert(&mut self, journaled: JournaledTx, record_len: u64) {
+        let _ = self.remove(&journaled.tx_id);
+        self.sequences.insert(journaled.tx_id, self.sequence);
+        self.txs.insert(self.sequence, (journaled, record_len));
+        self.len = self.len.saturating_add(record_len);
+        self.sequence = self.sequence.saturating_add(1);
+    }
+
+    /// Returns `true` if the transaction was journaled.
+    fn remove(&mut self, tx_id: &TxId) -> bool {
+        let removed = self
+            .sequences
+            .remove(tx_id)
+            .and_then(|sequence| self.txs.remove(&sequence));
+        match removed {
+            Some((_, record_len)) => {
+                self.len = self.len.saturating_sub(record_len);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+fn encode_insert(journaled: &JournaledTx) -> anyhow::Result<Vec<u8>> {
+    let submitted_at = journaled
+        .submitted_at
+        .duration_since(SystemTime::UNIX_EPOCH)?
+        .as_secs();
+    let tx = journaled.tx.to_bytes();
+    let len = u32::try_from(tx.len())?;
+
+    let mut record = vec![INSERT_RECORD];
+    record.extend_from_slice(journaled.tx_id.as_ref());
+    record.extend_from_slice(&submitted_at.to_le_bytes());
+    record.extend_from_slice(&len.to_le_bytes());
+    record.extend(tx);
+    Ok(record)
+}
+
+fn replay(mut bytes: &[u8]) -> Vec<JournaledTx> {
+    let mut txs = JournaledTxs::default();
+
+    while let Some((&kind, rest)) = bytes.split_first() {
+        let Some((tx_id, rest)) = split_tx_id(rest) else {
+            break
+        };
+        match kind {
+            INSERT_RECORD => {
+                let Some((submitted_at, rest)) = split_u64(rest) else {
+                    break
+                };
+                let Some((len, rest)) = split_u32(rest) else {
+                    break
+                };
+                let Some((tx, rest)) = rest.split_at_checked(len as usize) else {
+                    break
+                };
+                let record_len = bytes.len().saturating_sub(rest.len()) as u64;
+                bytes = rest;
+
+                let Ok(tx) = Transaction::from_bytes(tx) else {
+                    tracing::warn!(
+                        "Skipping the undecodable transaction {tx_id} in the journal"
+                    );
+                    continue
+                };
+                // The transaction with an invalid time is expired.
+                let submitted_at = SystemTime::UNIX_EPOCH
+                    .checked_add(Duration::from_secs(submitted_at))
+                    .unwrap_or(SystemTime::UNIX_EPOCH);
+                txs.insert(
+                    JournaledTx {
+                        tx_id,
+                        tx,
+                        submitted_at,
+                    },
+                    record_len,
+                );
+            }
+            REMOVE_RECORD => {
+                bytes = rest;
+                txs.remove(&tx_id);
+            }
+            _ => break,
+        }
+    }
+
+    if !bytes.is_empty() {
+        // The node may stop in the middle of writing the record.
+        tracing::warn!(
+            "Ignoring {} bytes of the incomplete record at the end of the journal",
+            bytes.len()
+        );
+    }
+
+    txs.txs.into_values().map(|(tx, _)| tx).collect()
+}
+
+fn split_tx_id(bytes: &[u8]) -> Option<(TxId, &[u8])> {
+    let (tx_id, rest) = bytes.split_first_chunk::<32>()?;
+    Some((TxId::from(*tx_id), rest))
+}
+
+fn split_u64(bytes: &[u8]) -> Option<(u64, &[u8])> {
+    let (value, rest) = bytes.split_first_chunk::<8>()?;
+    Some((u64::from_le_bytes(*value), rest))
+}
+
+fn split_u32(bytes: &[u8]) -> Option<(u32, &[u8])> {
+    let (value, rest) = bytes.split_first_chunk::<4>()?;
+    Some((u32::from_le_bytes(*value), rest))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    fn journaled(i: u8) -> JournaledTx {
+        JournaledTx {
+            tx_id: TxId::from([i; 32]),
+            tx: Transaction::default_test_tx(),
+            submitted_at: SystemTime::UNIX_EPOCH,
+        }
+    }
+
+    #[test]
+    fn record_remove__compacts_journal_after_threshold() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("txpool.journal");
+        let (mut journal, _) = Journal::open(&path).unwrap();
+        journal.compaction_threshold = 1024;
+        for i in 0..100 {
+            journal.record_insert(journaled(i)).unwrap();
+        }
+        let len_before = std::fs::metadata(&path).unwrap().len();
+
+        // When
+        journal
+            .record_remove((0..99).map(|i| TxId::from([i; 32])))
+            .unwrap();
+
+        // Then
+        let len_after = std::fs::metadata(&path).unwrap().len();
+        assert!(len_after < len_before);
+        let (_, txs) = Journal::open(&path).unwrap();
+        assert_eq!(txs, vec![journaled(99)]);
+    }
+
+    #[test]
+    fn record_remove__skips_not_journaled_transactions() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("txpool.journal");
+        let (mut journal, _) = Journal::open(&path).unwrap();
+        journal.record_insert(journaled(1)).unwrap();
+        let len_before = std::fs::metadata(&path).unwrap().len();
+
+        // When
+        journal.record_remove([TxId::from([2; 32])]).unwrap();
+
+        // Then
+        let len_after = std::fs::metadata(&path).unwrap().len();
+        assert_eq!(len_after, len_before);
+        let (_, txs) = Journal::open(&path).unwrap();
+        assert_eq!(txs, vec![journaled(1)]);
+    }
+
+    #[tokio::test]
+    async fn journal_writer__writes_records_before_stop() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("txpool.journal");
+        let (journal, _) = Journal::open(&path).unwrap();
+        let (writer, stopped) = JournalWriter::spawn(journal).unwrap();
+
+        // Given
+        writer.record_insert(journaled(1));
+        writer.record_insert(journaled(2));
+        writer.record_remove([TxId::from([1; 32])]);
+
+        // When
+        drop(writer);
+        stopped.await.unwrap();
+
+        // Then
+        let (_, txs) = Journal::open(&path).unwrap();
+        assert_eq!(txs, vec![journaled(2)]);
+    }
+}
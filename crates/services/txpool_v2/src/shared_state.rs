@@ -19,9 +19,15 @@ use tokio::sync::{
 };
 
 use crate::{
+    config::{
+        BlackList,
+        BlackListEntry,
+    },
     error::Error,
+    pool::TxPoolStats,
     service::{
         BorrowTxPoolRequest,
+        PendingTxsQuery,
         ReadPoolRequest,
         Shared,
         TxInfo,
@@ -130,6 +136,90 @@ impl SharedState {
             .map_err(|_| Error::ServiceCommunicationFailed)
     }
 
+    /// Get the page of the transactions from the pool ordered by their ids.
+    pub async fn pending_txs(
+        &self,
+        query: PendingTxsQuery,
+    ) -> Result<Vec<TxInfo>, Error> {
+        let (result_sender, result_receiver) = oneshot::channel();
+        self.read_pool_requests_sender
+            .send(ReadPoolRequest::GetPendingTxs {
+                query,
+                response_channel: result_sender,
+            })
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)?;
+        result_receiver
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)
+    }
+
+    /// Get the current usage of the pool along with its limits.
+    pub async fn stats(&self) -> Result<TxPoolStats, Error> {
+        let (result_sender, result_receiver) = oneshot::channel();
+        self.read_pool_requests_sender
+            .send(ReadPoolRequest::GetStats {
+                response_channel: result_sender,
+            })
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)?;
+        result_receiver
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)
+    }
+
+    /// Get the blacklist used by the pool.
+    pub async fn blacklist(&self) -> Result<BlackList, Error> {
+        let (result_sender, result_receiver) = oneshot::channel();
+        self.read_pool_requests_sender
+            .send(ReadPoolRequest::GetBlackList {
+                response_channel: result_sender,
+            })
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)?;
+        result_receiver
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)
+    }
+
+    /// Add the entry to the blacklist and evict the transactions that use it.
+    /// Returns the ids of the evicted transactions along with their dependents.
+    pub async fn add_to_blacklist(
+        &self,
+        entry: BlackListEntry,
+    ) -> Result<Vec<TxId>, Error> {
+        let (result_sender, result_receiver) = oneshot::channel();
+        self.write_pool_requests_sender
+            .send(WritePoolRequest::AddToBlackList {
+                entry,
+                response_channel: result_sender,
+            })
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)?;
+        result_receiver
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)
+    }
+
+    /// Remove the entry from the blacklist.
+    /// Returns `false` if the entry was not blacklisted.
+    pub async fn remove_from_blacklist(
+        &self,
+        entry: BlackListEntry,
+    ) -> Result<bool, Error> {
+        let (result_sender, result_receiver) = oneshot::channel();
+        self.write_pool_requests_sender
+            .send(WritePoolRequest::RemoveFromBlackList {
+                entry,
+                response_channel: result_sender,
+            })
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)?;
+        result_receiver
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)
+    }
+
     /// Get a notifier that is notified when new transactions are added to the pool.
     pub fn get_new_txs_notifier(&self) -> watch::Receiver<()> {
         self.new_txs_notifier.subscribe()
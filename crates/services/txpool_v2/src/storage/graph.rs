@@ -45,7 +45,7 @@ use crate::{
         InputValidationError,
     },
     ports::TxPoolPersistentStorage,
-    selection_algorithms::ratio_tip_gas::RatioTipGasSelectionAlgorithmStorage,
+    selection_algorithms::SelectionAlgorithmStorage,
     storage::checked_collision::CheckedTransaction,
 };
 
@@ -610,6 +610,24 @@ impl Storage for GraphStorage {
         self.get_direct_dependencies(*index).next().is_some()
     }
 
+    fn dependencies_depth(&self, index: &Self::StorageIndex) -> usize {
+        // The graph is acyclic, so the last non-empty layer of the traversal
+        // is reachable only by the longest chain.
+        let mut depth = 0usize;
+        let mut layer = HashSet::from([*index]);
+        loop {
+            let next_layer: HashSet<_> = layer
+                .iter()
+                .flat_map(|node| self.get_direct_dependencies(*node))
+                .collect();
+            if next_layer.is_empty() {
+                return depth
+            }
+            depth = depth.saturating_add(1);
+            layer = next_layer;
+        }
+    }
+
     fn validate_inputs(
         &self,
         transaction: &PoolTransaction,
@@ -709,7 +727,7 @@ impl Storage for GraphStorage {
     }
 }
 
-impl RatioTipGasSelectionAlgorithmStorage for GraphStorage {
+impl SelectionAlgorithmStorage for GraphStorage {
     type StorageIndex = NodeIndex;
 
     fn get(&self, index: &Self::StorageIndex) -> Option<&StorageData> {
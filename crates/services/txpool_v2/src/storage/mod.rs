@@ -86,6 +86,10 @@ pub trait Storage {
     /// Returns `true` if the transaction has dependencies.
     fn has_dependencies(&self, index: &Self::StorageIndex) -> bool;
 
+    /// Returns the length of the longest chain of dependencies of the transaction.
+    /// The transaction without dependencies has a depth of `0`.
+    fn dependencies_depth(&self, index: &Self::StorageIndex) -> usize;
+
     /// Validate inputs of a transaction.
     fn validate_inputs(
         &self,
@@ -54,7 +54,7 @@ use crate::{
     config::Config,
     error::Error,
     pool::Pool,
-    selection_algorithms::ratio_tip_gas::RatioTipGasSelection,
+    selection_algorithms::ConfiguredSelection,
     service::{
         RemovedTransactions,
         TxPool,
@@ -118,7 +118,7 @@ impl TestPoolUniverse {
                 max_txs_chain_count: self.config.max_txs_chain_count,
             }),
             BasicCollisionManager::new(),
-            RatioTipGasSelection::new(),
+            ConfiguredSelection::new(&self.config.selection_policy),
             self.config.clone(),
         )));
         self.pool = Some(pool.clone());
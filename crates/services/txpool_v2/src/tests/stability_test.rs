@@ -12,6 +12,7 @@ use crate::{
     config::{
         Config,
         PoolLimits,
+        SelectionPolicy,
     },
     selection_algorithms::Constraints,
     tests::universe::TestPoolUniverse,
@@ -159,6 +160,7 @@ fn stability_test_with_seed(seed: u64, limits: Limits, config: Config) {
 
     let mut errors = 0;
 
+    let selection_policy = config.selection_policy;
     let mut universe = TestPoolUniverse::default().config(config);
     universe.build_pool();
     let txpool = universe.get_pool();
@@ -192,6 +194,13 @@ fn stability_test_with_seed(seed: u64, limits: Limits, config: Config) {
             break
         }
 
+        let block_gas = result.iter().map(|tx| tx.max_gas()).sum::<u64>();
+        assert!(block_gas <= limits.max_block_gas);
+        if let SelectionPolicy::Fairness { max_txs_per_owner } = selection_policy {
+            // All transactions are created by the same owner.
+            assert!(result.len() <= max_txs_per_owner);
+        }
+
         txpool.read().storage.check_integrity();
     }
 
@@ -203,6 +212,7 @@ fn stability_test_with_seed(seed: u64, limits: Limits, config: Config) {
         assert!(txpool.selection_algorithm.is_empty());
         assert!(txpool.storage.is_empty());
         assert!(txpool.collision_manager.is_empty());
+        assert!(txpool.owners_usage.is_empty());
     }
 }
 
@@ -379,3 +389,72 @@ fn stability_test__wide_chain_of_transactions_with_conflicts() {
         stability_test(limit, config.clone());
     }
 }
+
+#[test]
+fn stability_test__fifo_policy() {
+    let config = Config {
+        utxo_validation: false,
+        selection_policy: SelectionPolicy::Fifo,
+        ..Default::default()
+    };
+
+    let limit = Limits {
+        max_inputs: 4,
+        min_outputs: 1,
+        max_outputs: 4,
+        utxo_id_range: 12,
+        gas_limit_range: 1000,
+        max_block_gas: 10_000,
+    };
+
+    for _ in 0..ROUNDS_PER_TEST {
+        stability_test(limit, config.clone());
+    }
+}
+
+#[test]
+fn stability_test__tip_per_byte_policy() {
+    let config = Config {
+        utxo_validation: false,
+        selection_policy: SelectionPolicy::TipPerByte,
+        ..Default::default()
+    };
+
+    let limit = Limits {
+        max_inputs: 4,
+        min_outputs: 1,
+        max_outputs: 4,
+        utxo_id_range: 12,
+        gas_limit_range: 1000,
+        max_block_gas: 10_000,
+    };
+
+    for _ in 0..ROUNDS_PER_TEST {
+        stability_test(limit, config.clone());
+    }
+}
+
+#[test]
+fn stability_test__fairness_policy() {
+    let config = Config {
+        utxo_validation: false,
+        max_txs_chain_count: 32,
+        selection_policy: SelectionPolicy::Fairness {
+            max_txs_per_owner: 5,
+        },
+        ..Default::default()
+    };
+
+    let limit = Limits {
+        max_inputs: 3,
+        min_outputs: 2,
+        max_outputs: 3,
+        utxo_id_range: 128,
+        gas_limit_range: 10_000,
+        max_block_gas: 100_000,
+    };
+
+    for _ in 0..ROUNDS_PER_TEST {
+        stability_test(limit, config.clone());
+    }
+}
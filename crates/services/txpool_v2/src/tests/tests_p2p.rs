@@ -29,6 +29,10 @@ use tokio::sync::{
 use tokio_stream::StreamExt;
 
 use crate::{
+    config::{
+        Config,
+        SenderLimits,
+    },
     tests::{
         mocks::MockP2P,
         universe::{
@@ -413,3 +417,42 @@ async fn test_gossipped_transaction_with_transient_error_ignored() {
     );
     service.stop_and_await().await.unwrap();
 }
+
+#[tokio::test]
+async fn test_gossipped_transaction_ignored_when_peer_hits_pending_limit() {
+    // verify that valid gossipped transactions are ignored (but not punished)
+    // when the peer has too many transactions pending verification
+    let mut universe = TestPoolUniverse::default().config(Config {
+        sender_limits: SenderLimits {
+            max_pending_txs_per_peer: 0,
+            ..Config::default().sender_limits
+        },
+        ..Default::default()
+    });
+    let tx1 = universe.build_script_transaction(None, None, 10);
+
+    // setup p2p mock - with tx incoming from p2p
+    let txs = vec![tx1.clone()];
+    let mut p2p = MockP2P::new_with_txs(txs);
+    let (send, mut receive) = broadcast::channel::<()>(1);
+    p2p.expect_notify_gossip_transaction_validity()
+        .returning(move |_, validity| {
+            // Expect the transaction to be ignored
+            assert_eq!(validity, GossipsubMessageAcceptance::Ignore);
+            // Notify test that the gossipsub acceptance was set
+            send.send(()).unwrap();
+            Ok(())
+        });
+
+    // build and start the txpool service
+    let service = universe.build_service(Some(p2p), None);
+    service.start_and_await().await.unwrap();
+    // verify p2p was notified about the transaction validity
+    let gossip_validity_notified =
+        tokio::time::timeout(Duration::from_millis(100), receive.recv()).await;
+    assert!(
+        gossip_validity_notified.is_ok(),
+        "expected to receive gossip validity notification"
+    );
+    service.stop_and_await().await.unwrap();
+}
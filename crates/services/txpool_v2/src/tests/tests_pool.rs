@@ -1,7 +1,10 @@
 use crate::{
     config::{
+        BlackListEntry,
         Config,
         PoolLimits,
+        SelectionPolicy,
+        SenderLimits,
     },
     error::{
         BlacklistedError,
@@ -175,6 +178,65 @@ fn insert__tx_with_blacklisted_message() {
     universe.assert_pool_integrity(&[]);
 }
 
+#[test]
+fn add_to_blacklist__evicts_blacklisted_txs_and_dependents() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let coin = universe.setup_coin().1;
+    let utxo_id = *coin.utxo_id().unwrap();
+    let (output, unset_input) = universe.create_output_and_input();
+    let tx1 = universe.build_script_transaction(Some(vec![coin]), Some(vec![output]), 0);
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&ChainId::default()), 0));
+    let tx2 = universe.build_script_transaction(Some(vec![input]), None, 0);
+    let tx3 = universe.build_script_transaction(None, None, 0);
+    let tx1 = universe.verify_and_insert(tx1).unwrap().0;
+    let tx2 = universe.verify_and_insert(tx2).unwrap().0;
+    let tx3 = universe.verify_and_insert(tx3).unwrap().0;
+
+    // When
+    let removed = universe
+        .get_pool()
+        .write()
+        .add_to_blacklist(BlackListEntry::Coin(utxo_id));
+
+    // Then
+    let mut removed: Vec<_> = removed.iter().map(|tx| tx.id()).collect();
+    removed.sort();
+    let mut expected = vec![tx1.id(), tx2.id()];
+    expected.sort();
+    assert_eq!(removed, expected);
+    universe.assert_pool_integrity(&[tx3]);
+}
+
+#[test]
+fn remove_from_blacklist__allows_insertion_again() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let coin = universe.setup_coin().1;
+    let owner = *coin.input_owner().unwrap();
+    let entry = BlackListEntry::Owner(owner);
+    let tx = universe.build_script_transaction(Some(vec![coin]), None, 0);
+    universe.get_pool().write().add_to_blacklist(entry);
+    let err = universe.verify_and_insert(tx.clone()).unwrap_err();
+    assert!(
+        matches!(err, Error::Blacklisted(BlacklistedError::BlacklistedOwner(id)) if id == owner)
+    );
+
+    // When
+    let removed = universe.get_pool().write().remove_from_blacklist(&entry);
+    let result = universe.verify_and_insert(tx);
+
+    // Then
+    assert!(removed);
+    assert!(universe.get_pool().read().blacklist().is_empty());
+    let tx = result.unwrap().0;
+    universe.assert_pool_integrity(&[tx]);
+}
+
 #[test]
 fn insert__tx2_succeeds_after_dependent_tx1() {
     let mut universe = TestPoolUniverse::default();
@@ -513,6 +575,102 @@ fn insert__tx_limit_hit() {
     universe.assert_pool_integrity(&[pool_tx]);
 }
 
+#[test]
+fn insert__tx_owner_tx_count_limit_hit() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        utxo_validation: false,
+        sender_limits: SenderLimits {
+            max_txs_per_owner: 1,
+            ..Config::default().sender_limits
+        },
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let predicate: Vec<u8> = vec![op::ret(RegId::ONE)].into_iter().collect();
+    let owner = Input::predicate_owner(&predicate);
+    let coin1 = universe
+        .custom_predicate(AssetId::BASE, TEST_COIN_AMOUNT, predicate.clone(), None)
+        .into_default_estimated();
+    let coin2 = universe
+        .custom_predicate(AssetId::BASE, TEST_COIN_AMOUNT, predicate, None)
+        .into_default_estimated();
+    let tx1 = universe.build_script_transaction(Some(vec![coin1]), None, 10);
+    let tx2 = universe.build_script_transaction(Some(vec![coin2]), None, 10);
+    let pool_tx = universe.verify_and_insert(tx1).unwrap().0;
+
+    // When
+    let result2 = universe.verify_and_insert(tx2);
+
+    // Then
+    let err = result2.unwrap_err();
+    assert!(matches!(
+        err,
+        Error::NotInsertedOwnerTxCountLimitHit { owner: err_owner, limit: 1 }
+            if err_owner == owner
+    ));
+    universe.assert_pool_integrity(&[pool_tx]);
+}
+
+#[test]
+fn insert__tx_owner_gas_limit_hit() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        sender_limits: SenderLimits {
+            max_gas_per_owner: 1,
+            ..Config::default().sender_limits
+        },
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let tx = universe.build_script_transaction(None, None, 10);
+
+    // When
+    let result = universe.verify_and_insert(tx);
+
+    // Then
+    let err = result.unwrap_err();
+    assert!(matches!(
+        err,
+        Error::NotInsertedOwnerGasLimitHit { limit: 1, .. }
+    ));
+    universe.assert_pool_integrity(&[]);
+}
+
+#[test]
+fn insert__tx_replacing_tx_of_owner_at_tx_count_limit() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        utxo_validation: false,
+        sender_limits: SenderLimits {
+            max_txs_per_owner: 1,
+            ..Config::default().sender_limits
+        },
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let predicate: Vec<u8> = vec![op::ret(RegId::ONE)].into_iter().collect();
+    let utxo_id = UtxoId::new([1; 32].into(), 0);
+    let coin = universe
+        .custom_predicate(AssetId::BASE, TEST_COIN_AMOUNT, predicate, Some(utxo_id))
+        .into_default_estimated();
+    let tx1 = universe.build_script_transaction(Some(vec![coin.clone()]), None, 10);
+    let tx2 = universe.build_script_transaction(Some(vec![coin]), None, 20);
+    let tx1_id = tx1.id(&ChainId::default());
+    universe.verify_and_insert(tx1).unwrap();
+
+    // When
+    let (pool_tx, removed_txs) = universe.verify_and_insert(tx2).unwrap();
+
+    // Then
+    assert_eq!(removed_txs.len(), 1);
+    assert_eq!(removed_txs[0].id(), tx1_id);
+    universe.assert_pool_integrity(&[pool_tx]);
+}
+
 #[test]
 fn insert__tx_gas_limit() {
     // Given
@@ -616,6 +774,43 @@ fn insert__dependency_chain_length_hit() {
     universe.assert_pool_integrity(&[tx1, tx2]);
 }
 
+#[test]
+fn dependencies_depth__counts_the_longest_chain_of_dependencies() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let (output, unset_input) = universe.create_output_and_input();
+    let tx1 = universe.build_script_transaction(None, Some(vec![output]), 0);
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&Default::default()), 0));
+
+    let (output, unset_input) = universe.create_output_and_input();
+    let tx2 = universe.build_script_transaction(Some(vec![input]), Some(vec![output]), 0);
+    let input = unset_input.into_input(UtxoId::new(tx2.id(&Default::default()), 0));
+
+    let tx3 = universe.build_script_transaction(Some(vec![input]), None, 0);
+    let tx1 = universe.verify_and_insert(tx1).unwrap().0;
+    let tx2 = universe.verify_and_insert(tx2).unwrap().0;
+    let tx3 = universe.verify_and_insert(tx3).unwrap().0;
+
+    // When
+    let pool = universe.get_pool();
+    let pool = pool.read();
+    let depths: Vec<_> = [&tx1, &tx2, &tx3]
+        .iter()
+        .map(|tx| pool.dependencies_depth(&tx.id()))
+        .collect();
+
+    // Then
+    assert_eq!(depths, vec![Some(0), Some(1), Some(2)]);
+    let stats = pool.stats();
+    assert_eq!(stats.tx_count, 3);
+    assert_eq!(
+        stats.total_gas,
+        tx1.max_gas() + tx2.max_gas() + tx3.max_gas()
+    );
+}
+
 #[test]
 fn get_sorted_out_tx1_2_3() {
     let mut universe = TestPoolUniverse::default();
@@ -810,6 +1005,162 @@ fn get_sorted_out_tx_by_creation_instant() {
     universe.assert_pool_integrity(&[]);
 }
 
+#[test]
+fn get_sorted_out_tx__fifo_policy_selects_oldest_first() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        selection_policy: SelectionPolicy::Fifo,
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let tx1 = universe.build_script_transaction(None, None, 1);
+    let tx2 = universe.build_script_transaction(None, None, 10);
+    let tx3 = universe.build_script_transaction(None, None, 100);
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2_id = tx2.id(&ChainId::default());
+    let tx3_id = tx3.id(&ChainId::default());
+
+    universe.verify_and_insert(tx1).unwrap();
+    universe.verify_and_insert(tx2).unwrap();
+    universe.verify_and_insert(tx3).unwrap();
+
+    // When
+    let txs = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block(Constraints {
+            minimal_gas_price: 0,
+            max_gas: u64::MAX,
+            maximum_txs: u16::MAX,
+            maximum_block_size: u32::MAX,
+        });
+
+    // Then
+    assert_eq!(txs.len(), 3, "Should have 3 txs");
+    assert_eq!(txs[0].id(), tx1_id, "First should be tx1");
+    assert_eq!(txs[1].id(), tx2_id, "Second should be tx2");
+    assert_eq!(txs[2].id(), tx3_id, "Third should be tx3");
+    universe.assert_pool_integrity(&[]);
+}
+
+#[test]
+fn get_sorted_out_tx__tip_per_byte_policy_prefers_smaller_txs() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        selection_policy: SelectionPolicy::TipPerByte,
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let gas_coin = universe.setup_coin().1;
+    let big_tx = TransactionBuilder::script(vec![], vec![0; 1000])
+        .tip(10)
+        .max_fee_limit(10)
+        .script_gas_limit(GAS_LIMIT / 10)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let (_, gas_coin) = universe.setup_coin();
+    let small_tx = TransactionBuilder::script(vec![], vec![])
+        .tip(10)
+        .max_fee_limit(10)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let big_tx_id = big_tx.id(&ChainId::default());
+    let small_tx_id = small_tx.id(&ChainId::default());
+
+    universe.verify_and_insert(big_tx).unwrap();
+    universe.verify_and_insert(small_tx).unwrap();
+
+    // When
+    let txs = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block(Constraints {
+            minimal_gas_price: 0,
+            max_gas: u64::MAX,
+            maximum_txs: u16::MAX,
+            maximum_block_size: u32::MAX,
+        });
+
+    // Then
+    assert_eq!(txs.len(), 2, "Should have 2 txs");
+    assert_eq!(txs[0].id(), small_tx_id, "First should be the small tx");
+    assert_eq!(txs[1].id(), big_tx_id, "Second should be the big tx");
+    universe.assert_pool_integrity(&[]);
+}
+
+#[test]
+fn get_sorted_out_tx__fairness_policy_limits_txs_per_owner() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        utxo_validation: false,
+        selection_policy: SelectionPolicy::Fairness {
+            max_txs_per_owner: 2,
+        },
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let predicate: Vec<u8> = vec![op::ret(RegId::ONE)].into_iter().collect();
+    let mut other_predicate = predicate.clone();
+    other_predicate.push(1);
+
+    let mut owner_txs = vec![];
+    for tip in [30, 20, 10] {
+        let coin = universe
+            .custom_predicate(AssetId::BASE, TEST_COIN_AMOUNT, predicate.clone(), None)
+            .into_default_estimated();
+        owner_txs.push(universe.build_script_transaction(Some(vec![coin]), None, tip));
+    }
+    let coin = universe
+        .custom_predicate(AssetId::BASE, TEST_COIN_AMOUNT, other_predicate, None)
+        .into_default_estimated();
+    let other_tx = universe.build_script_transaction(Some(vec![coin]), None, 1);
+
+    let owner_tx_ids: Vec<_> = owner_txs
+        .iter()
+        .map(|tx| tx.id(&ChainId::default()))
+        .collect();
+    let other_tx_id = other_tx.id(&ChainId::default());
+
+    for tx in owner_txs {
+        universe.verify_and_insert(tx).unwrap();
+    }
+    universe.verify_and_insert(other_tx).unwrap();
+
+    let constraints = || Constraints {
+        minimal_gas_price: 0,
+        max_gas: u64::MAX,
+        maximum_txs: u16::MAX,
+        maximum_block_size: u32::MAX,
+    };
+
+    // When
+    let first_block = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block(constraints());
+    let second_block = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block(constraints());
+
+    // Then
+    let first_block: Vec<_> = first_block.iter().map(|tx| tx.id()).collect();
+    let second_block: Vec<_> = second_block.iter().map(|tx| tx.id()).collect();
+    assert_eq!(
+        first_block,
+        vec![owner_tx_ids[0], owner_tx_ids[1], other_tx_id],
+        "The first block should have only 2 txs of the same owner"
+    );
+    assert_eq!(second_block, vec![owner_tx_ids[2]]);
+    universe.assert_pool_integrity(&[]);
+}
+
 #[test]
 fn insert_tx_at_least_min_gas_price() {
     // Given
@@ -1,14 +1,19 @@
 use fuel_core_services::Service as ServiceTrait;
+use fuel_core_storage::iter::IterDirection;
 use fuel_core_types::{
     fuel_tx::UniqueIdentifier,
     fuel_types::ChainId,
-    services::txpool::TransactionStatus,
+    services::txpool::{
+        PoolTransaction,
+        TransactionStatus,
+    },
 };
 use std::time::Duration;
 use tokio_stream::StreamExt;
 
 use crate::{
     config::Config,
+    service::PendingTxsQuery,
     tests::universe::TestPoolUniverse,
     tx_status_stream::TxStatusMessage,
 };
@@ -66,6 +71,64 @@ async fn test_find() {
     service.stop_and_await().await.unwrap();
 }
 
+#[tokio::test]
+async fn test_pending_txs() {
+    let mut universe = TestPoolUniverse::default();
+
+    let tx1 = universe.build_script_transaction(None, None, 10);
+    let tx2 = universe.build_script_transaction(None, None, 20);
+    let tx3 = universe.build_script_transaction(None, None, 30);
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // Given
+    let mut ids = vec![
+        tx1.id(&Default::default()),
+        tx2.id(&Default::default()),
+        tx3.id(&Default::default()),
+    ];
+    service
+        .shared
+        .try_insert(vec![tx1.clone(), tx2.clone(), tx3.clone()])
+        .unwrap();
+    universe
+        .waiting_txs_insertion(
+            service.shared.new_tx_notification_subscribe(),
+            ids.clone(),
+        )
+        .await;
+    ids.sort();
+    let pending_ids = |start, direction, max_txs, min_tip| {
+        let query = PendingTxsQuery {
+            start,
+            direction,
+            max_txs,
+            filter: Box::new(move |tx: &PoolTransaction| tx.tip() >= min_tip),
+        };
+        let shared = service.shared.clone();
+        async move {
+            let txs = shared.pending_txs(query).await.unwrap();
+            txs.iter().map(|info| info.tx().id()).collect::<Vec<_>>()
+        }
+    };
+
+    // When
+    let first_page = pending_ids(None, IterDirection::Forward, 2, 0).await;
+    let next_page = pending_ids(Some(ids[1]), IterDirection::Forward, 2, 0).await;
+    let last_page = pending_ids(None, IterDirection::Reverse, 2, 0).await;
+    let filtered = pending_ids(None, IterDirection::Forward, 10, 20).await;
+
+    // Then
+    assert_eq!(first_page, vec![ids[0], ids[1]]);
+    assert_eq!(next_page, vec![ids[1], ids[2]]);
+    assert_eq!(last_page, vec![ids[2], ids[1]]);
+    let mut expected = vec![tx2.id(&Default::default()), tx3.id(&Default::default())];
+    expected.sort();
+    assert_eq!(filtered, expected);
+    service.stop_and_await().await.unwrap();
+}
+
 #[tokio::test]
 async fn test_prune_transactions() {
     const TIMEOUT: u64 = 3;
@@ -319,3 +382,72 @@ async fn simple_insert_removal_subscription() {
 
     service.stop_and_await().await.unwrap();
 }
+
+#[tokio::test]
+async fn journal__restores_transactions_after_restart() {
+    let journal_dir = tempfile::tempdir().unwrap();
+    let mut universe = TestPoolUniverse::default().config(Config {
+        journal_path: Some(journal_dir.path().join("txpool.journal")),
+        ..Default::default()
+    });
+
+    let tx1 = universe.build_script_transaction(None, None, 10);
+    let tx2 = universe.build_script_transaction(None, None, 20);
+    let ids = vec![tx1.id(&Default::default()), tx2.id(&Default::default())];
+
+    // Given
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    service.shared.try_insert(vec![tx1, tx2]).unwrap();
+    universe
+        .waiting_txs_insertion(
+            service.shared.new_tx_notification_subscribe(),
+            ids.clone(),
+        )
+        .await;
+    service.stop_and_await().await.unwrap();
+
+    // When
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // Then
+    let out = service.shared.find(ids).await.unwrap();
+    assert!(out[0].is_some(), "Tx1 should be restored:{out:?}");
+    assert!(out[1].is_some(), "Tx2 should be restored:{out:?}");
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn journal__drops_expired_transactions_after_restart() {
+    let journal_dir = tempfile::tempdir().unwrap();
+    let mut universe = TestPoolUniverse::default().config(Config {
+        journal_path: Some(journal_dir.path().join("txpool.journal")),
+        ..Default::default()
+    });
+
+    let tx = universe.build_script_transaction(None, None, 10);
+    let ids = vec![tx.id(&Default::default())];
+
+    // Given
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    service.shared.try_insert(vec![tx]).unwrap();
+    universe
+        .waiting_txs_insertion(
+            service.shared.new_tx_notification_subscribe(),
+            ids.clone(),
+        )
+        .await;
+    service.stop_and_await().await.unwrap();
+
+    // When
+    universe.config.max_txs_ttl = Duration::ZERO;
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // Then
+    let out = service.shared.find(ids).await.unwrap();
+    assert!(out[0].is_none(), "Tx should be dropped:{out:?}");
+    service.stop_and_await().await.unwrap();
+}
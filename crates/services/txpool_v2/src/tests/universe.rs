@@ -62,7 +62,7 @@ use crate::{
     error::Error,
     new_service,
     pool::Pool,
-    selection_algorithms::ratio_tip_gas::RatioTipGasSelection,
+    selection_algorithms::ConfiguredSelection,
     service::{
         memory::MemoryPool,
         verifications::Verification,
@@ -134,7 +134,7 @@ impl TestPoolUniverse {
                 max_txs_chain_count: self.config.max_txs_chain_count,
             }),
             BasicCollisionManager::new(),
-            RatioTipGasSelection::new(),
+            ConfiguredSelection::new(&self.config.selection_policy),
             self.config.clone(),
         )));
         self.pool = Some(pool.clone());
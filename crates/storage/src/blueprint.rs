@@ -16,6 +16,7 @@ use crate::{
     },
     Mappable,
     Result as StorageResult,
+    SparseMerkleProof,
 };
 use fuel_vm_private::prelude::MerkleRoot;
 
@@ -158,3 +159,14 @@ where
     /// Returns the root of the Merkle tree.
     fn root(storage: &S, key: &Key) -> StorageResult<MerkleRoot>;
 }
+
+/// It is an extension of the blueprint that supports generation of the sparse Merkle proofs
+/// for the entries of the table.
+pub trait SupportsSparseMerkleProof<M, S>: BlueprintInspect<M, S>
+where
+    M: Mappable,
+    S: KeyValueInspect,
+{
+    /// Returns the proof of inclusion or exclusion of the `key` in the sparse Merkle tree.
+    fn proof(storage: &S, key: &M::Key) -> StorageResult<SparseMerkleProof>;
+}
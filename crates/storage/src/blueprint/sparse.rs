@@ -9,6 +9,7 @@ use crate::{
         BlueprintMutate,
         SupportsBatching,
         SupportsMerkle,
+        SupportsSparseMerkleProof,
     },
     codec::{
         Decode,
@@ -28,6 +29,7 @@ use crate::{
     Mappable,
     MerkleRoot,
     Result as StorageResult,
+    SparseMerkleProof,
     StorageAsMut,
     StorageInspect,
     StorageMutate,
@@ -273,6 +275,43 @@ where
     }
 }
 
+impl<M, S, KeyCodec, ValueCodec, Metadata, Nodes, KeyConverter>
+    SupportsSparseMerkleProof<M, S>
+    for Sparse<KeyCodec, ValueCodec, Metadata, Nodes, KeyConverter>
+where
+    M: Mappable,
+    S: KeyValueInspect,
+    Metadata: Mappable<Value = SparseMerkleMetadata, OwnedValue = SparseMerkleMetadata>,
+    Nodes: Mappable<
+        Key = MerkleRoot,
+        Value = sparse::Primitive,
+        OwnedValue = sparse::Primitive,
+    >,
+    KeyConverter: PrimaryKey<InputKey = M::Key, OutputKey = Metadata::Key>,
+    Self: BlueprintInspect<M, S>,
+    KeyCodec: Encode<M::Key>,
+    S: StorageInspect<Metadata, Error = StorageError>
+        + StorageInspect<Nodes, Error = StorageError>,
+{
+    fn proof(storage: &S, key: &M::Key) -> StorageResult<SparseMerkleProof> {
+        use crate::StorageAsRef;
+        let primary_key = KeyConverter::primary_key(key);
+        let metadata: Option<Cow<SparseMerkleMetadata>> =
+            storage.storage_as_ref::<Metadata>().get(primary_key)?;
+        let root = metadata
+            .map(|metadata| *metadata.root())
+            .unwrap_or_else(|| in_memory::MerkleTree::new().root());
+
+        let tree: MerkleTree<Nodes, _> = MerkleTree::load(storage, &root)
+            .map_err(|err| StorageError::Other(anyhow::anyhow!("{err:?}")))?;
+
+        let key_encoder = KeyCodec::encode(key);
+        let key_bytes = key_encoder.as_bytes();
+        tree.generate_proof(&MerkleTreeKey::new(key_bytes.as_ref()))
+            .map_err(|err| StorageError::Other(anyhow::anyhow!("{err:?}")))
+    }
+}
+
 type NodeKeyCodec<S, Nodes> =
     <<Nodes as TableWithBlueprint>::Blueprint as BlueprintInspect<Nodes, S>>::KeyCodec;
 type NodeValueCodec<S, Nodes> =
@@ -712,6 +751,86 @@ macro_rules! root_storage_tests {
 
                 assert!(metadata.is_none());
             }
+
+            #[test]
+            fn proof_of_inserted_key_is_inclusion_proof_for_the_latest_root() {
+                use $crate::SparseMerkleProofStorage;
+
+                let mut storage = InMemoryStorage::default();
+                let mut storage_transaction = storage.write_transaction();
+
+                let rng = &mut StdRng::seed_from_u64(1234);
+
+                // Given
+                let key = $generate_key(&$current_key, rng);
+                let state = $generate_value(rng);
+                storage_transaction
+                    .storage_as_mut::<$table>()
+                    .insert(&key, &state)
+                    .unwrap();
+                let other_key = $generate_key(&$current_key, rng);
+                let other_state = $generate_value(rng);
+                storage_transaction
+                    .storage_as_mut::<$table>()
+                    .insert(&other_key, &other_state)
+                    .unwrap();
+                let root = storage_transaction
+                    .storage_as_mut::<$table>()
+                    .root(&$current_key)
+                    .unwrap();
+
+                // When
+                let proof = SparseMerkleProofStorage::<$table>::proof(&storage_transaction, &key)
+                    .unwrap();
+
+                // Then
+                match proof {
+                    $crate::SparseMerkleProof::Inclusion(proof) => {
+                        assert_eq!(proof.root, root);
+                    }
+                    $crate::SparseMerkleProof::Exclusion(_) => {
+                        panic!("Expected the inclusion proof for the inserted key")
+                    }
+                }
+            }
+
+            #[test]
+            fn proof_of_missing_key_is_exclusion_proof_for_the_latest_root() {
+                use $crate::SparseMerkleProofStorage;
+
+                let mut storage = InMemoryStorage::default();
+                let mut storage_transaction = storage.write_transaction();
+
+                let rng = &mut StdRng::seed_from_u64(1234);
+
+                // Given
+                let key = $generate_key(&$current_key, rng);
+                let state = $generate_value(rng);
+                storage_transaction
+                    .storage_as_mut::<$table>()
+                    .insert(&key, &state)
+                    .unwrap();
+                let root = storage_transaction
+                    .storage_as_mut::<$table>()
+                    .root(&$current_key)
+                    .unwrap();
+                let missing_key = $generate_key(&$current_key, rng);
+
+                // When
+                let proof =
+                    SparseMerkleProofStorage::<$table>::proof(&storage_transaction, &missing_key)
+                        .unwrap();
+
+                // Then
+                match proof {
+                    $crate::SparseMerkleProof::Exclusion(proof) => {
+                        assert_eq!(proof.root, root);
+                    }
+                    $crate::SparseMerkleProof::Inclusion(_) => {
+                        panic!("Expected the exclusion proof for the missing key")
+                    }
+                }
+            }
         }}
     };
 }
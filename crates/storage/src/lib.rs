@@ -46,6 +46,8 @@ pub mod transactional;
 pub mod vm_storage;
 
 use fuel_core_types::fuel_merkle::binary::MerkleTreeError;
+/// The proof of inclusion or exclusion of the entry in the sparse Merkle tree.
+pub use fuel_core_types::fuel_merkle::sparse::proof::Proof as SparseMerkleProof;
 pub use fuel_vm_private::storage::{
     ContractsAssetKey,
     ContractsStateData,
@@ -176,6 +178,13 @@ pub trait StorageBatchMutate<Type: Mappable>: StorageMutate<Type> {
         Type::Key: 'a;
 }
 
+/// The storage that can generate the sparse Merkle proofs for the entries of the table.
+pub trait SparseMerkleProofStorage<Type: Mappable>: StorageInspect<Type> {
+    /// Returns the proof of inclusion or exclusion of the `key` in the sparse Merkle tree
+    /// that contains the entry.
+    fn proof(&self, key: &Type::Key) -> Result<SparseMerkleProof>;
+}
+
 /// Creates `StorageError::NotFound` error with file and line information inside.
 ///
 /// # Examples
@@ -7,6 +7,7 @@ use crate::{
         BlueprintMutate,
         SupportsBatching,
         SupportsMerkle,
+        SupportsSparseMerkleProof,
     },
     codec::{
         raw::Raw,
@@ -37,6 +38,8 @@ use crate::{
     MerkleRoot,
     MerkleRootStorage,
     Result as StorageResult,
+    SparseMerkleProof,
+    SparseMerkleProofStorage,
     StorageBatchMutate,
     StorageInspect,
     StorageMutate,
@@ -349,6 +352,17 @@ where
     }
 }
 
+impl<Column, S, M> SparseMerkleProofStorage<M> for StructuredStorage<S>
+where
+    S: KeyValueInspect<Column = Column>,
+    M: TableWithBlueprint<Column = Column>,
+    M::Blueprint: SupportsSparseMerkleProof<M, StructuredStorage<S>>,
+{
+    fn proof(&self, key: &M::Key) -> StorageResult<SparseMerkleProof> {
+        <M as TableWithBlueprint>::Blueprint::proof(self, key)
+    }
+}
+
 impl<Column, S, M> StorageRead<M> for StructuredStorage<S>
 where
     S: KeyValueInspect<Column = Column>,
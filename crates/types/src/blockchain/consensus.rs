@@ -10,8 +10,10 @@ use crate::{
 };
 
 // Different types of consensus are represented as separate modules
+pub mod bft;
 pub mod poa;
 
+use bft::BftConsensus;
 use poa::PoAConsensus;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -24,6 +26,8 @@ pub enum Consensus {
     Genesis(Genesis),
     /// Proof of authority consensus
     PoA(PoAConsensus),
+    /// Byzantine fault tolerant consensus of multiple validators
+    Bft(BftConsensus),
 }
 
 impl Consensus {
@@ -39,6 +43,9 @@ impl Consensus {
                 let address = Input::owner(&public_key);
                 Ok(address)
             }
+            Consensus::Bft(_) => Err(anyhow::anyhow!(
+                "The BFT block is sealed by a quorum of validators"
+            )),
         }
     }
 }
@@ -0,0 +1,247 @@
+//! Byzantine fault tolerant consensus
+
+use crate::{
+    blockchain::{
+        block::Block,
+        primitives::BlockId,
+    },
+    fuel_crypto::{
+        Hasher,
+        Message,
+        SecretKey,
+        Signature,
+    },
+    fuel_tx::Input,
+    fuel_types::{
+        Address,
+        BlockHeight,
+        ChainId,
+    },
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The consensus related data that doesn't live on the
+/// header. It proves that a quorum of validators committed the block.
+pub struct BftConsensus {
+    /// The round in which the validators committed the block.
+    pub round: u32,
+    /// The signatures of the validators' precommits for the block in the `round`.
+    pub signatures: Vec<Signature>,
+}
+
+impl BftConsensus {
+    /// Create a new block consensus.
+    pub fn new(round: u32, signatures: Vec<Signature>) -> Self {
+        Self { round, signatures }
+    }
+
+    /// Returns the addresses of the validators that signed the precommits for the block.
+    /// Signatures that can't be recovered are skipped.
+    pub fn signers(
+        &self,
+        chain_id: &ChainId,
+        height: BlockHeight,
+        block_id: &BlockId,
+    ) -> Vec<Address> {
+        let message =
+            Vote::precommit(height, self.round, Some(*block_id)).message(chain_id);
+        self.signatures
+            .iter()
+            .filter_map(|signature| signature.recover(&message).ok())
+            .map(|public_key| Input::owner(&public_key))
+            .collect()
+    }
+}
+
+/// The step of the round in which the validator votes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VoteType {
+    /// The first round of voting for the proposed block.
+    Prevote,
+    /// The second round of voting, which commits the block.
+    Precommit,
+}
+
+/// The vote of the validator for the block at the height and round.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vote {
+    /// The type of the vote.
+    pub vote_type: VoteType,
+    /// The height of the block.
+    pub height: BlockHeight,
+    /// The round of the consensus at the `height`.
+    pub round: u32,
+    /// The id of the block. `None` is a vote for nil.
+    pub block_id: Option<BlockId>,
+}
+
+impl Vote {
+    /// Creates a prevote for the `block_id`.
+    pub fn prevote(height: BlockHeight, round: u32, block_id: Option<BlockId>) -> Self {
+        Self {
+            vote_type: VoteType::Prevote,
+            height,
+            round,
+            block_id,
+        }
+    }
+
+    /// Creates a precommit for the `block_id`.
+    pub fn precommit(height: BlockHeight, round: u32, block_id: Option<BlockId>) -> Self {
+        Self {
+            vote_type: VoteType::Precommit,
+            height,
+            round,
+            block_id,
+        }
+    }
+
+    /// The message signed by the validator. It includes the `chain_id`,
+    /// so the vote is not valid on other chains with the same validators.
+    pub fn message(&self, chain_id: &ChainId) -> Message {
+        let vote_type: u8 = match self.vote_type {
+            VoteType::Prevote => 0,
+            VoteType::Precommit => 1,
+        };
+        let mut hasher = Hasher::default()
+            .chain(b"vote")
+            .chain(chain_id.to_be_bytes())
+            .chain([vote_type])
+            .chain(self.height.to_bytes())
+            .chain(self.round.to_be_bytes());
+        if let Some(block_id) = &self.block_id {
+            hasher = hasher.chain(block_id.as_slice());
+        }
+        Message::from_bytes(*hasher.finalize())
+    }
+
+    /// Signs the vote for the `chain_id` with the `secret_key` of the validator.
+    pub fn sign(self, chain_id: &ChainId, secret_key: &SecretKey) -> SignedVote {
+        let signature = Signature::sign(secret_key, &self.message(chain_id));
+        SignedVote {
+            vote: self,
+            signature,
+        }
+    }
+}
+
+/// The vote with the signature of the validator.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignedVote {
+    /// The vote.
+    pub vote: Vote,
+    /// The signature of the `Vote::message`.
+    pub signature: Signature,
+}
+
+impl SignedVote {
+    /// Recovers the address of the validator that signed the vote for the `chain_id`.
+    pub fn signer(&self, chain_id: &ChainId) -> Option<Address> {
+        let public_key = self.signature.recover(&self.vote.message(chain_id)).ok()?;
+        Some(Input::owner(&public_key))
+    }
+}
+
+/// The block proposed by the proposer of the round.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Proposal {
+    /// The height of the block.
+    pub height: BlockHeight,
+    /// The round of the consensus at the `height`.
+    pub round: u32,
+    /// The round in which the quorum of validators prevoted for the block, if any.
+    pub valid_round: Option<u32>,
+    /// The proposed block.
+    pub block: Block,
+}
+
+impl Proposal {
+    /// The message signed by the proposer. It includes the `chain_id`,
+    /// so the proposal is not valid on other chains with the same validators.
+    pub fn message(&self, chain_id: &ChainId) -> Message {
+        let mut hasher = Hasher::default()
+            .chain(b"proposal")
+            .chain(chain_id.to_be_bytes())
+            .chain(self.height.to_bytes())
+            .chain(self.round.to_be_bytes());
+        if let Some(valid_round) = self.valid_round {
+            hasher = hasher.chain(valid_round.to_be_bytes());
+        }
+        let hasher = hasher.chain(self.block.id().as_slice());
+        Message::from_bytes(*hasher.finalize())
+    }
+
+    /// Signs the proposal for the `chain_id` with the `secret_key` of the proposer.
+    pub fn sign(self, chain_id: &ChainId, secret_key: &SecretKey) -> SignedProposal {
+        let signature = Signature::sign(secret_key, &self.message(chain_id));
+        SignedProposal {
+            proposal: self,
+            signature,
+        }
+    }
+}
+
+/// The proposal with the signature of the proposer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignedProposal {
+    /// The proposal.
+    pub proposal: Proposal,
+    /// The signature of the `Proposal::message`.
+    pub signature: Signature,
+}
+
+impl SignedProposal {
+    /// Recovers the address of the validator that signed the proposal for the `chain_id`.
+    pub fn signer(&self, chain_id: &ChainId) -> Option<Address> {
+        let public_key = self
+            .signature
+            .recover(&self.proposal.message(chain_id))
+            .ok()?;
+        Some(Input::owner(&public_key))
+    }
+}
+
+/// The message exchanged by validators during the consensus.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConsensusMessage {
+    /// The block proposed for the round.
+    Proposal(SignedProposal),
+    /// The vote for the block or nil.
+    Vote(SignedVote),
+}
+
+impl ConsensusMessage {
+    /// The height of the consensus to which the message belongs.
+    pub fn height(&self) -> BlockHeight {
+        match self {
+            ConsensusMessage::Proposal(proposal) => proposal.proposal.height,
+            ConsensusMessage::Vote(vote) => vote.vote.height,
+        }
+    }
+
+    /// The round of the consensus to which the message belongs.
+    pub fn round(&self) -> u32 {
+        match self {
+            ConsensusMessage::Proposal(proposal) => proposal.proposal.round,
+            ConsensusMessage::Vote(vote) => vote.vote.round,
+        }
+    }
+
+    /// Recovers the address of the validator that signed the message for the `chain_id`.
+    pub fn signer(&self, chain_id: &ChainId) -> Option<Address> {
+        match self {
+            ConsensusMessage::Proposal(proposal) => proposal.signer(chain_id),
+            ConsensusMessage::Vote(vote) => vote.signer(chain_id),
+        }
+    }
+}
@@ -7,6 +7,10 @@ use serde::{
 };
 
 use crate::{
+    blockchain::{
+        consensus::bft::ConsensusMessage,
+        SealedBlock,
+    },
     fuel_tx::Transaction,
     fuel_types::BlockHeight,
 };
@@ -72,6 +76,12 @@ pub struct GossipData<T> {
 /// Transactions gossiped by peers for inclusion into a block
 pub type TransactionGossipData = GossipData<Transaction>;
 
+/// Consensus messages gossiped by validators
+pub type ConsensusMessageGossipData = GossipData<ConsensusMessage>;
+
+/// Sealed blocks gossiped by peers right after the block production
+pub type BlockGossipData = GossipData<SealedBlock>;
+
 #[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 /// The source of some network data.
 pub struct SourcePeer<T> {
@@ -0,0 +1,179 @@
+#![allow(non_snake_case)]
+
+use fuel_core::{
+    fuel_core_graphql_api::Role,
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::fuel_tx::Transaction;
+
+fn jwt(secret: &str, role: &str) -> String {
+    let claims = serde_json::json!({
+        "role": role,
+        "exp": u64::MAX / 2,
+    });
+    jsonwebtoken::encode(
+        &jsonwebtoken::Header::default(),
+        &claims,
+        &jsonwebtoken::EncodingKey::from_secret(secret.as_bytes()),
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn submit__requires_submit_role() {
+    let mut config = Config::local_node();
+    config.graphql_config.anonymous_role = Role::Read;
+    config
+        .graphql_config
+        .api_keys
+        .insert("submitter", Role::Submit);
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    // Given
+    let anonymous = FuelClient::from(srv.bound_address);
+    let submitter = FuelClient::from(srv.bound_address).with_bearer_token("submitter");
+
+    // When
+    let anonymous_result = anonymous.submit(&Transaction::default_test_tx()).await;
+    let submitter_result = submitter.submit(&Transaction::default_test_tx()).await;
+
+    // Then
+    let err = anonymous_result.expect_err("anonymous can't submit");
+    assert!(err.to_string().contains("requires the `submit` role"));
+    submitter_result.expect("submitter can submit");
+    assert!(anonymous.chain_info().await.is_ok());
+}
+
+#[tokio::test]
+async fn produce_blocks__requires_admin_role() {
+    let mut config = Config::local_node();
+    config.graphql_config.anonymous_role = Role::Read;
+    config.graphql_config.jwt_secret = Some("secret".to_string());
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    // Given
+    let submitter =
+        FuelClient::from(srv.bound_address).with_bearer_token(jwt("secret", "submit"));
+    let admin =
+        FuelClient::from(srv.bound_address).with_bearer_token(jwt("secret", "admin"));
+    let forged =
+        FuelClient::from(srv.bound_address).with_bearer_token(jwt("wrong", "admin"));
+
+    // When
+    let submitter_result = submitter.produce_blocks(1, None).await;
+    let admin_result = admin.produce_blocks(1, None).await;
+    let forged_result = forged.produce_blocks(1, None).await;
+
+    // Then
+    assert!(submitter_result.is_err());
+    assert_eq!(admin_result.unwrap(), 1u32.into());
+    let err = forged_result.expect_err("forged token is rejected");
+    assert!(err.to_string().contains("Invalid bearer token"));
+}
+
+#[tokio::test]
+async fn admin_addr__serves_admin_operations_separately() {
+    let mut config = Config::local_node();
+    config.graphql_config.admin_addr = Some("127.0.0.1:0".parse().unwrap());
+    let srv = FuelService::new_node(config).await.unwrap();
+    let admin_address = srv
+        .shared
+        .graph_ql
+        .admin_bound_address
+        .expect("admin listener is started");
+
+    // Given
+    let public = FuelClient::from(srv.bound_address);
+    let admin = FuelClient::from(admin_address);
+
+    // When
+    let public_result = public.produce_blocks(1, None).await;
+    let admin_result = admin.produce_blocks(1, None).await;
+
+    // Then
+    let err = public_result.expect_err("public listener doesn't serve admin operations");
+    assert!(err.to_string().contains("admin listener"));
+    assert_eq!(admin_result.unwrap(), 1u32.into());
+
+    let public_metrics = reqwest::get(format!("http://{}/v1/metrics", srv.bound_address))
+        .await
+        .unwrap();
+    let admin_metrics = reqwest::get(format!("http://{admin_address}/v1/metrics"))
+        .await
+        .unwrap();
+    assert_eq!(public_metrics.status(), reqwest::StatusCode::NOT_FOUND);
+    assert!(admin_metrics.status().is_success());
+}
+
+#[tokio::test]
+async fn metrics__requires_admin_role() {
+    let mut config = Config::local_node();
+    config.graphql_config.anonymous_role = Role::Read;
+    config.graphql_config.api_keys.insert("admin", Role::Admin);
+    config.graphql_config.api_keys.insert("submitter", Role::Submit);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let url = format!("http://{}/v1/metrics", srv.bound_address);
+
+    // Given
+    let client = reqwest::Client::new();
+
+    // When
+    let anonymous = client.get(&url).send().await.unwrap();
+    let submitter = client
+        .get(&url)
+        .bearer_auth("submitter")
+        .send()
+        .await
+        .unwrap();
+    let admin = client
+        .get(&url)
+        .bearer_auth("admin")
+        .send()
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(anonymous.status(), reqwest::StatusCode::UNAUTHORIZED);
+    assert_eq!(submitter.status(), reqwest::StatusCode::UNAUTHORIZED);
+    assert!(admin.status().is_success());
+}
+
+#[tokio::test]
+async fn cors__allows_only_configured_origins() {
+    let mut config = Config::local_node();
+    config.graphql_config.cors_allowed_origins = vec!["https://app.fuel.network".into()];
+    let srv = FuelService::new_node(config).await.unwrap();
+    let url = format!("http://{}/v1/health", srv.bound_address);
+    let client = reqwest::Client::new();
+
+    // When
+    let allowed = client
+        .get(&url)
+        .header("Origin", "https://app.fuel.network")
+        .send()
+        .await
+        .unwrap();
+    let denied = client
+        .get(&url)
+        .header("Origin", "https://evil.example")
+        .send()
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(
+        allowed
+            .headers()
+            .get("access-control-allow-origin")
+            .unwrap(),
+        "https://app.fuel.network"
+    );
+    assert!(denied
+        .headers()
+        .get("access-control-allow-origin")
+        .is_none());
+}
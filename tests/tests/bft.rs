@@ -0,0 +1,128 @@
+use fuel_core::p2p_test_helpers::*;
+use fuel_core_storage::transactional::AtomicView;
+use fuel_core_types::{
+    blockchain::consensus::{
+        bft::{
+            ConsensusMessage,
+            Vote,
+        },
+        Consensus,
+    },
+    fuel_crypto::SecretKey,
+};
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+use std::{
+    sync::Arc,
+    time::Duration,
+};
+
+async fn wait_for_blocks(validators: &[Node], number_of_blocks: usize) {
+    for validator in validators {
+        tokio::time::timeout(
+            Duration::from_secs(30),
+            validator.wait_for_blocks(number_of_blocks, false),
+        )
+        .await
+        .expect("Validators should agree on blocks in time");
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bft_validators_commit_blocks_sealed_by_quorum() {
+    let mut rng = StdRng::seed_from_u64(line!() as u64);
+
+    // Create a network of four validators, it tolerates one faulty validator.
+    let secrets: Vec<_> = (0..4).map(|_| SecretKey::random(&mut rng)).collect();
+    let Nodes {
+        validators,
+        bootstrap_nodes: _dont_drop,
+        ..
+    } = make_bft_nodes(secrets, None).await;
+
+    // Wait up to 30 seconds for each validator to import two blocks.
+    wait_for_blocks(&validators, 2).await;
+
+    // Every validator has the same first block sealed by the quorum.
+    let sealed_blocks: Vec<_> = validators
+        .iter()
+        .map(|validator| {
+            validator
+                .node
+                .shared
+                .database
+                .on_chain()
+                .latest_view()
+                .unwrap()
+                .get_sealed_block_by_height(&1.into())
+                .unwrap()
+                .expect("The first block should be committed")
+        })
+        .collect();
+    let expected_id = sealed_blocks[0].entity.id();
+    for sealed_block in sealed_blocks {
+        assert_eq!(sealed_block.entity.id(), expected_id);
+        let Consensus::Bft(consensus) = sealed_block.consensus else {
+            panic!("Not expected consensus");
+        };
+        assert!(consensus.signatures.len() >= 3);
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bft_quorum_commits_blocks_when_faulty_validator_is_offline() {
+    let mut rng = StdRng::seed_from_u64(line!() as u64);
+
+    // Four validators tolerate one faulty validator.
+    let secrets: Vec<_> = (0..4).map(|_| SecretKey::random(&mut rng)).collect();
+    let Nodes {
+        mut validators,
+        bootstrap_nodes: _dont_drop,
+        ..
+    } = make_bft_nodes(secrets, None).await;
+
+    // The faulty validator goes offline.
+    let mut offline = validators.pop().unwrap();
+    offline.shutdown().await;
+
+    // The quorum of the remaining validators keeps committing blocks.
+    wait_for_blocks(&validators, 3).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bft_quorum_commits_blocks_when_faulty_validator_floods_future_heights() {
+    let mut rng = StdRng::seed_from_u64(line!() as u64);
+
+    // Four validators tolerate one faulty validator.
+    let secrets: Vec<_> = (0..4).map(|_| SecretKey::random(&mut rng)).collect();
+    let faulty_secret = secrets[3];
+    let Nodes {
+        validators,
+        bootstrap_nodes: _dont_drop,
+        ..
+    } = make_bft_nodes(secrets, None).await;
+
+    // The faulty validator gossips the votes for many rounds of the future heights.
+    let faulty = &validators[3];
+    let chain_id = faulty
+        .config
+        .snapshot_reader
+        .chain_config()
+        .consensus_parameters
+        .chain_id();
+    let network = faulty.node.shared.network.as_ref().unwrap();
+    for height in 2..=11u32 {
+        for round in 0..100 {
+            let vote =
+                Vote::prevote(height.into(), round, None).sign(&chain_id, &faulty_secret);
+            network
+                .broadcast_consensus_message(Arc::new(ConsensusMessage::Vote(vote)))
+                .expect("The vote should be queued");
+        }
+    }
+
+    // The validators ignore the flood and keep committing blocks.
+    wait_for_blocks(&validators, 3).await;
+}
@@ -39,13 +39,19 @@ use fuel_core_types::{
     secrecy::ExposeSecret,
     tai64::Tai64,
 };
+use futures::StreamExt;
 use itertools::{
     rev,
     Itertools,
 };
 use rstest::rstest;
 use std::{
+    net::SocketAddr,
     ops::Deref,
+    sync::{
+        Arc,
+        Mutex,
+    },
     time::Duration,
 };
 use test_helpers::send_graph_ql_query;
@@ -176,6 +182,169 @@ async fn produce_block_manually() {
     }
 }
 
+#[tokio::test]
+async fn subscribe_blocks__returns_past_blocks_and_then_new_blocks() {
+    // Given
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(3, None).await.unwrap();
+
+    // When
+    let stream = client.subscribe_blocks(Some(1.into())).await.unwrap();
+    let mut stream = Box::pin(stream);
+    let mut heights = vec![];
+    for _ in 0..3 {
+        let block = stream.next().await.unwrap().unwrap();
+        heights.push(block.block.header.height);
+    }
+    client.produce_blocks(2, None).await.unwrap();
+    for _ in 0..2 {
+        let block = tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        heights.push(block.block.header.height);
+    }
+
+    // Then
+    assert_eq!(heights, vec![1, 2, 3, 4, 5]);
+}
+
+#[tokio::test]
+async fn subscribe_blocks__without_from_height_starts_from_the_next_block() {
+    // Given
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(2, None).await.unwrap();
+    let stream = client.subscribe_blocks(None).await.unwrap();
+    let mut stream = Box::pin(stream);
+
+    // When
+    client.produce_blocks(1, None).await.unwrap();
+
+    // Then
+    let block = tokio::time::timeout(Duration::from_secs(5), stream.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    assert_eq!(block.block.header.height, 3);
+}
+
+#[tokio::test]
+async fn subscribe_blocks__returns_transactions_with_statuses() {
+    // Given
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let tx = Transaction::default_test_tx();
+    let stream = client.subscribe_blocks(None).await.unwrap();
+    let mut stream = Box::pin(stream);
+
+    // When
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    // Then
+    let block = tokio::time::timeout(Duration::from_secs(5), stream.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    assert_eq!(block.block.header.height, 1);
+    assert_eq!(block.transactions.len(), 2 /* our tx + mint */);
+    assert_eq!(block.block.transactions.len(), block.transactions.len());
+    for transaction in block.transactions {
+        assert!(matches!(
+            transaction.status,
+            TransactionStatus::Success { block_height, .. } if block_height == 1.into()
+        ));
+    }
+}
+
+/// Forwards the TCP connections to the `target` and drops them on request
+/// to simulate the network failures between the client and the node.
+struct Proxy {
+    address: SocketAddr,
+    connections: Arc<Mutex<Vec<tokio::task::JoinHandle<()>>>>,
+}
+
+impl Proxy {
+    async fn spawn(target: SocketAddr) -> Self {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let connections: Arc<Mutex<Vec<_>>> = Default::default();
+        let accepted = connections.clone();
+        tokio::spawn(async move {
+            while let Ok((mut inbound, _)) = listener.accept().await {
+                let Ok(mut outbound) = tokio::net::TcpStream::connect(target).await
+                else {
+                    continue
+                };
+                let connection = tokio::spawn(async move {
+                    let _ =
+                        tokio::io::copy_bidirectional(&mut inbound, &mut outbound).await;
+                });
+                accepted.lock().unwrap().push(connection);
+            }
+        });
+
+        Self {
+            address,
+            connections,
+        }
+    }
+
+    fn drop_connections(&self) {
+        for connection in self.connections.lock().unwrap().drain(..) {
+            connection.abort();
+        }
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn subscribe_blocks__reconnects_without_skipping_heights_after_connection_drop() {
+    // Given
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let producer = FuelClient::from(srv.bound_address);
+    let proxy = Proxy::spawn(srv.bound_address).await;
+    let client = FuelClient::from(proxy.address);
+    let stream = client.subscribe_blocks(None).await.unwrap();
+    let mut stream = Box::pin(stream);
+    producer.produce_blocks(2, None).await.unwrap();
+    let mut heights = vec![];
+    for _ in 0..2 {
+        let block = tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        heights.push(block.block.header.height);
+    }
+
+    // When
+    proxy.drop_connections();
+    producer.produce_blocks(3, None).await.unwrap();
+
+    // Then
+    for _ in 0..3 {
+        let block = tokio::time::timeout(Duration::from_secs(10), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        heights.push(block.block.header.height);
+    }
+    assert_eq!(heights, vec![1, 2, 3, 4, 5]);
+}
+
 #[tokio::test]
 async fn produce_block_negative() {
     let db = Database::default();
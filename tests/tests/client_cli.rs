@@ -0,0 +1,102 @@
+#![allow(non_snake_case)]
+
+use clap::Parser;
+use fuel_core::{
+    chain_config::TESTNET_WALLET_SECRETS,
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_client_bin::cli::CliArgs;
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_tx::Contract,
+    fuel_types::{
+        Address,
+        Salt,
+    },
+};
+use tempfile::TempDir;
+
+/// Runs the client CLI against the `node` with the funded secret key.
+async fn run_cli(node: &FuelService, args: &[&str]) -> anyhow::Result<()> {
+    let endpoint = node.bound_address.to_string();
+    let args = ["fuel-core-client", "--endpoint", endpoint.as_str()]
+        .into_iter()
+        .chain(args.iter().copied())
+        .chain(["--secret-key", TESTNET_WALLET_SECRETS[0]]);
+    CliArgs::try_parse_from(args)?.exec().await
+}
+
+#[tokio::test]
+async fn transfer__recipient_receives_coins() {
+    let node = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(node.bound_address);
+
+    // Given
+    let recipient = Address::from([7; 32]);
+
+    // When
+    run_cli(&node, &["transaction", "transfer", &recipient.to_string(), "100"])
+        .await
+        .unwrap();
+
+    // Then
+    let balance = client.balance(&recipient, None).await.unwrap();
+    assert_eq!(balance, 100);
+}
+
+#[tokio::test]
+async fn deploy_contract__contract_can_be_called_by_script() {
+    let node = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(node.bound_address);
+    let dir = TempDir::new().unwrap();
+
+    // Given
+    let bytecode: Vec<u8> = op::ret(RegId::ONE).to_bytes().into_iter().collect();
+    let bytecode_path = dir.path().join("contract.bin");
+    std::fs::write(&bytecode_path, &bytecode).unwrap();
+    let salt = Salt::zeroed();
+    let contract = Contract::from(bytecode);
+    let contract_id = contract.id(
+        &salt,
+        &contract.root(),
+        &Contract::default_state_root(),
+    );
+    let script_path = dir.path().join("script.bin");
+    std::fs::write(&script_path, op::ret(RegId::ONE).to_bytes()).unwrap();
+
+    // When
+    run_cli(
+        &node,
+        &[
+            "transaction",
+            "deploy-contract",
+            bytecode_path.to_str().unwrap(),
+            "--salt",
+            &salt.to_string(),
+        ],
+    )
+    .await
+    .unwrap();
+    let call_result = run_cli(
+        &node,
+        &[
+            "transaction",
+            "call-script",
+            script_path.to_str().unwrap(),
+            "--contract",
+            &contract_id.to_string(),
+        ],
+    )
+    .await;
+
+    // Then
+    assert!(client.contract(&contract_id).await.unwrap().is_some());
+    call_result.expect("The script calling the contract is successful");
+}
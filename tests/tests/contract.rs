@@ -20,7 +20,15 @@ use fuel_core_client::client::{
     types::TransactionStatus,
     FuelClient,
 };
-use fuel_core_storage::tables::Coins;
+use fuel_core_storage::{
+    tables::{
+        Coins,
+        ContractsAssets,
+        ContractsState,
+    },
+    transactional::AtomicView,
+    StorageAsRef,
+};
 use fuel_core_types::{
     fuel_asm::*,
     fuel_tx::*,
@@ -222,6 +230,251 @@ async fn test_5_contract_balances(
     }
 }
 
+#[rstest]
+#[tokio::test]
+async fn contract_storage_slots__returns_all_slots_of_the_contract(
+    #[values(PageDirection::Forward, PageDirection::Backward)] direction: PageDirection,
+) {
+    use fuel_core::chain_config::ContractStateConfig;
+
+    // Given
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let (_, contract_id) = test_builder.setup_contract(vec![], vec![], None);
+    let (_, other_contract_id) = test_builder.setup_contract(vec![], vec![], None);
+    let states = (1..=3u8)
+        .map(|i| ContractStateConfig {
+            key: key(i),
+            value: vec![i; 32],
+        })
+        .collect::<Vec<_>>();
+    test_builder.contracts.get_mut(&contract_id).unwrap().states = states.clone();
+    // The slots of other contracts should not be returned.
+    test_builder
+        .contracts
+        .get_mut(&other_contract_id)
+        .unwrap()
+        .states = vec![ContractStateConfig {
+        key: key(4),
+        value: vec![4; 32],
+    }];
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    // When
+    let first_page = client
+        .contract_storage_slots(
+            &contract_id,
+            PaginationRequest {
+                cursor: None,
+                results: 2,
+                direction,
+            },
+        )
+        .await
+        .unwrap();
+    let second_page = client
+        .contract_storage_slots(
+            &contract_id,
+            PaginationRequest {
+                cursor: first_page.cursor.clone(),
+                results: 2,
+                direction,
+            },
+        )
+        .await
+        .unwrap();
+
+    // Then
+    let mut slots = first_page
+        .results
+        .into_iter()
+        .chain(second_page.results)
+        .map(|slot| (slot.key, slot.value))
+        .collect::<Vec<_>>();
+    if direction == PageDirection::Backward {
+        slots.reverse();
+    }
+    let expected = states
+        .into_iter()
+        .map(|state| (state.key, state.value))
+        .collect::<Vec<_>>();
+    assert_eq!(slots, expected);
+}
+
+#[tokio::test]
+async fn contract_storage_values__returns_values_of_existing_slots() {
+    use fuel_core::chain_config::ContractStateConfig;
+
+    // Given
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let (_, contract_id) = test_builder.setup_contract(vec![], vec![], None);
+    test_builder.contracts.get_mut(&contract_id).unwrap().states = (1..=3u8)
+        .map(|i| ContractStateConfig {
+            key: key(i),
+            value: vec![i; 32],
+        })
+        .collect();
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    // When
+    let slots = client
+        .contract_storage_values(&contract_id, &[key(3), key(5), key(1)])
+        .await
+        .unwrap();
+
+    // Then
+    let slots = slots
+        .into_iter()
+        .map(|slot| (slot.key, slot.value))
+        .collect::<Vec<_>>();
+    assert_eq!(slots, vec![(key(3), vec![3; 32]), (key(1), vec![1; 32])]);
+}
+
+#[tokio::test]
+async fn contract_state_proof__verifies_existing_and_missing_slots() {
+    use fuel_core::chain_config::ContractStateConfig;
+
+    // Given
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let (_, contract_id) = test_builder.setup_contract(vec![], vec![], None);
+    test_builder.contracts.get_mut(&contract_id).unwrap().states = (1..=3u8)
+        .map(|i| ContractStateConfig {
+            key: key(i),
+            value: vec![i; 32],
+        })
+        .collect();
+
+    let TestContext { client, srv, .. } = test_builder.finalize().await;
+    let state_root = contract_state_root(&srv, &contract_id);
+
+    // When
+    let existing = client
+        .contract_state_proof(&contract_id, &key(2))
+        .await
+        .unwrap();
+    let missing = client
+        .contract_state_proof(&contract_id, &key(5))
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(existing.value, Some(vec![2; 32]));
+    assert!(existing.verify(&state_root));
+    assert_eq!(missing.value, None);
+    assert!(missing.verify(&state_root));
+}
+
+#[tokio::test]
+async fn contract_state_proof__does_not_verify_against_other_root() {
+    use fuel_core::chain_config::ContractStateConfig;
+
+    // Given
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let (_, contract_id) = test_builder.setup_contract(vec![], vec![], None);
+    test_builder.contracts.get_mut(&contract_id).unwrap().states =
+        vec![ContractStateConfig {
+            key: key(1),
+            value: vec![1; 32],
+        }];
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+    let proof = client
+        .contract_state_proof(&contract_id, &key(1))
+        .await
+        .unwrap();
+
+    // When
+    let other_root = Bytes32::new([1; 32]);
+
+    // Then
+    assert!(proof.verify(&proof.proof.root));
+    assert!(!proof.verify(&other_root));
+}
+
+#[tokio::test]
+async fn contract_balance_proof__verifies_existing_and_missing_balances() {
+    use fuel_core::chain_config::ContractBalanceConfig;
+
+    // Given
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let balances = [
+        (AssetId::new([1u8; 32]), 1000),
+        (AssetId::new([2u8; 32]), 400),
+    ]
+    .map(|(asset_id, amount)| ContractBalanceConfig { asset_id, amount })
+    .to_vec();
+    let (_, contract_id) = test_builder.setup_contract(vec![], balances, None);
+
+    let TestContext { client, srv, .. } = test_builder.finalize().await;
+    let balance_root = contract_balance_root(&srv, &contract_id);
+
+    // When
+    let existing = client
+        .contract_balance_proof(&contract_id, &AssetId::new([2u8; 32]))
+        .await
+        .unwrap();
+    let missing = client
+        .contract_balance_proof(&contract_id, &AssetId::new([3u8; 32]))
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(existing.amount, Some(400));
+    assert!(existing.verify(&balance_root));
+    assert_eq!(missing.amount, None);
+    assert!(missing.verify(&balance_root));
+}
+
+#[tokio::test]
+async fn contract_balance_proof__does_not_verify_tampered_amount() {
+    use fuel_core::chain_config::ContractBalanceConfig;
+
+    // Given
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let asset_id = AssetId::new([1u8; 32]);
+    let balances = vec![ContractBalanceConfig {
+        asset_id,
+        amount: 1000,
+    }];
+    let (_, contract_id) = test_builder.setup_contract(vec![], balances, None);
+
+    let TestContext { client, srv, .. } = test_builder.finalize().await;
+    let balance_root = contract_balance_root(&srv, &contract_id);
+    let mut proof = client
+        .contract_balance_proof(&contract_id, &asset_id)
+        .await
+        .unwrap();
+
+    // When
+    proof.amount = Some(1001);
+
+    // Then
+    assert!(!proof.verify(&balance_root));
+}
+
+fn contract_state_root(srv: &FuelService, contract_id: &ContractId) -> Bytes32 {
+    let view = srv.shared.database.on_chain().latest_view().unwrap();
+    view.storage::<ContractsState>().root(contract_id).unwrap().into()
+}
+
+fn contract_balance_root(srv: &FuelService, contract_id: &ContractId) -> Bytes32 {
+    let view = srv.shared.database.on_chain().latest_view().unwrap();
+    view.storage::<ContractsAssets>().root(contract_id).unwrap().into()
+}
+
 fn key(i: u8) -> Bytes32 {
     Bytes32::new(
         [0u8; 31]
@@ -0,0 +1,106 @@
+#![allow(non_snake_case)]
+
+use fuel_core::{
+    combined_database::CombinedDatabase,
+    fuel_core_graphql_api::Role,
+    service::{
+        Config,
+        DbType,
+        FuelService,
+    },
+    state::historical_rocksdb::StateRewindPolicy,
+    ShutdownListener,
+};
+use fuel_core_client::client::FuelClient;
+use std::{
+    net::SocketAddr,
+    path::Path,
+};
+use tempfile::TempDir;
+
+async fn create_checkpoint(
+    address: SocketAddr,
+    token: Option<&str>,
+    path: &Path,
+) -> serde_json::Value {
+    let query = "mutation($path: String!) { \
+        createDatabaseCheckpoint(path: $path) { onChainHeight offChainHeight } \
+    }";
+    let mut request = reqwest::Client::new()
+        .post(format!("http://{address}/v1/graphql"))
+        .json(&serde_json::json!({
+            "query": query,
+            "variables": { "path": path },
+        }));
+    if let Some(token) = token {
+        request = request.bearer_auth(token);
+    }
+    request.send().await.unwrap().json().await.unwrap()
+}
+
+fn node_config(path: &Path) -> Config {
+    let mut config = Config::local_node();
+    config.combined_db_config.database_path = path.to_path_buf();
+    config.combined_db_config.database_type = DbType::RocksDb;
+    config.graphql_config.admin_api_token = Some("secret".to_string());
+    config.graphql_config.anonymous_role = Role::Read;
+    config
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn create_database_checkpoint__can_be_restored_as_new_node() {
+    let db_dir = TempDir::new().unwrap();
+    let checkpoint_dir = TempDir::new().unwrap();
+    let checkpoint_path = checkpoint_dir.path().join("checkpoint");
+    let restore_dir = TempDir::new().unwrap();
+    let node = FuelService::new_node(node_config(db_dir.path()))
+        .await
+        .unwrap();
+    let client = FuelClient::from(node.bound_address);
+    client.produce_blocks(3, None).await.unwrap();
+
+    // Given
+    let response =
+        create_checkpoint(node.bound_address, Some("secret"), &checkpoint_path).await;
+    let checkpoint = &response["data"]["createDatabaseCheckpoint"];
+    assert_eq!(checkpoint["onChainHeight"], "3", "{response}");
+    assert_eq!(checkpoint["offChainHeight"], "3", "{response}");
+    client.produce_blocks(2, None).await.unwrap();
+
+    // When
+    let manifest = CombinedDatabase::restore_checkpoint(
+        &checkpoint_path,
+        restore_dir.path(),
+        1024 * 1024,
+        StateRewindPolicy::RewindFullRange,
+        512,
+        &mut ShutdownListener::spawn(),
+    )
+    .unwrap();
+
+    // Then
+    assert_eq!(manifest.on_chain_height, Some(3u32.into()));
+    let restored = FuelService::new_node(node_config(restore_dir.path()))
+        .await
+        .unwrap();
+    let restored_client = FuelClient::from(restored.bound_address);
+    let chain = restored_client.chain_info().await.unwrap();
+    assert_eq!(chain.latest_block.header.height, 3);
+}
+
+#[tokio::test]
+async fn create_database_checkpoint__requires_admin_role() {
+    let db_dir = TempDir::new().unwrap();
+    let checkpoint_dir = TempDir::new().unwrap();
+    let checkpoint_path = checkpoint_dir.path().join("checkpoint");
+    let node = FuelService::new_node(node_config(db_dir.path()))
+        .await
+        .unwrap();
+
+    // When
+    let response = create_checkpoint(node.bound_address, None, &checkpoint_path).await;
+
+    // Then
+    assert!(response["errors"].is_array(), "{response}");
+    assert!(!checkpoint_path.exists());
+}
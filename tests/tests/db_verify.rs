@@ -0,0 +1,93 @@
+#![allow(non_snake_case)]
+
+use clap::Parser;
+use fuel_core::{
+    combined_database::CombinedDatabase,
+    database::{
+        database_description::off_chain::OffChain,
+        Database,
+    },
+    fuel_core_graphql_api::storage::balances::CoinBalances,
+    state::historical_rocksdb::StateRewindPolicy,
+};
+use fuel_core_storage::{
+    iter::IteratorOverTable,
+    transactional::ReadTransaction,
+    StorageAsMut,
+};
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+use test_helpers::{
+    fuel_core_driver::FuelCoreDriver,
+    produce_block_with_tx,
+};
+
+async fn spawn_node_with_transactions() -> tempfile::TempDir {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let driver = FuelCoreDriver::spawn_feeless(&["--debug", "--poa-instant", "true"])
+        .await
+        .unwrap();
+    for _ in 0..5 {
+        produce_block_with_tx(&mut rng, &driver.client).await;
+    }
+    driver.kill().await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn db_verify__reports_consistent_databases() {
+    let db_dir = spawn_node_with_transactions().await;
+
+    // When
+    let report = CombinedDatabase::verify(db_dir.path(), 512).unwrap();
+
+    // Then
+    assert!(report.is_consistent(), "{report:#?}");
+    assert_eq!(report.on_chain_height, report.off_chain_height);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn db_verify__repairs_corrupted_balances() {
+    let db_dir = spawn_node_with_transactions().await;
+
+    // Given
+    let off_chain = Database::<OffChain>::open_rocksdb(
+        db_dir.path(),
+        None,
+        StateRewindPolicy::RewindFullRange,
+        512,
+    )
+    .unwrap();
+    let (key, amount) = off_chain
+        .iter_all::<CoinBalances>(None)
+        .next()
+        .unwrap()
+        .unwrap();
+    let mut transaction = off_chain.read_transaction();
+    transaction
+        .storage_as_mut::<CoinBalances>()
+        .insert(&key, &amount.saturating_add(1))
+        .unwrap();
+    off_chain
+        .commit_changes_without_height(transaction.into_changes())
+        .unwrap();
+    drop(off_chain);
+    let report = CombinedDatabase::verify(db_dir.path(), 512).unwrap();
+    assert!(!report.is_consistent());
+
+    // When
+    let args = [
+        "_IGNORED_",
+        "verify",
+        "--db-path",
+        db_dir.path().to_str().unwrap(),
+        "--repair",
+    ];
+    let command = fuel_core_bin::cli::db::Command::parse_from(args);
+    fuel_core_bin::cli::db::exec(command).await.unwrap();
+
+    // Then
+    let report = CombinedDatabase::verify(db_dir.path(), 512).unwrap();
+    assert!(report.is_consistent(), "{report:#?}");
+}
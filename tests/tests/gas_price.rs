@@ -20,8 +20,15 @@ use fuel_core_client::client::{
     FuelClient,
 };
 use fuel_core_gas_price_service::{
-    common::fuel_core_storage_adapter::storage::GasPriceMetadata,
+    common::{
+        fuel_core_storage_adapter::storage::GasPriceMetadata,
+        updater_metadata::UpdaterMetadata,
+    },
     v0::metadata::V0Metadata,
+    v1::{
+        da_source_service::block_committer_costs::RawDaBlockCosts,
+        metadata::V1Metadata,
+    },
 };
 use fuel_core_poa::Trigger;
 use fuel_core_storage::{
@@ -43,13 +50,35 @@ use fuel_core_types::{
     },
     services::executor::TransactionExecutionResult,
 };
+use futures::StreamExt;
+use hyper::{
+    service::{
+        make_service_fn,
+        service_fn,
+    },
+    Body,
+    Request,
+    Response,
+    Server,
+    StatusCode,
+};
 use rand::Rng;
 use std::{
+    convert::Infallible,
     iter::repeat,
+    net::{
+        Ipv4Addr,
+        SocketAddr,
+    },
     ops::Deref,
+    sync::{
+        Arc,
+        Mutex,
+    },
     time::Duration,
 };
 use test_helpers::fuel_core_driver::FuelCoreDriver;
+use tokio::sync::oneshot;
 
 fn tx_for_gas_limit(max_fee_limit: Word) -> Transaction {
     TransactionBuilder::script(vec![], vec![])
@@ -416,3 +445,268 @@ async fn startup__can_override_gas_price_values_by_changing_config() {
     assert_eq!(l2_block_height, new_height);
     recovered_driver.kill().await;
 }
+
+/// Serves the cost bundles in the same way as the block committer API.
+struct FakeBlockCommitter {
+    bundles: Arc<Mutex<Vec<RawDaBlockCosts>>>,
+    address: SocketAddr,
+    _shutdown: oneshot::Sender<()>,
+}
+
+impl FakeBlockCommitter {
+    fn spawn() -> Self {
+        let bundles = Arc::new(Mutex::new(Vec::<RawDaBlockCosts>::new()));
+        let make_service = make_service_fn({
+            let bundles = bundles.clone();
+            move |_conn| {
+                let bundles = bundles.clone();
+                async move {
+                    Ok::<_, Infallible>(service_fn(move |req: Request<Body>| {
+                        let bundles = bundles.clone();
+                        async move {
+                            let bundles = bundles.lock().unwrap();
+                            let path = req.uri().path().trim_start_matches('/');
+                            let bundle = if path.is_empty() {
+                                bundles.last()
+                            } else {
+                                let bundle_id: u32 = path.parse().unwrap();
+                                bundles.iter().find(|b| b.bundle_id == bundle_id)
+                            };
+                            let response = match bundle {
+                                Some(bundle) => Response::new(Body::from(
+                                    serde_json::to_string(bundle).unwrap(),
+                                )),
+                                None => Response::builder()
+                                    .status(StatusCode::NOT_FOUND)
+                                    .body(Body::empty())
+                                    .unwrap(),
+                            };
+                            Ok::<_, Infallible>(response)
+                        }
+                    }))
+                }
+            }
+        });
+
+        let server =
+            Server::bind(&SocketAddr::from((Ipv4Addr::LOCALHOST, 0))).serve(make_service);
+        let address = server.local_addr();
+        let (shutdown, rx) = oneshot::channel();
+        tokio::spawn(server.with_graceful_shutdown(async {
+            rx.await.ok();
+        }));
+
+        Self {
+            bundles,
+            address,
+            _shutdown: shutdown,
+        }
+    }
+
+    fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    fn add_bundle(&self, bundle: RawDaBlockCosts) {
+        self.bundles.lock().unwrap().push(bundle);
+    }
+}
+
+fn gas_price_metadata(driver: &FuelCoreDriver, height: u32) -> UpdaterMetadata {
+    driver
+        .node
+        .shared
+        .database
+        .gas_price()
+        .latest_view()
+        .unwrap()
+        .storage::<GasPriceMetadata>()
+        .get(&height.into())
+        .unwrap()
+        .unwrap()
+        .deref()
+        .clone()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn produce_block__v1_algorithm_applies_costs_from_block_committer() {
+    // given
+    let committer = FakeBlockCommitter::spawn();
+    let url = committer.url();
+    let args = vec![
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--gas-price-algorithm",
+        "v1",
+        "--da-committer-url",
+        url.as_str(),
+        "--da-poll-interval",
+        "10ms",
+    ];
+    let driver = FuelCoreDriver::spawn(&args).await.unwrap();
+    driver.client.produce_blocks(2, None).await.unwrap();
+    committer.add_bundle(RawDaBlockCosts {
+        bundle_id: 1,
+        blocks_heights: vec![1, 2],
+        da_block_height: 100u64.into(),
+        total_cost: 1_000_000,
+        total_size_bytes: 1_000,
+    });
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    // when
+    driver.client.produce_blocks(1, None).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    // then
+    let metadata: V1Metadata = gas_price_metadata(&driver, 3).try_into().unwrap();
+    assert_eq!(metadata.latest_known_total_da_cost_excess, 1_000_000);
+    assert_eq!(metadata.latest_da_cost_per_byte, 1_000);
+    driver.kill().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn startup__v1_algorithm_migrates_v0_metadata() {
+    // given
+    let committer = FakeBlockCommitter::spawn();
+    let url = committer.url();
+    let args = vec!["--debug", "--poa-instant", "true"];
+    let driver = FuelCoreDriver::spawn(&args).await.unwrap();
+    driver.client.produce_blocks(2, None).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    let V0Metadata { new_exec_price, .. } =
+        gas_price_metadata(&driver, 2).try_into().unwrap();
+    let temp_dir = driver.kill().await;
+
+    // when
+    let new_args = vec![
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--gas-price-algorithm",
+        "v1",
+        "--da-committer-url",
+        url.as_str(),
+    ];
+    let recovered_driver = FuelCoreDriver::spawn_with_directory(temp_dir, &new_args)
+        .await
+        .unwrap();
+
+    // then
+    let metadata: V1Metadata =
+        gas_price_metadata(&recovered_driver, 2).try_into().unwrap();
+    assert_eq!(metadata.l2_block_height, 2);
+    assert_eq!(
+        metadata.new_scaled_exec_price,
+        new_exec_price * metadata.gas_price_factor.get()
+    );
+    recovered_driver
+        .client
+        .produce_blocks(1, None)
+        .await
+        .unwrap();
+    recovered_driver.kill().await;
+}
+
+#[tokio::test]
+async fn gas_price_history__returns_entries_for_the_requested_range() {
+    // given
+    let mut node_config = Config::local_node();
+    node_config.starting_gas_price = 1_000;
+    node_config.gas_price_change_percent = 10;
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(4, None).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    // when
+    let history = client.gas_price_history(2.into(), 3.into()).await.unwrap();
+
+    // then
+    let heights: Vec<u32> = history.iter().map(|e| *e.block_height).collect();
+    assert_eq!(heights, vec![2, 3]);
+    for entry in history {
+        assert_eq!(entry.gas_price, entry.exec_gas_price + entry.da_gas_price);
+        assert_eq!(entry.recorded_da_cost, 0);
+    }
+}
+
+#[tokio::test]
+async fn gas_price_history__rejects_inverted_range() {
+    // given
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // when
+    let result = client.gas_price_history(3.into(), 2.into()).await;
+
+    // then
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn subscribe_gas_prices__returns_entry_for_each_new_block() {
+    // given
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let stream = client.subscribe_gas_prices().await.unwrap();
+    let mut stream = Box::pin(stream);
+
+    // when
+    client.produce_blocks(2, None).await.unwrap();
+
+    // then
+    let mut heights = vec![];
+    for _ in 0..2 {
+        let entry = tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        heights.push(*entry.block_height);
+    }
+    assert_eq!(heights, vec![1, 2]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn gas_price_history__v1_algorithm_records_da_costs() {
+    // given
+    let committer = FakeBlockCommitter::spawn();
+    let url = committer.url();
+    let args = vec![
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--gas-price-algorithm",
+        "v1",
+        "--da-committer-url",
+        url.as_str(),
+        "--da-poll-interval",
+        "10ms",
+    ];
+    let driver = FuelCoreDriver::spawn(&args).await.unwrap();
+    driver.client.produce_blocks(2, None).await.unwrap();
+    committer.add_bundle(RawDaBlockCosts {
+        bundle_id: 1,
+        blocks_heights: vec![1, 2],
+        da_block_height: 100u64.into(),
+        total_cost: 1_000_000,
+        total_size_bytes: 1_000,
+    });
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    driver.client.produce_blocks(1, None).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    // when
+    let history = driver
+        .client
+        .gas_price_history(1.into(), 3.into())
+        .await
+        .unwrap();
+
+    // then
+    let recorded: Vec<u128> = history.iter().map(|e| e.recorded_da_cost).collect();
+    assert_eq!(recorded, vec![0, 0, 1_000_000]);
+    driver.kill().await;
+}
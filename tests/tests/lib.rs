@@ -1,6 +1,8 @@
 #![deny(unused_must_use)]
 #![deny(warnings)]
 
+#[cfg(not(feature = "only-p2p"))]
+mod api_auth;
 #[cfg(not(feature = "only-p2p"))]
 mod balances;
 #[cfg(not(feature = "only-p2p"))]
@@ -10,6 +12,8 @@ mod blocks;
 #[cfg(not(feature = "only-p2p"))]
 mod chain;
 #[cfg(not(feature = "only-p2p"))]
+mod client_cli;
+#[cfg(not(feature = "only-p2p"))]
 mod coin;
 #[cfg(not(feature = "only-p2p"))]
 mod coins;
@@ -20,6 +24,10 @@ mod da_compression;
 #[cfg(not(feature = "only-p2p"))]
 mod dap;
 #[cfg(not(feature = "only-p2p"))]
+mod database_checkpoint;
+#[cfg(not(feature = "only-p2p"))]
+mod db_verify;
+#[cfg(not(feature = "only-p2p"))]
 mod debugger;
 #[cfg(not(feature = "only-p2p"))]
 mod dos;
@@ -42,12 +50,18 @@ mod node_info;
 #[cfg(not(feature = "only-p2p"))]
 mod poa;
 #[cfg(not(feature = "only-p2p"))]
+mod read_replica;
+#[cfg(not(feature = "only-p2p"))]
 mod recovery;
 #[cfg(not(feature = "only-p2p"))]
 mod regenesis;
 #[cfg(not(feature = "only-p2p"))]
+mod reindex;
+#[cfg(not(feature = "only-p2p"))]
 mod relayer;
 #[cfg(not(feature = "only-p2p"))]
+mod remote_signer;
+#[cfg(not(feature = "only-p2p"))]
 mod snapshot;
 #[cfg(not(feature = "only-p2p"))]
 mod state_rewind;
@@ -57,7 +71,13 @@ mod trigger_integration;
 mod tx;
 #[cfg(not(feature = "only-p2p"))]
 mod vm_storage;
+#[cfg(not(feature = "only-p2p"))]
+mod websocket;
 
+#[cfg(feature = "only-p2p")]
+mod bft;
+#[cfg(feature = "only-p2p")]
+mod state_sync;
 #[cfg(feature = "only-p2p")]
 mod sync;
 #[cfg(feature = "only-p2p")]
@@ -287,6 +287,9 @@ mod p2p {
             ConsensusConfig::PoAV2(poa) => {
                 poa.set_genesis_signing_key(key);
             }
+            consensus @ ConsensusConfig::Bft { .. } => {
+                *consensus = ConsensusConfig::bft(vec![key]);
+            }
         }
         config.snapshot_reader = snapshot_reader.clone().with_chain_config(chain_config)
     }
@@ -0,0 +1,88 @@
+#![allow(non_snake_case)]
+
+use fuel_core::{
+    combined_database::ReadReplicaConfig,
+    service::{
+        Config,
+        DbType,
+        FuelService,
+    },
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::fuel_tx::Transaction;
+use std::time::Duration;
+use tempfile::TempDir;
+
+async fn wait_for_height(client: &FuelClient, height: u32) {
+    for _ in 0..50 {
+        let chain = client.chain_info().await.unwrap();
+        if chain.latest_block.header.height >= height {
+            return
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("The read replica didn't catch up with the height {height}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn read_replica__follows_primary_and_rejects_mutations() {
+    let primary_dir = TempDir::new().unwrap();
+    let replica_dir = TempDir::new().unwrap();
+
+    let mut primary_config = Config::local_node();
+    primary_config.combined_db_config.database_path = primary_dir.path().to_path_buf();
+    primary_config.combined_db_config.database_type = DbType::RocksDb;
+    let primary = FuelService::new_node(primary_config.clone()).await.unwrap();
+    let primary_client = FuelClient::from(primary.bound_address);
+    primary_client.produce_blocks(2, None).await.unwrap();
+
+    // Given
+    let mut replica_config = primary_config;
+    replica_config.combined_db_config.database_path = replica_dir.path().to_path_buf();
+    replica_config.combined_db_config.read_replica = Some(ReadReplicaConfig {
+        primary_path: primary_dir.path().to_path_buf(),
+        catch_up_interval: Duration::from_millis(50),
+    });
+    let replica = FuelService::new_node(replica_config).await.unwrap();
+    let replica_client = FuelClient::from(replica.bound_address);
+    wait_for_height(&replica_client, 2).await;
+
+    // When
+    primary_client.produce_blocks(3, None).await.unwrap();
+
+    // Then
+    wait_for_height(&replica_client, 5).await;
+    let block = replica_client.block_by_height(5u32.into()).await.unwrap();
+    assert!(block.is_some());
+
+    let submit = replica_client
+        .submit(&Transaction::default_test_tx())
+        .await
+        .expect_err("the replica doesn't accept transactions");
+    assert!(submit.to_string().contains("read replica"));
+    let produce = replica_client
+        .produce_blocks(1, None)
+        .await
+        .expect_err("the replica doesn't produce blocks");
+    assert!(produce.to_string().contains("read replica"));
+}
+
+#[tokio::test]
+async fn read_replica__can_not_use_primary_path() {
+    let primary_dir = TempDir::new().unwrap();
+
+    // Given
+    let mut config = Config::local_node();
+    config.combined_db_config.database_path = primary_dir.path().to_path_buf();
+    config.combined_db_config.database_type = DbType::RocksDb;
+    config.combined_db_config.read_replica = Some(ReadReplicaConfig {
+        primary_path: primary_dir.path().to_path_buf(),
+        catch_up_interval: Duration::from_millis(50),
+    });
+
+    // When
+    let result = FuelService::new_node(config).await;
+
+    // Then
+    assert!(result.is_err());
+}
@@ -0,0 +1,152 @@
+#![allow(non_snake_case)]
+
+use clap::Parser;
+use fuel_core::{
+    database::{
+        database_description::{
+            off_chain::OffChain,
+            IndexationKind,
+        },
+        Database,
+    },
+    state::historical_rocksdb::StateRewindPolicy,
+};
+use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    types::{
+        Block,
+        TransactionResponse,
+    },
+    FuelClient,
+};
+use fuel_core_storage::transactional::HistoricalView;
+use fuel_core_types::fuel_tx::{
+    Address,
+    AssetId,
+};
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+use test_helpers::{
+    fuel_core_driver::FuelCoreDriver,
+    produce_block_with_tx,
+};
+
+const NODE_ARGS: &[&str] = &[
+    "--debug",
+    "--poa-instant",
+    "true",
+    "--state-rewind-duration",
+    "136y",
+];
+
+#[derive(Debug, PartialEq)]
+struct ChainState {
+    balance: u64,
+    transactions: Vec<TransactionResponse>,
+    blocks: Vec<Block>,
+}
+
+fn all_results() -> PaginationRequest<String> {
+    PaginationRequest {
+        cursor: None,
+        results: 100,
+        direction: PageDirection::Forward,
+    }
+}
+
+async fn chain_state(client: &FuelClient) -> ChainState {
+    let balance = client
+        .balance(&Address::default(), Some(&AssetId::default()))
+        .await
+        .unwrap();
+    let transactions = client.transactions(all_results()).await.unwrap().results;
+    let blocks = client.blocks(all_results()).await.unwrap().results;
+    ChainState {
+        balance,
+        transactions,
+        blocks,
+    }
+}
+
+async fn spawn_node_with_transactions() -> (ChainState, tempfile::TempDir) {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let driver = FuelCoreDriver::spawn_feeless(NODE_ARGS).await.unwrap();
+    for _ in 0..5 {
+        produce_block_with_tx(&mut rng, &driver.client).await;
+    }
+    let state = chain_state(&driver.client).await;
+    (state, driver.kill().await)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reindex__rebuilds_selected_indexes_on_start() {
+    let (expected_state, db_dir) = spawn_node_with_transactions().await;
+
+    // Given
+    let off_chain = Database::<OffChain>::open_rocksdb(
+        db_dir.path(),
+        None,
+        StateRewindPolicy::RewindFullRange,
+        512,
+    )
+    .unwrap();
+    off_chain
+        .set_indexation_availability(IndexationKind::Balances, false)
+        .unwrap();
+    drop(off_chain);
+
+    // When
+    let mut args = NODE_ARGS.to_vec();
+    args.extend(["--reindex", "balances,coins"]);
+    let driver = FuelCoreDriver::spawn_feeless_with_directory(db_dir, &args)
+        .await
+        .unwrap();
+
+    // Then
+    let off_chain = driver.node.shared.database.off_chain();
+    assert!(off_chain
+        .indexation_available(IndexationKind::Balances)
+        .unwrap());
+    let actual_state = chain_state(&driver.client).await;
+    assert_eq!(actual_state, expected_state);
+    driver.kill().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reindex__rebuilds_off_chain_database_by_replaying_blocks() {
+    let (expected_state, db_dir) = spawn_node_with_transactions().await;
+
+    // When
+    let args = [
+        "_IGNORED_",
+        "--db-path",
+        db_dir.path().to_str().unwrap(),
+        "--state-rewind-duration",
+        "136y",
+    ];
+    let command = fuel_core_bin::cli::reindex::Command::parse_from(args);
+    fuel_core_bin::cli::reindex::exec(command).await.unwrap();
+
+    // Then
+    assert!(!db_dir.path().join("reindex").exists());
+    let driver = FuelCoreDriver::spawn_feeless_with_directory(db_dir, NODE_ARGS)
+        .await
+        .unwrap();
+    let database = &driver.node.shared.database;
+    assert_eq!(
+        database.off_chain().latest_height(),
+        database.on_chain().latest_height()
+    );
+    let actual_state = chain_state(&driver.client).await;
+    assert_eq!(actual_state, expected_state);
+    for block in &expected_state.blocks {
+        let block_by_id = driver.client.block(&block.id).await.unwrap();
+        assert_eq!(block_by_id.as_ref(), Some(block));
+    }
+    driver.kill().await;
+}
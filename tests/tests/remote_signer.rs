@@ -0,0 +1,119 @@
+#![allow(non_snake_case)]
+
+use fuel_core_mock_signer::MockSigner;
+use fuel_core_poa::signer::{
+    RemoteSigner,
+    RemoteSignerConfig,
+    SignMode,
+};
+use fuel_core_storage::transactional::AtomicView;
+use fuel_core_types::{
+    blockchain::consensus::Consensus,
+    fuel_crypto::SecretKey,
+};
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+use std::time::Duration;
+use test_helpers::fuel_core_driver::FuelCoreDriver;
+
+async fn spawn_signer(seed: u64) -> MockSigner {
+    let secret = SecretKey::random(&mut StdRng::seed_from_u64(seed));
+    MockSigner::spawn(secret, "127.0.0.1:0".parse().unwrap())
+        .await
+        .unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn remote_signer__signs_produced_blocks() {
+    // Given
+    let signer = spawn_signer(1).await;
+    let url = signer.url();
+    let driver = FuelCoreDriver::spawn(&[
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--consensus-remote-signer",
+        url.as_str(),
+    ])
+    .await
+    .unwrap();
+
+    // When
+    driver.client.produce_blocks(3, None).await.unwrap();
+
+    // Then
+    let view = driver
+        .node
+        .shared
+        .database
+        .on_chain()
+        .latest_view()
+        .unwrap();
+    for height in 1..=3u32 {
+        let block = view
+            .get_sealed_block_by_height(&height.into())
+            .unwrap()
+            .expect("expected sealed block to be available");
+        let signature = match block.consensus {
+            Consensus::PoA(poa) => poa.signature,
+            _ => panic!("Not expected consensus"),
+        };
+        signature
+            .verify(&signer.public_key(), &block.entity.id().into_message())
+            .expect("failed to verify signature");
+    }
+    driver.kill().await;
+}
+
+#[tokio::test]
+async fn remote_signer__is_unavailable_while_health_check_fails() {
+    // Given
+    let signer = spawn_signer(2).await;
+    let remote = RemoteSigner::connect(RemoteSignerConfig {
+        url: signer.url(),
+        timeout: Duration::from_secs(1),
+        retries: 0,
+        health_check_interval: Duration::from_millis(50),
+    })
+    .await
+    .unwrap();
+    let sign_mode = SignMode::Remote(remote);
+    assert!(sign_mode.is_available());
+    assert_eq!(sign_mode.public_key().unwrap(), Some(signer.public_key()));
+
+    // When
+    signer.set_healthy(false);
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    // Then
+    assert!(!sign_mode.is_available());
+
+    // When
+    signer.set_healthy(true);
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    // Then
+    assert!(sign_mode.is_available());
+}
+
+#[tokio::test]
+async fn remote_signer__connect_fails_when_signer_is_unreachable() {
+    // Given
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    drop(listener);
+
+    // When
+    let result = RemoteSigner::connect(RemoteSignerConfig {
+        url,
+        timeout: Duration::from_millis(100),
+        retries: 1,
+        health_check_interval: Duration::from_secs(1),
+    })
+    .await;
+
+    // Then
+    assert!(result.is_err());
+}
@@ -10,7 +10,9 @@ use fuel_core::{
 };
 use fuel_core_storage::transactional::AtomicView;
 use fuel_core_types::{
+    fuel_crypto::SecretKey,
     fuel_tx::{
+        Address,
         AssetId,
         Input,
         Output,
@@ -296,3 +298,105 @@ async fn rollback_to__should_work_with_empty_gas_price_database() -> anyhow::Res
 
     Ok(())
 }
+
+fn transfer_to_owner(owner: Address, amount: u64, rng: &mut StdRng) -> Transaction {
+    TransactionBuilder::script(vec![], vec![])
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            amount,
+            AssetId::BASE,
+            Default::default(),
+        )
+        .add_output(Output::coin(owner, amount, AssetId::BASE))
+        .finalize_as_transaction()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn balance_at_height__returns_historical_balances() -> anyhow::Result<()> {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let driver = FuelCoreDriver::spawn_feeless(&[
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--state-rewind-duration",
+        "7d",
+    ])
+    .await?;
+    let client = &driver.client;
+
+    // Given
+    const AMOUNT: u64 = 100;
+    const TOTAL_BLOCKS: u32 = 5;
+    let owner: Address = rng.gen();
+    for _ in 0..TOTAL_BLOCKS {
+        let tx = transfer_to_owner(owner, AMOUNT, &mut rng);
+        let status = client.submit_and_await_commit(&tx).await?;
+        assert!(matches!(
+            status,
+            fuel_core_client::client::types::TransactionStatus::Success { .. }
+        ));
+    }
+
+    for height in 1..=TOTAL_BLOCKS {
+        // When
+        let balance = client
+            .balance_at_height(&owner, Some(&AssetId::BASE), height.into())
+            .await?;
+
+        // Then
+        assert_eq!(balance, AMOUNT * height as u64);
+    }
+
+    driver.kill().await;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn balance_at_height__fails_for_height_outside_of_rewind_window(
+) -> anyhow::Result<()> {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let driver = FuelCoreDriver::spawn_feeless(&[
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--state-rewind-duration",
+        "0s",
+    ])
+    .await?;
+    let client = &driver.client;
+
+    // Given
+    let owner: Address = rng.gen();
+    for _ in 0..3 {
+        let tx = transfer_to_owner(owner, 100, &mut rng);
+        client.submit_and_await_commit(&tx).await?;
+    }
+
+    // When
+    let too_old = client
+        .balance_at_height(&owner, Some(&AssetId::BASE), 1u32.into())
+        .await;
+    let too_new = client
+        .balance_at_height(&owner, Some(&AssetId::BASE), 4u32.into())
+        .await;
+
+    // Then
+    let too_old = too_old.expect_err("The history for the height 1 is pruned");
+    assert!(
+        too_old
+            .to_string()
+            .contains("the oldest available height is 0x2"),
+        "{too_old}"
+    );
+    let too_new = too_new.expect_err("The height 4 is not produced yet");
+    assert!(
+        too_new
+            .to_string()
+            .contains("is above the latest available height 0x3"),
+        "{too_new}"
+    );
+
+    driver.kill().await;
+    Ok(())
+}
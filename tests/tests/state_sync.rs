@@ -0,0 +1,171 @@
+#![allow(non_snake_case)]
+
+use fuel_core::{
+    database::Database,
+    service::{
+        config::StateSyncConfig,
+        Config,
+        DbType,
+        FuelService,
+    },
+    state::historical_rocksdb::StateRewindPolicy,
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_p2p::{
+    Multiaddr,
+    PeerId,
+};
+use fuel_core_poa::Trigger;
+use fuel_core_storage::{
+    iter::{
+        IterableTable,
+        IteratorOverTable,
+    },
+    tables::{
+        merkle::{
+            DenseMerkleMetadata,
+            DenseMetadataKey,
+            FuelBlockMerkleData,
+            FuelBlockMerkleMetadata,
+        },
+        Coins,
+        Messages,
+    },
+    transactional::AtomicView,
+    Mappable,
+    StorageAsRef,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+use itertools::Itertools;
+use std::time::Duration;
+use tempfile::TempDir;
+
+fn free_port() -> u16 {
+    std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port()
+}
+
+async fn wait_for_height(node: &FuelService, height: u32) {
+    for _ in 0..300 {
+        let latest_height = node
+            .shared
+            .database
+            .on_chain()
+            .latest_height_from_metadata()
+            .unwrap();
+        if latest_height >= Some(height.into()) {
+            return
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("The node didn't reach the height {height}");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn sync_state_from_peers__imports_state_of_peer_behind_tip() {
+    // The producer keeps the history of the state to serve it at old heights.
+    let producer_dir = TempDir::new().unwrap();
+    let port = free_port();
+    let mut producer_config = Config::local_node();
+    producer_config.block_production = Trigger::Never;
+    producer_config.combined_db_config.database_path = producer_dir.path().to_path_buf();
+    producer_config.combined_db_config.database_type = DbType::RocksDb;
+    producer_config.combined_db_config.state_rewind_policy =
+        StateRewindPolicy::RewindFullRange;
+    let producer_p2p = producer_config.p2p.as_mut().unwrap();
+    producer_p2p.tcp_port = port;
+    let producer_id = PeerId::from_public_key(&producer_p2p.keypair.public());
+    let producer_address: Multiaddr =
+        format!("/ip4/127.0.0.1/tcp/{port}/p2p/{producer_id}")
+            .parse()
+            .unwrap();
+    let producer = FuelService::new_node(producer_config).await.unwrap();
+    let producer_client = FuelClient::from(producer.bound_address);
+    producer_client.produce_blocks(10, None).await.unwrap();
+
+    // Given
+    let mut syncer_config = Config::local_node();
+    syncer_config.block_production = Trigger::Never;
+    syncer_config.p2p.as_mut().unwrap().reserved_nodes = vec![producer_address];
+    syncer_config.state_sync = Some(StateSyncConfig {
+        blocks_behind_tip: 3,
+        peers_timeout: Duration::from_secs(30),
+    });
+
+    // When
+    let syncer = FuelService::new_node(syncer_config).await.unwrap();
+
+    // Then
+    let synced_height = BlockHeight::from(7);
+    let view = syncer.shared.database.on_chain().latest_view().unwrap();
+    assert!(view
+        .get_sealed_block_by_height(&synced_height)
+        .unwrap()
+        .is_some());
+    let previous_height = BlockHeight::from(6);
+    assert!(view
+        .get_sealed_block_by_height(&previous_height)
+        .unwrap()
+        .is_none());
+
+    // The blocks after the synced height are imported from the producer.
+    wait_for_height(&syncer, 10).await;
+    let producer_db = producer.shared.database.on_chain();
+    let syncer_db = syncer.shared.database.on_chain();
+    assert_eq!(
+        producer_db.latest_height_from_metadata().unwrap(),
+        syncer_db.latest_height_from_metadata().unwrap()
+    );
+    assert_eq!(table::<Coins>(producer_db), table::<Coins>(syncer_db));
+    assert_eq!(table::<Messages>(producer_db), table::<Messages>(syncer_db));
+    assert_eq!(
+        table::<FuelBlockMerkleData>(producer_db),
+        table::<FuelBlockMerkleData>(syncer_db)
+    );
+    let tip = DenseMetadataKey::Primary(BlockHeight::from(10));
+    let producer_tree = merkle_metadata(producer_db, &tip);
+    assert!(producer_tree.is_some());
+    assert_eq!(producer_tree, merkle_metadata(syncer_db, &tip));
+}
+
+#[tokio::test]
+async fn sync_state_from_peers__requires_reserved_nodes() {
+    // Given
+    let mut syncer_config = Config::local_node();
+    syncer_config.block_production = Trigger::Never;
+    syncer_config.state_sync = Some(StateSyncConfig {
+        blocks_behind_tip: 3,
+        peers_timeout: Duration::from_secs(1),
+    });
+
+    // When
+    let result = FuelService::new_node(syncer_config).await;
+
+    // Then
+    let err = result.err().expect("The state sync without reserved nodes fails");
+    assert!(err.to_string().contains("requires the reserved nodes"), "{err}");
+}
+
+fn table<T>(database: &Database) -> Vec<(T::OwnedKey, T::OwnedValue)>
+where
+    T: Mappable,
+    Database: IterableTable<T>,
+{
+    database.iter_all::<T>(None).try_collect().unwrap()
+}
+
+fn merkle_metadata(
+    database: &Database,
+    key: &DenseMetadataKey<BlockHeight>,
+) -> Option<DenseMerkleMetadata> {
+    database
+        .latest_view()
+        .unwrap()
+        .storage::<FuelBlockMerkleMetadata>()
+        .get(key)
+        .unwrap()
+        .map(|metadata| metadata.into_owned())
+}
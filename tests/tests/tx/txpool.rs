@@ -4,13 +4,33 @@ use crate::helpers::{
     TestContext,
     TestSetupBuilder,
 };
+use fuel_core::{
+    fuel_core_graphql_api::{
+        storage::tx_pool_blacklist::TxPoolBlackList,
+        Role,
+    },
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    types::TxPoolBlackListEntry,
+    FuelClient,
+};
 use fuel_core_poa::Trigger;
+use fuel_core_storage::StorageAsRef;
 use fuel_core_types::{
     fuel_asm::*,
     fuel_crypto::*,
     fuel_tx,
     fuel_tx::*,
 };
+use futures::StreamExt;
 use itertools::Itertools;
 use rand::{
     rngs::StdRng,
@@ -72,3 +92,179 @@ async fn txs_max_script_gas_limit() {
         transactions.len() + 1 // coinbase
     )
 }
+
+fn transactions_with_tips(rng: &mut StdRng, tips: &[u64]) -> Vec<Script> {
+    tips.iter()
+        .map(|tip| {
+            TransactionBuilder::script(
+                op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+                vec![],
+            )
+            .script_gas_limit(10_000)
+            .tip(*tip)
+            .max_fee_limit(*tip)
+            .add_unsigned_coin_input(
+                SecretKey::random(rng),
+                rng.gen(),
+                1000,
+                Default::default(),
+                Default::default(),
+            )
+            .add_output(Output::Change {
+                amount: 0,
+                asset_id: Default::default(),
+                to: rng.gen(),
+            })
+            .finalize()
+        })
+        .collect_vec()
+}
+
+#[tokio::test]
+async fn pending_transactions__returns_filtered_transactions_from_the_pool() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    let transactions = transactions_with_tips(&mut rng, &[1, 2, 3]);
+    test_builder.config_coin_inputs_from_transactions(&transactions.iter().collect_vec());
+    test_builder.trigger = Trigger::Never;
+    let TestContext { client, srv, .. } = test_builder.finalize().await;
+
+    // Given
+    for tx in transactions.iter() {
+        srv.shared
+            .txpool_shared_state
+            .insert(tx.clone().into())
+            .await
+            .unwrap();
+    }
+
+    // When
+    let stats = client.tx_pool_stats().await.unwrap();
+    let pending = client
+        .pending_transactions(
+            None,
+            None,
+            Some(2),
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(stats.tx_count, 3);
+    let mut expected_ids = transactions[1..]
+        .iter()
+        .map(|tx| tx.id(&Default::default()))
+        .collect_vec();
+    expected_ids.sort();
+    let actual_ids = pending.results.iter().map(|tx| tx.id).collect_vec();
+    assert_eq!(actual_ids, expected_ids);
+    assert!(pending.results.iter().all(|tx| tx.dependencies_depth == 0));
+}
+
+#[tokio::test]
+async fn new_pending_transactions__streams_accepted_transactions() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    let transactions = transactions_with_tips(&mut rng, &[5]);
+    test_builder.config_coin_inputs_from_transactions(&transactions.iter().collect_vec());
+    test_builder.trigger = Trigger::Never;
+    let TestContext { client, .. } = test_builder.finalize().await;
+    let mut stream = client.subscribe_new_pending_transactions().await.unwrap();
+
+    // When
+    let tx: fuel_tx::Transaction = transactions[0].clone().into();
+    client.submit(&tx).await.unwrap();
+
+    // Then
+    let pending = stream.next().await.unwrap().unwrap();
+    assert_eq!(pending.id, tx.id(&Default::default()));
+    assert_eq!(pending.tip, 5);
+    assert_eq!(pending.transaction, tx);
+}
+
+#[tokio::test]
+async fn tx_pool_blacklist__requires_admin_role() {
+    let mut config = Config::local_node();
+    config.graphql_config.admin_api_token = Some("secret".to_string());
+    config.graphql_config.anonymous_role = Role::Read;
+    config
+        .graphql_config
+        .api_keys
+        .insert("submitter", Role::Submit);
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    // Given
+    let client = FuelClient::from(srv.bound_address).with_bearer_token("submitter");
+
+    // When
+    let result = client
+        .add_to_tx_pool_blacklist(TxPoolBlackListEntry::Owner(Default::default()))
+        .await;
+
+    // Then
+    assert!(result.is_err());
+    let blacklist = srv.shared.txpool_shared_state.blacklist().await.unwrap();
+    assert!(blacklist.is_empty());
+}
+
+#[tokio::test]
+async fn tx_pool_blacklist__evicts_and_persists_entries() {
+    let mut config = Config::local_node();
+    config.block_production = Trigger::Never;
+    config.graphql_config.admin_api_token = Some("secret".to_string());
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address).with_bearer_token("secret");
+
+    // Given
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret = SecretKey::random(&mut rng);
+    let owner = Input::owner(&secret.public_key());
+    let tx = TransactionBuilder::script(
+        op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+        vec![],
+    )
+    .script_gas_limit(10_000)
+    .add_unsigned_coin_input(
+        secret,
+        rng.gen(),
+        1000,
+        Default::default(),
+        Default::default(),
+    )
+    .finalize_as_transaction();
+    client.submit(&tx).await.unwrap();
+    let entry = TxPoolBlackListEntry::Owner(owner);
+
+    // When
+    let evicted = client.add_to_tx_pool_blacklist(entry).await.unwrap();
+
+    // Then
+    assert_eq!(evicted, vec![tx.id(&Default::default())]);
+    assert_eq!(client.tx_pool_stats().await.unwrap().tx_count, 0);
+    assert_eq!(
+        client.tx_pool_blacklist().await.unwrap().owners,
+        vec![owner]
+    );
+    assert!(client.submit(&tx).await.is_err());
+    let persisted = srv
+        .shared
+        .database
+        .off_chain()
+        .storage::<TxPoolBlackList>()
+        .contains_key(&fuel_core::txpool::config::BlackListEntry::Owner(owner))
+        .unwrap();
+    assert!(persisted);
+
+    // When
+    let removed = client.remove_from_tx_pool_blacklist(entry).await.unwrap();
+
+    // Then
+    assert!(removed);
+    assert!(client.tx_pool_blacklist().await.unwrap().owners.is_empty());
+    client.submit(&tx).await.unwrap();
+}
@@ -0,0 +1,174 @@
+use fuel_core::{
+    database::Database,
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::FuelClient;
+use futures::{
+    SinkExt,
+    StreamExt,
+};
+use serde_json::{
+    json,
+    Value,
+};
+use std::{
+    net::SocketAddr,
+    time::Duration,
+};
+use tokio::net::TcpStream;
+use tokio_tungstenite::{
+    tungstenite::{
+        client::IntoClientRequest,
+        Message,
+    },
+    MaybeTlsStream,
+    WebSocketStream,
+};
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+const BLOCKS_SUBSCRIPTION: &str = "subscription { blocks { block { height } } }";
+
+async fn connect(address: SocketAddr) -> Socket {
+    let mut request = format!("ws://{address}/v1/graphql-ws")
+        .into_client_request()
+        .unwrap();
+    request.headers_mut().insert(
+        "Sec-WebSocket-Protocol",
+        "graphql-transport-ws".parse().unwrap(),
+    );
+    let (mut socket, _) = tokio_tungstenite::connect_async(request).await.unwrap();
+
+    send(
+        &mut socket,
+        json!({ "type": "connection_init", "payload": {} }),
+    )
+    .await;
+    let ack = receive(&mut socket).await;
+    assert_eq!(ack["type"], "connection_ack");
+
+    socket
+}
+
+async fn send(socket: &mut Socket, message: Value) {
+    socket
+        .send(Message::Text(message.to_string()))
+        .await
+        .unwrap();
+}
+
+async fn receive(socket: &mut Socket) -> Value {
+    loop {
+        let message = tokio::time::timeout(Duration::from_secs(5), socket.next())
+            .await
+            .expect("Should receive a message in time")
+            .unwrap()
+            .unwrap();
+        if let Message::Text(text) = message {
+            return serde_json::from_str(&text).unwrap()
+        }
+    }
+}
+
+async fn subscribe(socket: &mut Socket, id: &str) {
+    send(
+        socket,
+        json!({
+            "type": "subscribe",
+            "id": id,
+            "payload": { "query": BLOCKS_SUBSCRIPTION },
+        }),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn websocket__ping_is_answered_with_pong() {
+    // Given
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let mut socket = connect(srv.bound_address).await;
+
+    // When
+    send(&mut socket, json!({ "type": "ping" })).await;
+
+    // Then
+    let pong = receive(&mut socket).await;
+    assert_eq!(pong["type"], "pong");
+}
+
+#[tokio::test]
+async fn websocket__subscriptions_are_multiplexed_over_one_connection() {
+    // Given
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let mut socket = connect(srv.bound_address).await;
+    subscribe(&mut socket, "1").await;
+    subscribe(&mut socket, "2").await;
+    // Gives the server time to start both subscriptions before producing the block.
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    // When
+    client.produce_blocks(1, None).await.unwrap();
+
+    // Then
+    let mut ids = vec![];
+    for _ in 0..2 {
+        let message = receive(&mut socket).await;
+        assert_eq!(message["type"], "next");
+        assert_eq!(message["payload"]["data"]["blocks"]["block"]["height"], "1");
+        ids.push(message["id"].as_str().unwrap().to_string());
+    }
+    ids.sort();
+    assert_eq!(ids, vec!["1", "2"]);
+}
+
+#[tokio::test]
+async fn websocket__subscriptions_above_the_connection_limit_are_rejected() {
+    // Given
+    let mut config = Config::local_node();
+    config.graphql_config.max_subscriptions_per_connection = 1;
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let mut socket = connect(srv.bound_address).await;
+    subscribe(&mut socket, "1").await;
+
+    // When
+    subscribe(&mut socket, "2").await;
+
+    // Then
+    let message = receive(&mut socket).await;
+    assert_eq!(message["type"], "error");
+    assert_eq!(message["id"], "2");
+}
+
+#[tokio::test]
+async fn websocket__finished_subscription_releases_the_connection_slot() {
+    // Given
+    let mut config = Config::local_node();
+    config.graphql_config.max_subscriptions_per_connection = 1;
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let mut socket = connect(srv.bound_address).await;
+    subscribe(&mut socket, "1").await;
+    send(&mut socket, json!({ "type": "complete", "id": "1" })).await;
+
+    // When
+    subscribe(&mut socket, "2").await;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    client.produce_blocks(1, None).await.unwrap();
+
+    // Then
+    let message = receive(&mut socket).await;
+    assert_eq!(message["type"], "next");
+    assert_eq!(message["id"], "2");
+}